@@ -0,0 +1,49 @@
+//! Measures the cost of passing a `RenderConfig` by value to many prompts,
+//! as done when a single theme is shared by every prompt of a long form.
+//!
+//! Run with `cargo bench --features test-util --bench render_config`.
+//!
+//! `RenderConfig` is `Copy`, so setting it on a prompt is a plain copy of the
+//! struct, whose size is reported along with the elapsed times. The time spent
+//! rendering the same prompts is reported as a reference.
+
+use std::{hint::black_box, mem::size_of, time::Instant};
+
+use inquire::{
+    test::FakeBackend,
+    ui::{Key, RenderConfig},
+    Text,
+};
+
+const PROMPT_COUNT: u32 = 100_000;
+
+fn main() {
+    let theme = RenderConfig::default_colored();
+    let theme = &theme;
+
+    println!(
+        "RenderConfig size: {} bytes",
+        size_of::<RenderConfig<'static>>()
+    );
+
+    let start = Instant::now();
+    for _ in 0..PROMPT_COUNT {
+        let prompt = Text::new("Name:").with_render_config(*theme);
+        black_box(prompt);
+    }
+    let elapsed = start.elapsed() / PROMPT_COUNT;
+    println!("with_render_config: {elapsed:>10.2?} per prompt");
+
+    let start = Instant::now();
+    for _ in 0..PROMPT_COUNT {
+        let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+        let answer = Text::new("Name:")
+            .with_render_config(*theme)
+            .prompt_with_backend(&mut backend)
+            .expect("prompt should be answered");
+        black_box(answer);
+    }
+    let elapsed = start.elapsed() / PROMPT_COUNT;
+    println!("prompt_with_backend: {elapsed:>10.2?} per prompt");
+}
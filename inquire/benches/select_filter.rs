@@ -0,0 +1,60 @@
+//! Measures how a `Select` prompt with a large list of options keeps up with
+//! a filter query being typed, one key at a time.
+//!
+//! Run with `cargo bench --features test-util --bench select_filter`.
+//!
+//! Besides the elapsed time, the number of scorer calls is reported. Only the
+//! first keystroke scores the whole list: every following character appended
+//! to the query only scores the options matched by the previous one.
+
+use std::{cell::Cell, time::Instant};
+
+use inquire::{
+    test::FakeBackend,
+    type_aliases::Scorer,
+    ui::{Key, KeyModifiers},
+    Select,
+};
+
+const OPTION_COUNTS: [usize; 3] = [10_000, 50_000, 100_000];
+const QUERY: &str = "4242";
+const ROUNDS: u32 = 5;
+
+fn main() {
+    for count in OPTION_COUNTS {
+        let options: Vec<String> = (0..count).map(|i| format!("option {i}")).collect();
+
+        let calls = Cell::new(0usize);
+        let scorer = |input: &str, option: &String, string_value: &str, idx: usize| {
+            calls.set(calls.get() + 1);
+            Select::<String>::DEFAULT_SCORER(input, option, string_value, idx)
+        };
+        let scorer: Scorer<'_, String> = &scorer;
+
+        let start = Instant::now();
+
+        for _ in 0..ROUNDS {
+            let mut keys: Vec<Key> = QUERY
+                .chars()
+                .map(|c| Key::Char(c, KeyModifiers::NONE))
+                .collect();
+            keys.push(Key::Enter);
+
+            let mut backend = FakeBackend::new(keys);
+
+            Select::new("Option?", options.clone())
+                .with_scorer(scorer)
+                .prompt_with_backend(&mut backend)
+                .expect("prompt should be answered");
+        }
+
+        let elapsed = start.elapsed() / ROUNDS;
+        let calls = calls.get() / ROUNDS as usize;
+        let full_rescore_calls = count * (QUERY.len() + 1);
+
+        println!(
+            "{count:>7} options: {elapsed:>10.2?} per query, {calls:>7} scorer calls \
+             ({full_rescore_calls} when re-scoring the whole list on every keystroke)"
+        );
+    }
+}
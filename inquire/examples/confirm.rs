@@ -19,6 +19,7 @@ fn main() {
         message: "Are you happy?",
         starting_input: None,
         default: Some(false),
+        default_as_initial: false,
         placeholder: Some("sim|não"),
         help_message: Some("It's alright if you're not"),
         formatter: &|ans| match ans {
@@ -30,7 +31,11 @@ fn main() {
             "não" => Ok(false),
             _ => Err(()),
         },
+        accepted_words: None,
         error_message: "Reply with 'sim' or 'não'".into(),
+        instant_submit: false,
+        timeout: None,
+        non_interactive_fallback: None,
         default_value_formatter: &|def| match def {
             true => String::from("sim"),
             false => String::from("não"),
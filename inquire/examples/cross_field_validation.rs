@@ -0,0 +1,51 @@
+use inquire::{
+    validator::{PromptContext, Validation},
+    Text,
+};
+
+#[derive(Default)]
+struct Signup {
+    email: String,
+    password: String,
+}
+
+fn main() {
+    // every clone of the context refers to the same answers, which the
+    // validators below read when the user submits their input.
+    let context = PromptContext::new(Signup::default());
+
+    let email = Text::new("Email:")
+        .prompt()
+        .expect("An error happened when asking for the email");
+    context.update(|signup| signup.email = email);
+
+    let _confirmation = Text::new("Confirm email:")
+        .with_context_validator(&context, |input: &str, signup: &Signup| {
+            match input == signup.email {
+                true => Ok(Validation::Valid),
+                false => Ok(Validation::Invalid("The emails do not match".into())),
+            }
+        })
+        .prompt()
+        .expect("An error happened when asking for the email confirmation");
+
+    let password = Text::new("Password:")
+        .with_context_validator(&context, |input: &str, signup: &Signup| {
+            match signup.email.starts_with(input) && !input.is_empty() {
+                true => Ok(Validation::Invalid(
+                    "The password can not be part of your email".into(),
+                )),
+                false => Ok(Validation::Valid),
+            }
+        })
+        .prompt()
+        .expect("An error happened when asking for the password");
+    context.update(|signup| signup.password = password);
+
+    let signup = context.get();
+    println!(
+        "Signed up as {} with a {}-character password",
+        signup.email,
+        signup.password.len()
+    );
+}
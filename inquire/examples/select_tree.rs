@@ -0,0 +1,25 @@
+use inquire::{list_option::TreeOption, Select};
+
+fn main() {
+    let options = vec![
+        TreeOption::new("Display", 0).with_expanded(true),
+        TreeOption::new("Resolution", 1),
+        TreeOption::new("Brightness", 1),
+        TreeOption::new("Night light", 1),
+        TreeOption::new("Sound", 0),
+        TreeOption::new("Output", 1),
+        TreeOption::new("Speakers", 2),
+        TreeOption::new("Headphones", 2),
+        TreeOption::new("Input", 1),
+        TreeOption::new("Network", 0),
+        TreeOption::new("Wi-Fi", 1),
+        TreeOption::new("Ethernet", 1),
+    ];
+
+    let ans = Select::new_tree("Which setting do you want to change?", options).prompt();
+
+    match ans {
+        Ok(setting) => println!("Opening {setting} settings..."),
+        Err(_) => println!("There was an error, please try again"),
+    }
+}
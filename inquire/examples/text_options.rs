@@ -14,12 +14,27 @@ fn main() {
         message: "How are you feeling?",
         initial_value: None,
         default: None,
+        default_as_initial: false,
         placeholder: Some("Good"),
+        input_mask: None,
+        unmasked_answer: false,
         help_message: None,
+        dynamic_help_message: None,
+        counters: vec![],
         formatter: Text::DEFAULT_FORMATTER,
+        formatter_ex: None,
+        transformer: None,
         validators: Vec::new(),
+        clipboard_paste: false,
+        show_all_errors: Text::DEFAULT_SHOW_ALL_ERRORS,
         page_size: Text::DEFAULT_PAGE_SIZE,
         autocompleter: None,
+        async_autocompleter: None,
+        inline_suggestion: false,
+        history: vec![],
+        submit_key: None,
+        timeout: None,
+        non_interactive_fallback: None,
         render_config: RenderConfig::default(),
     }
     .prompt()
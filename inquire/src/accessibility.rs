@@ -0,0 +1,121 @@
+//! Announcements of the state of prompts for screen readers and other
+//! assistive technologies.
+
+use crate::type_aliases::AccessibilityAnnouncer;
+
+/// State of a prompt that can be announced by a screen reader, e.g.
+/// `Select, 3 of 12, highlighted: Banana, filter: ba`.
+///
+/// Announcements are plain text without styles, meant to be spoken by a
+/// text-to-speech engine. See [`Select::with_accessibility_announcer`] and
+/// [`MultiSelect::with_accessibility_announcer`].
+///
+/// [`Select::with_accessibility_announcer`]: crate::Select::with_accessibility_announcer
+/// [`MultiSelect::with_accessibility_announcer`]: crate::MultiSelect::with_accessibility_announcer
+pub trait AccessibleState {
+    /// Describes the current state of the prompt.
+    fn announce(&self) -> String;
+}
+
+/// Calls the accessibility announcer, if any, whenever the announcement of
+/// the prompt changes.
+pub(crate) struct Announcer<'a> {
+    callback: Option<AccessibilityAnnouncer<'a>>,
+    last_announcement: Option<String>,
+}
+
+impl<'a> Announcer<'a> {
+    pub fn new(callback: Option<AccessibilityAnnouncer<'a>>) -> Self {
+        Self {
+            callback,
+            last_announcement: None,
+        }
+    }
+
+    /// Whether there is a callback, in which case the announcements of the
+    /// prompt should be computed and passed to [`update`](Self::update).
+    pub fn is_enabled(&self) -> bool {
+        self.callback.is_some()
+    }
+
+    pub fn update(&mut self, announcement: String) {
+        let callback = match self.callback {
+            Some(callback) => callback,
+            None => return,
+        };
+
+        if self.last_announcement.as_ref() != Some(&announcement) {
+            callback(&announcement);
+            self.last_announcement = Some(announcement);
+        }
+    }
+}
+
+/// Formats the position of the highlighted option followed by `details`,
+/// or notes that no option matches the filter input.
+pub(crate) fn describe_options(
+    kind: &str,
+    position: Option<(usize, usize, String)>,
+    details: &[String],
+    filter: Option<&str>,
+) -> String {
+    let mut parts = vec![kind.to_owned()];
+
+    match position {
+        Some((index, total, label)) => {
+            parts.push(format!("{} of {}", index + 1, total));
+            parts.push(format!("highlighted: {}", label));
+        }
+        None => parts.push(String::from("no matching options")),
+    }
+
+    parts.extend(details.iter().cloned());
+
+    if let Some(filter) = filter.filter(|filter| !filter.is_empty()) {
+        parts.push(format!("filter: {}", filter));
+    }
+
+    parts.join(", ")
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::{describe_options, Announcer};
+
+    #[test]
+    fn options_are_described_with_position_and_filter() {
+        assert_eq!(
+            "Select, 3 of 12, highlighted: Banana, filter: ba",
+            describe_options("Select", Some((2, 12, "Banana".into())), &[], Some("ba"))
+        );
+        assert_eq!(
+            "Select, no matching options, filter: zz",
+            describe_options("Select", None, &[], Some("zz"))
+        );
+        assert_eq!(
+            "MultiSelect, 1 of 2, highlighted: Apple, checked",
+            describe_options(
+                "MultiSelect",
+                Some((0, 2, "Apple".into())),
+                &["checked".into()],
+                Some("")
+            )
+        );
+    }
+
+    #[test]
+    fn announcer_skips_unchanged_announcements() {
+        let announcements = RefCell::new(vec![]);
+        let callback =
+            |announcement: &str| announcements.borrow_mut().push(announcement.to_owned());
+        let mut announcer = Announcer::new(Some(&callback));
+
+        announcer.update("a".into());
+        announcer.update("a".into());
+        announcer.update("b".into());
+
+        assert_eq!(vec!["a", "b"], *announcements.borrow());
+    }
+}
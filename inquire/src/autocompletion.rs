@@ -10,10 +10,15 @@
 //!
 //! Check the example files to see some usages, recommended are `expense_tracker.rs`
 //! and `complex_autocompletion.rs`.
+//!
+//! For suggestions from a fixed list of words, [WordListCompleter] provides a
+//! ready-made implementation.
+
+use std::cmp::Reverse;
 
 use dyn_clone::DynClone;
 
-use crate::CustomUserError;
+use crate::{matching::MatchingStrategy, CustomUserError};
 
 /// Used when an autocompletion is triggered for the user's text input.
 ///
@@ -26,6 +31,8 @@ pub type Replacement = Option<String>;
 /// - `get_suggestions` is called whenever the user's text input is modified, e.g. a new letter is typed, returning a `Vec<String>`. The `Vec<String>` is the list of suggestions that the prompt displays to the user according to their text input. The user can then navigate through the list and if they submit while highlighting one of these suggestions, the suggestion is treated as the final answer.
 /// - `get_completion` is called whenever the user presses the autocompletion hotkey (`tab` by default), with the current text input and the text of the currently highlighted suggestion, if any, as parameters. This method should return whether any text replacement (an autocompletion) should be made. If the prompt receives a replacement to be made, it substitutes the current text input for the string received from the `get_completion` call.
 ///
+/// Completers that need to know where the cursor is, e.g. to complete a word in the middle of the input, can override `get_completion_at` instead, which also receives the cursor position and by default delegates to `get_completion`.
+///
 /// For example, in the `complex_autocompletion.rs` example file, the `FilePathCompleter` scans the file system based on the current text input, storing a list of paths that match the current text input.
 ///
 /// Every time `get_suggestions` is called, the method returns the list of paths that match the user input. When the user presses the autocompletion hotkey, the `FilePathCompleter` checks whether there is any path selected from the list, if there is, it decides to replace the current text input for it. The interesting piece of functionality is that if there isn't a path selected from the list, the `FilePathCompleter` calculates the longest common prefix amongst all scanned paths and updates the text input to an unambiguous new value. Similar to how terminals work when traversing paths.
@@ -36,6 +43,12 @@ pub trait Autocomplete: DynClone {
     /// If the user presses the autocompletion hotkey (`tab` as default) with
     /// a suggestion highlighted, the user's text input will be replaced by the
     /// content of the suggestion string.
+    ///
+    /// Returning an error does not abort the prompt: the error message is
+    /// displayed above the prompt, styled with
+    /// [`RenderConfig::suggestion_error`](crate::ui::RenderConfig::suggestion_error),
+    /// and no suggestions are listed until the next successful call. The same
+    /// applies to errors returned when completing the input.
     fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError>;
 
     /// Standalone autocompletion that can be implemented based solely on the user's
@@ -55,6 +68,25 @@ pub trait Autocomplete: DynClone {
         input: &str,
         highlighted_suggestion: Option<String>,
     ) -> Result<Replacement, CustomUserError>;
+
+    /// Cursor-aware variant of [`get_completion`](Self::get_completion), called by
+    /// the prompt when the user presses the autocompletion hotkey.
+    ///
+    /// `cursor` is the position of the cursor in the text input, counted in
+    /// grapheme clusters from its start, which allows completing a word in the
+    /// middle of the input.
+    ///
+    /// The default implementation ignores the cursor position and delegates to
+    /// [`get_completion`](Self::get_completion).
+    fn get_completion_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        let _ = cursor;
+        self.get_completion(input, highlighted_suggestion)
+    }
 }
 
 impl Clone for Box<dyn Autocomplete> {
@@ -63,6 +95,12 @@ impl Clone for Box<dyn Autocomplete> {
     }
 }
 
+impl Clone for Box<dyn Autocomplete + Send> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
 /// Empty struct and implementation of Autocomplete trait. Used for the default
 /// autocompleter of `Text` prompts.
 #[derive(Clone, Default)]
@@ -98,3 +136,216 @@ where
         Ok(suggestion)
     }
 }
+
+/// Ready-made autocompleter suggesting the words of a fixed list that match
+/// the user input.
+///
+/// By default, suggestions are the words containing the input, ignoring case,
+/// in the order of the list. With [`with_fuzzy`](Self::with_fuzzy), words are
+/// fuzzy-matched instead and sorted by their score.
+///
+/// Pressing the autocompletion hotkey replaces the input with the highlighted
+/// suggestion. With [`with_longest_common_prefix_completion`](Self::with_longest_common_prefix_completion),
+/// it also completes the input up to the longest prefix shared by the words
+/// starting with it when no suggestion is highlighted, like shells do with paths.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{autocompletion::{Autocomplete, WordListCompleter}, Text};
+///
+/// let words = vec!["rustc".to_owned(), "rustdoc".to_owned(), "cargo".to_owned()];
+/// let mut completer = WordListCompleter::new(words).with_longest_common_prefix_completion();
+///
+/// assert_eq!(vec!["rustc", "rustdoc"], completer.get_suggestions("rust")?);
+/// assert_eq!(Some("rust".to_owned()), completer.get_completion("r", None)?);
+///
+/// let prompt = Text::new("Command:").with_autocomplete(completer);
+/// # Ok::<(), inquire::CustomUserError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordListCompleter {
+    words: Vec<String>,
+    fuzzy: bool,
+    longest_common_prefix: bool,
+}
+
+impl WordListCompleter {
+    /// Creates a completer suggesting the given words.
+    pub fn new(words: Vec<String>) -> Self {
+        Self {
+            words,
+            fuzzy: false,
+            longest_common_prefix: false,
+        }
+    }
+
+    /// Fuzzy-matches the words against the input, sorting the suggestions by
+    /// their score.
+    ///
+    /// Requires the `fuzzy` feature. When it is not enabled, words containing
+    /// the input are suggested instead.
+    pub fn with_fuzzy(mut self) -> Self {
+        self.fuzzy = true;
+        self
+    }
+
+    /// Completes the input up to the longest prefix shared by the words
+    /// starting with it when the hotkey is pressed without a highlighted
+    /// suggestion.
+    pub fn with_longest_common_prefix_completion(mut self) -> Self {
+        self.longest_common_prefix = true;
+        self
+    }
+
+    fn matching_words(&self, input: &str) -> Vec<String> {
+        let strategy = match self.fuzzy {
+            true => MatchingStrategy::Fuzzy,
+            false => MatchingStrategy::Substring,
+        };
+        let scorer = strategy.scorer();
+
+        let mut matches: Vec<(&String, i64)> = self
+            .words
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, word)| scorer(input, word, word, idx).map(|score| (word, score)))
+            .collect();
+
+        // stable, so that words with the same score keep the order of the list.
+        matches.sort_by_key(|(_, score)| Reverse(*score));
+        matches.into_iter().map(|(word, _)| word.clone()).collect()
+    }
+
+    /// Longest prefix shared by the words starting with `input`, if it is
+    /// longer than the input itself.
+    fn longest_common_prefix(&self, input: &str) -> Option<String> {
+        let mut candidates = self.words.iter().filter(|word| word.starts_with(input));
+
+        let mut prefix: &str = candidates.next()?;
+        for word in candidates {
+            let shared = prefix
+                .char_indices()
+                .zip(word.chars())
+                .find(|((_, a), b)| a != b)
+                .map(|((idx, _), _)| idx);
+
+            if let Some(idx) = shared {
+                prefix = &prefix[..idx];
+            } else if word.len() < prefix.len() {
+                prefix = word;
+            }
+        }
+
+        match prefix.len() > input.len() {
+            true => Some(prefix.to_owned()),
+            false => None,
+        }
+    }
+}
+
+impl Autocomplete for WordListCompleter {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.matching_words(input))
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        if highlighted_suggestion.is_some() {
+            return Ok(highlighted_suggestion);
+        }
+
+        match self.longest_common_prefix {
+            true => Ok(self.longest_common_prefix(input)),
+            false => Ok(Replacement::None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Autocomplete, WordListCompleter};
+
+    fn completer() -> WordListCompleter {
+        let words = ["cargo", "rustc", "rustdoc", "rustup", "Rust book"];
+        WordListCompleter::new(words.iter().map(|&w| w.to_owned()).collect())
+    }
+
+    #[test]
+    fn suggestions_contain_the_input_ignoring_case() {
+        assert_eq!(
+            vec!["rustc", "rustdoc", "rustup", "Rust book"],
+            completer().get_suggestions("rust").unwrap()
+        );
+        assert_eq!(vec!["rustdoc"], completer().get_suggestions("DOC").unwrap());
+        assert!(completer().get_suggestions("python").unwrap().is_empty());
+    }
+
+    #[test]
+    fn empty_input_suggests_every_word() {
+        assert_eq!(5, completer().get_suggestions("").unwrap().len());
+    }
+
+    #[cfg(feature = "fuzzy")]
+    #[test]
+    fn fuzzy_suggestions_are_sorted_by_score() {
+        let mut completer = completer().with_fuzzy();
+
+        let suggestions = completer.get_suggestions("rsd").unwrap();
+
+        assert_eq!(vec!["rustdoc"], suggestions);
+        assert_eq!("rustup", completer.get_suggestions("rup").unwrap()[0]);
+    }
+
+    #[test]
+    fn highlighted_suggestion_is_completed() {
+        assert_eq!(
+            Some("rustup".to_owned()),
+            completer()
+                .get_completion("ru", Some("rustup".to_owned()))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn no_completion_without_highlighted_suggestion_by_default() {
+        assert_eq!(None, completer().get_completion("ru", None).unwrap());
+    }
+
+    #[test]
+    fn longest_common_prefix_is_completed() {
+        let mut completer = completer().with_longest_common_prefix_completion();
+
+        assert_eq!(
+            Some("rust".to_owned()),
+            completer.get_completion("r", None).unwrap()
+        );
+        assert_eq!(
+            Some("rustdoc".to_owned()),
+            completer.get_completion("rustd", None).unwrap()
+        );
+    }
+
+    #[test]
+    fn longest_common_prefix_must_extend_the_input() {
+        let mut completer = completer().with_longest_common_prefix_completion();
+
+        assert_eq!(None, completer.get_completion("rust", None).unwrap());
+        assert_eq!(None, completer.get_completion("", None).unwrap());
+        assert_eq!(None, completer.get_completion("python", None).unwrap());
+    }
+
+    #[test]
+    fn longest_common_prefix_handles_shorter_words_and_multi_byte_chars() {
+        let words = vec!["ação".to_owned(), "açaí".to_owned(), "aç".to_owned()];
+        let mut completer = WordListCompleter::new(words).with_longest_common_prefix_completion();
+
+        assert_eq!(
+            Some("aç".to_owned()),
+            completer.get_completion("a", None).unwrap()
+        );
+    }
+}
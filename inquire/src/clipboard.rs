@@ -0,0 +1,49 @@
+//! Access to the system clipboard, used by prompts pasting from it on request.
+
+use crate::validator::ErrorMessage;
+
+/// Reads the text content of the system clipboard, returning the message to
+/// display when it is unavailable or does not hold text.
+pub(crate) fn read_text() -> Result<String, ErrorMessage> {
+    #[cfg(test)]
+    {
+        if let Some(text) = test::take_stubbed_text() {
+            return Ok(text);
+        }
+    }
+
+    read_system_text()
+}
+
+#[cfg(feature = "clipboard")]
+fn read_system_text() -> Result<String, ErrorMessage> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|err| ErrorMessage::Custom(format!("Could not paste from the clipboard: {}", err)))
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn read_system_text() -> Result<String, ErrorMessage> {
+    Err(ErrorMessage::Custom(String::from(
+        "Could not paste from the clipboard: the `clipboard` feature is disabled",
+    )))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static STUBBED_TEXT: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    /// Makes the next clipboard read of the current thread return `text`
+    /// instead of reading the system clipboard.
+    pub(crate) fn stub_next_read(text: &str) {
+        STUBBED_TEXT.with(|stub| *stub.borrow_mut() = Some(text.to_owned()));
+    }
+
+    pub(super) fn take_stubbed_text() -> Option<String> {
+        STUBBED_TEXT.with(|stub| stub.borrow_mut().take())
+    }
+}
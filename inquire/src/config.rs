@@ -1,10 +1,13 @@
 //! Global config definitions.
 
-use std::sync::Mutex;
+use std::{fs::File, io, sync::Mutex};
 
 use once_cell::sync::Lazy;
 
-use crate::ui::RenderConfig;
+use crate::{
+    ui::{Key, KeyModifiers, RenderConfig},
+    InputAction,
+};
 
 static GLOBAL_RENDER_CONFIGURATION: Lazy<Mutex<RenderConfig<'static>>> =
     Lazy::new(|| Mutex::new(RenderConfig::default()));
@@ -20,8 +23,181 @@ pub fn set_global_render_config(config: RenderConfig<'static>) {
     *guard = config;
 }
 
+static GLOBAL_OUTPUT: Lazy<Mutex<Option<File>>> = Lazy::new(|| Mutex::new(None));
+
+/// Returns a handle to the output set with [set_global_output], if any.
+#[allow(unused)]
+pub fn get_output() -> io::Result<Option<File>> {
+    duplicate_output(GLOBAL_OUTPUT.lock().unwrap().as_ref())
+}
+
+fn duplicate_output(output: Option<&File>) -> io::Result<Option<File>> {
+    match output {
+        Some(file) => file.try_clone().map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Acquires a write lock to the global output and replaces it with the
+/// provided file, such as `/dev/tty` opened for reading and writing, to which
+/// all prompts are rendered from then on. Pass `None` to go back to the
+/// default terminal handles.
+///
+/// This is useful for tools that print their results to a redirected stdout
+/// but still need interactive prompts.
+///
+/// The file is owned by inquire until it is replaced, each prompt rendering to
+/// a duplicate of the handle that is closed when the prompt finishes. It must
+/// be a terminal, as prompts put it in raw mode.
+///
+/// Where keys are read from depends on the terminal backend:
+///
+/// - `crossterm`: keys are read from stdin, or from `/dev/tty` on Unix when
+///   stdin is not a terminal, regardless of the output.
+/// - `termion`: keys are read from the provided file.
+/// - `console`: the global output is not supported and is ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::OpenOptions;
+///
+/// let tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+/// inquire::set_global_output(Some(tty));
+///
+/// let name = inquire::Text::new("What's your name?").prompt();
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn set_global_output(output: Option<File>) {
+    let mut guard = GLOBAL_OUTPUT.lock().unwrap();
+    *guard = output;
+}
+
+type EventObserver = Box<dyn FnMut(Key, Option<InputAction>) + Send>;
+
+static GLOBAL_EVENT_OBSERVER: Lazy<Mutex<Option<EventObserver>>> = Lazy::new(|| Mutex::new(None));
+
+/// Acquires a write lock to the global event observer and replaces it with
+/// the provided function, which is then called on every key event processed
+/// by any prompt, e.g. to collect usage metrics or to build tutorials.
+///
+/// The observer receives the key and, when the key edits a text input such as
+/// the value of a [`Text`](crate::Text) prompt or the filter of a
+/// [`Select`](crate::Select) prompt, the resulting input action. It is called
+/// before the prompt handles the key and can not alter its behavior.
+///
+/// The content of [`Password`](crate::Password) prompts is never observed:
+/// their character keys, modifiers included, are reported as `*` without
+/// modifiers, their pastes as empty pastes, and none of their input actions
+/// are passed to the observer.
+///
+/// The observer must not run prompts itself, as it is called while the lock
+/// is held. Use [`clear_event_observer`] to remove it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inquire::ui::Key;
+///
+/// inquire::set_event_observer(|key, _action| {
+///     if matches!(key, Key::Tab) {
+///         eprintln!("tab pressed");
+///     }
+/// });
+///
+/// let name = inquire::Text::new("What's your name?").prompt();
+/// ```
+pub fn set_event_observer<F>(observer: F)
+where
+    F: FnMut(Key, Option<InputAction>) + Send + 'static,
+{
+    let mut guard = GLOBAL_EVENT_OBSERVER.lock().unwrap();
+    *guard = Some(Box::new(observer));
+}
+
+/// Removes the observer set with [`set_event_observer`], if any.
+pub fn clear_event_observer() {
+    let mut guard = GLOBAL_EVENT_OBSERVER.lock().unwrap();
+    *guard = None;
+}
+
+/// Calls the global event observer, if any, with the key and the input action
+/// it resulted in. The action is only cloned when an observer is set.
+pub(crate) fn notify_event_observer(key: Key, action: Option<&InputAction>) {
+    if let Some(observer) = GLOBAL_EVENT_OBSERVER.lock().unwrap().as_mut() {
+        observer(key, action.cloned());
+    }
+}
+
+/// Replaces the content typed or pasted with `key` by a placeholder, for
+/// prompts whose content must not reach the event observer.
+pub(crate) fn redact_content(key: Key) -> Key {
+    match key {
+        Key::Char(_, _) => Key::Char('*', KeyModifiers::NONE),
+        Key::Paste(_) => Key::Paste(String::new()),
+        key => key,
+    }
+}
+
 /// Default page size when displaying options to the user.
 pub const DEFAULT_PAGE_SIZE: usize = 7;
 
 /// Default value of vim mode.
 pub const DEFAULT_VIM_MODE: bool = false;
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{
+        fs,
+        io::Write,
+        sync::{Arc, Mutex, PoisonError},
+        thread,
+    };
+
+    use crate::{ui::Key, InputAction};
+
+    use super::{clear_event_observer, duplicate_output, set_event_observer};
+
+    /// Serializes the tests replacing the global event observer.
+    static EVENT_OBSERVER_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Runs `f` with an event observer recording the events of the prompts run
+    /// on the current thread, ignoring the ones of tests running concurrently.
+    pub(crate) fn observe_events<F, R>(f: F) -> (R, Vec<(Key, Option<InputAction>)>)
+    where
+        F: FnOnce() -> R,
+    {
+        let _guard = EVENT_OBSERVER_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let recorder = Arc::clone(&events);
+        let current = thread::current().id();
+        set_event_observer(move |key, action| {
+            if thread::current().id() == current {
+                recorder.lock().unwrap().push((key, action));
+            }
+        });
+
+        let result = f();
+        clear_event_observer();
+
+        let events = events.lock().unwrap().clone();
+        (result, events)
+    }
+
+    #[test]
+    fn global_output_is_duplicated_for_each_prompt() {
+        let output = tempfile::NamedTempFile::new().unwrap();
+
+        let mut first = duplicate_output(Some(output.as_file())).unwrap().unwrap();
+        first.write_all(b"first").unwrap();
+        let mut second = duplicate_output(Some(output.as_file())).unwrap().unwrap();
+        second.write_all(b" second").unwrap();
+
+        assert_eq!("first second", fs::read_to_string(output.path()).unwrap());
+
+        assert!(duplicate_output(None).unwrap().is_none());
+    }
+}
@@ -0,0 +1,110 @@
+//! Live counters displaying the length of the input of text prompts.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Unit of a counter displayed below the input of [Text](crate::Text) and
+/// [Editor](crate::Editor) prompts, e.g. `[142 chars, 25 words]`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{counter::CounterKind, Text};
+///
+/// let bio = Text::new("Bio:")
+///     .with_counter(CounterKind::Chars)
+///     .with_counter(CounterKind::Words);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CounterKind {
+    /// Unicode scalar values, as returned by [`str::chars`].
+    Chars,
+
+    /// Words, as delimited by the Unicode word boundaries.
+    Words,
+
+    /// Bytes of the UTF-8 encoded input.
+    Bytes,
+
+    /// Extended grapheme clusters, i.e. user-perceived characters: an emoji
+    /// made of several code points is counted once.
+    GraphemeClusters,
+}
+
+impl CounterKind {
+    /// Length of `text` measured in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::counter::CounterKind;
+    ///
+    /// let text = "héllo wörld 👍🏽";
+    ///
+    /// assert_eq!(14, CounterKind::Chars.count(text));
+    /// assert_eq!(2, CounterKind::Words.count(text));
+    /// assert_eq!(22, CounterKind::Bytes.count(text));
+    /// assert_eq!(13, CounterKind::GraphemeClusters.count(text));
+    /// ```
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            Self::Chars => text.chars().count(),
+            Self::Words => text.unicode_words().count(),
+            Self::Bytes => text.len(),
+            Self::GraphemeClusters => text.graphemes(true).count(),
+        }
+    }
+
+    fn unit(self, count: usize) -> &'static str {
+        match (self, count) {
+            (Self::Chars, 1) => "char",
+            (Self::Chars, _) => "chars",
+            (Self::Words, 1) => "word",
+            (Self::Words, _) => "words",
+            (Self::Bytes, 1) => "byte",
+            (Self::Bytes, _) => "bytes",
+            (Self::GraphemeClusters, 1) => "grapheme",
+            (Self::GraphemeClusters, _) => "graphemes",
+        }
+    }
+}
+
+/// Formats the counts of each counter, e.g. `142 chars, 25 words`, or `None`
+/// when there are no counters.
+pub(crate) fn format_counts(
+    counters: &[CounterKind],
+    count: impl Fn(CounterKind) -> usize,
+) -> Option<String> {
+    if counters.is_empty() {
+        return None;
+    }
+
+    let counts = counters
+        .iter()
+        .map(|&kind| {
+            let count = count(kind);
+            format!("{} {}", count, kind.unit(count))
+        })
+        .collect::<Vec<String>>();
+
+    Some(counts.join(", "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{format_counts, CounterKind};
+
+    #[test]
+    fn counts_are_joined_with_singular_units() {
+        let counters = [CounterKind::Chars, CounterKind::Words];
+
+        assert_eq!(None, format_counts(&[], |kind| kind.count("a")));
+        assert_eq!(
+            Some("1 char, 1 word".to_owned()),
+            format_counts(&counters, |kind| kind.count("a"))
+        );
+        assert_eq!(
+            Some("7 chars, 2 words".to_owned()),
+            format_counts(&counters, |kind| kind.count("añb cdé"))
+        );
+    }
+}
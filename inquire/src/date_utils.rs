@@ -1,6 +1,7 @@
 use core::panic;
+use std::ops::Sub;
 
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, NaiveDate};
 
 pub fn get_current_date() -> NaiveDate {
     chrono::Local::now().date_naive()
@@ -10,6 +11,47 @@ pub fn get_start_date(month: chrono::Month, year: i32) -> NaiveDate {
     NaiveDate::from_ymd_opt(year, month.number_from_month(), 1).unwrap()
 }
 
+/// First date displayed in the calendar of the given month, which is possibly
+/// in the previous month as week-lines always start on `week_start`.
+pub fn get_first_rendered_date(
+    month: chrono::Month,
+    year: i32,
+    week_start: chrono::Weekday,
+) -> NaiveDate {
+    let mut date_it = get_start_date(month, year);
+
+    if date_it.weekday() == week_start {
+        date_it = date_it
+            .sub(Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"));
+    } else {
+        while date_it.weekday() != week_start {
+            date_it = match date_it.pred_opt() {
+                Some(date) => date,
+                None => break,
+            };
+        }
+    }
+
+    date_it
+}
+
+/// ISO 8601 week number of a calendar row starting on `row_start`.
+///
+/// ISO weeks start on Monday, so rows starting on other weekdays overlap two
+/// of them. Rows are numbered after the week of their Thursday, the one most
+/// of the row belongs to, just like ISO weeks are assigned to years.
+pub fn get_week_number(row_start: NaiveDate) -> u32 {
+    let days_to_thursday = (chrono::Weekday::Thu.num_days_from_monday() + 7
+        - row_start.weekday().num_days_from_monday())
+        % 7;
+
+    row_start
+        .checked_add_days(chrono::Days::new(u64::from(days_to_thursday)))
+        .unwrap_or(row_start)
+        .iso_week()
+        .week()
+}
+
 pub fn get_month(month: u32) -> chrono::Month {
     match month {
         1 => chrono::Month::January,
@@ -63,6 +105,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_get_first_rendered_date() {
+        assert_eq!(
+            get_first_rendered_date(chrono::Month::January, 2023, chrono::Weekday::Sun),
+            NaiveDate::from_ymd_opt(2022, 12, 25).unwrap()
+        );
+        assert_eq!(
+            get_first_rendered_date(chrono::Month::March, 2023, chrono::Weekday::Mon),
+            NaiveDate::from_ymd_opt(2023, 2, 27).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_get_week_number() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(get_week_number(date(2024, 1, 1)), 1);
+        assert_eq!(get_week_number(date(2024, 6, 10)), 24);
+        // sunday-based rows are numbered after the monday that follows.
+        assert_eq!(get_week_number(date(2024, 6, 9)), 24);
+        // friday-based rows are numbered after the monday that follows.
+        assert_eq!(get_week_number(date(2024, 6, 7)), 24);
+        // thursday-based rows are numbered after the monday that precedes.
+        assert_eq!(get_week_number(date(2024, 6, 6)), 23);
+    }
+
+    #[test]
+    fn test_get_week_number_around_year_boundaries() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 2020 has 53 weeks, 2021-01-03 is still part of its last one.
+        assert_eq!(get_week_number(date(2020, 12, 28)), 53);
+        assert_eq!(get_week_number(date(2020, 12, 27)), 53);
+        assert_eq!(get_week_number(date(2021, 1, 4)), 1);
+        // 2024-12-30 belongs to the first week of 2025.
+        assert_eq!(get_week_number(date(2024, 12, 30)), 1);
+        assert_eq!(get_week_number(date(2024, 12, 29)), 1);
+        assert_eq!(get_week_number(date(2024, 12, 23)), 52);
+    }
+
     #[test]
     // this is basically a reimplementation but it works as a sanity check
     fn test_get_month() {
@@ -36,6 +36,71 @@ pub enum InquireError {
     Custom(CustomUserError),
 }
 
+impl InquireError {
+    /// Returns `true` if the user canceled the prompt by pressing ESC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::InquireError;
+    ///
+    /// assert!(InquireError::OperationCanceled.is_operation_canceled());
+    /// assert!(!InquireError::OperationInterrupted.is_operation_canceled());
+    /// ```
+    pub fn is_operation_canceled(&self) -> bool {
+        matches!(self, InquireError::OperationCanceled)
+    }
+
+    /// Returns `true` if the user interrupted the prompt by pressing Ctrl+C.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::InquireError;
+    ///
+    /// assert!(InquireError::OperationInterrupted.is_operation_interrupted());
+    /// assert!(!InquireError::OperationCanceled.is_operation_interrupted());
+    /// ```
+    pub fn is_operation_interrupted(&self) -> bool {
+        matches!(self, InquireError::OperationInterrupted)
+    }
+
+    /// Returns `true` if the error was caused by a failed IO operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use inquire::InquireError;
+    ///
+    /// let err = InquireError::IO(io::Error::new(io::ErrorKind::Other, "oh no!"));
+    /// assert!(err.is_io_error());
+    /// assert!(!InquireError::NotTTY.is_io_error());
+    /// ```
+    pub fn is_io_error(&self) -> bool {
+        matches!(self, InquireError::IO(_))
+    }
+
+    /// Returns the underlying IO error, if the error was caused by a failed IO operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use inquire::InquireError;
+    ///
+    /// let err = InquireError::IO(io::Error::new(io::ErrorKind::BrokenPipe, "oh no!"));
+    /// assert_eq!(Some(io::ErrorKind::BrokenPipe), err.io_error().map(io::Error::kind));
+    /// assert!(InquireError::OperationCanceled.io_error().is_none());
+    /// ```
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match self {
+            InquireError::IO(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 impl Error for InquireError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -75,7 +140,11 @@ impl From<io::Error> for InquireError {
 impl fmt::Display for InquireError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            InquireError::NotTTY => f.write_str("The input device is not a TTY"),
+            InquireError::NotTTY => f.write_str(
+                "The input device is not a TTY. Prompts require an interactive terminal, \
+                 set a non-interactive fallback to answer them when there is none, \
+                 e.g. with piped input or in CI",
+            ),
             InquireError::InvalidConfiguration(s) => {
                 write!(f, "The prompt configuration is invalid: {}", s)
             }
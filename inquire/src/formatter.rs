@@ -65,6 +65,26 @@ use crate::list_option::ListOption;
 /// ```
 pub type StringFormatter<'a> = &'a dyn Fn(&str) -> String;
 
+/// Type alias for formatters used in [Text](crate::Text) prompts that also
+/// need to know whether the answer came from the prompt's default value.
+///
+/// The second argument is `true` when the user submitted an empty input and
+/// the default value was returned in its place.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::StringFormatterEx;
+///
+/// let formatter: StringFormatterEx = &|i, used_default| match used_default {
+///     true => format!("{} (default)", i),
+///     false => i.to_owned(),
+/// };
+/// assert_eq!(String::from("Lisbon (default)"), formatter("Lisbon", true));
+/// assert_eq!(String::from("Porto"), formatter("Porto", false));
+/// ```
+pub type StringFormatterEx<'a> = &'a dyn Fn(&str, bool) -> String;
+
 /// Type alias for formatters used in [Confirm](crate::Confirm) prompts.
 ///
 /// Formatters receive the user input and return a [String] to be displayed
@@ -148,7 +168,26 @@ pub type MultiOptionFormatter<'a, T> = &'a dyn Fn(&[ListOption<&T>]) -> String;
 /// ```
 pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 
-#[cfg(feature = "date")]
+/// Type alias for formatters used in [`CustomType`](crate::CustomType) prompts
+/// that also need to know whether the answer came from the prompt's default value.
+///
+/// The second argument is `true` when the user submitted an empty input and
+/// the default value was returned in its place.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::formatter::CustomTypeFormatterEx;
+///
+/// let formatter: CustomTypeFormatterEx<u16> = &|i, used_default| match used_default {
+///     true => format!("{} (default)", i),
+///     false => i.to_string(),
+/// };
+///
+/// assert_eq!(String::from("8080 (default)"), formatter(&8080, true));
+/// assert_eq!(String::from("3000"), formatter(&3000, false));
+/// ```
+pub type CustomTypeFormatterEx<'a, T> = &'a dyn Fn(&T, bool) -> String;
 
 /// Type alias for formatters used in [`DateSelect`](crate::DateSelect) prompts.
 ///
@@ -168,6 +207,7 @@ pub type CustomTypeFormatter<'a, T> = &'a dyn Fn(T) -> String;
 ///     formatter(NaiveDate::from_ymd(2021, 7, 25)),
 /// );
 /// ```
+#[cfg(feature = "date")]
 pub type DateFormatter<'a> = &'a dyn Fn(chrono::NaiveDate) -> String;
 
 /// String formatter used by default in inputs that return a `String` as input.
@@ -204,6 +244,46 @@ pub const DEFAULT_BOOL_FORMATTER: BoolFormatter<'_> = &|ans| {
     }
 };
 
+#[cfg(feature = "duration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "duration")))]
+/// Formatter for durations, printing each non-zero component with the units
+/// accepted by [`parse_duration`](crate::parser::parse_duration).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::formatter::DEFAULT_DURATION_FORMATTER;
+///
+/// let formatter = DEFAULT_DURATION_FORMATTER;
+///
+/// assert_eq!(String::from("1h 30m"), formatter(Duration::from_secs(5400)));
+/// assert_eq!(String::from("2d 12h"), formatter(Duration::from_secs(216_000)));
+/// assert_eq!(String::from("1s 500ms"), formatter(Duration::from_millis(1500)));
+/// assert_eq!(String::from("0s"), formatter(Duration::ZERO));
+/// ```
+pub const DEFAULT_DURATION_FORMATTER: CustomTypeFormatter<'_, std::time::Duration> = &|val| {
+    let secs = val.as_secs();
+    let components = [
+        (secs / 86_400, "d"),
+        (secs / 3_600 % 24, "h"),
+        (secs / 60 % 60, "m"),
+        (secs % 60, "s"),
+        (u64::from(val.subsec_millis()), "ms"),
+    ];
+
+    let formatted = components
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect::<Vec<_>>();
+
+    match formatted.is_empty() {
+        true => String::from("0s"),
+        false => formatted.join(" "),
+    }
+};
+
 #[cfg(feature = "date")]
 /// String formatter used by default in [`DateSelect`](crate::DateSelect) prompts.
 /// Prints the selected date in the format: Month Day, Year.
@@ -6,7 +6,7 @@ use crate::{
 use super::{LineDirection, Magnitude};
 
 /// Set of actions for a text input handler.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum InputAction {
     /// Deletes a substring of the input according to the magnitude and the
     /// direction to delete.
@@ -16,6 +16,10 @@ pub enum InputAction {
     /// Writes a character to the content, according to the current cursor
     /// position.
     Write(char),
+    /// Writes pasted text to the content, according to the current cursor
+    /// position. Line breaks are collapsed into single spaces, as inputs
+    /// hold a single line.
+    Paste(String),
 }
 
 impl InnerAction for InputAction {
@@ -34,6 +38,17 @@ impl InnerAction for InputAction {
                 return None;
             }
 
+            // readline shortcuts
+            Key::Char('u', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Line, LineDirection::Left)
+            }
+            Key::Char('k', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Line, LineDirection::Right)
+            }
+            Key::Char('w', KeyModifiers::CONTROL) => {
+                Self::Delete(Magnitude::Word, LineDirection::Left)
+            }
+
             Key::Delete(m) if m.contains(KeyModifiers::CONTROL) => {
                 Self::Delete(Magnitude::Word, LineDirection::Right)
             }
@@ -52,6 +67,7 @@ impl InnerAction for InputAction {
             Key::Right(_) => Self::MoveCursor(Magnitude::Char, LineDirection::Right),
 
             Key::Char(c, _) => Self::Write(c),
+            Key::Paste(text) => Self::Paste(text),
             _ => return None,
         };
 
@@ -87,6 +103,22 @@ mod test {
         );
     }
 
+    #[test]
+    fn readline_shortcuts_result_in_deletions() {
+        assert_eq!(
+            InputAction::from_key(Key::Char('u', KeyModifiers::CONTROL), &()),
+            Some(InputAction::Delete(Magnitude::Line, LineDirection::Left))
+        );
+        assert_eq!(
+            InputAction::from_key(Key::Char('k', KeyModifiers::CONTROL), &()),
+            Some(InputAction::Delete(Magnitude::Line, LineDirection::Right))
+        );
+        assert_eq!(
+            InputAction::from_key(Key::Char('w', KeyModifiers::CONTROL), &()),
+            Some(InputAction::Delete(Magnitude::Word, LineDirection::Left))
+        );
+    }
+
     #[test]
     fn ctrl_backspace_does_nothing() {
         // Ctrl+Backspace is tricky, we don't want to handle a Ctrl+H
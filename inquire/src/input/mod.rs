@@ -1,10 +1,15 @@
 pub mod action;
+#[cfg(feature = "editor")]
+mod multiline;
 #[cfg(test)]
 mod test;
 
+#[cfg(feature = "editor")]
+pub use multiline::MultilineInput;
+
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::InputAction;
+use crate::{counter::CounterKind, InputAction};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Magnitude {
@@ -74,6 +79,11 @@ impl Input {
         self
     }
 
+    #[cfg(feature = "editor")]
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.length);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.length == 0
     }
@@ -93,6 +103,7 @@ impl Input {
                 LineDirection::Right => self.forwards_delete(mag),
             },
             InputAction::Write(c) => self.insert(c),
+            InputAction::Paste(text) => self.insert_pasted(&text),
         }
     }
 
@@ -110,10 +121,23 @@ impl Input {
         self.length
     }
 
+    /// Length of the content in the given unit, reusing the grapheme count
+    /// kept up to date on every edit.
+    pub fn count(&self, kind: CounterKind) -> usize {
+        match kind {
+            CounterKind::GraphemeClusters => self.length,
+            kind => kind.count(&self.content),
+        }
+    }
+
     pub fn cursor(&self) -> usize {
         self.cursor
     }
 
+    pub fn post_cursor(&self) -> &str {
+        &self.content[self.pre_cursor().len()..]
+    }
+
     pub fn pre_cursor(&self) -> &str {
         if self.cursor == self.length {
             // hot path, skip counting graphemes every time.
@@ -203,6 +227,29 @@ impl Input {
         0
     }
 
+    fn insert_pasted(&mut self, text: &str) -> InputActionResult {
+        let text = text
+            .split(['\n', '\r'])
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>()
+            .join(" ");
+
+        if text.is_empty() {
+            return InputActionResult::Clean;
+        }
+
+        let at = self.pre_cursor().len();
+        self.content.insert_str(at, &text);
+
+        let previous_length = self.length;
+        self.update_length();
+        self.cursor = self
+            .cursor
+            .saturating_add(self.length.saturating_sub(previous_length));
+
+        InputActionResult::ContentChanged
+    }
+
     fn insert(&mut self, c: char) -> InputActionResult {
         let at = self.cursor;
 
@@ -0,0 +1,269 @@
+use crate::InputAction;
+
+use super::{Input, InputActionResult, LineDirection, Magnitude};
+
+/// Text input spanning several lines, each one handled by an [Input].
+///
+/// Horizontal cursor movements and deletions wrap around line boundaries,
+/// while line breaks are inserted by writing a `'\n'` character.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultilineInput {
+    lines: Vec<Input>,
+    row: usize,
+}
+
+impl MultilineInput {
+    /// Creates an input with the given content, placing the cursor at its end.
+    pub fn new_with(content: &str) -> Self {
+        let lines: Vec<Input> = content
+            .split('\n')
+            .map(|line| Input::new_with(line.strip_suffix('\r').unwrap_or(line)))
+            .collect();
+
+        Self {
+            row: lines.len() - 1,
+            lines,
+        }
+    }
+
+    /// Places the cursor at the given line and grapheme column, both clamped
+    /// to the content.
+    pub fn with_cursor(mut self, row: usize, column: usize) -> Self {
+        self.row = row.min(self.lines.len() - 1);
+        self.lines[self.row].set_cursor(column);
+        self
+    }
+
+    /// Lines of the input, without the line breaks.
+    pub fn lines(&self) -> &[Input] {
+        &self.lines
+    }
+
+    /// Index of the line the cursor is at.
+    pub fn cursor_row(&self) -> usize {
+        self.row
+    }
+
+    /// Content of the input, with lines joined by `'\n'`.
+    pub fn content(&self) -> String {
+        self.lines
+            .iter()
+            .map(Input::content)
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    pub fn handle(&mut self, action: InputAction) -> InputActionResult {
+        let line = &self.lines[self.row];
+        let at_line_start = line.cursor() == 0;
+        let at_line_end = line.cursor() == line.length();
+        let is_first_line = self.row == 0;
+        let is_last_line = self.row + 1 == self.lines.len();
+
+        match action {
+            InputAction::Write('\n' | '\r') => self.insert_line_break(),
+            InputAction::Paste(text) => self.insert_pasted(&text),
+            InputAction::Delete(_, LineDirection::Left) if at_line_start && !is_first_line => {
+                self.row -= 1;
+                self.join_with_next_line()
+            }
+            InputAction::Delete(_, LineDirection::Right) if at_line_end && !is_last_line => {
+                self.join_with_next_line()
+            }
+            InputAction::MoveCursor(Magnitude::Char | Magnitude::Word, LineDirection::Left)
+                if at_line_start && !is_first_line =>
+            {
+                self.row -= 1;
+                let length = self.lines[self.row].length();
+                self.lines[self.row].set_cursor(length);
+                InputActionResult::PositionChanged
+            }
+            InputAction::MoveCursor(Magnitude::Char | Magnitude::Word, LineDirection::Right)
+                if at_line_end && !is_last_line =>
+            {
+                self.row += 1;
+                self.lines[self.row].set_cursor(0);
+                InputActionResult::PositionChanged
+            }
+            action => self.lines[self.row].handle(action),
+        }
+    }
+
+    /// Moves the cursor to the line above, keeping its column when possible.
+    pub fn move_up(&mut self) -> InputActionResult {
+        match self.row.checked_sub(1) {
+            Some(row) => self.move_to_row(row),
+            None => InputActionResult::Clean,
+        }
+    }
+
+    /// Moves the cursor to the line below, keeping its column when possible.
+    pub fn move_down(&mut self) -> InputActionResult {
+        if self.row + 1 < self.lines.len() {
+            self.move_to_row(self.row + 1)
+        } else {
+            InputActionResult::Clean
+        }
+    }
+
+    fn move_to_row(&mut self, row: usize) -> InputActionResult {
+        let col = self.lines[self.row].cursor();
+        self.row = row;
+
+        let length = self.lines[row].length();
+        self.lines[row].set_cursor(col.min(length));
+
+        InputActionResult::PositionChanged
+    }
+
+    fn insert_line_break(&mut self) -> InputActionResult {
+        let line = &self.lines[self.row];
+        let next_line = Input::new_with(line.post_cursor()).with_cursor(0);
+        let line = Input::new_with(line.pre_cursor());
+
+        self.lines[self.row] = line;
+        self.row += 1;
+        self.lines.insert(self.row, next_line);
+
+        InputActionResult::ContentChanged
+    }
+
+    fn insert_pasted(&mut self, text: &str) -> InputActionResult {
+        let mut result = InputActionResult::Clean;
+
+        for (i, piece) in text.split('\n').enumerate() {
+            if i > 0 {
+                result = self.insert_line_break();
+            }
+
+            let piece = piece.strip_suffix('\r').unwrap_or(piece);
+            if !piece.is_empty() {
+                result = self.lines[self.row].handle(InputAction::Paste(piece.to_owned()));
+            }
+        }
+
+        result
+    }
+
+    fn join_with_next_line(&mut self) -> InputActionResult {
+        let next_line = self.lines.remove(self.row + 1);
+        let line = &self.lines[self.row];
+
+        let cursor = line.length();
+        let content = format!("{}{}", line.content(), next_line.content());
+        self.lines[self.row] = Input::new_with(content).with_cursor(cursor);
+
+        InputActionResult::ContentChanged
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MultilineInput;
+    use crate::{
+        input::{InputActionResult, LineDirection, Magnitude},
+        InputAction,
+    };
+
+    fn write(input: &mut MultilineInput, text: &str) {
+        for c in text.chars() {
+            input.handle(InputAction::Write(c));
+        }
+    }
+
+    #[test]
+    fn line_breaks_split_the_current_line() {
+        let mut input = MultilineInput::new_with("");
+        write(&mut input, "hello\nworld");
+
+        assert_eq!("hello\nworld", input.content());
+        assert_eq!(1, input.cursor_row());
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Right,
+        ));
+        write(&mut input, "\n");
+
+        assert_eq!("hello\nw\norld", input.content());
+        assert_eq!(2, input.cursor_row());
+        assert_eq!(0, input.lines()[2].cursor());
+    }
+
+    #[test]
+    fn deleting_at_line_boundaries_joins_lines() {
+        let mut input = MultilineInput::new_with("ab\ncd");
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+        input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Left));
+
+        assert_eq!("abcd", input.content());
+        assert_eq!(0, input.cursor_row());
+        assert_eq!(2, input.lines()[0].cursor());
+
+        let mut input = MultilineInput::new_with("ab\ncd");
+        input.move_up();
+        input.handle(InputAction::Delete(Magnitude::Char, LineDirection::Right));
+
+        assert_eq!("abcd", input.content());
+        assert_eq!(2, input.lines()[0].cursor());
+    }
+
+    #[test]
+    fn horizontal_movements_wrap_around_lines() {
+        let mut input = MultilineInput::new_with("ab\ncd");
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Line,
+            LineDirection::Left,
+        ));
+
+        let result = input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Left,
+        ));
+        assert_eq!(InputActionResult::PositionChanged, result);
+        assert_eq!(0, input.cursor_row());
+        assert_eq!(2, input.lines()[0].cursor());
+
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Right,
+        ));
+        assert_eq!(1, input.cursor_row());
+        assert_eq!(0, input.lines()[1].cursor());
+    }
+
+    #[test]
+    fn vertical_movements_keep_the_column_when_possible() {
+        let mut input = MultilineInput::new_with("abcd\nx\nabcd");
+        input.handle(InputAction::MoveCursor(
+            Magnitude::Char,
+            LineDirection::Left,
+        ));
+
+        input.move_up();
+        assert_eq!(1, input.cursor_row());
+        assert_eq!(1, input.lines()[1].cursor());
+
+        input.move_up();
+        assert_eq!(0, input.cursor_row());
+        assert_eq!(1, input.lines()[0].cursor());
+
+        assert_eq!(InputActionResult::Clean, input.move_up());
+    }
+
+    #[test]
+    fn pasted_line_breaks_are_kept() {
+        let mut input = MultilineInput::new_with("a");
+        input.handle(InputAction::Paste(String::from("b\r\nc\n")));
+
+        assert_eq!("ab\nc\n", input.content());
+        assert_eq!(2, input.cursor_row());
+    }
+}
@@ -3,7 +3,8 @@ use unicode_segmentation::UnicodeSegmentation;
 use super::Input;
 use crate::{
     input::{InputActionResult, LineDirection, Magnitude},
-    InputAction,
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
 };
 
 #[test]
@@ -475,3 +476,67 @@ fn generic_user_scenario() {
     assert_eq!("great idea! you", input.pre_cursor());
     assert_eq!(15, input.cursor());
 }
+
+#[test]
+fn paste_inserts_text_at_cursor() {
+    let mut input = Input::new_with("hello world").with_cursor(6);
+
+    let result = input.handle(InputAction::Paste(String::from("big 🌍 ")));
+
+    assert_eq!(InputActionResult::ContentChanged, result);
+    assert_eq!("hello big 🌍 world", input.content());
+    assert_eq!(12, input.cursor());
+    assert_eq!(17, input.length());
+}
+
+#[test]
+fn paste_collapses_line_breaks() {
+    let mut input = Input::new();
+
+    input.handle(InputAction::Paste(String::from(
+        "first\r\nsecond\n\nthird\n",
+    )));
+
+    assert_eq!("first second third", input.content());
+    assert_eq!(input.length(), input.cursor());
+
+    let result = input.handle(InputAction::Paste(String::from("\n\r\n")));
+
+    assert_eq!(InputActionResult::Clean, result);
+    assert_eq!("first second third", input.content());
+}
+
+fn handle_key(input: &mut Input, key: Key) -> InputActionResult {
+    let action = InputAction::from_key(key, &()).unwrap();
+    input.handle(action)
+}
+
+#[test]
+fn ctrl_u_deletes_until_line_start() {
+    let mut input = Input::new_with("hello big world").with_cursor(10);
+
+    handle_key(&mut input, Key::Char('u', KeyModifiers::CONTROL));
+
+    assert_eq!("world", input.content());
+    assert_eq!(0, input.cursor());
+}
+
+#[test]
+fn ctrl_k_deletes_until_line_end() {
+    let mut input = Input::new_with("hello big world").with_cursor(6);
+
+    handle_key(&mut input, Key::Char('k', KeyModifiers::CONTROL));
+
+    assert_eq!("hello ", input.content());
+    assert_eq!(6, input.cursor());
+}
+
+#[test]
+fn ctrl_w_deletes_previous_word() {
+    let mut input = Input::new_with("hello big world").with_cursor(10);
+
+    handle_key(&mut input, Key::Char('w', KeyModifiers::CONTROL));
+
+    assert_eq!("hello world", input.content());
+    assert_eq!(6, input.cursor());
+}
@@ -66,25 +66,41 @@
 #![deny(unused_crate_dependencies)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::bool_to_int_with_if)]
+pub mod accessibility;
 mod ansi;
 pub mod autocompletion;
+mod clipboard;
 mod config;
+pub mod counter;
 #[cfg(feature = "date")]
 mod date_utils;
 pub mod error;
 pub mod formatter;
 mod input;
 pub mod list_option;
+pub mod matching;
+pub mod non_interactive;
 pub mod parser;
 mod prompts;
 mod terminal;
+#[cfg(any(test, feature = "test-util"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod test;
 pub mod type_aliases;
 pub mod ui;
 mod utils;
 pub mod validator;
 
+// chrono is a dev-dependency only used by tests of the `date` feature.
+#[cfg(all(test, not(feature = "date")))]
+use chrono as _;
+
 pub use crate::autocompletion::Autocomplete;
-pub use crate::config::set_global_render_config;
+pub use crate::config::{
+    clear_event_observer, set_event_observer, set_global_output, set_global_render_config,
+};
 pub use crate::error::{CustomUserError, InquireError};
 pub use crate::input::action::*;
 pub use crate::prompts::*;
+#[cfg(feature = "crossterm")]
+pub use crate::terminal::{enter_alternate_screen, install_panic_restore, AlternateScreen};
@@ -1,8 +1,11 @@
 //! Utilities used to wrap user selections in [Select](crate::Select) and
-//! [`MultiSelect`](crate::MultiSelect) prompts.
+//! [`MultiSelect`](crate::MultiSelect) prompts, as well as the options of
+//! [Select](crate::Select) prompts displayed as a tree or fetched on demand.
 
 use std::fmt;
 
+use dyn_clone::DynClone;
+
 /// Represents a selection made by the user when prompted to select one or several
 /// options among those presented.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,6 +40,33 @@ impl<T> ListOption<T> {
     pub fn as_ref(&self) -> ListOption<&T> {
         ListOption::new(self.index, &self.value)
     }
+
+    /// Converts from `&mut ListOption<T>` to `ListOption<&mut T>`.
+    pub fn as_mut(&mut self) -> ListOption<&mut T> {
+        ListOption::new(self.index, &mut self.value)
+    }
+
+    /// Maps the value of the option, keeping its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    ///
+    /// let answer = ListOption::new(3, "42").map(|v| v.parse::<u32>().unwrap());
+    /// assert_eq!(ListOption::new(3, 42), answer);
+    /// ```
+    pub fn map<U, F>(self, f: F) -> ListOption<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        ListOption::new(self.index, f(self.value))
+    }
+
+    /// Consumes the option, returning its value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
 }
 
 impl<T> fmt::Display for ListOption<T>
@@ -47,3 +77,185 @@ where
         self.value.fmt(f)
     }
 }
+
+/// Convenience methods for the lists of [`ListOption`]s returned by the
+/// `raw_prompt` method of [`MultiSelect`](crate::MultiSelect) prompts.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::list_option::{ListOption, ListOptions};
+///
+/// let answer = vec![ListOption::new(0, "a"), ListOption::new(2, "c")];
+///
+/// assert_eq!(vec![0, 2], answer.indices());
+/// assert_eq!(vec!["a", "c"], answer.values());
+/// ```
+pub trait ListOptions<T> {
+    /// Consumes the options, returning their values.
+    fn values(self) -> Vec<T>;
+
+    /// Indexes of the options relative to the original list.
+    fn indices(&self) -> Vec<usize>;
+}
+
+impl<T> ListOptions<T> for Vec<ListOption<T>> {
+    fn values(self) -> Vec<T> {
+        self.into_iter().map(ListOption::into_inner).collect()
+    }
+
+    fn indices(&self) -> Vec<usize> {
+        self.iter().map(|option| option.index).collect()
+    }
+}
+
+/// Source of options fetched on demand by [Select](crate::Select) prompts created
+/// with [`Select::new_from_provider`](crate::Select::new_from_provider), e.g. from a
+/// paginated API.
+///
+/// The total number of options does not need to be known upfront: the prompt keeps
+/// fetching pages as the user scrolls, until a page comes back with fewer options
+/// than requested, which marks the end of the list.
+pub trait OptionProvider<T>: DynClone {
+    /// Returns up to `limit` options of the list matching `filter`, starting at
+    /// the `offset`-th one. An empty `filter` matches all options.
+    ///
+    /// Returning fewer than `limit` options signals that there are no options
+    /// after them.
+    fn fetch(&mut self, offset: usize, limit: usize, filter: &str) -> Vec<T>;
+}
+
+impl<'a, T> Clone for Box<dyn OptionProvider<T> + 'a> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Options that may point to an URL, rendered as a clickable hyperlink by
+/// [Select](crate::Select) and [`MultiSelect`](crate::MultiSelect) prompts
+/// created with `with_links` in terminals that support it.
+///
+/// Hyperlinks are only emitted when enabled in the render config, see
+/// [`RenderConfig::with_hyperlinks`](crate::ui::RenderConfig::with_hyperlinks).
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+/// use inquire::list_option::Linkable;
+///
+/// struct Crate {
+///     name: &'static str,
+///     docs: Option<&'static str>,
+/// }
+///
+/// impl fmt::Display for Crate {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         self.name.fmt(f)
+///     }
+/// }
+///
+/// impl Linkable for Crate {
+///     fn url(&self) -> Option<&str> {
+///         self.docs
+///     }
+/// }
+/// ```
+pub trait Linkable {
+    /// URL the option points to, if any.
+    fn url(&self) -> Option<&str>;
+}
+
+impl<T> Linkable for &T
+where
+    T: Linkable + ?Sized,
+{
+    fn url(&self) -> Option<&str> {
+        (**self).url()
+    }
+}
+
+/// Position of an option in a tree of options, see [`TreeOption`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TreeLevel {
+    /// Depth of the option in the tree, 0 for top-level options.
+    pub depth: usize,
+
+    /// Whether the children of the option are displayed.
+    pub expanded: bool,
+}
+
+/// Option of a [Select](crate::Select) prompt created with
+/// [`Select::new_tree`](crate::Select::new_tree).
+///
+/// Options are listed in depth-first order: the children of an option are the
+/// options right after it with a greater depth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeOption<T> {
+    /// Value of the option.
+    pub value: T,
+
+    /// Depth and expansion state of the option.
+    pub level: TreeLevel,
+}
+
+impl<T> TreeOption<T> {
+    /// Creates a collapsed option at the given depth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::TreeOption;
+    ///
+    /// let options = vec![
+    ///     TreeOption::new("Display", 0).with_expanded(true),
+    ///     TreeOption::new("Resolution", 1),
+    ///     TreeOption::new("Brightness", 1),
+    ///     TreeOption::new("Sound", 0),
+    /// ];
+    /// ```
+    pub fn new(value: T, depth: usize) -> Self {
+        Self {
+            value,
+            level: TreeLevel {
+                depth,
+                expanded: false,
+            },
+        }
+    }
+
+    /// Sets whether the children of the option are initially displayed.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.level.expanded = expanded;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ListOption, ListOptions};
+
+    #[test]
+    fn map_keeps_index() {
+        let option = ListOption::new(5, 2).map(|v| v * 10);
+
+        assert_eq!(ListOption::new(5, 20), option);
+        assert_eq!(20, option.into_inner());
+    }
+
+    #[test]
+    fn as_mut_modifies_value_in_place() {
+        let mut option = ListOption::new(1, String::from("a"));
+        option.as_mut().value.push('b');
+
+        assert_eq!(ListOption::new(1, String::from("ab")), option);
+    }
+
+    #[test]
+    fn values_and_indices_keep_order() {
+        let options = vec![ListOption::new(4, 'e'), ListOption::new(1, 'b')];
+
+        assert_eq!(vec![4, 1], options.indices());
+        assert_eq!(vec!['e', 'b'], options.values());
+    }
+}
@@ -0,0 +1,211 @@
+//! Strategies used to match options against the filter input of list prompts.
+
+#[cfg(feature = "fuzzy")]
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+#[cfg(feature = "fuzzy")]
+use once_cell::sync::Lazy;
+
+use std::ops::Range;
+
+use crate::type_aliases::{HighlightingScorer, Scorer};
+
+#[cfg(feature = "fuzzy")]
+static FUZZY_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::default().ignore_case());
+
+/// Strategy used by [Select](crate::Select) and [MultiSelect](crate::MultiSelect)
+/// prompts to filter and sort their options according to the user input.
+///
+/// Unlike the default scorer of these prompts, which is chosen at compile time
+/// depending on the `fuzzy` feature, the strategy can be picked for each prompt.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{matching::MatchingStrategy, Select};
+///
+/// let options = vec!["Banana", "Apple", "Strawberry"];
+///
+/// let select = Select::new("Fruit:", options)
+///     .with_matching_strategy(MatchingStrategy::Substring);
+/// ```
+pub enum MatchingStrategy<'a, T> {
+    /// Options containing the input, ignoring case, are kept in their original order.
+    Substring,
+
+    /// Options are fuzzy-matched against the input and sorted by their score.
+    ///
+    /// Requires the `fuzzy` feature. When it is not enabled, this strategy
+    /// falls back to [Substring](MatchingStrategy::Substring) matching.
+    Fuzzy,
+
+    /// Options are scored by the provided function.
+    Custom(Scorer<'a, T>),
+}
+
+impl<'a, T> MatchingStrategy<'a, T> {
+    /// Scorer that keeps the options containing the input, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::matching::MatchingStrategy;
+    ///
+    /// let scorer = MatchingStrategy::<&str>::SUBSTRING_SCORER;
+    /// assert_eq!(None,    scorer("sa", &"New York",   "New York",   0));
+    /// assert_eq!(Some(0), scorer("sa", &"Sacramento", "Sacramento", 1));
+    /// assert_eq!(Some(0), scorer("sa", &"Kansas",     "Kansas",     2));
+    /// ```
+    pub const SUBSTRING_SCORER: Scorer<'a, T> =
+        &|input, _option, string_value, _idx| -> Option<i64> {
+            let filter = input.to_lowercase();
+            match string_value.to_lowercase().contains(&filter) {
+                true => Some(0),
+                false => None,
+            }
+        };
+
+    /// Scorer that fuzzy-matches the options against the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::matching::MatchingStrategy;
+    ///
+    /// let scorer = MatchingStrategy::<&str>::FUZZY_SCORER;
+    /// assert_eq!(None,     scorer("sa", &"New York",   "New York",   0));
+    /// assert_eq!(Some(49), scorer("sa", &"Sacramento", "Sacramento", 1));
+    /// assert_eq!(Some(35), scorer("sa", &"Kansas",     "Kansas",     2));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const FUZZY_SCORER: Scorer<'a, T> = &|input, _option, string_value, _idx| -> Option<i64> {
+        FUZZY_MATCHER.fuzzy_match(string_value, input)
+    };
+
+    /// Highlighting scorer that fuzzy-matches the options against the input,
+    /// reporting the matched characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::matching::MatchingStrategy;
+    ///
+    /// let scorer = MatchingStrategy::<&str>::FUZZY_HIGHLIGHTING_SCORER;
+    /// assert_eq!(None, scorer("sa", &"New York", "New York", 0));
+    /// assert_eq!(Some((49, vec![0..2])), scorer("sa", &"Sacramento", "Sacramento", 1));
+    /// assert_eq!(Some((35, vec![3..5])), scorer("sa", &"Kansas", "Kansas", 2));
+    /// ```
+    #[cfg(feature = "fuzzy")]
+    pub const FUZZY_HIGHLIGHTING_SCORER: HighlightingScorer<'a, T> =
+        &|input, _option, string_value, _idx| {
+            let (score, indices) = FUZZY_MATCHER.fuzzy_indices(string_value, input)?;
+            Some((score, char_indices_to_ranges(string_value, &indices)))
+        };
+
+    /// Returns the scoring function implementing this strategy.
+    pub fn scorer(&self) -> Scorer<'a, T> {
+        match self {
+            MatchingStrategy::Substring => Self::SUBSTRING_SCORER,
+            #[cfg(feature = "fuzzy")]
+            MatchingStrategy::Fuzzy => Self::FUZZY_SCORER,
+            #[cfg(not(feature = "fuzzy"))]
+            MatchingStrategy::Fuzzy => Self::SUBSTRING_SCORER,
+            MatchingStrategy::Custom(scorer) => *scorer,
+        }
+    }
+}
+
+/// Scores an option with the highlighting scorer if there is one, falling back
+/// to the plain scorer without any highlighted ranges otherwise.
+pub(crate) fn score_option<T>(
+    scorer: Scorer<'_, T>,
+    highlighting_scorer: Option<HighlightingScorer<'_, T>>,
+    input: &str,
+    option: &T,
+    string_value: &str,
+    idx: usize,
+) -> Option<(i64, Vec<Range<usize>>)> {
+    match highlighting_scorer {
+        Some(highlighting_scorer) => highlighting_scorer(input, option, string_value, idx),
+        None => scorer(input, option, string_value, idx).map(|score| (score, vec![])),
+    }
+}
+
+/// Shifts the highlighted byte ranges of `string_value` to where it is displayed
+/// within `label`, e.g. after the indentation of a tree row or inside the escape
+/// sequences of a hyperlink. Returns no ranges when `label` does not display it.
+pub(crate) fn ranges_in_label(
+    label: &str,
+    string_value: &str,
+    ranges: &[Range<usize>],
+) -> Vec<Range<usize>> {
+    // the value is displayed last, only followed by the end of a hyperlink, so
+    // the last occurrence is used in case the url also contains it.
+    match label.rfind(string_value) {
+        Some(offset) => ranges
+            .iter()
+            .map(|range| range.start + offset..range.end + offset)
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Converts sorted char indices of `value` into byte ranges, merging adjacent
+/// characters into a single range.
+#[cfg(feature = "fuzzy")]
+fn char_indices_to_ranges(value: &str, indices: &[usize]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut indices = indices.iter().peekable();
+
+    for (char_idx, (byte_idx, c)) in value.char_indices().enumerate() {
+        if indices.peek() != Some(&&char_idx) {
+            continue;
+        }
+        indices.next();
+
+        let end = byte_idx + c.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.end == byte_idx => last.end = end,
+            _ => ranges.push(byte_idx..end),
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod test {
+    use super::ranges_in_label;
+
+    #[test]
+    fn ranges_are_shifted_to_the_value_in_the_label() {
+        let ranges = [0..1, 2..3];
+
+        assert_eq!(ranges.to_vec(), ranges_in_label("abc", "abc", &ranges));
+        assert_eq!(vec![6..7, 8..9], ranges_in_label("  ▸ abc", "abc", &ranges));
+        assert_eq!(
+            vec![16..17, 18..19],
+            ranges_in_label("\x1b]8;;http://abc\x07abc\x1b]8;;\x07", "abc", &ranges)
+        );
+        assert!(ranges_in_label("other", "abc", &ranges).is_empty());
+    }
+}
+
+#[cfg(all(test, feature = "fuzzy"))]
+mod fuzzy_test {
+    use super::char_indices_to_ranges;
+
+    #[test]
+    fn adjacent_chars_are_merged() {
+        assert_eq!(vec![0..2, 3..4], char_indices_to_ranges("abcd", &[0, 1, 3]));
+    }
+
+    #[test]
+    fn multi_byte_chars_are_converted_to_byte_ranges() {
+        assert_eq!(vec![2..5], char_indices_to_ranges("ãéi", &[1, 2]));
+    }
+
+    #[test]
+    fn no_indices_means_no_ranges() {
+        assert!(char_indices_to_ranges("abc", &[]).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+//! Fallback behaviors for prompts executed without an interactive terminal.
+
+use std::io::{self, BufRead, Write};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    ui::RenderConfig,
+    validator::ErrorMessage,
+};
+
+/// Defines how a prompt answers when stdin is not attached to an interactive
+/// terminal, e.g. when input is piped into the program in a CI environment.
+///
+/// The check is performed by `prompt()` before the terminal is acquired, so
+/// prompts executed with `prompt_with_backend` are not affected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NonInteractiveFallback {
+    /// Answers with the default value of the prompt, failing with
+    /// [`InquireError::NotTTY`] when no default value is set.
+    UseDefault,
+
+    /// Reads a single line from stdin and treats it as the user input.
+    /// An empty line is answered with the default value, when one is set.
+    ReadLine,
+
+    /// Fails right away with [`InquireError::NotTTY`].
+    Error,
+}
+
+/// Reads a single line, stripping the line terminator.
+pub(crate) fn read_line<R: BufRead>(reader: &mut R) -> InquireResult<String> {
+    let mut line = String::new();
+
+    if reader.read_line(&mut line)? == 0 {
+        return Err(InquireError::IO(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "input was closed before an answer could be read",
+        )));
+    }
+
+    let len = line.trim_end_matches(&['\n', '\r'][..]).len();
+    line.truncate(len);
+
+    Ok(line)
+}
+
+/// Plain line-based prompt used in terminals unable to move the cursor, e.g.
+/// with `TERM=dumb`, where the frames of interactive prompts are garbled.
+///
+/// The message is written and a line is read until `answer` accepts it. When
+/// it is rejected, the error is written on its own line and the message is
+/// written again. Nothing is ever cleared or redrawn, and no styles are applied.
+pub(crate) fn prompt_lines<R, W, T, F>(
+    reader: &mut R,
+    writer: &mut W,
+    render_config: &RenderConfig<'_>,
+    message: &str,
+    default: Option<&str>,
+    mut answer: F,
+) -> InquireResult<T>
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(String) -> InquireResult<Result<T, String>>,
+{
+    loop {
+        write!(
+            writer,
+            "{} {}",
+            render_config.prompt_prefix.content, message
+        )?;
+        if let Some(default) = default {
+            write!(writer, " ({})", default)?;
+        }
+        write!(writer, " ")?;
+        writer.flush()?;
+
+        match answer(read_line(reader)?)? {
+            Ok(answer) => return Ok(answer),
+            Err(error) => writeln!(
+                writer,
+                "{} {}",
+                render_config.error_message.prefix.content, error
+            )?,
+        }
+    }
+}
+
+/// Output of line-based prompts: the global output when set, stderr otherwise.
+pub(crate) fn line_output() -> io::Result<Box<dyn Write>> {
+    Ok(match crate::config::get_output()? {
+        Some(file) => Box::new(file),
+        None => Box::new(io::stderr()),
+    })
+}
+
+/// Resolves the message of a failed validation into the text displayed to the
+/// user, or reported in an error when there is no user around to correct it.
+pub(crate) fn resolve_message(message: ErrorMessage, default_message: &str) -> String {
+    match message {
+        ErrorMessage::Default => default_message.to_owned(),
+        ErrorMessage::Custom(message) => message,
+        ErrorMessage::Multiple(messages) => messages
+            .into_iter()
+            .map(|message| resolve_message(message, default_message))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    }
+}
@@ -6,6 +6,10 @@
 //! a successful result ([Ok]) containing the parsed value or an empty [Err]
 //! if a value could not be parsed.
 
+#[cfg(feature = "duration")]
+use std::time::Duration;
+use std::{fmt, num::ParseIntError, str::FromStr};
+
 /// Type alias for parsers used in [Confirm](crate::Confirm) prompts.
 ///
 /// [`BoolParser`]s receive the user input to a given prompt and return either
@@ -52,6 +56,24 @@ pub type BoolParser<'a> = &'a dyn Fn(&str) -> Result<bool, ()>;
 /// ```
 pub type CustomTypeParser<'a, T> = &'a dyn Fn(&str) -> Result<T, ()>;
 
+/// Type alias for reparse hints used in [`CustomType`](crate::CustomType) prompts.
+///
+/// Reparse hints are called with the user input whenever it could not be parsed
+/// on submission. When they return `Some(String)`, the returned value replaces the
+/// current input so the user can review it and submit again. When they return `None`,
+/// the input is left untouched.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::ReparseHint;
+///
+/// let hint: ReparseHint = &|input| input.strip_prefix('$').map(String::from);
+/// assert_eq!(Some(String::from("12.50")), hint("$12.50"));
+/// assert_eq!(None, hint("twelve"));
+/// ```
+pub type ReparseHint<'a> = &'a dyn Fn(&str) -> Option<String>;
+
 /// Bool formatter used  by default in [Confirm](crate::Confirm) prompts.
 pub const DEFAULT_BOOL_PARSER: BoolParser<'_> = &|ans| {
     if ans.len() > 3 {
@@ -67,6 +89,187 @@ pub const DEFAULT_BOOL_PARSER: BoolParser<'_> = &|ans| {
     }
 };
 
+/// Words accepted as affirmative and negative answers by [Confirm](crate::Confirm)
+/// prompts, e.g. to support languages other than English.
+///
+/// Answers are matched case-insensitively against the words, ignoring surrounding
+/// whitespace. Answers matching words of both lists are ambiguous and rejected.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::AcceptedWords;
+///
+/// let words = AcceptedWords::new(&["ja", "j"], &["nein", "n"]);
+/// assert_eq!(Ok(true), words.parse(" Ja "));
+/// assert_eq!(Ok(false), words.parse("NEIN"));
+/// assert_eq!(Err(()), words.parse("yes"));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AcceptedWords<'a> {
+    /// Words accepted as an affirmative answer.
+    pub affirmative: &'a [&'a str],
+
+    /// Words accepted as a negative answer.
+    pub negative: &'a [&'a str],
+}
+
+impl<'a> AcceptedWords<'a> {
+    /// Creates the lists of accepted affirmative and negative words.
+    pub fn new(affirmative: &'a [&'a str], negative: &'a [&'a str]) -> Self {
+        Self {
+            affirmative,
+            negative,
+        }
+    }
+
+    /// Parses the answer into `true` or `false` when it matches an affirmative
+    /// or negative word, respectively.
+    #[allow(clippy::result_unit_err)]
+    pub fn parse(&self, ans: &str) -> Result<bool, ()> {
+        let ans = ans.trim().to_lowercase();
+        if ans.is_empty() {
+            return Err(());
+        }
+
+        let matches = |words: &[&str]| words.iter().any(|w| w.trim().to_lowercase() == ans);
+
+        match (matches(self.affirmative), matches(self.negative)) {
+            (true, false) => Ok(true),
+            (false, true) => Ok(false),
+            (true, true) | (false, false) => Err(()),
+        }
+    }
+}
+
+/// Integer types that can be parsed from digits in a given radix, used by
+/// [`parse_with_radix`].
+pub trait RadixInteger: Sized {
+    /// Parses digits in the given radix, optionally preceded by a sign.
+    /// Mirrors the inherent `from_str_radix` of primitive integers.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_radix_integer {
+    ($($type:ty),*) => {
+        $(
+            impl RadixInteger for $type {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$type>::from_str_radix(src, radix)
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Parser for integers written like Rust integer literals, accepting the `0x`,
+/// `0o` and `0b` prefixes for hexadecimal, octal and binary values, as well as
+/// underscores between digits. Values without a prefix are parsed as decimal.
+///
+/// A sign, if any, goes before the prefix, e.g. `-0x10`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::{parse_with_radix, CustomTypeParser};
+///
+/// let parser: CustomTypeParser<i64> = &parse_with_radix;
+/// assert_eq!(Ok(255), parser("0xFF"));
+/// assert_eq!(Ok(15), parser("0o17"));
+/// assert_eq!(Ok(10), parser("0b1010"));
+/// assert_eq!(Ok(-16), parser("-0x10"));
+/// assert_eq!(Ok(1_000), parser("1_000"));
+/// assert_eq!(Err(()), parser("0xZZ"));
+/// ```
+// returns an empty error to match the signature of CustomTypeParser.
+#[allow(clippy::result_unit_err)]
+pub fn parse_with_radix<T: RadixInteger>(input: &str) -> Result<T, ()> {
+    let (sign, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x" | "0X") => (16, &unsigned[2..]),
+        Some("0o" | "0O") => (8, &unsigned[2..]),
+        Some("0b" | "0B") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+
+    // from_str_radix accepts a sign of its own, which would allow e.g. "0x-1".
+    if digits.is_empty() || digits.starts_with(['_', '+', '-']) {
+        return Err(());
+    }
+
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+
+    T::from_str_radix(&format!("{sign}{digits}"), radix).map_err(|_e| ())
+}
+
+/// Parser for durations written in a humantime-like format, i.e. a sequence of
+/// integers each followed by a unit, such as `1h30m` or `2d 12h`.
+///
+/// The accepted units are `w` (weeks), `d` (days), `h` (hours), `m` or `min`
+/// (minutes), `s` or `sec` (seconds) and `ms` (milliseconds). Whitespace between
+/// components is ignored, and durations too large to be represented are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use inquire::parser::{parse_duration, CustomTypeParser};
+///
+/// let parser: CustomTypeParser<Duration> = &parse_duration;
+/// assert_eq!(Ok(Duration::from_secs(5400)), parser("1h30m"));
+/// assert_eq!(Ok(Duration::from_secs(216_000)), parser("2d 12h"));
+/// assert_eq!(Ok(Duration::from_millis(1500)), parser("1s500ms"));
+/// assert_eq!(Err(()), parser("10 parsecs"));
+/// assert_eq!(Err(()), parser("90"));
+/// ```
+#[cfg(feature = "duration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "duration")))]
+// returns an empty error to match the signature of CustomTypeParser.
+#[allow(clippy::result_unit_err)]
+pub fn parse_duration(input: &str) -> Result<Duration, ()> {
+    let mut rest = input.trim();
+    if rest.is_empty() {
+        return Err(());
+    }
+
+    let mut total = Duration::ZERO;
+
+    while !rest.is_empty() {
+        let digits_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (digits, after_digits) = rest.split_at(digits_end);
+        let unit_end = after_digits
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(after_digits.len());
+        let (unit, after_unit) = after_digits.split_at(unit_end);
+
+        let value = digits.parse::<u64>().map_err(|_e| ())?;
+        let component = match unit {
+            "w" => value.checked_mul(7 * 24 * 60 * 60).map(Duration::from_secs),
+            "d" => value.checked_mul(24 * 60 * 60).map(Duration::from_secs),
+            "h" => value.checked_mul(60 * 60).map(Duration::from_secs),
+            "m" | "min" => value.checked_mul(60).map(Duration::from_secs),
+            "s" | "sec" => Some(Duration::from_secs(value)),
+            "ms" => Some(Duration::from_millis(value)),
+            _ => return Err(()),
+        };
+
+        total = component
+            .and_then(|component| total.checked_add(component))
+            .ok_or(())?;
+        rest = after_unit.trim_start();
+    }
+
+    Ok(total)
+}
+
 #[macro_export]
 #[cfg(feature = "macros")]
 /// Built-in parser creator that checks whether the answer is able to be successfully
@@ -95,8 +298,335 @@ macro_rules! parse_type {
     }};
 }
 
+/// Parser of the list answers of [`CustomType::list`](crate::CustomType::list)
+/// prompts, splitting the input on a separator and parsing each element.
+///
+/// Empty elements, such as the one after a trailing separator, are skipped.
+/// When an element can not be parsed, the error message points to it, e.g.
+/// `Invalid value 'foo' at position 3`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::parser::ListParser;
+///
+/// let parser = ListParser::<Vec<u32>>::new();
+/// assert_eq!(Ok(vec![1, 2, 3]), parser.parse("1, 2, 3"));
+/// assert_eq!(Ok(vec![1, 2]), parser.parse("1,,2,"));
+/// assert_eq!(
+///     Err(String::from("Invalid value 'x' at position 2")),
+///     parser.parse("1, x, 3")
+/// );
+/// assert_eq!("1, 2, 3", parser.format(&vec![1, 2, 3]));
+/// ```
+pub struct ListParser<T> {
+    /// Character separating the elements of the list.
+    pub separator: char,
+
+    /// Whether whitespace around each element is trimmed before parsing it.
+    pub trim: bool,
+
+    parse: fn(&str, char, bool) -> Result<T, String>,
+    format: fn(&T, char, bool) -> String,
+}
+
+impl<T> ListParser<T> {
+    /// Parses the input, returning the error message to display if it fails.
+    pub fn parse(&self, input: &str) -> Result<T, String> {
+        (self.parse)(input, self.separator, self.trim)
+    }
+
+    /// Joins the elements of the list with the separator, followed by a space
+    /// when elements are trimmed.
+    pub fn format(&self, value: &T) -> String {
+        (self.format)(value, self.separator, self.trim)
+    }
+}
+
+impl<U> ListParser<Vec<U>>
+where
+    U: FromStr + ToString,
+{
+    /// Default separator of list elements.
+    pub const DEFAULT_SEPARATOR: char = ',';
+
+    /// Default value of trimming list elements.
+    pub const DEFAULT_TRIM: bool = true;
+
+    /// Creates a parser of comma-separated lists, trimming each element.
+    pub fn new() -> Self {
+        Self {
+            separator: Self::DEFAULT_SEPARATOR,
+            trim: Self::DEFAULT_TRIM,
+            parse: parse_list,
+            format: |values, separator, trim| format_list(values, separator, trim),
+        }
+    }
+}
+
+impl<U> Default for ListParser<Vec<U>>
+where
+    U: FromStr + ToString,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ListParser<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ListParser<T> {}
+
+impl<T> fmt::Debug for ListParser<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListParser")
+            .field("separator", &self.separator)
+            .field("trim", &self.trim)
+            .finish()
+    }
+}
+
+fn parse_list<U: FromStr>(input: &str, separator: char, trim: bool) -> Result<Vec<U>, String> {
+    let mut values = vec![];
+
+    for (idx, element) in input.split(separator).enumerate() {
+        let element = match trim {
+            true => element.trim(),
+            false => element,
+        };
+
+        if element.is_empty() {
+            continue;
+        }
+
+        match element.parse() {
+            Ok(value) => values.push(value),
+            Err(_) => {
+                return Err(format!(
+                    "Invalid value '{}' at position {}",
+                    element,
+                    idx + 1
+                ))
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+fn format_list<U: ToString>(values: &[U], separator: char, trim: bool) -> String {
+    let separator = match trim {
+        true => format!("{separator} "),
+        false => separator.to_string(),
+    };
+
+    values
+        .iter()
+        .map(U::to_string)
+        .collect::<Vec<String>>()
+        .join(&separator)
+}
+
 #[cfg(test)]
 mod test {
+    mod list_parser {
+        use crate::parser::ListParser;
+
+        #[test]
+        fn elements_are_trimmed_by_default() {
+            let parser = ListParser::<Vec<String>>::new();
+            assert_eq!(Ok(vec!["a".into(), "b c".into()]), parser.parse(" a ,b c "));
+        }
+
+        #[test]
+        fn untrimmed_elements_keep_whitespace() {
+            let parser = ListParser::<Vec<String>> {
+                trim: false,
+                ..ListParser::new()
+            };
+            assert_eq!(Ok(vec![" a ".into(), "b".into()]), parser.parse(" a ,b"));
+            assert_eq!(" a ,b", parser.format(&vec![" a ".into(), "b".into()]));
+        }
+
+        #[test]
+        fn empty_elements_are_skipped() {
+            let parser = ListParser::<Vec<u8>>::new();
+            assert_eq!(Ok(vec![1, 2]), parser.parse("1,,2"));
+            assert_eq!(Ok(vec![1, 2]), parser.parse("1, 2,"));
+            assert_eq!(Ok(vec![1]), parser.parse(",1"));
+            assert_eq!(Ok(vec![]), parser.parse(""));
+            assert_eq!(Ok(vec![]), parser.parse(" , "));
+        }
+
+        #[test]
+        fn invalid_element_position_is_reported() {
+            let parser = ListParser::<Vec<u8>>::new();
+            assert_eq!(
+                Err("Invalid value 'foo' at position 3".into()),
+                parser.parse("1, 2, foo, 4")
+            );
+            assert_eq!(
+                Err("Invalid value '300' at position 3".into()),
+                parser.parse("1,,300")
+            );
+        }
+
+        #[test]
+        fn custom_separator_is_used_to_split_and_join() {
+            let parser = ListParser::<Vec<u8>> {
+                separator: ';',
+                ..ListParser::new()
+            };
+            assert_eq!(Ok(vec![1, 2]), parser.parse("1; 2"));
+            assert_eq!(
+                Err("Invalid value '1,2' at position 1".into()),
+                parser.parse("1,2")
+            );
+            assert_eq!("1; 2", parser.format(&vec![1, 2]));
+        }
+    }
+
+    mod parse_with_radix {
+        use crate::parser::parse_with_radix;
+
+        #[test]
+        fn prefixes_select_the_radix() {
+            assert_eq!(Ok(255i64), parse_with_radix("0xFF"));
+            assert_eq!(Ok(255i64), parse_with_radix("0Xff"));
+            assert_eq!(Ok(15i64), parse_with_radix("0o17"));
+            assert_eq!(Ok(10i64), parse_with_radix("0b1010"));
+            assert_eq!(Ok(42i64), parse_with_radix("42"));
+            assert_eq!(Ok(42i64), parse_with_radix("+42"));
+            assert_eq!(Ok(0i64), parse_with_radix("0"));
+        }
+
+        #[test]
+        fn sign_goes_before_the_prefix() {
+            assert_eq!(Ok(-16i64), parse_with_radix("-0x10"));
+            assert_eq!(Ok(-5i64), parse_with_radix("-0b101"));
+            assert_eq!(Ok(i64::MIN), parse_with_radix("-0x8000000000000000"));
+            assert_eq!(Err(()), parse_with_radix::<i64>("0x-10"));
+            assert_eq!(Err(()), parse_with_radix::<u8>("-0x10"));
+        }
+
+        #[test]
+        fn underscores_separate_digits() {
+            assert_eq!(Ok(1_000i64), parse_with_radix("1_000"));
+            assert_eq!(Ok(0xFF_FFi64), parse_with_radix("0xFF_FF"));
+            assert_eq!(Err(()), parse_with_radix::<i64>("_1000"));
+            assert_eq!(Err(()), parse_with_radix::<i64>("0x_FF"));
+        }
+
+        #[test]
+        fn invalid_inputs() {
+            assert_eq!(Err(()), parse_with_radix::<i64>(""));
+            assert_eq!(Err(()), parse_with_radix::<i64>("0x"));
+            assert_eq!(Err(()), parse_with_radix::<i64>("0b102"));
+            assert_eq!(Err(()), parse_with_radix::<i64>("12a"));
+            assert_eq!(Err(()), parse_with_radix::<u8>("0x100"));
+        }
+    }
+
+    #[cfg(feature = "duration")]
+    mod parse_duration {
+        use std::time::Duration;
+
+        use crate::parser::parse_duration;
+
+        #[test]
+        fn single_components() {
+            assert_eq!(Ok(Duration::ZERO), parse_duration("0s"));
+            assert_eq!(Ok(Duration::from_secs(604_800)), parse_duration("1w"));
+            assert_eq!(Ok(Duration::from_secs(86_400)), parse_duration("1d"));
+            assert_eq!(Ok(Duration::from_secs(7_200)), parse_duration("2h"));
+            assert_eq!(Ok(Duration::from_secs(300)), parse_duration("5m"));
+            assert_eq!(Ok(Duration::from_secs(300)), parse_duration("5min"));
+            assert_eq!(Ok(Duration::from_secs(45)), parse_duration("45sec"));
+            assert_eq!(Ok(Duration::from_millis(250)), parse_duration("250ms"));
+        }
+
+        #[test]
+        fn compound_forms_are_added_up() {
+            assert_eq!(Ok(Duration::from_secs(5_400)), parse_duration("1h30m"));
+            assert_eq!(Ok(Duration::from_secs(216_000)), parse_duration("2d12h"));
+            assert_eq!(Ok(Duration::from_secs(216_000)), parse_duration(" 2d 12h "));
+            assert_eq!(Ok(Duration::from_secs(120)), parse_duration("1m1m"));
+            assert_eq!(Ok(Duration::from_millis(1_500)), parse_duration("1s500ms"));
+        }
+
+        #[test]
+        fn invalid_inputs() {
+            assert_eq!(Err(()), parse_duration(""));
+            assert_eq!(Err(()), parse_duration("   "));
+            assert_eq!(Err(()), parse_duration("90"));
+            assert_eq!(Err(()), parse_duration("h"));
+            assert_eq!(Err(()), parse_duration("10y"));
+            assert_eq!(Err(()), parse_duration("1H"));
+            assert_eq!(Err(()), parse_duration("-1h"));
+            assert_eq!(Err(()), parse_duration("1.5h"));
+            assert_eq!(Err(()), parse_duration("1h 30"));
+        }
+
+        #[test]
+        fn overflow_is_rejected() {
+            assert_eq!(Err(()), parse_duration("99999999999999999999s"));
+            assert_eq!(Err(()), parse_duration("18446744073709551615w"));
+            assert_eq!(
+                Err(()),
+                parse_duration("18446744073709551615s 18446744073709551615s")
+            );
+            assert_eq!(
+                Ok(Duration::from_secs(u64::MAX)),
+                parse_duration("18446744073709551615s")
+            );
+        }
+    }
+
+    mod accepted_words {
+        use crate::parser::AcceptedWords;
+
+        const GERMAN: AcceptedWords<'static> = AcceptedWords {
+            affirmative: &["ja", "j"],
+            negative: &["nein", "n"],
+        };
+
+        #[test]
+        fn words_are_matched_case_insensitively() {
+            assert_eq!(Ok(true), GERMAN.parse("ja"));
+            assert_eq!(Ok(true), GERMAN.parse("JA"));
+            assert_eq!(Ok(true), GERMAN.parse("J"));
+            assert_eq!(Ok(false), GERMAN.parse("Nein"));
+            assert_eq!(Ok(false), GERMAN.parse("n"));
+        }
+
+        #[test]
+        fn surrounding_whitespace_is_ignored() {
+            assert_eq!(Ok(true), GERMAN.parse("  ja\t"));
+            assert_eq!(Ok(false), GERMAN.parse(" nein "));
+            assert_eq!(Err(()), GERMAN.parse("   "));
+        }
+
+        #[test]
+        fn other_words_are_rejected() {
+            assert_eq!(Err(()), GERMAN.parse("yes"));
+            assert_eq!(Err(()), GERMAN.parse("jein"));
+            assert_eq!(Err(()), GERMAN.parse("ja nein"));
+        }
+
+        #[test]
+        fn words_in_both_lists_are_ambiguous() {
+            let words = AcceptedWords::new(&["si", "ok"], &["no", "OK "]);
+
+            assert_eq!(Ok(true), words.parse("si"));
+            assert_eq!(Ok(false), words.parse("no"));
+            assert_eq!(Err(()), words.parse("ok"));
+        }
+    }
+
     mod default_bool_parser {
         use crate::parser::DEFAULT_BOOL_PARSER;
 
@@ -3,7 +3,10 @@
 
 use std::fmt::Debug;
 
-use crate::ui::{Key, KeyModifiers};
+use crate::{
+    ui::{Key, KeyModifiers},
+    InputAction,
+};
 
 /// Top-level type to describe the directives a prompt
 /// receives.
@@ -14,7 +17,7 @@ use crate::ui::{Key, KeyModifiers};
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Action<I>
 where
-    I: Copy + Clone + PartialEq + Eq,
+    I: Clone + PartialEq + Eq,
 {
     /// Submits the current prompt answer, finishing the prompt if valid.
     Submit,
@@ -28,7 +31,7 @@ where
 
 impl<I> Action<I>
 where
-    I: Copy + Clone + PartialEq + Eq,
+    I: Clone + PartialEq + Eq,
 {
     /// Derives a prompt action from a Key event.
     pub fn from_key<C>(key: Key, config: &C) -> Option<Action<I>>
@@ -52,7 +55,7 @@ where
 /// from a key event.
 pub trait InnerAction
 where
-    Self: Sized + Copy + Clone + PartialEq + Eq,
+    Self: Sized + Clone + PartialEq + Eq,
 {
     /// Configuration type for the prompt.
     ///
@@ -63,6 +66,11 @@ where
     fn from_key(key: Key, config: &Self::Config) -> Option<Self>
     where
         Self: Sized;
+
+    /// Text input action wrapped by this action, if any.
+    fn input_action(&self) -> Option<&InputAction> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +80,7 @@ mod test {
         Action, InnerAction,
     };
 
-    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub enum MockInnerAction {
         Action(Key),
     }
@@ -4,14 +4,16 @@ mod test;
 
 pub use action::*;
 
+use std::time::Duration;
+
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::{BoolFormatter, DEFAULT_BOOL_FORMATTER},
-    parser::{BoolParser, DEFAULT_BOOL_PARSER},
-    terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
-    CustomType,
+    non_interactive::NonInteractiveFallback,
+    parser::{AcceptedWords, BoolParser, DEFAULT_BOOL_PARSER},
+    ui::{CustomTypeBackend, RenderConfig, Styled},
+    CustomType, PromptTimeout, TimeoutAction,
 };
 
 /// Prompt to ask the user for simple yes/no questions, commonly known by asking the user displaying the `(y/n)` text.
@@ -73,6 +75,10 @@ pub struct Confirm<'a> {
     /// Default value, returned when the user input is empty.
     pub default: Option<bool>,
 
+    /// Whether the default value is written as the initial value of the text
+    /// input, when no starting input is set.
+    pub default_as_initial: bool,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
@@ -85,12 +91,28 @@ pub struct Confirm<'a> {
     /// Function that parses the user input and returns the result value.
     pub parser: BoolParser<'a>,
 
+    /// Words accepted as affirmative and negative answers. When set, they are
+    /// used to parse the user input instead of the parser.
+    pub accepted_words: Option<AcceptedWords<'a>>,
+
     /// Function that formats the default value to be presented to the user
     pub default_value_formatter: BoolFormatter<'a>,
 
     /// Error message displayed when a value could not be parsed from input.
     pub error_message: String,
 
+    /// Whether the prompt is submitted as soon as the first typed character
+    /// is recognized by the parser as a complete answer, e.g. `y` or `n`.
+    pub instant_submit: bool,
+
+    /// Inactivity timeout after which the prompt is resolved without further input.
+    pub timeout: Option<PromptTimeout>,
+
+    /// How the prompt is answered when stdin is not an interactive terminal.
+    ///
+    /// When `None`, the prompt tries to acquire the terminal regardless.
+    pub non_interactive_fallback: Option<NonInteractiveFallback>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -126,12 +148,17 @@ impl<'a> Confirm<'a> {
             message,
             starting_input: None,
             default: None,
+            default_as_initial: false,
             placeholder: None,
             help_message: None,
             formatter: Self::DEFAULT_FORMATTER,
             parser: Self::DEFAULT_PARSER,
+            accepted_words: None,
             default_value_formatter: Self::DEFAULT_DEFAULT_VALUE_FORMATTER,
             error_message: String::from(Self::DEFAULT_ERROR_MESSAGE),
+            instant_submit: false,
+            timeout: None,
+            non_interactive_fallback: None,
             render_config: get_configuration(),
         }
     }
@@ -152,6 +179,21 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Prefills the text input with the default value, if any, letting the user
+    /// edit it or accept it as is. A starting input set with
+    /// [`with_starting_input`](Self::with_starting_input) takes precedence.
+    ///
+    /// The default is written as the first affirmative or negative word set with
+    /// [`with_accepted_words`](Self::with_accepted_words), or as `yes` or `no`
+    /// otherwise. The default value formatter is only used for the hint displayed
+    /// next to the prompt, as its output, e.g. `Y/n`, is not a valid answer. When a
+    /// custom parser does not parse `yes` or `no` back to the default, the input is
+    /// left empty.
+    pub fn with_default_as_initial(mut self) -> Self {
+        self.default_as_initial = true;
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
@@ -176,6 +218,43 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets the words accepted as affirmative and negative answers, e.g. `["ja"]`
+    /// and `["nein"]` for German, used to parse the user input instead of the parser.
+    ///
+    /// Answers are matched case-insensitively, ignoring surrounding whitespace,
+    /// and answers matching words of both lists are rejected as ambiguous.
+    ///
+    /// The error message is updated to suggest the first word of each list. The
+    /// `Y/n` hint of the default value is not, see
+    /// [`with_default_value_formatter`](Self::with_default_value_formatter).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Confirm;
+    ///
+    /// let ans = Confirm::new("Fortfahren?")
+    ///     .with_accepted_words(&["ja", "j"], &["nein", "n"])
+    ///     .with_default_value_formatter(&|ans| String::from(if ans { "J/n" } else { "j/N" }))
+    ///     .prompt();
+    /// ```
+    pub fn with_accepted_words(
+        mut self,
+        affirmative: &'a [&'a str],
+        negative: &'a [&'a str],
+    ) -> Self {
+        if let (Some(yes), Some(no)) = (affirmative.first(), negative.first()) {
+            self.error_message = format!(
+                "Invalid answer, try typing '{}' for yes or '{}' for no",
+                yes.trim(),
+                no.trim()
+            );
+        }
+
+        self.accepted_words = Some(AcceptedWords::new(affirmative, negative));
+        self
+    }
+
     /// Sets a custom error message displayed when a submission could not be parsed to a value.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
@@ -188,6 +267,37 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Submits the prompt as soon as the first typed character is recognized
+    /// by the parser as a complete answer, without waiting for enter.
+    ///
+    /// With the default parser, typing `y` or `n` answers the prompt right away.
+    /// Any other character is kept in the input and can be edited as usual.
+    pub fn with_instant_submit(mut self) -> Self {
+        self.instant_submit = true;
+        self
+    }
+
+    /// Resolves the prompt with `action` when the user does not press any keys
+    /// for `duration`. The timer is reset on every key press.
+    ///
+    /// With [`TimeoutAction::Cancel`], the prompt returns `Err(InquireError::OperationCanceled)`,
+    /// just as when the user presses ESC.
+    ///
+    /// Timeouts require the `crossterm` backend, see [`PromptTimeout`].
+    pub fn with_timeout(mut self, duration: Duration, action: TimeoutAction) -> Self {
+        self.timeout = Some(PromptTimeout::new(duration, action));
+        self
+    }
+
+    /// Sets how the prompt is answered when stdin is not an interactive terminal,
+    /// e.g. when input is piped into the program in a CI environment.
+    ///
+    /// See [`NonInteractiveFallback`] for the available behaviors.
+    pub fn with_non_interactive_fallback(mut self, fallback: NonInteractiveFallback) -> Self {
+        self.non_interactive_fallback = Some(fallback);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -201,6 +311,26 @@ impl<'a> Confirm<'a> {
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -221,16 +351,80 @@ impl<'a> Confirm<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<bool> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        let accepted_words = self.accepted_words;
+        let words_parser = move |ans: &str| Self::parse_accepted_words(accepted_words, ans);
+
+        self.into_custom_type(&words_parser).prompt()
+    }
+
+    /// Same as [`prompt`](Self::prompt), also returning the raw input exactly
+    /// as submitted by the user, e.g. `"yep"` when it is an accepted word.
+    ///
+    /// The raw input is empty when the default value is used.
+    pub fn prompt_with_raw(self) -> InquireResult<(bool, String)> {
+        let accepted_words = self.accepted_words;
+        let words_parser = move |ans: &str| Self::parse_accepted_words(accepted_words, ans);
+
+        self.into_custom_type(&words_parser).prompt_with_raw()
+    }
+
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: CustomTypeBackend>(self, backend: &mut B) -> InquireResult<bool> {
+        let accepted_words = self.accepted_words;
+        let words_parser = move |ans: &str| Self::parse_accepted_words(accepted_words, ans);
+
+        self.into_custom_type(&words_parser)
+            .prompt_with_backend(backend)
     }
 
-    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
-        self,
-        backend: &mut B,
-    ) -> InquireResult<bool> {
-        CustomType::from(self).prompt_with_backend(backend)
+    /// Word prefilled in the text input when the default is used as initial value.
+    fn default_as_initial_input(&self) -> Option<&'a str> {
+        let default = match (self.default_as_initial, self.default) {
+            (true, Some(default)) => default,
+            _ => return None,
+        };
+
+        if let Some(words) = self.accepted_words {
+            let word = match default {
+                true => words.affirmative.first(),
+                false => words.negative.first(),
+            };
+            return word.copied().map(str::trim);
+        }
+
+        let word = if default { "yes" } else { "no" };
+        // custom parsers might not understand english words.
+        ((self.parser)(word) == Ok(default)).then_some(word)
+    }
+
+    fn parse_accepted_words(
+        accepted_words: Option<AcceptedWords<'_>>,
+        ans: &str,
+    ) -> Result<bool, ()> {
+        match accepted_words {
+            Some(words) => words.parse(ans),
+            None => Err(()),
+        }
+    }
+
+    /// Converts the prompt into the underlying [CustomType] prompt, parsing the
+    /// input with `words_parser` when there are accepted words. The parser is
+    /// owned by the caller, as parsers are borrowed by [CustomType] prompts.
+    fn into_custom_type<'b>(self, words_parser: BoolParser<'b>) -> CustomType<'b, bool>
+    where
+        'a: 'b,
+    {
+        let has_accepted_words = self.accepted_words.is_some();
+
+        let mut custom_type = CustomType::from(self);
+        if has_accepted_words {
+            custom_type.parser = words_parser;
+        }
+        custom_type
     }
 }
 
@@ -244,15 +438,24 @@ impl<'a> From<Confirm<'a>> for CustomType<'a, bool> {
     fn from(co: Confirm<'a>) -> Self {
         Self {
             message: co.message,
-            starting_input: co.starting_input,
+            starting_input: co.starting_input.or_else(|| co.default_as_initial_input()),
             default: co.default,
             default_value_formatter: co.default_value_formatter,
+            formatter_ex: None,
             placeholder: co.placeholder,
+            default_as_placeholder: false,
             help_message: co.help_message,
+            dynamic_help_message: None,
             formatter: co.formatter,
             parser: co.parser,
+            reparse_hint: None,
             validators: vec![],
+            show_all_errors: false,
             error_message: co.error_message,
+            list_parser: None,
+            instant_submit: co.instant_submit,
+            timeout: co.timeout,
+            non_interactive_fallback: co.non_interactive_fallback,
             render_config: co.render_config,
         }
     }
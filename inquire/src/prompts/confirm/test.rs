@@ -1,14 +1,12 @@
-use std::vec;
+use std::{time::Duration, vec};
 
 use rstest::rstest;
 
 use crate::{
     error::InquireResult,
-    ui::{
-        test::{FakeBackend, Token},
-        Key, KeyModifiers,
-    },
-    Confirm, InquireError,
+    test::{FakeBackend, Token},
+    ui::{Key, KeyModifiers},
+    Confirm, InquireError, TimeoutAction,
 };
 
 #[test]
@@ -145,7 +143,7 @@ fn placeholder_is_rendered() -> InquireResult<()> {
             );
         } else {
             assert!(
-                frame.tokens().iter().any(|t| matches!(t, Token::Input(input) if input.placeholder() == Some("Placeholder"))),
+                frame.has_token(&Token::Placeholder("Placeholder".into())),
                 "Frame {} did not contain a placeholder token",
                 idx
             );
@@ -536,7 +534,7 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     assert_eq!(
         1,
-        backend.frames.len(),
+        backend.frames().len(),
         "Only an initial frame should have been rendered",
     );
 
@@ -548,3 +546,172 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn instant_submit_answers_on_first_recognized_key() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Char('y', KeyModifiers::NONE)]);
+
+    let result = Confirm::from("Question")
+        .with_instant_submit()
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the expected one");
+
+    Ok(())
+}
+
+#[test]
+fn instant_submit_keeps_unrecognized_input_editable() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char('n', KeyModifiers::NONE),
+    ]);
+
+    let result = Confirm::from("Question")
+        .with_instant_submit()
+        .prompt_with_backend(&mut backend)?;
+    assert!(!result, "Answer was not the expected one");
+
+    Ok(())
+}
+
+#[test]
+fn instant_submit_is_disabled_by_default() {
+    let mut backend = FakeBackend::new(vec![Key::Char('y', KeyModifiers::NONE)]);
+
+    let result = Confirm::from("Question").prompt_with_backend(&mut backend);
+    assert!(result.is_err(), "Prompt should not have been submitted");
+}
+
+#[test]
+fn timeout_submits_default() -> InquireResult<()> {
+    let mut backend =
+        FakeBackend::new_with_timeouts(vec![Some(Key::Char('n', KeyModifiers::NONE)), None]);
+
+    let result = Confirm::from("Question")
+        .with_default(true)
+        .with_timeout(Duration::from_millis(10), TimeoutAction::SubmitDefault)
+        .prompt_with_backend(&mut backend)?;
+    assert!(result, "Answer was not the default one");
+
+    Ok(())
+}
+
+#[test]
+fn timeout_cancels_prompt() {
+    let mut backend = FakeBackend::new_with_timeouts(vec![None]);
+
+    let result = Confirm::from("Question")
+        .with_timeout(Duration::from_millis(10), TimeoutAction::Cancel)
+        .prompt_with_backend(&mut backend);
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+}
+
+#[rstest]
+#[case("ja", true)]
+#[case("JA", true)]
+#[case(" j ", true)]
+#[case("nein", false)]
+#[case("Nein", false)]
+#[case("n", false)]
+fn accepted_words_are_parsed(#[case] input: &str, #[case] expected_result: bool) {
+    let mut keys = Key::char_keys_from_str(input);
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::new("Fortfahren?")
+        .with_accepted_words(&["ja", "j"], &["nein", "n"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(expected_result, result);
+}
+
+#[test]
+fn answers_not_in_accepted_words_are_rejected() {
+    let mut keys = Key::char_keys_from_str("yes");
+    keys.push(Key::Enter);
+    keys.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::new("Fortfahren?")
+        .with_accepted_words(&["ja"], &["nein"])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::OperationCanceled)));
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::ErrorMessage(
+            "Invalid answer, try typing 'ja' for yes or 'nein' for no".into()
+        ))));
+}
+
+#[rstest]
+#[case(true, "yes")]
+#[case(false, "no")]
+fn default_as_initial_prefills_the_input(#[case] default: bool, #[case] prefilled: &str) {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = Confirm::new("Question")
+        .with_default(default)
+        .with_default_as_initial()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(default, result);
+    assert!(backend.frames()[0].has_token(&Token::Input(prefilled.into())));
+}
+
+#[test]
+fn default_as_initial_can_be_edited() {
+    let mut keys = vec![Key::Backspace; 3];
+    keys.push(Key::Char('n', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let result = Confirm::new("Question")
+        .with_default(true)
+        .with_default_as_initial()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(!result);
+}
+
+#[test]
+fn default_as_initial_uses_the_accepted_words() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = Confirm::new("Fortfahren?")
+        .with_accepted_words(&["ja", "j"], &["nein", "n"])
+        .with_default(false)
+        .with_default_as_initial()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(!result);
+    assert!(backend.frames()[0].has_token(&Token::Input("nein".into())));
+}
+
+#[test]
+fn default_as_initial_is_skipped_when_the_parser_rejects_it() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let result = Confirm::new("Está feliz?")
+        .with_parser(&|ans| match ans {
+            "sim" => Ok(true),
+            "não" => Ok(false),
+            _ => Err(()),
+        })
+        .with_default(true)
+        .with_default_as_initial()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(result);
+    assert!(!backend.frames()[0].has_token(&Token::Input("yes".into())));
+}
@@ -3,7 +3,7 @@ use crate::{ui::Key, InnerAction, InputAction};
 use super::config::CustomTypeConfig;
 
 /// Set of actions for a CustomTypePrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CustomTypePromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
@@ -20,4 +20,9 @@ impl InnerAction for CustomTypePromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        let Self::ValueInput(action) = self;
+        Some(action)
+    }
 }
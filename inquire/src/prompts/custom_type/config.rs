@@ -1,10 +1,21 @@
 use crate::CustomType;
 
 /// Configuration settings used in the execution of a CustomTypePrompt.
-pub struct CustomTypeConfig {}
+pub struct CustomTypeConfig {
+    /// Whether to submit the prompt as soon as a single typed character parses.
+    pub instant_submit: bool,
+    /// Whether to display the errors of all failing validators.
+    pub show_all_errors: bool,
+    /// Whether the default value is displayed as the placeholder of the input.
+    pub default_as_placeholder: bool,
+}
 
 impl<T> From<&CustomType<'_, T>> for CustomTypeConfig {
-    fn from(_value: &CustomType<'_, T>) -> Self {
-        Self {}
+    fn from(value: &CustomType<'_, T>) -> Self {
+        Self {
+            instant_submit: value.instant_submit,
+            show_all_errors: value.show_all_errors,
+            default_as_placeholder: value.default_as_placeholder,
+        }
     }
 }
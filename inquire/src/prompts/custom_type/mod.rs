@@ -2,19 +2,30 @@ mod action;
 mod config;
 mod prompt;
 
+#[cfg(test)]
+mod test;
+
 pub use action::*;
 
-use std::str::FromStr;
+use std::{
+    io::{self, BufRead, Write},
+    str::FromStr,
+    time::Duration,
+};
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
-    formatter::CustomTypeFormatter,
-    parser::CustomTypeParser,
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, CustomTypeBackend, RenderConfig},
-    validator::CustomTypeValidator,
+    formatter::{CustomTypeFormatter, CustomTypeFormatterEx},
+    non_interactive::{
+        line_output, prompt_lines, read_line, resolve_message, NonInteractiveFallback,
+    },
+    parser::{parse_with_radix, CustomTypeParser, ListParser, RadixInteger, ReparseHint},
+    prompts::{ensure_timeout_supported, prompt::Prompt, PromptTimeout, TimeoutAction},
+    terminal::{get_default_terminal, is_dumb_terminal, record_answer, stdin_is_tty},
+    type_aliases::DynamicHelpMessage,
+    ui::{Backend, CustomTypeBackend, RenderConfig, Styled},
+    validator::{merge_validations, CustomTypeValidator, Validation},
 };
 
 use self::prompt::CustomTypePrompt;
@@ -45,15 +56,24 @@ use self::prompt::CustomTypePrompt;
 ///     starting_input: None,
 ///     formatter: &|i| format!("${:.2}", i),
 ///     default_value_formatter: &|i| format!("${:.2}", i),
+///     formatter_ex: None,
 ///     default: None,
 ///     validators: vec![],
+///     show_all_errors: false,
 ///     placeholder: Some("123.45"),
+///     default_as_placeholder: false,
 ///     error_message: "Please type a valid number.".into(),
 ///     help_message: "Do not use currency and the number should use dots as the decimal separator.".into(),
+///     dynamic_help_message: None,
 ///     parser: &|i| match i.parse::<f64>() {
 ///         Ok(val) => Ok(val),
 ///         Err(_) => Err(()),
 ///     },
+///     list_parser: None,
+///     reparse_hint: None,
+///     instant_submit: false,
+///     timeout: None,
+///     non_interactive_fallback: None,
 ///     render_config: RenderConfig::default(),
 /// };
 /// ```
@@ -94,18 +114,50 @@ pub struct CustomType<'a, T> {
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Whether the formatted default value is displayed as the placeholder of
+    /// the input, instead of next to the prompt message.
+    pub default_as_placeholder: bool,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current input on every render.
+    ///
+    /// When set, it takes precedence over [`help_message`](Self::help_message),
+    /// which is only displayed when the function returns `None`.
+    pub dynamic_help_message: Option<DynamicHelpMessage<'a>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: CustomTypeFormatter<'a, T>,
 
     /// Function that formats the provided value. Useful for example when you want to format a default `true` to the string "Y/n", common in confirmation prompts.
     pub default_value_formatter: CustomTypeFormatter<'a, T>,
 
+    /// Function that formats the final answer knowing whether it came from the default value.
+    ///
+    /// When set, it takes precedence over [`formatter`](Self::formatter).
+    pub formatter_ex: Option<CustomTypeFormatterEx<'a, T>>,
+
     /// Function that parses the user input and returns the result value.
     pub parser: CustomTypeParser<'a, T>,
 
+    /// Parser of list answers, set by [`list`](CustomType::list).
+    ///
+    /// When set, it is used instead of [`parser`](Self::parser), displaying its
+    /// own error messages, and it formats the answer and the default value
+    /// instead of [`formatter`](Self::formatter) and
+    /// [`default_value_formatter`](Self::default_value_formatter).
+    /// [`formatter_ex`](Self::formatter_ex) still takes precedence.
+    pub list_parser: Option<ListParser<T>>,
+
+    /// Function called with the user input when it could not be parsed, optionally
+    /// returning a corrected input to replace it with.
+    pub reparse_hint: Option<ReparseHint<'a>>,
+
+    /// Whether the prompt is submitted as soon as the first typed character
+    /// is parsed successfully, without waiting for the user to press enter.
+    pub instant_submit: bool,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -114,9 +166,21 @@ pub struct CustomType<'a, T> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn CustomTypeValidator<T>>>,
 
+    /// Whether to execute all validators and display the errors of every failing one,
+    /// each on its own line, instead of stopping at the first error.
+    pub show_all_errors: bool,
+
     /// Error message displayed when value could not be parsed from input.
     pub error_message: String,
 
+    /// Inactivity timeout after which the prompt is resolved without further input.
+    pub timeout: Option<PromptTimeout>,
+
+    /// How the prompt is answered when stdin is not an interactive terminal.
+    ///
+    /// When `None`, the prompt tries to acquire the terminal regardless.
+    pub non_interactive_fallback: Option<NonInteractiveFallback>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -128,6 +192,116 @@ pub struct CustomType<'a, T> {
     pub render_config: RenderConfig<'a>,
 }
 
+#[cfg(feature = "duration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "duration")))]
+impl<'a> CustomType<'a, Duration> {
+    /// Creates a [CustomType] asking for a duration written in a humantime-like
+    /// format, such as `1h30m` or `2d 12h`, using [`parse_duration`] as the parser
+    /// and [`DEFAULT_DURATION_FORMATTER`] to print the answer back.
+    ///
+    /// [`parse_duration`]: crate::parser::parse_duration
+    /// [`DEFAULT_DURATION_FORMATTER`]: crate::formatter::DEFAULT_DURATION_FORMATTER
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let timeout = CustomType::duration("Timeout:")
+    ///     .with_placeholder("1h30m")
+    ///     .prompt();
+    /// ```
+    pub fn duration(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            default_as_placeholder: false,
+            help_message: None,
+            dynamic_help_message: None,
+            formatter: crate::formatter::DEFAULT_DURATION_FORMATTER,
+            default_value_formatter: crate::formatter::DEFAULT_DURATION_FORMATTER,
+            formatter_ex: None,
+            parser: &crate::parser::parse_duration,
+            list_parser: None,
+            reparse_hint: None,
+            instant_submit: false,
+            timeout: None,
+            non_interactive_fallback: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            show_all_errors: Self::DEFAULT_SHOW_ALL_ERRORS,
+            error_message: "Invalid duration, use numbers followed by the units w, d, h, m, s or ms, e.g. 1h30m".into(),
+            render_config: get_configuration(),
+        }
+    }
+}
+
+impl<'a, U> CustomType<'a, Vec<U>>
+where
+    U: Clone + FromStr + ToString,
+{
+    /// Creates a [CustomType] asking for a list of values, such as comma-separated
+    /// tags, parsing each element with its `FromStr` implementation.
+    ///
+    /// Elements are separated by commas and trimmed by default, see
+    /// [`with_separator`](Self::with_separator) and [`with_trim`](Self::with_trim).
+    /// Empty elements are skipped, and the error message points to the first
+    /// element that could not be parsed. The answer is formatted by joining the
+    /// elements with the separator.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let ports = CustomType::<Vec<u16>>::list("Ports:")
+    ///     .with_placeholder("80, 443")
+    ///     .prompt();
+    /// ```
+    pub fn list(message: &'a str) -> Self {
+        Self {
+            message,
+            starting_input: None,
+            default: None,
+            placeholder: None,
+            default_as_placeholder: false,
+            help_message: None,
+            dynamic_help_message: None,
+            formatter: &|values| ListParser::new().format(&values),
+            default_value_formatter: &|values| ListParser::new().format(&values),
+            formatter_ex: None,
+            parser: &|_| Err(()),
+            list_parser: Some(ListParser::new()),
+            reparse_hint: None,
+            instant_submit: false,
+            timeout: None,
+            non_interactive_fallback: None,
+            validators: Self::DEFAULT_VALIDATORS,
+            show_all_errors: Self::DEFAULT_SHOW_ALL_ERRORS,
+            error_message: "Invalid input".into(),
+            render_config: get_configuration(),
+        }
+    }
+
+    /// Sets the character separating the elements of a list answer.
+    pub fn with_separator(mut self, separator: char) -> Self {
+        if let Some(list_parser) = &mut self.list_parser {
+            list_parser.separator = separator;
+        }
+        self
+    }
+
+    /// Sets whether whitespace around the elements of a list answer is trimmed
+    /// before parsing them.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        if let Some(list_parser) = &mut self.list_parser {
+            list_parser.trim = trim;
+        }
+        self
+    }
+}
+
 impl<'a, T> CustomType<'a, T>
 where
     T: Clone,
@@ -135,6 +309,9 @@ where
     /// Default validators added to the [CustomType] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn CustomTypeValidator<T>>> = vec![];
 
+    /// Default value for the show all errors variable.
+    pub const DEFAULT_SHOW_ALL_ERRORS: bool = false;
+
     /// Creates a [CustomType] with the provided message and default configuration values.
     pub fn new(message: &'a str) -> Self
     where
@@ -145,11 +322,20 @@ where
             starting_input: None,
             default: None,
             placeholder: None,
+            default_as_placeholder: false,
             help_message: None,
+            dynamic_help_message: None,
             formatter: &|val| val.to_string(),
             default_value_formatter: &|val| val.to_string(),
+            formatter_ex: None,
             parser: &|a| a.parse::<T>().map_err(|_e| ()),
+            list_parser: None,
+            reparse_hint: None,
+            instant_submit: false,
+            timeout: None,
+            non_interactive_fallback: None,
             validators: Self::DEFAULT_VALIDATORS,
+            show_all_errors: Self::DEFAULT_SHOW_ALL_ERRORS,
             error_message: "Invalid input".into(),
             render_config: get_configuration(),
         }
@@ -177,18 +363,48 @@ where
         self
     }
 
+    /// Displays the default value, formatted with the default value formatter,
+    /// as the placeholder of the input instead of next to the prompt message,
+    /// e.g. `? Amount: 123.45` with `123.45` greyed out instead of `? Amount: (123.45)`.
+    ///
+    /// As usual, the default value is returned when the input is submitted empty.
+    /// It takes precedence over the placeholder set with [`with_placeholder`](Self::with_placeholder),
+    /// which is still displayed when there is no default value.
+    pub fn with_default_as_placeholder(mut self) -> Self {
+        self.default_as_placeholder = true;
+        self
+    }
+
     /// Sets the help message of the prompt.
     pub fn with_help_message(mut self, message: &'a str) -> Self {
         self.help_message = Some(message);
         self
     }
 
+    /// Sets a function computing the help message from the current input on every render.
+    ///
+    /// When the function returns `None`, the help message set in
+    /// [`with_help_message`](Self::with_help_message) is displayed, if any.
+    pub fn with_dynamic_help_message(mut self, help_message: DynamicHelpMessage<'a>) -> Self {
+        self.dynamic_help_message = Some(help_message);
+        self
+    }
+
     /// Sets the formatter
     pub fn with_formatter(mut self, formatter: CustomTypeFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets a formatter that also receives whether the answer is the default value,
+    /// i.e. whether the user submitted an empty input.
+    ///
+    /// When set, it takes precedence over the formatter set in [`with_formatter`](Self::with_formatter).
+    pub fn with_formatter_ex(mut self, formatter: CustomTypeFormatterEx<'a, T>) -> Self {
+        self.formatter_ex = Some(formatter);
+        self
+    }
+
     /// Sets the formatter for default values.
     ///
     /// Useful for example when you want to format a default `true` to the string "Y/n", common in confirmation prompts,
@@ -204,6 +420,47 @@ where
         self
     }
 
+    /// Sets the parser to [`parse_with_radix`], accepting integers written with
+    /// the `0x`, `0o` and `0b` prefixes as well as underscores between digits.
+    pub fn with_radix_parser(mut self) -> Self
+    where
+        T: RadixInteger,
+    {
+        self.parser = &parse_with_radix::<T>;
+        self
+    }
+
+    /// Sets a reparse hint, called with the user input whenever it fails to be parsed.
+    ///
+    /// If the hint returns `Some(String)`, the input is replaced by the returned value
+    /// and the cursor is moved to its end, letting the user review the correction before
+    /// submitting again. The parsing error message is still displayed. If the hint
+    /// returns `None`, the input is kept as is.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::CustomType;
+    ///
+    /// let amount = CustomType::<f64>::new("Amount:")
+    ///     .with_reparse_hint(&|input| input.strip_prefix('$').map(String::from))
+    ///     .prompt();
+    /// ```
+    pub fn with_reparse_hint(mut self, reparse_hint: ReparseHint<'a>) -> Self {
+        self.reparse_hint = Some(reparse_hint);
+        self
+    }
+
+    /// Submits the prompt as soon as the input is a single character that
+    /// the parser accepts, without waiting for the user to press enter.
+    ///
+    /// Inputs that are not parsed successfully can still be edited and
+    /// submitted with enter as usual.
+    pub fn with_instant_submit(mut self) -> Self {
+        self.instant_submit = true;
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to require certain features from the parsed user's answer.
     ///
@@ -235,12 +492,40 @@ where
         self
     }
 
+    /// Executes all validators on submission and displays the errors of every failing
+    /// one, each on its own line, instead of stopping at the first error.
+    pub fn with_all_errors_shown(mut self) -> Self {
+        self.show_all_errors = true;
+        self
+    }
+
     /// Sets a custom error message displayed when a submission could not be parsed to a value.
     pub fn with_error_message(mut self, error_message: &'a str) -> Self {
         self.error_message = String::from(error_message);
         self
     }
 
+    /// Resolves the prompt with `action` when the user does not press any keys
+    /// for `duration`. The timer is reset on every key press.
+    ///
+    /// With [`TimeoutAction::Cancel`], the prompt returns `Err(InquireError::OperationCanceled)`,
+    /// just as when the user presses ESC.
+    ///
+    /// Timeouts require the `crossterm` backend, see [`PromptTimeout`].
+    pub fn with_timeout(mut self, duration: Duration, action: TimeoutAction) -> Self {
+        self.timeout = Some(PromptTimeout::new(duration, action));
+        self
+    }
+
+    /// Sets how the prompt is answered when stdin is not an interactive terminal,
+    /// e.g. when input is piped into the program in a CI environment.
+    ///
+    /// See [`NonInteractiveFallback`] for the available behaviors.
+    pub fn with_non_interactive_fallback(mut self, fallback: NonInteractiveFallback) -> Self {
+        self.non_interactive_fallback = Some(fallback);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -254,6 +539,26 @@ where
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -274,15 +579,152 @@ where
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<T> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.prompt_with_raw().map(|(answer, _raw)| answer)
+    }
+
+    /// Same as [`prompt`](Self::prompt), also returning the raw input exactly
+    /// as submitted by the user, e.g. to record it in audit logs.
+    ///
+    /// The raw input is empty when the default value is used.
+    pub fn prompt_with_raw(self) -> InquireResult<(T, String)> {
+        let line_answer = match self.non_interactive_fallback {
+            Some(fallback) if !stdin_is_tty() => {
+                self.non_interactive_answer(fallback, &mut io::stdin().lock())
+            }
+            _ if is_dumb_terminal() => {
+                self.dumb_terminal_answer(&mut io::stdin().lock(), &mut line_output()?)
+            }
+            _ => {
+                ensure_timeout_supported(self.timeout.as_ref())?;
+                let (input_reader, terminal) = get_default_terminal()?;
+                let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+                return CustomTypePrompt::from(self)
+                    .prompt_with_render_callback(&mut backend, record_answer);
+            }
+        };
+
+        let (answer, raw) = line_answer?;
+        let used_default = raw.is_empty() && self.default.is_some();
+        record_answer(
+            self.message,
+            &self.format_line_answer(&answer, used_default),
+        );
+        Ok((answer, raw))
+    }
+
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: CustomTypeBackend>(self, backend: &mut B) -> InquireResult<T> {
+        CustomTypePrompt::from(self)
+            .prompt(backend)
+            .map(|(answer, _raw)| answer)
+    }
+
+    fn non_interactive_answer<R: BufRead>(
+        &self,
+        fallback: NonInteractiveFallback,
+        reader: &mut R,
+    ) -> InquireResult<(T, String)> {
+        let line = match fallback {
+            NonInteractiveFallback::Error => return Err(InquireError::NotTTY),
+            NonInteractiveFallback::UseDefault if self.default.is_none() => {
+                return Err(InquireError::NotTTY)
+            }
+            // an empty line is answered with the default value
+            NonInteractiveFallback::UseDefault => String::new(),
+            NonInteractiveFallback::ReadLine => read_line(reader)?,
+        };
+
+        match self.line_answer(&line)? {
+            Ok(answer) => Ok((answer, line)),
+            Err(message) => Err(InquireError::Custom(message.into())),
+        }
+    }
+
+    /// Line-based version of the prompt for terminals unable to move the cursor,
+    /// asking again until the answer is parsed and valid.
+    fn dumb_terminal_answer<R: BufRead, W: Write>(
+        &self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> InquireResult<(T, String)> {
+        let default = self.default.as_ref().map(|val| match &self.list_parser {
+            Some(list_parser) => list_parser.format(val),
+            None => (self.default_value_formatter)(val.clone()),
+        });
+
+        prompt_lines(
+            reader,
+            writer,
+            &self.render_config,
+            self.message,
+            default.as_deref(),
+            |line| {
+                let answer = self.line_answer(&line)?;
+                Ok(answer.map(|answer| (answer, line)))
+            },
+        )
+    }
+
+    /// Parses and validates a line typed as the answer, returning the error
+    /// message to display when it is invalid.
+    fn line_answer(&self, line: &str) -> InquireResult<Result<T, String>> {
+        let answer = match &self.default {
+            Some(default) if line.is_empty() => default.clone(),
+            _ => match parse_input(
+                self.parser,
+                self.list_parser.as_ref(),
+                &self.error_message,
+                line,
+            ) {
+                Ok(answer) => answer,
+                Err(message) => return Ok(Err(message)),
+            },
+        };
+
+        let results = self
+            .validators
+            .iter()
+            .map(|validator| validator.validate(&answer));
+
+        match merge_validations(results, self.show_all_errors) {
+            Ok(Validation::Valid | Validation::Warning(_)) => {}
+            Ok(Validation::Invalid(msg)) => {
+                let default_message = self.render_config.error_message.default_message;
+                return Ok(Err(resolve_message(msg, default_message)));
+            }
+            Err(err) => return Err(InquireError::Custom(err)),
+        }
+
+        Ok(Ok(answer))
     }
 
-    pub(crate) fn prompt_with_backend<B: CustomTypeBackend>(
-        self,
-        backend: &mut B,
-    ) -> InquireResult<T> {
-        CustomTypePrompt::from(self).prompt(backend)
+    /// Formats an answer typed as a line the same way the interactive prompt
+    /// renders its final line.
+    fn format_line_answer(&self, answer: &T, used_default: bool) -> String {
+        match self.formatter_ex {
+            Some(formatter) => formatter(answer, used_default),
+            None => match &self.list_parser {
+                Some(list_parser) => list_parser.format(answer),
+                None => (self.formatter)(answer.clone()),
+            },
+        }
+    }
+}
+
+/// Parses the input with the list parser, if any, or the parser otherwise,
+/// returning the error message to display when it fails.
+fn parse_input<T>(
+    parser: CustomTypeParser<'_, T>,
+    list_parser: Option<&ListParser<T>>,
+    error_message: &str,
+    input: &str,
+) -> Result<T, String> {
+    match list_parser {
+        Some(list_parser) => list_parser.parse(input),
+        None => parser(input).map_err(|_e| error_message.to_owned()),
     }
 }
@@ -1,28 +1,40 @@
 use crate::{
     error::InquireResult,
-    formatter::CustomTypeFormatter,
+    formatter::{CustomTypeFormatter, CustomTypeFormatterEx},
     input::Input,
-    parser::CustomTypeParser,
-    prompts::prompt::{ActionResult, Prompt},
+    parser::{CustomTypeParser, ListParser, ReparseHint},
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        PromptTimeout,
+    },
+    type_aliases::DynamicHelpMessage,
     ui::CustomTypeBackend,
-    validator::{CustomTypeValidator, ErrorMessage, Validation},
-    CustomType, InquireError,
+    validator::{merge_validations, AnswerWarning, CustomTypeValidator, ErrorMessage, Validation},
+    CustomType, InputAction, InquireError,
 };
 
-use super::{action::CustomTypePromptAction, config::CustomTypeConfig};
+use super::{action::CustomTypePromptAction, config::CustomTypeConfig, parse_input};
 
 pub struct CustomTypePrompt<'a, T> {
     message: &'a str,
     config: CustomTypeConfig,
     error: Option<ErrorMessage>,
+    warning: Option<AnswerWarning<String>>,
     help_message: Option<&'a str>,
+    dynamic_help_message: Option<DynamicHelpMessage<'a>>,
     default: Option<T>,
     input: Input,
     formatter: CustomTypeFormatter<'a, T>,
     default_value_formatter: CustomTypeFormatter<'a, T>,
+    formatter_ex: Option<CustomTypeFormatterEx<'a, T>>,
+    used_default: bool,
     validators: Vec<Box<dyn CustomTypeValidator<T>>>,
     parser: CustomTypeParser<'a, T>,
+    list_parser: Option<ListParser<T>>,
+    reparse_hint: Option<ReparseHint<'a>>,
+    placeholder: Option<String>,
     error_message: String,
+    timeout: Option<PromptTimeout>,
 }
 
 impl<'a, T> From<CustomType<'a, T>> for CustomTypePrompt<'a, T>
@@ -30,8 +42,18 @@ where
     T: Clone,
 {
     fn from(co: CustomType<'a, T>) -> Self {
+        let default_placeholder = match (&co.default, co.default_as_placeholder) {
+            (Some(default), true) => Some(format_default(
+                co.list_parser.as_ref(),
+                co.default_value_formatter,
+                default,
+            )),
+            _ => None,
+        };
+        let placeholder = default_placeholder.or_else(|| co.placeholder.map(String::from));
+
         let input = Input::new_with(co.starting_input.unwrap_or_default());
-        let input = if let Some(placeholder) = co.placeholder {
+        let input = if let Some(placeholder) = &placeholder {
             input.with_placeholder(placeholder)
         } else {
             input
@@ -41,43 +63,91 @@ where
             message: co.message,
             config: (&co).into(),
             error: None,
+            warning: None,
             default: co.default,
             help_message: co.help_message,
+            dynamic_help_message: co.dynamic_help_message,
             formatter: co.formatter,
             default_value_formatter: co.default_value_formatter,
+            formatter_ex: co.formatter_ex,
+            used_default: false,
             validators: co.validators,
             parser: co.parser,
+            list_parser: co.list_parser,
+            reparse_hint: co.reparse_hint,
+            placeholder,
             input,
             error_message: co.error_message,
+            timeout: co.timeout,
         }
     }
 }
 
+/// Formats the default value as displayed to the user.
+fn format_default<T: Clone>(
+    list_parser: Option<&ListParser<T>>,
+    default_value_formatter: CustomTypeFormatter<'_, T>,
+    value: &T,
+) -> String {
+    match list_parser {
+        Some(list_parser) => list_parser.format(value),
+        None => default_value_formatter(value.clone()),
+    }
+}
+
 impl<'a, T> CustomTypePrompt<'a, T>
 where
     T: Clone,
 {
     fn validate_current_answer(&self, value: &T) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(value) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
-            }
-        }
+        let results = self
+            .validators
+            .iter()
+            .map(|validator| validator.validate(value));
+
+        merge_validations(results, self.config.show_all_errors).map_err(InquireError::Custom)
+    }
 
-        Ok(Validation::Valid)
+    fn is_default_answer(&self) -> bool {
+        self.default.is_some() && self.input.content().is_empty()
     }
 
     fn get_final_answer(&self) -> Result<T, String> {
         match &self.default {
-            Some(val) if self.input.content().is_empty() => return Ok(val.clone()),
+            Some(val) if self.is_default_answer() => return Ok(val.clone()),
             _ => {}
         }
 
-        match (self.parser)(self.input.content()) {
-            Ok(val) => Ok(val),
-            Err(_) => Err(self.error_message.clone()),
+        self.parse(self.input.content())
+    }
+
+    fn parse(&self, input: &str) -> Result<T, String> {
+        parse_input(
+            self.parser,
+            self.list_parser.as_ref(),
+            &self.error_message,
+            input,
+        )
+    }
+
+    fn is_instant_answer(&self) -> bool {
+        let content = self.input.content();
+
+        self.config.instant_submit && content.chars().count() == 1 && self.parse(content).is_ok()
+    }
+
+    fn apply_reparse_hint(&mut self) {
+        let hint = match self.reparse_hint {
+            Some(hint) => hint,
+            None => return,
+        };
+
+        if let Some(corrected) = hint(self.input.content()) {
+            let input = Input::new_with(corrected);
+            self.input = match &self.placeholder {
+                Some(placeholder) => input.with_placeholder(placeholder),
+                None => input,
+            };
         }
     }
 }
@@ -89,7 +159,8 @@ where
 {
     type Config = CustomTypeConfig;
     type InnerAction = CustomTypePromptAction;
-    type Output = T;
+    /// Parsed answer along with the raw input submitted by the user.
+    type Output = (T, String);
 
     fn message(&self) -> &str {
         self.message
@@ -99,38 +170,69 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &T) -> String {
-        (self.formatter)((*answer).clone())
+    fn format_answer(&self, (answer, _raw): &(T, String)) -> String {
+        match self.formatter_ex {
+            Some(formatter) => formatter(answer, self.used_default),
+            None => match &self.list_parser {
+                Some(list_parser) => list_parser.format(answer),
+                None => (self.formatter)((*answer).clone()),
+            },
+        }
     }
 
-    fn submit(&mut self) -> InquireResult<Option<T>> {
+    fn submit(&mut self) -> InquireResult<Option<(T, String)>> {
+        self.used_default = self.is_default_answer();
+
         let answer = match self.get_final_answer() {
             Ok(answer) => match self.validate_current_answer(&answer)? {
                 Validation::Valid => Some(answer),
+                Validation::Warning(msg) => {
+                    self.error = None;
+                    let input = self.input.content().to_owned();
+                    AnswerWarning::confirm(&mut self.warning, msg, input).then_some(answer)
+                }
                 Validation::Invalid(msg) => {
                     self.error = Some(msg);
+                    self.warning = None;
                     None
                 }
             },
             Err(message) => {
+                self.apply_reparse_hint();
                 self.error = Some(message.into());
+                self.warning = None;
                 None
             }
         };
 
-        Ok(answer)
+        Ok(answer.map(|answer| (answer, self.input.content().to_owned())))
     }
 
     fn handle(&mut self, action: CustomTypePromptAction) -> InquireResult<ActionResult> {
         let result = match action {
             CustomTypePromptAction::ValueInput(input_action) => {
-                self.input.handle(input_action).into()
+                let is_write = matches!(input_action, InputAction::Write(_));
+                let result = self.input.handle(input_action).into();
+
+                if is_write && self.is_instant_answer() {
+                    ActionResult::NeedsSubmit
+                } else {
+                    result
+                }
             }
         };
 
         Ok(result)
     }
 
+    fn timeout(&self) -> Option<PromptTimeout> {
+        self.timeout
+    }
+
+    fn restore_default(&mut self) {
+        self.input.clear();
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -138,15 +240,24 @@ where
             backend.render_error_message(error)?;
         }
 
-        let default_value_formatter = self.default_value_formatter;
-        let default_message = self
-            .default
-            .as_ref()
-            .map(|val| default_value_formatter(val.clone()));
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        }
+
+        let default_message = match self.config.default_as_placeholder {
+            true => None,
+            false => self.default.as_ref().map(|val| {
+                format_default(self.list_parser.as_ref(), self.default_value_formatter, val)
+            }),
+        };
 
         backend.render_prompt(prompt, default_message.as_deref(), &self.input)?;
 
-        if let Some(message) = self.help_message {
+        let dynamic_help_message = self
+            .dynamic_help_message
+            .and_then(|help_message| help_message(self.input.content()));
+
+        if let Some(message) = dynamic_help_message.as_deref().or(self.help_message) {
             backend.render_help_message(message)?;
         }
 
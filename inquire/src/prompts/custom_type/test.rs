@@ -0,0 +1,480 @@
+#[cfg(feature = "duration")]
+use std::time::Duration;
+
+use crate::{
+    non_interactive::NonInteractiveFallback,
+    prompts::prompt::Prompt,
+    test::{FakeBackend, Token},
+    ui::{Key, KeyModifiers},
+    validator::{CustomTypeValidator, ErrorMessage, Validation},
+    Confirm, CustomType, CustomUserError, InquireError,
+};
+
+use super::prompt::CustomTypePrompt;
+
+#[test]
+fn reparse_hint_replaces_input_after_failed_parse() {
+    let mut keys = Key::char_keys_from_str("$12.5");
+    keys.push(Key::Enter);
+    keys.push(Key::Char('0', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<f64>::new("Amount:")
+        .with_reparse_hint(&|input| input.strip_prefix('$').map(String::from))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(12.50, ans);
+
+    let after_submit = &backend.frames()[6];
+    assert!(after_submit.has_token(&Token::Input("12.5".into())));
+    assert!(after_submit
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::ErrorMessage(_))));
+}
+
+#[test]
+fn reparse_hint_returning_none_keeps_input() {
+    let mut keys = Key::char_keys_from_str("abc");
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<f64>::new("Amount:")
+        .with_reparse_hint(&|_| None)
+        .prompt_with_backend(&mut backend);
+
+    assert!(ans.is_err());
+
+    let after_submit = &backend.frames()[4];
+    assert!(after_submit.has_token(&Token::Input("abc".into())));
+}
+
+#[test]
+fn non_interactive_fallback_parses_line_from_input() {
+    let mut input = "42\n".as_bytes();
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_default(7)
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input)
+        .unwrap();
+
+    assert_eq!((42, "42".to_owned()), ans);
+}
+
+#[test]
+fn dumb_terminal_asks_again_after_parse_errors() {
+    let mut input = "not a number\n42\n".as_bytes();
+    let mut output = vec![];
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_default(7)
+        .with_error_message("Please type a number")
+        .dumb_terminal_answer(&mut input, &mut output)
+        .unwrap();
+
+    assert_eq!((42, "42".to_owned()), ans);
+    assert_eq!(
+        "? Question (7) # Please type a number\n? Question (7) ",
+        String::from_utf8(output).unwrap()
+    );
+}
+
+#[test]
+fn dumb_terminal_fails_when_input_is_closed() {
+    let mut input = "not a number\n".as_bytes();
+    let mut output = vec![];
+
+    let ans = CustomType::<u32>::new("Question").dumb_terminal_answer(&mut input, &mut output);
+
+    assert!(matches!(ans, Err(InquireError::IO(_))));
+}
+
+#[test]
+fn non_interactive_fallback_reports_parse_errors() {
+    let mut input = "not a number\n".as_bytes();
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_error_message("Please type a number")
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input);
+
+    match ans {
+        Err(InquireError::Custom(err)) => assert_eq!("Please type a number", err.to_string()),
+        _ => panic!("Expected a parse error"),
+    }
+}
+
+#[test]
+fn non_interactive_fallback_uses_default_value() {
+    let mut input = "".as_bytes();
+
+    let ans = CustomType::<u32>::new("Question")
+        .with_default(7)
+        .non_interactive_answer(NonInteractiveFallback::UseDefault, &mut input)
+        .unwrap();
+
+    assert_eq!((7, String::new()), ans);
+}
+
+#[test]
+fn pasted_value_is_parsed_as_a_whole() {
+    let mut backend = FakeBackend::new(vec![Key::Paste(String::from("1234\n")), Key::Enter]);
+
+    let ans = CustomType::<u32>::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1234, ans);
+}
+
+#[test]
+fn formatter_ex_knows_when_default_was_used() {
+    let formatter = &|port: &u16, used_default: bool| match used_default {
+        true => format!("{port} (default)"),
+        false => port.to_string(),
+    };
+
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let ans = CustomType::<u16>::new("Port:")
+        .with_default(8080)
+        .with_formatter_ex(formatter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(8080, ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Port:".into(),
+            "8080 (default)".into()
+        )));
+
+    let mut keys = Key::char_keys_from_str("8080");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+    let ans = CustomType::<u16>::new("Port:")
+        .with_default(8080)
+        .with_formatter_ex(formatter)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(8080, ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Port:".into(), "8080".into())));
+}
+
+#[test]
+fn radix_parser_accepts_prefixed_integers() {
+    let mut keys = Key::char_keys_from_str("-0x10");
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<i64>::new("Offset:")
+        .with_radix_parser()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(-16, ans);
+}
+
+#[test]
+fn dynamic_help_message_falls_back_to_static_one() {
+    let mut keys = Key::char_keys_from_str("x");
+    keys.push(Key::Backspace);
+    keys.push(Key::Char('1', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    CustomType::<u8>::new("Age:")
+        .with_help_message("Type a number")
+        .with_dynamic_help_message(&|input| match input.parse::<u8>() {
+            Ok(_) => None,
+            Err(_) if input.is_empty() => None,
+            Err(_) => Some(format!("'{input}' is not a number")),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::HelpMessage("Type a number".into())));
+    assert!(frames[1].has_token(&Token::HelpMessage("'x' is not a number".into())));
+    assert!(frames[2].has_token(&Token::HelpMessage("Type a number".into())));
+    assert!(frames[3].has_token(&Token::HelpMessage("Type a number".into())));
+}
+
+#[test]
+fn all_errors_are_displayed_when_enabled() {
+    let mut keys = Key::char_keys_from_str("7");
+    keys.push(Key::Enter);
+    keys.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(keys);
+    let res = CustomType::<u32>::new("Amount")
+        .with_validator(|n: &u32| match n % 2 {
+            0 => Ok(Validation::Valid),
+            _ => Ok(Validation::Invalid("Must be even".into())),
+        })
+        .with_validator(|n: &u32| match *n >= 10 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Must be at least 10".into())),
+        })
+        .with_all_errors_shown()
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(res, Err(InquireError::OperationCanceled)));
+    assert!(
+        backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Multiple(vec![
+            "Must be even".into(),
+            "Must be at least 10".into(),
+        ])))
+    );
+}
+
+#[cfg(feature = "duration")]
+#[test]
+fn duration_constructor_parses_and_formats_durations() {
+    let mut keys = Key::char_keys_from_str("90");
+    keys.push(Key::Enter);
+    keys.push(Key::Char('m', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::duration("Timeout:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Duration::from_secs(5_400), ans);
+    assert!(backend.frames().iter().any(|frame| frame
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::ErrorMessage(_)))));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Timeout:".into(), "1h 30m".into())));
+}
+
+#[test]
+fn list_constructor_parses_elements_and_joins_them() {
+    let mut keys = Key::char_keys_from_str("rust,cli ,, tui,");
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<Vec<String>>::list("Tags:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["rust", "cli", "tui"], ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt(
+            "Tags:".into(),
+            "rust, cli, tui".into()
+        )));
+}
+
+#[test]
+fn list_reports_the_position_of_invalid_elements() {
+    let mut keys = Key::char_keys_from_str("1, x");
+    keys.push(Key::Enter);
+    keys.push(Key::Backspace);
+    keys.push(Key::Char('2', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<Vec<u8>>::list("Numbers:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![1, 2], ans);
+    assert!(
+        backend.frames()[5].has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "Invalid value 'x' at position 2".into()
+        )))
+    );
+}
+
+#[test]
+fn list_separator_and_trimming_are_configurable() {
+    let mut keys = Key::char_keys_from_str("a b; c;");
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<Vec<String>>::list("Names:")
+        .with_separator(';')
+        .with_trim(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec!["a b", " c"], ans);
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Names:".into(), "a b; c".into())));
+}
+
+#[test]
+fn list_default_is_formatted_with_the_separator() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = CustomType::<Vec<u8>>::list("Numbers:")
+        .with_separator('|')
+        .with_default(vec![1, 2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![1, 2], ans);
+    assert!(backend.frames()[0].has_token(&Token::DefaultValue("1| 2".into())));
+}
+
+#[test]
+fn non_interactive_list_reports_invalid_elements() {
+    let mut input = "1, 2, three\n".as_bytes();
+
+    let ans = CustomType::<Vec<u8>>::list("Numbers:")
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input);
+
+    match ans {
+        Err(InquireError::Custom(err)) => {
+            assert_eq!("Invalid value 'three' at position 3", err.to_string());
+        }
+        _ => panic!("Expected a parse error"),
+    }
+}
+
+#[test]
+fn ctrl_k_deletes_until_end_of_input() {
+    let mut keys = Key::char_keys_from_str("123");
+    keys.push(Key::Left(KeyModifiers::NONE));
+    keys.push(Key::Left(KeyModifiers::NONE));
+    keys.push(Key::Char('k', KeyModifiers::CONTROL));
+    keys.push(Key::Char('9', KeyModifiers::NONE));
+    keys.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomType::<u32>::new("Amount:")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(19, ans);
+}
+
+#[test]
+fn raw_input_is_returned_along_with_parsed_value() {
+    let mut keys = Key::char_keys_from_str(" 42");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomTypePrompt::from(
+        CustomType::<u32>::new("Question")
+            .with_parser(&|input| input.trim().parse().map_err(|_e| ())),
+    )
+    .prompt(&mut backend)
+    .unwrap();
+
+    assert_eq!((42, " 42".to_owned()), ans);
+}
+
+#[test]
+fn raw_input_of_confirm_is_the_typed_word() {
+    let mut keys = Key::char_keys_from_str("YES");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = CustomTypePrompt::from(CustomType::from(Confirm::new("Proceed?")))
+        .prompt(&mut backend)
+        .unwrap();
+
+    assert_eq!((true, "YES".to_owned()), ans);
+}
+
+#[test]
+fn raw_input_is_empty_when_default_is_used() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = CustomTypePrompt::from(CustomType::from(
+        Confirm::new("Proceed?").with_default(false),
+    ))
+    .prompt(&mut backend)
+    .unwrap();
+
+    assert_eq!((false, String::new()), ans);
+}
+
+#[derive(Clone)]
+struct AtMost(u32);
+
+impl CustomTypeValidator<u32> for AtMost {
+    fn validate(&self, input: &u32) -> Result<Validation, CustomUserError> {
+        match *input <= self.0 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid(ErrorMessage::Default)),
+        }
+    }
+}
+
+#[test]
+fn cloned_prompt_keeps_boxed_struct_validator() {
+    let prompt = CustomType::<u32>::new("Guests:").with_validator(AtMost(8));
+    let copy = prompt.clone();
+
+    for prompt in [prompt, copy] {
+        let mut backend = FakeBackend::new(vec![
+            Key::Char('9', KeyModifiers::NONE),
+            Key::Enter,
+            Key::Backspace,
+            Key::Char('7', KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        assert_eq!(7, prompt.prompt_with_backend(&mut backend).unwrap());
+        assert!(backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Default)));
+    }
+}
+
+#[test]
+fn default_as_placeholder_is_rendered_and_returned_on_empty_submit() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = CustomType::<f64>::new("Amount:")
+        .with_default(123.45)
+        .with_placeholder("amount in USD")
+        .with_default_as_placeholder()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(123.45, ans);
+
+    let placeholder = Token::Placeholder("123.45".into());
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&placeholder));
+    assert!(!frames[0]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::DefaultValue(_))));
+    assert!(!frames[1].has_token(&placeholder));
+    assert!(frames[2].has_token(&placeholder));
+}
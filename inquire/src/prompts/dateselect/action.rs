@@ -25,6 +25,8 @@ pub enum DateSelectPromptAction {
     GoToPrevYear,
     /// Move day cursor to the next year.
     GoToNextYear,
+    /// Move day cursor to the current date.
+    GoToToday,
 }
 
 impl InnerAction for DateSelectPromptAction {
@@ -77,6 +79,10 @@ impl InnerAction for DateSelectPromptAction {
             | Key::Down(_) // alternative 2, when the down above with no modifiers is not matched
             => Self::GoToNextYear,
 
+            Key::Char('t', KeyModifiers::NONE)
+            | Key::Home
+            => Self::GoToToday,
+
             _ => return None,
         };
 
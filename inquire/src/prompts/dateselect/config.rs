@@ -1,4 +1,4 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
 
 use crate::DateSelect;
 
@@ -13,6 +13,18 @@ pub struct DateSelectConfig {
 
     /// Weekday to start the week on.
     pub week_start: chrono::Weekday,
+
+    /// Whether ISO week numbers are displayed before each week row.
+    pub week_numbers: bool,
+
+    /// Step in minutes of the time selection, disabled when `None`.
+    pub time_step_minutes: Option<u32>,
+
+    /// Min time of day allowed to be selected.
+    pub min_time: Option<NaiveTime>,
+
+    /// Max time of day allowed to be selected.
+    pub max_time: Option<NaiveTime>,
 }
 
 impl From<&DateSelect<'_>> for DateSelectConfig {
@@ -21,6 +33,10 @@ impl From<&DateSelect<'_>> for DateSelectConfig {
             min_date: value.min_date,
             max_date: value.max_date,
             week_start: value.week_start,
+            week_numbers: value.week_numbers,
+            time_step_minutes: value.time_step_minutes,
+            min_time: value.min_time,
+            max_time: value.max_time,
         }
     }
 }
@@ -7,7 +7,7 @@ mod test;
 
 pub use action::*;
 
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 use crate::{
     config::get_configuration,
@@ -15,14 +15,15 @@ use crate::{
     error::{InquireError, InquireResult},
     formatter::{self, DateFormatter},
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{date::DateSelectBackend, Backend, RenderConfig},
+    terminal::{get_default_terminal, record_answer},
+    type_aliases::DisabledDatePredicate,
+    ui::{date::DateSelectBackend, Backend, RenderConfig, Styled},
     validator::DateValidator,
 };
 
 use self::prompt::DateSelectPrompt;
 
-/// Prompt that allows user to select a date (time not supported) from an interactive calendar. Available via the `date` feature.
+/// Prompt that allows user to select a date, and optionally a time of day, from an interactive calendar. Available via the `date` feature.
 ///
 /// By default, the initial selected date is the current date. The user can navigate through the calendar by pressing the keyboard arrows. If the user also presses the control key along with the arrows, the user will be able to "fast-forward" to previous or next months or years.
 ///
@@ -44,6 +45,9 @@ use self::prompt::DateSelectPrompt;
 /// - **Validators**: Custom validators to the user's selected date, displaying an error message if the date does not pass the requirements.
 /// - **Week start**: Which day of the week should be displayed in the first column of the calendar, Sunday by default.
 /// - **Min and max date**: Inclusive boundaries of allowed dates in the interactive calendar. If any boundary is set, the user will not be able to move past them, consequently not being able to select any dates out of the allowed range.
+/// - **Disabled dates**: Predicate marking dates, such as weekends or holidays, that are rendered as unavailable and skipped over when navigating the calendar.
+/// - **Time selection**: When enabled via `with_time_selection()`, [`prompt_datetime()`](DateSelect::prompt_datetime) adds a second stage after the date is selected, in which the user picks the time of day. Up and down arrows change the time by the configured step in minutes, left and right arrows by one hour, and ESC goes back to the calendar.
+/// - **Min and max time**: Inclusive boundaries of allowed times of day in the time selection stage.
 ///
 /// # Example
 ///
@@ -72,6 +76,9 @@ pub struct DateSelect<'a> {
     /// First day of the week when displaying week rows.
     pub week_start: chrono::Weekday,
 
+    /// Whether the ISO 8601 week number of each week row is displayed before it.
+    pub week_numbers: bool,
+
     /// Starting date to be selected.
     pub starting_date: NaiveDate,
 
@@ -81,6 +88,26 @@ pub struct DateSelect<'a> {
     /// Max date allowed to be selected.
     pub max_date: Option<NaiveDate>,
 
+    /// Predicate that returns whether a date can not be selected.
+    ///
+    /// Disabled dates are rendered as unavailable and the cursor skips over them,
+    /// stopping at the nearest enabled date.
+    pub disabled_predicate: Option<DisabledDatePredicate<'a>>,
+
+    /// Step, in minutes, by which the time of day is changed in the time selection stage.
+    ///
+    /// When `None`, time selection is disabled.
+    pub time_step_minutes: Option<u32>,
+
+    /// Starting time of day to be selected in the time selection stage.
+    pub starting_time: NaiveTime,
+
+    /// Min time of day allowed to be selected.
+    pub min_time: Option<NaiveTime>,
+
+    /// Max time of day allowed to be selected.
+    pub max_time: Option<NaiveTime>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
@@ -117,6 +144,10 @@ impl<'a> DateSelect<'a> {
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("arrows to move, []{} move months and years, enter to select");
 
+    /// Help message displayed in the time selection stage.
+    pub const DEFAULT_TIME_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to change minutes, ←→ to change hours, enter to select, esc to go back");
+
     /// Default validators added to the [DateSelect] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn DateValidator>> = vec![];
 
@@ -129,6 +160,18 @@ impl<'a> DateSelect<'a> {
     /// Default max date.
     pub const DEFAULT_MAX_DATE: Option<NaiveDate> = None;
 
+    /// Default disabled date predicate, none.
+    pub const DEFAULT_DISABLED_PREDICATE: Option<DisabledDatePredicate<'a>> = None;
+
+    /// Default starting time, midnight.
+    pub const DEFAULT_STARTING_TIME: NaiveTime = NaiveTime::MIN;
+
+    /// Default min time.
+    pub const DEFAULT_MIN_TIME: Option<NaiveTime> = None;
+
+    /// Default max time.
+    pub const DEFAULT_MAX_TIME: Option<NaiveTime> = None;
+
     /// Creates a [DateSelect] with the provided message, along with default configuration values.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -136,10 +179,16 @@ impl<'a> DateSelect<'a> {
             starting_date: get_current_date(),
             min_date: Self::DEFAULT_MIN_DATE,
             max_date: Self::DEFAULT_MAX_DATE,
+            disabled_predicate: Self::DEFAULT_DISABLED_PREDICATE,
+            time_step_minutes: None,
+            starting_time: Self::DEFAULT_STARTING_TIME,
+            min_time: Self::DEFAULT_MIN_TIME,
+            max_time: Self::DEFAULT_MAX_TIME,
             help_message: Self::DEFAULT_HELP_MESSAGE,
             formatter: Self::DEFAULT_FORMATTER,
             validators: Self::DEFAULT_VALIDATORS,
             week_start: Self::DEFAULT_WEEK_START,
+            week_numbers: false,
             render_config: get_configuration(),
         }
     }
@@ -167,6 +216,15 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Displays the ISO 8601 week number of each week row in a column before it.
+    ///
+    /// ISO weeks start on Monday regardless of the week start, so rows starting
+    /// on other weekdays are numbered after the week of their Thursday.
+    pub fn with_week_numbers(mut self) -> Self {
+        self.week_numbers = true;
+        self
+    }
+
     /// Sets the min date.
     pub fn with_min_date(mut self, min_date: NaiveDate) -> Self {
         self.min_date = Some(min_date);
@@ -179,12 +237,61 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the predicate used to disable dates, such as weekends or holidays.
+    ///
+    /// Disabled dates are rendered as unavailable and the cursor skips over them when
+    /// navigating, stopping at the nearest enabled date. When the starting date is disabled,
+    /// submitting moves the cursor to the next enabled date instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use chrono::{Datelike, Weekday};
+    /// use inquire::DateSelect;
+    ///
+    /// let date = DateSelect::new("Meeting date:")
+    ///     .with_disabled_predicate(&|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    ///     .prompt();
+    /// ```
+    pub fn with_disabled_predicate(mut self, predicate: DisabledDatePredicate<'a>) -> Self {
+        self.disabled_predicate = Some(predicate);
+        self
+    }
+
     /// Sets the starting date. Equivalent to [DateSelect::with_default](DateSelect::with_default).
     pub fn with_starting_date(mut self, starting_date: NaiveDate) -> Self {
         self.starting_date = starting_date;
         self
     }
 
+    /// Enables the time selection stage of [`prompt_datetime()`](DateSelect::prompt_datetime),
+    /// in which the up and down arrows change the time by `step_minutes`.
+    ///
+    /// The step must be between 1 and 1439 minutes, otherwise the prompt will fail
+    /// with an `InquireError::InvalidConfiguration` error.
+    pub fn with_time_selection(mut self, step_minutes: u32) -> Self {
+        self.time_step_minutes = Some(step_minutes);
+        self
+    }
+
+    /// Sets the starting time of the time selection stage.
+    pub fn with_starting_time(mut self, starting_time: NaiveTime) -> Self {
+        self.starting_time = starting_time;
+        self
+    }
+
+    /// Sets the min time of day.
+    pub fn with_min_time(mut self, min_time: NaiveTime) -> Self {
+        self.min_time = Some(min_time);
+        self
+    }
+
+    /// Sets the max time of day.
+    pub fn with_max_time(mut self, max_time: NaiveTime) -> Self {
+        self.max_time = Some(max_time);
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as not allowing weekends.
     ///
@@ -234,6 +341,26 @@ impl<'a> DateSelect<'a> {
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -253,16 +380,81 @@ impl<'a> DateSelect<'a> {
 
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
-    pub fn prompt(self) -> InquireResult<NaiveDate> {
+    pub fn prompt(mut self) -> InquireResult<NaiveDate> {
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.time_step_minutes = None;
+        DateSelectPrompt::new(self)?
+            .prompt_with_render_callback(&mut backend, record_answer)
+            .map(|answer| answer.date())
     }
 
-    pub(crate) fn prompt_with_backend<B: DateSelectBackend>(
-        self,
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: DateSelectBackend>(
+        mut self,
         backend: &mut B,
     ) -> InquireResult<NaiveDate> {
+        self.time_step_minutes = None;
+        DateSelectPrompt::new(self)?
+            .prompt(backend)
+            .map(|answer| answer.date())
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for a date and a time of day, according to the defined rules.
+    ///
+    /// This method is intended for flows where the user skipping/cancelling
+    /// the prompt - by pressing ESC - is considered normal behavior. In this case,
+    /// it does not return `Err(InquireError::OperationCanceled)`, but `Ok(None)`.
+    ///
+    /// Meanwhile, if the user does submit an answer, the method wraps the return
+    /// type with `Some`.
+    pub fn prompt_datetime_skippable(self) -> InquireResult<Option<NaiveDateTime>> {
+        match self.prompt_datetime() {
+            Ok(answer) => Ok(Some(answer)),
+            Err(InquireError::OperationCanceled) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for a date and a time of day, according to the defined rules.
+    ///
+    /// Requires time selection to be enabled with
+    /// [`with_time_selection()`](DateSelect::with_time_selection), otherwise the prompt
+    /// fails with an `InquireError::InvalidConfiguration` error.
+    pub fn prompt_datetime(self) -> InquireResult<NaiveDateTime> {
+        self.validate_time_selection()?;
+
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        DateSelectPrompt::new(self)?.prompt_with_render_callback(&mut backend, record_answer)
+    }
+
+    /// Prompts the user for a date and a time of day using the provided backend
+    /// instead of the default terminal.
+    ///
+    /// See [`prompt_with_backend()`](DateSelect::prompt_with_backend) for more details.
+    pub fn prompt_datetime_with_backend<B: DateSelectBackend>(
+        self,
+        backend: &mut B,
+    ) -> InquireResult<NaiveDateTime> {
+        self.validate_time_selection()?;
+
         DateSelectPrompt::new(self)?.prompt(backend)
     }
+
+    fn validate_time_selection(&self) -> InquireResult<()> {
+        if self.time_step_minutes.is_none() {
+            return Err(InquireError::InvalidConfiguration(
+                "Time selection must be enabled to prompt for a date and time".into(),
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -1,30 +1,49 @@
 use std::{
     cmp::{max, min, Ordering},
+    convert::TryFrom,
     ops::Add,
 };
 
-use chrono::{Datelike, Duration, Months, NaiveDate};
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 
 use crate::{
     date_utils::{get_current_date, get_month},
     error::InquireResult,
     formatter::DateFormatter,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::DisabledDatePredicate,
     ui::date::DateSelectBackend,
-    validator::{DateValidator, ErrorMessage, Validation},
+    validator::{AnswerWarning, DateValidator, ErrorMessage, Validation},
     DateSelect, InquireError,
 };
 
 use super::{action::DateSelectPromptAction, config::DateSelectConfig};
 
+const SECONDS_IN_MINUTE: i64 = 60;
+const MINUTES_IN_HOUR: i64 = 60;
+const MINUTES_IN_DAY: u32 = 24 * 60;
+/// Max number of consecutive disabled dates skipped when looking for an enabled one,
+/// so that a predicate disabling every date does not hang the prompt.
+const MAX_SKIPPED_DATES: usize = 3 * 366;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Stage {
+    Date,
+    Time,
+}
+
 pub struct DateSelectPrompt<'a> {
     message: &'a str,
     config: DateSelectConfig,
+    stage: Stage,
     current_date: NaiveDate,
+    current_time: NaiveTime,
     help_message: Option<&'a str>,
     formatter: DateFormatter<'a>,
+    disabled_predicate: Option<DisabledDatePredicate<'a>>,
     validators: Vec<Box<dyn DateValidator>>,
     error: Option<ErrorMessage>,
+    warning: Option<AnswerWarning<NaiveDate>>,
 }
 
 impl<'a> DateSelectPrompt<'a> {
@@ -44,19 +63,55 @@ impl<'a> DateSelectPrompt<'a> {
             }
         }
 
+        if let Some(step) = so.time_step_minutes {
+            if step == 0 || step >= MINUTES_IN_DAY {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Time step of {} minutes must be between 1 and {}",
+                    step,
+                    MINUTES_IN_DAY - 1
+                )));
+            }
+        }
+        if let (Some(min_time), Some(max_time)) = (so.min_time, so.max_time) {
+            if min_time > max_time {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min time can not be greater than max time".into(),
+                ));
+            }
+        }
+        if let Some(min_time) = so.min_time {
+            if min_time > so.starting_time {
+                return Err(InquireError::InvalidConfiguration(
+                    "Min time can not be greater than starting time".into(),
+                ));
+            }
+        }
+        if let Some(max_time) = so.max_time {
+            if max_time < so.starting_time {
+                return Err(InquireError::InvalidConfiguration(
+                    "Max time can not be smaller than starting time".into(),
+                ));
+            }
+        }
+
         Ok(Self {
             message: so.message,
+            stage: Stage::Date,
             current_date: so.starting_date,
+            current_time: so.starting_time,
             config: (&so).into(),
             help_message: so.help_message,
             formatter: so.formatter,
+            disabled_predicate: so.disabled_predicate,
             validators: so.validators,
             error: None,
+            warning: None,
         })
     }
 
     fn shift_date(&mut self, duration: Duration) -> ActionResult {
-        self.update_date(self.current_date.add(duration))
+        let forward = duration >= Duration::zero();
+        self.update_date(self.current_date.add(duration), forward)
     }
 
     fn shift_months(&mut self, qty: i32) -> ActionResult {
@@ -75,35 +130,123 @@ impl<'a> DateSelectPrompt<'a> {
             }
         };
 
-        self.update_date(new_date)
+        self.update_date(new_date, qty >= 0)
+    }
+
+    fn go_to_today(&mut self) -> ActionResult {
+        let today = get_current_date();
+        let forward = today >= self.current_date;
+        self.update_date(today, forward)
     }
 
-    fn update_date(&mut self, new_date: NaiveDate) -> ActionResult {
+    fn update_date(&mut self, mut new_date: NaiveDate, forward: bool) -> ActionResult {
         if self.current_date == new_date {
             return ActionResult::Clean;
         }
 
-        self.current_date = new_date;
         if let Some(min_date) = self.config.min_date {
-            self.current_date = max(self.current_date, min_date);
+            new_date = max(new_date, min_date);
         }
         if let Some(max_date) = self.config.max_date {
-            self.current_date = min(self.current_date, max_date);
+            new_date = min(new_date, max_date);
+        }
+
+        // land on the nearest enabled date, preferably past the target in the
+        // direction of the movement.
+        self.current_date = self
+            .nearest_enabled_date(new_date, forward)
+            .or_else(|| self.nearest_enabled_date(new_date, !forward))
+            .unwrap_or(self.current_date);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn is_disabled(&self, date: NaiveDate) -> bool {
+        match self.disabled_predicate {
+            Some(predicate) => predicate(date),
+            None => false,
+        }
+    }
+
+    /// Returns the first enabled date starting from `date`, inclusive, moving in the given
+    /// direction without leaving the min and max date boundaries.
+    fn nearest_enabled_date(&self, date: NaiveDate, forward: bool) -> Option<NaiveDate> {
+        let step = |date: &NaiveDate| match forward {
+            true => date.succ_opt(),
+            false => date.pred_opt(),
+        };
+        let in_bounds = |date: &NaiveDate| {
+            self.config.min_date.map(|min| *date >= min).unwrap_or(true)
+                && self.config.max_date.map(|max| *date <= max).unwrap_or(true)
+        };
+
+        std::iter::successors(Some(date), step)
+            .take(MAX_SKIPPED_DATES)
+            .take_while(in_bounds)
+            .find(|date| !self.is_disabled(*date))
+    }
+
+    fn shift_time(&mut self, minutes: i64) -> ActionResult {
+        let lower_bound = self
+            .config
+            .min_time
+            .map(|time| i64::from(time.num_seconds_from_midnight()))
+            .unwrap_or(0);
+        let upper_bound = self
+            .config
+            .max_time
+            .map(|time| i64::from(time.num_seconds_from_midnight()))
+            .unwrap_or(i64::from(MINUTES_IN_DAY) * SECONDS_IN_MINUTE - 1);
+
+        // time selection does not wrap around midnight, as the date is already set.
+        let seconds = i64::from(self.current_time.num_seconds_from_midnight())
+            .saturating_add(minutes.saturating_mul(SECONDS_IN_MINUTE))
+            .clamp(lower_bound, upper_bound);
+
+        let new_time = u32::try_from(seconds)
+            .ok()
+            .and_then(|seconds| NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0))
+            .unwrap_or(self.current_time);
+
+        if new_time == self.current_time {
+            return ActionResult::Clean;
         }
 
+        self.current_time = new_time;
         ActionResult::NeedsRedraw
     }
 
+    fn time_step(&self) -> i64 {
+        self.config.time_step_minutes.map(i64::from).unwrap_or(1)
+    }
+
+    fn handle_time_stage(&mut self, action: DateSelectPromptAction) -> ActionResult {
+        match action {
+            DateSelectPromptAction::GoToPrevWeek => self.shift_time(self.time_step()),
+            DateSelectPromptAction::GoToNextWeek => self.shift_time(-self.time_step()),
+            DateSelectPromptAction::GoToPrevDay => self.shift_time(-MINUTES_IN_HOUR),
+            DateSelectPromptAction::GoToNextDay => self.shift_time(MINUTES_IN_HOUR),
+            _ => ActionResult::Clean,
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
+        let mut warning = None;
+
         for validator in &self.validators {
             match validator.validate(self.cur_answer()) {
                 Ok(Validation::Valid) => {}
+                Ok(Validation::Warning(msg)) => {
+                    warning.get_or_insert(msg);
+                }
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
         }
 
-        Ok(Validation::Valid)
+        Ok(warning
+            .map(Validation::Warning)
+            .unwrap_or(Validation::Valid))
     }
 
     fn cur_answer(&self) -> NaiveDate {
@@ -117,33 +260,83 @@ where
 {
     type Config = DateSelectConfig;
     type InnerAction = DateSelectPromptAction;
-    type Output = NaiveDate;
+    type Output = NaiveDateTime;
 
     fn message(&self) -> &str {
         self.message
     }
 
-    fn format_answer(&self, answer: &NaiveDate) -> String {
-        (self.formatter)(*answer)
+    fn format_answer(&self, answer: &NaiveDateTime) -> String {
+        let date = (self.formatter)(answer.date());
+
+        match self.config.time_step_minutes {
+            Some(_) => format!("{} {}", date, answer.format("%H:%M")),
+            None => date,
+        }
     }
 
     fn config(&self) -> &DateSelectConfig {
         &self.config
     }
 
-    fn submit(&mut self) -> InquireResult<Option<NaiveDate>> {
-        let answer = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.cur_answer()),
+    fn pre_cancel(&mut self) -> InquireResult<bool> {
+        if self.stage == Stage::Time {
+            self.stage = Stage::Date;
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn submit(&mut self) -> InquireResult<Option<NaiveDateTime>> {
+        if self.stage == Stage::Time {
+            return Ok(Some(self.cur_answer().and_time(self.current_time)));
+        }
+
+        if self.is_disabled(self.current_date) {
+            if let Some(date) = self
+                .nearest_enabled_date(self.current_date, true)
+                .or_else(|| self.nearest_enabled_date(self.current_date, false))
+            {
+                self.current_date = date;
+            }
+            return Ok(None);
+        }
+
+        match self.validate_current_answer()? {
+            Validation::Valid => {}
+            Validation::Warning(msg) => {
+                self.error = None;
+                let answer = self.cur_answer();
+                if !AnswerWarning::confirm(&mut self.warning, msg, answer) {
+                    return Ok(None);
+                }
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
+                self.warning = None;
+                return Ok(None);
+            }
+        }
+
+        self.error = None;
+
+        let answer = match self.config.time_step_minutes {
+            Some(_) => {
+                self.stage = Stage::Time;
                 None
             }
+            None => Some(self.cur_answer().and_time(NaiveTime::MIN)),
         };
 
         Ok(answer)
     }
 
     fn handle(&mut self, action: DateSelectPromptAction) -> InquireResult<ActionResult> {
+        if self.stage == Stage::Time {
+            return Ok(self.handle_time_stage(action));
+        }
+
         let result = match action {
             DateSelectPromptAction::GoToPrevWeek => self.shift_date(
                 Duration::try_weeks(-1)
@@ -165,6 +358,7 @@ where
             DateSelectPromptAction::GoToNextYear => self.shift_months(12),
             DateSelectPromptAction::GoToPrevMonth => self.shift_months(-1),
             DateSelectPromptAction::GoToNextMonth => self.shift_months(1),
+            DateSelectPromptAction::GoToToday => self.go_to_today(),
         };
 
         Ok(result)
@@ -177,19 +371,34 @@ where
             backend.render_error_message(err)?;
         }
 
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        }
+
         backend.render_calendar_prompt(prompt)?;
 
         backend.render_calendar(
             get_month(self.current_date.month()),
             self.current_date.year(),
             self.config.week_start,
+            self.config.week_numbers,
             get_current_date(),
             self.current_date,
             self.config.min_date,
             self.config.max_date,
+            &|date| self.is_disabled(date),
         )?;
 
-        if let Some(help_message) = self.help_message {
+        if self.config.time_step_minutes.is_some() {
+            backend.render_time_selector(self.current_time, self.stage == Stage::Time)?;
+        }
+
+        let help_message = match self.stage {
+            Stage::Date => self.help_message,
+            Stage::Time => DateSelect::DEFAULT_TIME_HELP_MESSAGE,
+        };
+
+        if let Some(help_message) = help_message {
             backend.render_help_message(help_message)?;
         }
 
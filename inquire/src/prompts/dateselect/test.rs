@@ -3,14 +3,12 @@ use std::vec;
 use crate::{
     date_utils::get_current_date,
     error::InquireResult,
-    ui::{
-        test::{FakeBackend, Token},
-        Key, KeyModifiers,
-    },
+    test::{FakeBackend, Token},
+    ui::{Key, KeyModifiers},
     validator::{ErrorMessage, Validation},
     DateSelect, InquireError,
 };
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveTime};
 
 fn default<'a>() -> DateSelect<'a> {
     DateSelect::new("Question?")
@@ -811,7 +809,7 @@ fn ctrl_c_interrupts_prompt() -> InquireResult<()> {
 
     assert_eq!(
         1,
-        backend.frames.len(),
+        backend.frames().len(),
         "Only an initial frame should have been rendered",
     );
 
@@ -1017,3 +1015,306 @@ fn multiple_validators_are_respected() -> InquireResult<()> {
 
     Ok(())
 }
+
+fn time(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+}
+
+#[test]
+fn time_selection_adjusts_time_by_step_and_hours() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let date = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(date)
+        .with_starting_time(time(12, 0))
+        .with_time_selection(15)
+        .prompt_datetime_with_backend(&mut backend)?;
+
+    assert_eq!(date.and_time(time(13, 15)), ans);
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::TimeSelector {
+        time: time(12, 0),
+        active: false,
+    }));
+    assert!(frames[1].has_token(&Token::TimeSelector {
+        time: time(12, 0),
+        active: true,
+    }));
+    assert!(frames.last().unwrap().has_token(&Token::AnsweredPrompt(
+        "Question".into(),
+        "January 9, 2021 13:15".into()
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn time_selection_cancel_goes_back_to_calendar() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Escape,
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let date = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(date)
+        .with_time_selection(30)
+        .prompt_datetime_with_backend(&mut backend)?;
+
+    assert_eq!(date.succ_opt().unwrap().and_time(time(0, 0)), ans);
+
+    Ok(())
+}
+
+#[test]
+fn time_selection_respects_min_and_max_time() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Enter,
+        Key::Down(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let date = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(date)
+        .with_starting_time(time(9, 0))
+        .with_min_time(time(9, 0))
+        .with_max_time(time(10, 30))
+        .with_time_selection(10)
+        .prompt_datetime_with_backend(&mut backend)?;
+
+    assert_eq!(date.and_time(time(10, 30)), ans);
+
+    Ok(())
+}
+
+#[test]
+fn prompt_datetime_requires_time_selection() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = DateSelect::new("Question").prompt_datetime_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn prompt_ignores_time_selection() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let date = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(date)
+        .with_time_selection(15)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(date, ans);
+    assert!(!backend.frames()[0].has_token(&Token::TimeSelector {
+        time: time(0, 0),
+        active: false,
+    }));
+
+    Ok(())
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), chrono::Weekday::Sat | chrono::Weekday::Sun)
+}
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn navigation_skips_disabled_dates() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(ymd(2023, 1, 6))
+        .with_disabled_predicate(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ymd(2023, 1, 9), ans);
+
+    let selected_dates: Vec<NaiveDate> = backend
+        .frames()
+        .iter()
+        .filter_map(|frame| {
+            frame.tokens().iter().find_map(|t| match t {
+                Token::Calendar { selected_date, .. } => Some(*selected_date),
+                _ => None,
+            })
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ymd(2023, 1, 6),
+            ymd(2023, 1, 9),
+            ymd(2023, 1, 6),
+            ymd(2023, 1, 9)
+        ],
+        selected_dates
+    );
+
+    Ok(())
+}
+
+#[test]
+fn navigation_stays_put_when_no_enabled_date_is_in_bounds() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(ymd(2023, 1, 6))
+        .with_max_date(ymd(2023, 1, 8))
+        .with_disabled_predicate(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ymd(2023, 1, 6), ans);
+
+    Ok(())
+}
+
+#[test]
+fn submitting_disabled_starting_date_moves_to_next_enabled_date() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter, Key::Enter]);
+
+    let ans = DateSelect::new("Question")
+        .with_starting_date(ymd(2023, 1, 7))
+        .with_disabled_predicate(&is_weekend)
+        .prompt_with_backend(&mut backend)?;
+
+    assert_eq!(ymd(2023, 1, 9), ans);
+    assert_eq!(3, backend.frames().len());
+
+    Ok(())
+}
+
+#[test]
+fn disabled_dates_are_sent_to_backend() -> InquireResult<()> {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let _ = DateSelect::new("Question")
+        .with_starting_date(ymd(2023, 1, 6))
+        .with_disabled_predicate(&|date| date == ymd(2023, 1, 16) || date == ymd(2023, 2, 1))
+        .prompt_with_backend(&mut backend)?;
+
+    assert!(backend.frames()[0].tokens().iter().any(|t| matches!(
+        t,
+        Token::Calendar { disabled_dates, .. } if *disabled_dates == vec![ymd(2023, 1, 16), ymd(2023, 2, 1)]
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn week_numbers_are_rendered_for_each_row() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    DateSelect::new("Question")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 1, 10).unwrap())
+        .with_week_start(chrono::Weekday::Mon)
+        .with_week_numbers()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let week_numbers = backend.frames()[0]
+        .tokens()
+        .iter()
+        .filter_map(|token| match token {
+            Token::WeekNumber(week) => Some(*week),
+            _ => None,
+        })
+        .collect::<Vec<u32>>();
+
+    // january 2021 starts in the last week of 2020, its 53rd.
+    assert_eq!(vec![53, 1, 2, 3, 4, 5], week_numbers);
+}
+
+#[test]
+fn week_numbers_are_not_rendered_by_default() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    DateSelect::new("Question")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(!backend.frames()[0]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::WeekNumber(_))));
+}
+
+date_test!(
+    t_moves_cursor_to_today,
+    vec![Key::Char('t', KeyModifiers::NONE), Key::Enter],
+    get_current_date(),
+    DateSelect::new("Date").with_starting_date(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+date_test!(
+    home_moves_cursor_to_today,
+    vec![Key::Home, Key::Enter],
+    get_current_date(),
+    DateSelect::new("Date").with_starting_date(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+);
+
+date_test!(
+    go_to_today_is_clamped_to_max_date,
+    vec![Key::Char('t', KeyModifiers::NONE), Key::Enter],
+    NaiveDate::from_ymd_opt(2021, 2, 1).unwrap(),
+    DateSelect::new("Date")
+        .with_starting_date(NaiveDate::from_ymd_opt(2021, 1, 9).unwrap())
+        .with_max_date(NaiveDate::from_ymd_opt(2021, 2, 1).unwrap())
+);
+
+#[test]
+fn today_is_marked_separately_from_selected_date() {
+    let mut backend = FakeBackend::new(vec![Key::Char('t', KeyModifiers::NONE), Key::Enter]);
+    let starting_date = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+    let today = get_current_date();
+
+    DateSelect::new("Date")
+        .with_starting_date(starting_date)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let calendar_dates = |frame: usize| {
+        backend.frames()[frame]
+            .tokens()
+            .iter()
+            .find_map(|token| match token {
+                Token::Calendar {
+                    today,
+                    selected_date,
+                    ..
+                } => Some((*today, *selected_date)),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    assert_eq!((today, starting_date), calendar_dates(0));
+    assert_eq!((today, today), calendar_dates(1));
+}
@@ -1,18 +1,35 @@
-use crate::{ui::Key, InnerAction};
+use crate::{
+    ui::{Key, KeyModifiers},
+    InnerAction, InputAction,
+};
 
 use super::config::EditorConfig;
 
 /// Set of actions for an EditorPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EditorPromptAction {
     /// Open the editor.
     OpenEditor,
+    /// Action on the text area of the inline mode.
+    ValueInput(InputAction),
+    /// Moves the cursor of the inline mode text area to the line above.
+    MoveToPreviousLine,
+    /// Moves the cursor of the inline mode text area to the line below.
+    MoveToNextLine,
 }
 
 impl InnerAction for EditorPromptAction {
     type Config = EditorConfig;
 
-    fn from_key(key: Key, _config: &EditorConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &EditorConfig) -> Option<Self> {
+        if config.inline_mode {
+            return match key {
+                Key::Up(KeyModifiers::NONE) => Some(Self::MoveToPreviousLine),
+                Key::Down(KeyModifiers::NONE) => Some(Self::MoveToNextLine),
+                key => InputAction::from_key(key, &()).map(Self::ValueInput),
+            };
+        }
+
         let action = match key {
             Key::Char('e', _) => Self::OpenEditor,
             _ => return None,
@@ -20,4 +37,11 @@ impl InnerAction for EditorPromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        match self {
+            Self::ValueInput(action) => Some(action),
+            _ => None,
+        }
+    }
 }
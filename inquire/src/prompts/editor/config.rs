@@ -1,4 +1,4 @@
-use crate::Editor;
+use crate::{ui::Key, Editor};
 use std::ffi::OsString;
 
 /// Configuration settings used in the execution of an EditorPrompt.
@@ -8,6 +8,10 @@ pub struct EditorConfig {
     pub editor_command: OsString,
     /// The arguments to pass to the editor command.
     pub editor_command_args: Vec<OsString>,
+    /// Whether the text is written in the terminal instead of an external editor.
+    pub inline_mode: bool,
+    /// Key submitting the answer instead of the default one, when set.
+    pub submit_key: Option<Key>,
 }
 
 impl<'a> From<&Editor<'a>> for EditorConfig {
@@ -15,6 +19,8 @@ impl<'a> From<&Editor<'a>> for EditorConfig {
         Self {
             editor_command: value.editor_command.into(),
             editor_command_args: value.editor_command_args.iter().map(Into::into).collect(),
+            inline_mode: value.inline_mode,
+            submit_key: value.submit_key.clone(),
         }
     }
 }
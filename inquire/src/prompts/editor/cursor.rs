@@ -0,0 +1,113 @@
+use std::{ffi::OsStr, path::Path};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Position of the cursor marker in the predefined text.
+///
+/// The line is 1-based, to match the `+line` argument of terminal editors,
+/// while the column is the 0-based grapheme offset in that line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CursorPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Removes the first occurrence of `marker` from `text`, returning the
+/// stripped text and the position the marker was at.
+///
+/// When the marker is empty or not found, the text is returned unchanged
+/// without a position.
+pub fn strip_cursor_marker(text: &str, marker: &str) -> (String, Option<CursorPosition>) {
+    let index = match text.find(marker) {
+        Some(index) if !marker.is_empty() => index,
+        _ => return (text.to_owned(), None),
+    };
+
+    let before = &text[..index];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+    let position = CursorPosition {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].graphemes(true).count(),
+    };
+
+    let stripped = format!("{}{}", before, &text[index + marker.len()..]);
+
+    (stripped, Some(position))
+}
+
+/// Returns the argument that makes the editor open the file at the given
+/// position, or `None` when the editor is not known to support it.
+///
+/// Supported editors are `vi`, `vim`, `nvim` and `emacs`, which receive the
+/// line, and `nano`, which also receives the column.
+pub fn cursor_arg(editor_command: &OsStr, position: CursorPosition) -> Option<String> {
+    let editor = Path::new(editor_command).file_stem()?.to_str()?;
+
+    match editor {
+        "vi" | "vim" | "nvim" | "emacs" => Some(format!("+{}", position.line)),
+        "nano" => Some(format!("+{},{}", position.line, position.column + 1)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+
+    use super::{cursor_arg, strip_cursor_marker, CursorPosition};
+
+    #[test]
+    fn marker_is_stripped_and_its_position_recorded() {
+        let (text, position) = strip_cursor_marker("Title\n\nBody: |here", "|");
+
+        assert_eq!("Title\n\nBody: here", text);
+        assert_eq!(Some(CursorPosition { line: 3, column: 6 }), position);
+    }
+
+    #[test]
+    fn multi_character_marker_at_start_of_text() {
+        let (text, position) = strip_cursor_marker("${cursor}rest", "${cursor}");
+
+        assert_eq!("rest", text);
+        assert_eq!(Some(CursorPosition { line: 1, column: 0 }), position);
+    }
+
+    #[test]
+    fn only_first_occurrence_of_marker_is_stripped() {
+        let (text, position) = strip_cursor_marker("a|b\n|c", "|");
+
+        assert_eq!("ab\n|c", text);
+        assert_eq!(Some(CursorPosition { line: 1, column: 1 }), position);
+    }
+
+    #[test]
+    fn column_counts_graphemes() {
+        let (text, position) = strip_cursor_marker("ção|", "|");
+
+        assert_eq!("ção", text);
+        assert_eq!(Some(CursorPosition { line: 1, column: 3 }), position);
+    }
+
+    #[test]
+    fn missing_or_empty_marker_leaves_text_unchanged() {
+        assert_eq!(("abc".to_owned(), None), strip_cursor_marker("abc", "|"));
+        assert_eq!(("abc".to_owned(), None), strip_cursor_marker("abc", ""));
+    }
+
+    #[test]
+    fn cursor_arg_depends_on_editor() {
+        let position = CursorPosition { line: 4, column: 2 };
+
+        assert_eq!(
+            Some("+4".to_owned()),
+            cursor_arg(OsStr::new("/usr/bin/vim"), position)
+        );
+        assert_eq!(
+            Some("+4,3".to_owned()),
+            cursor_arg(OsStr::new("nano"), position)
+        );
+        assert_eq!(None, cursor_arg(OsStr::new("code"), position));
+        assert_eq!(None, cursor_arg(OsStr::new(""), position));
+    }
+}
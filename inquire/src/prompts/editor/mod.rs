@@ -1,22 +1,27 @@
 mod action;
 mod config;
+mod cursor;
 mod prompt;
+#[cfg(test)]
+mod test;
 
 pub use action::*;
 
 use std::{
     env,
     ffi::{OsStr, OsString},
+    path::PathBuf,
 };
 
 use once_cell::sync::Lazy;
 
 use crate::{
+    counter::CounterKind,
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, EditorBackend, RenderConfig},
+    terminal::{get_default_terminal, record_answer},
+    ui::{Backend, EditorBackend, Key, RenderConfig, Styled},
     validator::StringValidator,
 };
 
@@ -36,13 +41,17 @@ static DEFAULT_EDITOR: Lazy<OsString> = Lazy::new(get_default_editor_command);
 ///
 /// If the user presses `enter` without ever modyfing the temporary file, it will be treated as an empty submission. If this is unwanted behavior, you can control the user input by using validators.
 ///
+/// Alternatively, the prompt can be set to [inline mode](Editor::with_inline_mode), in which the text is written in a simple text area rendered in the terminal itself, without spawning any process.
+///
 /// Finally, this prompt allows a great range of customizable options as all others:
 ///
 /// - **Prompt message**: Main message when prompting the user for input, `"What is your name?"` in the example above.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Editor command and its args**: If you want to override the selected editor, you can pass over the command and additional args.
 /// - **File extension**: Custom extension for the temporary file, useful as a proxy for proper syntax highlighting for example.
+/// - **Temporary file location**: Custom directory and name prefix of the temporary file, useful for tools watching specific directories.
 /// - **Predefined text**: Pre-defined text to be written to the temporary file before the user is allowed to edit it.
+/// - **Cursor marker**: Marker in the predefined text where the cursor is placed when the editor opens, for editors that support it.
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, a successfully submitted answer is displayed to the user simply as `<received>`.
@@ -62,12 +71,36 @@ pub struct Editor<'a> {
     /// The dot prefix should be included in the string, e.g. ".rs".
     pub file_extension: &'a str,
 
+    /// Prefix of the name of the file opened in the text editor.
+    pub temp_file_prefix: &'a str,
+
+    /// Directory in which the file opened in the text editor is created.
+    ///
+    /// When `None`, the file is created in the default temporary directory of
+    /// the system.
+    pub temp_dir: Option<PathBuf>,
+
     /// Predefined text to be present on the text file on the text editor.
     pub predefined_text: Option<&'a str>,
 
+    /// Marker in the predefined text indicating where the cursor should be
+    /// placed when the editor opens.
+    pub cursor_marker: Option<&'a str>,
+
+    /// Whether the text is written in a text area rendered in the terminal,
+    /// instead of in an external editor.
+    pub inline_mode: bool,
+
+    /// Key submitting the answer instead of the default one, when set.
+    pub submit_key: Option<Key>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Counters of the length of the text displayed below the prompt, updated
+    /// after each edit.
+    pub counters: Vec<CounterKind>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
@@ -100,6 +133,9 @@ impl<'a> Editor<'a> {
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
+    /// Default prefix of the name of the temporary file.
+    pub const DEFAULT_TEMP_FILE_PREFIX: &'a str = "tmp-";
+
     /// Creates a [Editor] with the provided message and default options.
     pub fn new(message: &'a str) -> Self {
         Self {
@@ -107,8 +143,14 @@ impl<'a> Editor<'a> {
             editor_command: &DEFAULT_EDITOR,
             editor_command_args: &[],
             file_extension: ".txt",
+            temp_file_prefix: Self::DEFAULT_TEMP_FILE_PREFIX,
+            temp_dir: None,
             predefined_text: None,
+            cursor_marker: None,
+            inline_mode: false,
+            submit_key: None,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            counters: vec![],
             validators: Self::DEFAULT_VALIDATORS,
             formatter: Self::DEFAULT_FORMATTER,
             render_config: RenderConfig::default(),
@@ -127,12 +169,45 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets a marker that indicates, in the predefined text, where the cursor
+    /// should be placed when the editor opens.
+    ///
+    /// The first occurrence of the marker is stripped from the text before it
+    /// is written to the temporary file. Its line is then passed to the editor
+    /// as a `+line` argument, which is supported by `vi`, `vim`, `nvim`,
+    /// `emacs` and `nano`, the latter also receiving the column. Other editors
+    /// open the file as usual, with the marker still stripped.
+    ///
+    /// In inline mode, the cursor of the text area is placed at the marker.
+    pub fn with_cursor_marker(mut self, marker: &'a str) -> Self {
+        self.cursor_marker = Some(marker);
+        self
+    }
+
     /// Sets the file extension of the temporary file.
     pub fn with_file_extension(mut self, file_extension: &'a str) -> Self {
         self.file_extension = file_extension;
         self
     }
 
+    /// Sets the prefix of the name of the temporary file, making it recognizable
+    /// by tools watching the directory it is created in.
+    pub fn with_temp_file_prefix(mut self, prefix: &'a str) -> Self {
+        self.temp_file_prefix = prefix;
+        self
+    }
+
+    /// Sets the directory in which the temporary file is created, instead of the
+    /// default temporary directory of the system.
+    ///
+    /// The file is still deleted once the prompt finishes. If the directory does
+    /// not exist or is not writable, the prompt fails with an
+    /// `InquireError::InvalidConfiguration` error.
+    pub fn with_temp_dir(mut self, dir: PathBuf) -> Self {
+        self.temp_dir = Some(dir);
+        self
+    }
+
     /// Sets the command to open the editor.
     pub fn with_editor_command(mut self, editor_command: &'a OsStr) -> Self {
         self.editor_command = editor_command;
@@ -145,6 +220,44 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Writes the text in a text area rendered in the terminal instead of
+    /// launching an external editor, which is lighter for quick notes and
+    /// works where no editor is available.
+    ///
+    /// In inline mode, the predefined text is the initial content of the text
+    /// area, and the editor command, its args and the file extension are ignored.
+    ///
+    /// Keybindings in inline mode:
+    ///
+    /// - `enter` inserts a line break.
+    /// - `ctrl+d` submits the text.
+    /// - Arrow keys move the cursor, with `up` and `down` moving across lines.
+    /// - `esc` cancels the prompt.
+    pub fn with_inline_mode(mut self) -> Self {
+        self.inline_mode = true;
+        self
+    }
+
+    /// Sets the key submitting the answer, e.g. `ctrl+s`, instead of the
+    /// default one, `enter`, or `ctrl+d` in inline mode, which then no longer
+    /// submits the prompt.
+    ///
+    /// The keys cancelling (`esc`) and interrupting (`ctrl+c`) the prompt are
+    /// not affected.
+    pub fn with_submit_key(mut self, key: Key) -> Self {
+        self.submit_key = Some(key);
+        self
+    }
+
+    /// Adds a counter of the length of the text, e.g. `[142 chars]`, displayed
+    /// below the prompt once the text is edited.
+    ///
+    /// Several counters are displayed together, e.g. `[142 chars, 25 words]`.
+    pub fn with_counter(mut self, counter: CounterKind) -> Self {
+        self.counters.push(counter);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
@@ -196,6 +309,26 @@ impl<'a> Editor<'a> {
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -218,13 +351,15 @@ impl<'a> Editor<'a> {
     pub fn prompt(self) -> InquireResult<String> {
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        EditorPrompt::new(self)?.prompt_with_render_callback(&mut backend, record_answer)
     }
 
-    pub(crate) fn prompt_with_backend<B: EditorBackend>(
-        self,
-        backend: &mut B,
-    ) -> InquireResult<String> {
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: EditorBackend>(self, backend: &mut B) -> InquireResult<String> {
         EditorPrompt::new(self)?.prompt(backend)
     }
 }
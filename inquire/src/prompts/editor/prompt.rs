@@ -1,17 +1,31 @@
-use std::{fs, io::Write, path::Path, process};
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::Path,
+    process,
+};
 
 use tempfile::NamedTempFile;
 
 use crate::{
+    counter::{format_counts, CounterKind},
     error::InquireResult,
     formatter::StringFormatter,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::EditorBackend,
-    validator::{ErrorMessage, StringValidator, Validation},
-    Editor, InquireError,
+    input::MultilineInput,
+    prompts::{
+        action::Action,
+        prompt::{ActionResult, Prompt},
+    },
+    ui::{EditorBackend, Key, KeyModifiers},
+    validator::{AnswerWarning, ErrorMessage, StringValidator, Validation},
+    Editor, InputAction, InquireError,
 };
 
-use super::{action::EditorPromptAction, config::EditorConfig};
+use super::{
+    action::EditorPromptAction,
+    config::EditorConfig,
+    cursor::{cursor_arg, strip_cursor_marker, CursorPosition},
+};
 
 pub struct EditorPrompt<'a> {
     message: &'a str,
@@ -20,7 +34,20 @@ pub struct EditorPrompt<'a> {
     formatter: StringFormatter<'a>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
-    tmp_file: NamedTempFile,
+    warning: Option<AnswerWarning<String>>,
+    content: EditorContent,
+    cursor_position: Option<CursorPosition>,
+    counters: Vec<CounterKind>,
+    /// Counts of the text in the temporary file, computed after each edit.
+    file_counts: Option<String>,
+}
+
+/// Where the text of the prompt is written to.
+enum EditorContent {
+    /// Temporary file edited in an external editor.
+    File(NamedTempFile),
+    /// Text area rendered in the terminal, in inline mode.
+    Inline(MultilineInput),
 }
 
 impl<'a> From<&'a str> for Editor<'a> {
@@ -31,6 +58,22 @@ impl<'a> From<&'a str> for Editor<'a> {
 
 impl<'a> EditorPrompt<'a> {
     pub fn new(so: Editor<'a>) -> InquireResult<Self> {
+        let predefined_text = so.predefined_text.unwrap_or("");
+        let (text, cursor_position) = match so.cursor_marker {
+            Some(marker) => strip_cursor_marker(predefined_text, marker),
+            None => (predefined_text.to_owned(), None),
+        };
+
+        let content = if so.inline_mode {
+            let mut input = MultilineInput::new_with(&text);
+            if let Some(position) = cursor_position {
+                input = input.with_cursor(position.line - 1, position.column);
+            }
+            EditorContent::Inline(input)
+        } else {
+            EditorContent::File(Self::create_file(&so, &text)?)
+        };
+
         Ok(Self {
             message: so.message,
             config: (&so).into(),
@@ -38,57 +81,121 @@ impl<'a> EditorPrompt<'a> {
             formatter: so.formatter,
             validators: so.validators,
             error: None,
-            tmp_file: Self::create_file(so.file_extension, so.predefined_text)?,
+            warning: None,
+            content,
+            cursor_position,
+            counters: so.counters,
+            file_counts: None,
         })
     }
 
-    fn create_file(
-        file_extension: &str,
-        predefined_text: Option<&str>,
-    ) -> std::io::Result<NamedTempFile> {
-        let mut tmp_file = tempfile::Builder::new()
-            .prefix("tmp-")
-            .suffix(file_extension)
-            .rand_bytes(10)
-            .tempfile()?;
+    fn create_file(so: &Editor<'_>, text: &str) -> InquireResult<NamedTempFile> {
+        let mut builder = tempfile::Builder::new();
+        builder
+            .prefix(so.temp_file_prefix)
+            .suffix(so.file_extension)
+            .rand_bytes(10);
+
+        let mut tmp_file = match &so.temp_dir {
+            Some(dir) if !dir.is_dir() => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Temporary file directory {} does not exist",
+                    dir.display()
+                )));
+            }
+            Some(dir) => builder.tempfile_in(dir).map_err(|err| match err.kind() {
+                ErrorKind::PermissionDenied => InquireError::InvalidConfiguration(format!(
+                    "Temporary file directory {} is not writable",
+                    dir.display()
+                )),
+                _ => InquireError::IO(err),
+            })?,
+            None => builder.tempfile()?,
+        };
 
-        if let Some(predefined_text) = predefined_text {
-            tmp_file.write_all(predefined_text.as_bytes())?;
+        if !text.is_empty() {
+            tmp_file.write_all(text.as_bytes())?;
             tmp_file.flush()?;
         }
 
         Ok(tmp_file)
     }
 
+    #[cfg(test)]
+    pub fn temp_file_path(&self) -> Option<&Path> {
+        match &self.content {
+            EditorContent::File(tmp_file) => Some(tmp_file.path()),
+            EditorContent::Inline(_) => None,
+        }
+    }
+
     fn run_editor(&mut self) -> InquireResult<()> {
-        process::Command::new(&self.config.editor_command)
-            .args(&self.config.editor_command_args)
-            .arg(self.tmp_file.path())
-            .spawn()?
-            .wait()?;
+        if let EditorContent::File(tmp_file) = &self.content {
+            let cursor_arg = self
+                .cursor_position
+                .and_then(|position| cursor_arg(&self.config.editor_command, position));
+
+            process::Command::new(&self.config.editor_command)
+                .args(&self.config.editor_command_args)
+                .args(cursor_arg)
+                .arg(tmp_file.path())
+                .spawn()?
+                .wait()?;
+        }
 
         Ok(())
     }
 
+    fn update_file_counts(&mut self) -> InquireResult<()> {
+        if self.counters.is_empty() {
+            return Ok(());
+        }
+
+        let answer = self.cur_answer()?;
+        self.file_counts = format_counts(&self.counters, |kind| kind.count(&answer));
+
+        Ok(())
+    }
+
+    fn counts(&self) -> Option<String> {
+        match &self.content {
+            EditorContent::File(_) => self.file_counts.clone(),
+            EditorContent::Inline(input) => {
+                let content = input.content();
+                format_counts(&self.counters, |kind| kind.count(&content))
+            }
+        }
+    }
+
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if self.validators.is_empty() {
             return Ok(Validation::Valid);
         }
 
         let cur_answer = self.cur_answer()?;
+        let mut warning = None;
+
         for validator in &self.validators {
             match validator.validate(&cur_answer) {
                 Ok(Validation::Valid) => {}
+                Ok(Validation::Warning(msg)) => {
+                    warning.get_or_insert(msg);
+                }
                 Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
                 Err(err) => return Err(InquireError::Custom(err)),
             }
         }
 
-        Ok(Validation::Valid)
+        Ok(warning
+            .map(Validation::Warning)
+            .unwrap_or(Validation::Valid))
     }
 
     fn cur_answer(&self) -> InquireResult<String> {
-        let mut submission = fs::read_to_string(self.tmp_file.path())?;
+        let mut submission = match &self.content {
+            EditorContent::File(tmp_file) => fs::read_to_string(tmp_file.path())?,
+            EditorContent::Inline(input) => input.content(),
+        };
         let len = submission.trim_end_matches(&['\n', '\r'][..]).len();
         submission.truncate(len);
 
@@ -112,6 +219,26 @@ where
         &self.config
     }
 
+    fn submit_key(&self) -> Option<&Key> {
+        self.config.submit_key.as_ref()
+    }
+
+    fn action_from_key(&self, key: Key) -> Option<Action<EditorPromptAction>> {
+        if !self.config.inline_mode {
+            return Action::from_key(key, &self.config);
+        }
+
+        match key {
+            Key::Char('d', KeyModifiers::CONTROL) => Some(Action::Submit),
+            Key::Enter
+            | Key::Char('\n', KeyModifiers::NONE)
+            | Key::Char('j', KeyModifiers::CONTROL) => Some(Action::Inner(
+                EditorPromptAction::ValueInput(InputAction::Write('\n')),
+            )),
+            key => Action::from_key(key, &self.config),
+        }
+    }
+
     fn format_answer(&self, answer: &String) -> String {
         (self.formatter)(answer)
     }
@@ -119,8 +246,14 @@ where
     fn submit(&mut self) -> InquireResult<Option<String>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.cur_answer()?),
+            Validation::Warning(msg) => {
+                self.error = None;
+                let answer = self.cur_answer()?;
+                AnswerWarning::confirm(&mut self.warning, msg, answer.clone()).then_some(answer)
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
+                self.warning = None;
                 None
             }
         };
@@ -132,8 +265,21 @@ where
         match action {
             EditorPromptAction::OpenEditor => {
                 self.run_editor()?;
+                self.update_file_counts()?;
                 Ok(ActionResult::NeedsRedraw)
             }
+            EditorPromptAction::ValueInput(input_action) => match &mut self.content {
+                EditorContent::Inline(input) => Ok(input.handle(input_action).into()),
+                EditorContent::File(_) => Ok(ActionResult::Clean),
+            },
+            EditorPromptAction::MoveToPreviousLine => match &mut self.content {
+                EditorContent::Inline(input) => Ok(input.move_up().into()),
+                EditorContent::File(_) => Ok(ActionResult::Clean),
+            },
+            EditorPromptAction::MoveToNextLine => match &mut self.content {
+                EditorContent::Inline(input) => Ok(input.move_down().into()),
+                EditorContent::File(_) => Ok(ActionResult::Clean),
+            },
         }
     }
 
@@ -144,18 +290,31 @@ where
             backend.render_error_message(err)?;
         }
 
-        let path = Path::new(&self.config.editor_command);
-        let editor_name = path
-            .file_stem()
-            .and_then(|f| f.to_str())
-            .unwrap_or("editor");
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        }
+
+        match &self.content {
+            EditorContent::File(_) => {
+                let path = Path::new(&self.config.editor_command);
+                let editor_name = path
+                    .file_stem()
+                    .and_then(|f| f.to_str())
+                    .unwrap_or("editor");
 
-        backend.render_prompt(prompt, editor_name)?;
+                backend.render_prompt(prompt, editor_name)?;
+            }
+            EditorContent::Inline(input) => backend.render_inline_prompt(prompt, input)?,
+        }
 
         if let Some(message) = self.help_message {
             backend.render_help_message(message)?;
         }
 
+        if let Some(counts) = self.counts() {
+            backend.render_help_message(&counts)?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,211 @@
+use std::ffi::OsStr;
+
+use super::prompt::EditorPrompt;
+use crate::{
+    counter::CounterKind,
+    error::InquireError,
+    test::{FakeBackend, Token},
+    ui::{Key, KeyModifiers},
+    validator::{ErrorMessage, Validation},
+    Editor,
+};
+
+macro_rules! text_to_events {
+    ($text:expr) => {{
+        $text
+            .chars()
+            .map(|c| Key::Char(c, KeyModifiers::NONE))
+            .collect::<Vec<Key>>()
+    }};
+}
+
+fn submit() -> Key {
+    Key::Char('d', KeyModifiers::CONTROL)
+}
+
+#[test]
+fn inline_mode_inserts_line_breaks_on_enter() {
+    let mut events = text_to_events!("first");
+    events.push(Key::Enter);
+    events.extend(text_to_events!("second"));
+    events.push(submit());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Editor::new("Notes")
+        .with_inline_mode()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("first\nsecond", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::Input(String::from("first\nsecond")))));
+}
+
+#[test]
+fn inline_mode_submits_on_the_configured_key_only() {
+    let save = Key::Char('s', KeyModifiers::CONTROL);
+
+    let mut events = text_to_events!("first");
+    events.push(submit());
+    events.push(Key::Enter);
+    events.extend(text_to_events!("second"));
+    events.push(save.clone());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Editor::new("Notes")
+        .with_inline_mode()
+        .with_submit_key(save)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("first\nsecond", ans);
+}
+
+#[test]
+fn inline_mode_moves_across_lines() {
+    let mut events = vec![Key::Up(KeyModifiers::NONE), Key::End];
+    events.extend(text_to_events!("!"));
+    events.push(Key::Down(KeyModifiers::NONE));
+    events.push(Key::Home);
+    events.extend(text_to_events!("> "));
+    events.push(submit());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Editor::new("Notes")
+        .with_inline_mode()
+        .with_predefined_text("hello\nworld")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("hello!\n> world", ans);
+}
+
+#[test]
+fn inline_mode_runs_validators_on_submit() {
+    let mut events = vec![submit()];
+    events.extend(text_to_events!("text"));
+    events.push(submit());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Editor::new("Notes")
+        .with_inline_mode()
+        .with_validator(|ans: &str| match ans.is_empty() {
+            true => Ok(Validation::Invalid("empty".into())),
+            false => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("text", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(|frame| frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom("empty".into())))));
+}
+
+#[test]
+fn temp_file_is_created_with_prefix_in_dir() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let editor = Editor::new("Notes")
+        .with_temp_dir(dir.path().to_path_buf())
+        .with_temp_file_prefix("notes-")
+        .with_file_extension(".md");
+    let prompt = EditorPrompt::new(editor).unwrap();
+
+    let path = prompt.temp_file_path().unwrap().to_path_buf();
+    let name = path.file_name().unwrap().to_str().unwrap();
+    assert_eq!(Some(dir.path()), path.parent());
+    assert!(name.starts_with("notes-"));
+    assert!(name.ends_with(".md"));
+    assert!(path.exists());
+
+    drop(prompt);
+    assert!(!path.exists());
+}
+
+#[test]
+fn missing_temp_dir_is_an_invalid_configuration() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let editor = Editor::new("Notes").with_temp_dir(dir.path().join("missing"));
+
+    assert!(matches!(
+        EditorPrompt::new(editor),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn cursor_marker_is_stripped_from_temp_file() {
+    let editor = Editor::new("Commit message")
+        .with_predefined_text("Title: <|>\n\nBody")
+        .with_cursor_marker("<|>");
+    let prompt = EditorPrompt::new(editor).unwrap();
+
+    let content = std::fs::read_to_string(prompt.temp_file_path().unwrap()).unwrap();
+    assert_eq!("Title: \n\nBody", content);
+}
+
+#[test]
+fn inline_mode_places_cursor_at_marker() {
+    let mut events = text_to_events!("fix");
+    events.push(submit());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Editor::new("Commit message")
+        .with_inline_mode()
+        .with_predefined_text("Title: |\n\nBody")
+        .with_cursor_marker("|")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("Title: fix\n\nBody", ans);
+}
+
+#[test]
+fn inline_mode_counts_the_text_on_every_keystroke() {
+    let mut events = text_to_events!("né");
+    events.push(Key::Enter);
+    events.extend(text_to_events!("ü"));
+    events.push(submit());
+
+    let mut backend = FakeBackend::new(events);
+    Editor::new("Notes")
+        .with_inline_mode()
+        .with_counter(CounterKind::Chars)
+        .with_counter(CounterKind::Bytes)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::HelpMessage("0 chars, 0 bytes".into())));
+    assert!(frames[2].has_token(&Token::HelpMessage("2 chars, 3 bytes".into())));
+    assert!(frames[4].has_token(&Token::HelpMessage("4 chars, 6 bytes".into())));
+}
+
+#[test]
+#[cfg(unix)]
+fn file_counts_are_displayed_after_editing() {
+    let args = [
+        OsStr::new("-c"),
+        OsStr::new("printf 'héllo wörld' > \"$0\""),
+    ];
+
+    let mut backend = FakeBackend::new(vec![Key::Char('e', KeyModifiers::NONE), Key::Enter]);
+    let ans = Editor::new("Notes")
+        .with_editor_command(OsStr::new("sh"))
+        .with_args(&args)
+        .with_counter(CounterKind::Words)
+        .with_counter(CounterKind::Chars)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let counts = Token::HelpMessage("2 words, 11 chars".into());
+    let frames = backend.frames();
+    assert_eq!("héllo wörld", ans);
+    assert!(!frames[0].has_token(&counts));
+    assert!(frames[1].has_token(&counts));
+}
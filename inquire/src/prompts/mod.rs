@@ -10,9 +10,11 @@ mod one_liners;
 mod password;
 mod prompt;
 mod select;
+mod session;
 #[cfg(test)]
 pub(crate) mod test;
 mod text;
+mod timeout;
 
 pub use action::*;
 pub use confirm::*;
@@ -26,4 +28,6 @@ pub use multiselect::*;
 pub use one_liners::*;
 pub use password::*;
 pub use select::*;
+pub use session::*;
 pub use text::*;
+pub use timeout::*;
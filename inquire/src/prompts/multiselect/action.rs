@@ -6,7 +6,7 @@ use crate::{
 use super::config::MultiSelectConfig;
 
 /// Set of actions for a MultiSelectPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum MultiSelectPromptAction {
     /// Action on the value text input handler.
     FilterInput(InputAction),
@@ -28,6 +28,17 @@ pub enum MultiSelectPromptAction {
     SelectAll,
     /// Deselects all options.
     ClearSelections,
+    /// Flips the selection of every option, including the ones hidden by the filter.
+    InvertSelections,
+    /// Selects all options matching the current filter, or deselects them
+    /// if they are all selected already. Other selections are kept.
+    ToggleVisibleOptions,
+    /// Sets the range anchor at the current option or, if it is set already,
+    /// toggles every option between the anchor and the cursor.
+    ToggleRange,
+    /// Toggles the option rendered at the given row of the prompt, where the
+    /// mouse was clicked.
+    Click(u16),
 }
 
 impl InnerAction for MultiSelectPromptAction {
@@ -49,17 +60,26 @@ impl InnerAction for MultiSelectPromptAction {
         }
 
         let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) | Key::ScrollUp => {
+                Self::MoveUp
+            }
             Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Down(KeyModifiers::NONE)
+            | Key::Char('n', KeyModifiers::CONTROL)
+            | Key::ScrollDown => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
             Key::Char(' ', KeyModifiers::NONE) => Self::ToggleCurrentOption,
+            Key::Char(' ', KeyModifiers::SHIFT) => Self::ToggleRange,
             Key::Right(KeyModifiers::NONE) => Self::SelectAll,
             Key::Left(KeyModifiers::NONE) => Self::ClearSelections,
+            // ctrl+i is indistinguishable from tab in most terminals.
+            Key::Char('r', KeyModifiers::CONTROL) => Self::InvertSelections,
+            Key::Char('t', KeyModifiers::CONTROL) => Self::ToggleVisibleOptions,
+            Key::Click { row, .. } => Self::Click(row),
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
                 None => return None,
@@ -68,4 +88,11 @@ impl InnerAction for MultiSelectPromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        match self {
+            Self::FilterInput(action) => Some(action),
+            _ => None,
+        }
+    }
 }
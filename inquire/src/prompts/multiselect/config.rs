@@ -1,4 +1,4 @@
-use crate::MultiSelect;
+use crate::{ui::StyleSheet, MultiSelect};
 
 /// Configuration settings used in the execution of a MultiSelectPrompt.
 #[derive(Copy, Clone, Debug)]
@@ -7,19 +7,43 @@ pub struct MultiSelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether the page size is computed from the height of the terminal.
+    pub auto_page_size: bool,
     /// Whether to keep the filter text when an option is selected.
     pub keep_filter: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether options that have an URL are rendered as hyperlinks.
+    pub emit_hyperlinks: bool,
+    /// Whether the cursor wraps around when moving past the first or last option.
+    pub wrap_around: bool,
+    /// Whether to return the selections in the order they were checked.
+    pub preserve_selection_order: bool,
+    /// Whether mouse events are reported to the prompt.
+    pub mouse_support: bool,
+    /// Style sheet of options.
+    pub option: StyleSheet,
+    /// Style sheet applied on top of the option style to mandatory options.
+    pub mandatory_option: StyleSheet,
 }
 
 impl<T> From<&MultiSelect<'_, T>> for MultiSelectConfig {
     fn from(value: &MultiSelect<'_, T>) -> Self {
         Self {
             vim_mode: value.vim_mode,
-            page_size: value.page_size,
+            page_size: match value.page_size {
+                0 => crate::config::DEFAULT_PAGE_SIZE,
+                page_size => page_size,
+            },
+            auto_page_size: value.page_size == 0,
             keep_filter: value.keep_filter,
             reset_cursor: value.reset_cursor,
+            wrap_around: value.wrap_around,
+            emit_hyperlinks: value.render_config.emit_hyperlinks,
+            preserve_selection_order: value.preserve_selection_order,
+            mouse_support: value.mouse_support,
+            option: value.render_config.option,
+            mandatory_option: value.render_config.mandatory_option,
         }
     }
 }
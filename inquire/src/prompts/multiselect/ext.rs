@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::MultiSelect;
+
+/// Extension trait to build a [`MultiSelect`] prompt out of any collection of
+/// displayable items.
+///
+/// The collection is consumed: its items are collected into the `Vec` of
+/// options owned by the prompt. Call it on `.iter()` to select among
+/// references instead.
+///
+/// # Example
+///
+/// ```
+/// use inquire::MultiSelectExt;
+///
+/// let toppings = vec!["Cheese", "Olives", "Mushrooms", "Pepperoni"];
+///
+/// let prompt = toppings
+///     .into_iter()
+///     .multi_select("Toppings:")
+///     .with_page_size(3);
+///
+/// assert_eq!(4, prompt.options.len());
+/// assert_eq!(3, prompt.page_size);
+/// ```
+pub trait MultiSelectExt: IntoIterator + Sized
+where
+    Self::Item: Display,
+{
+    /// Creates a [`MultiSelect`] prompt with the given message and the items
+    /// of this collection as options, in iteration order.
+    fn multi_select(self, message: &str) -> MultiSelect<'_, Self::Item> {
+        MultiSelect::new(message, self.into_iter().collect())
+    }
+}
+
+impl<I> MultiSelectExt for I
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+}
@@ -1,11 +1,13 @@
 mod action;
 mod config;
+mod ext;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
+pub use ext::*;
 
 use std::fmt::Display;
 
@@ -13,16 +15,34 @@ use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::MultiOptionFormatter,
-    list_option::ListOption,
+    list_option::{Linkable, ListOption},
+    matching::MatchingStrategy,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, MultiSelectBackend, RenderConfig},
+    terminal::{get_default_terminal, record_answer},
+    type_aliases::{
+        AccessibilityAnnouncer, HighlightingScorer, OptionOrdering, OptionStyle, Scorer,
+    },
+    ui::{Backend, MultiSelectBackend, RenderConfig, Styled},
     validator::MultiOptionValidator,
 };
 
 use self::prompt::MultiSelectPrompt;
 
+/// Options of a [MultiSelect] prompt partitioned by the answer of the user,
+/// returned by [`MultiSelect::prompt_with_counts`].
+///
+/// The index of each [`ListOption`] is the position of the option in the
+/// list originally provided to the prompt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiSelection<T> {
+    /// Options selected by the user, in the same order as the ones returned
+    /// by [`MultiSelect::raw_prompt`].
+    pub selected: Vec<ListOption<T>>,
+
+    /// Options left unselected by the user, in their original order.
+    pub unselected: Vec<ListOption<T>>,
+}
+
 #[cfg(feature = "fuzzy")]
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 #[cfg(feature = "fuzzy")]
@@ -32,6 +52,8 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// Prompt suitable for when you need the user to select many options (including none if applicable) among a list of them.
 ///
 /// The user can select (or deselect) the current highlighted option by pressing space, clean all selections by pressing the left arrow and select all options by pressing the right arrow.
+/// Ctrl+R inverts the selection of every option, and Ctrl+T selects all options matching the current filter, or deselects them if they are all selected already.
+/// Shift+Space sets an anchor at the highlighted option; moving the cursor then extends a range from it, and pressing Shift+Space again selects every option in the range, or deselects them if they are all selected already.
 ///
 /// This prompt requires a prompt message and a **non-empty** `Vec` of options to be displayed to the user. The options can be of any type as long as they implement the `Display` trait. It is required that the `Vec` is moved to the prompt, as the prompt will return the ownership of the `Vec` after the user submits, with only the selected options inside it.
 /// - If the list is empty, the prompt operation will fail with an `InquireError::InvalidConfiguration` error.
@@ -71,10 +93,16 @@ pub struct MultiSelect<'a, T> {
     /// Default indexes of options to be selected from the start.
     pub default: Option<Vec<usize>>,
 
+    /// Indexes of options that are selected from the start and can not be
+    /// unchecked, always being part of the answer.
+    pub mandatory: Vec<usize>,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
     /// Page size of the options displayed to the user.
+    ///
+    /// When `0`, the page size is fit to the height of the terminal.
     pub page_size: usize,
 
     /// Whether vim mode is enabled. When enabled, the user can
@@ -87,10 +115,25 @@ pub struct MultiSelect<'a, T> {
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
+    /// Placeholder displayed in the filter input while it is empty.
+    pub filter_placeholder: Option<&'a str>,
+
     /// Reset cursor position to first option on filter input change.
     /// Defaults to true.
     pub reset_cursor: bool,
 
+    /// Whether moving the cursor past the last option goes back to the first
+    /// one, and vice-versa.
+    ///
+    /// Defaults to true.
+    pub wrap_around: bool,
+
+    /// Whether the mouse wheel moves the cursor and clicking an option
+    /// toggles it. Only supported by the `crossterm` back-end.
+    ///
+    /// Defaults to false.
+    pub mouse_support: bool,
+
     /// Whether to allow the option list to be filtered by user input or not.
     ///
     /// Defaults to true.
@@ -99,11 +142,64 @@ pub struct MultiSelect<'a, T> {
     /// Function called with the current user input to score the provided
     /// options.
     /// The list of options is sorted in descending order (highest score first)
+    ///
+    /// When the user appends characters to the filter input, only the options
+    /// matched by the previous input are scored again, so an option that does
+    /// not match an input must not match any input starting with it either.
     pub scorer: Scorer<'a, T>,
 
+    /// Function scoring the options like [`scorer`](Self::scorer), also
+    /// reporting the parts of each option that matched the filter input so they
+    /// are highlighted with the
+    /// [`option_match`](crate::ui::RenderConfig::option_match) style.
+    ///
+    /// When set, it is used instead of the scorer.
+    pub highlighting_scorer: Option<HighlightingScorer<'a, T>>,
+
+    /// Function ordering the options displayed while the filter input is empty,
+    /// e.g. most recently used first. Once the user types a filter, options are
+    /// ranked by the scorer instead.
+    pub default_ordering: Option<OptionOrdering<'a, T>>,
+
+    /// Strings used in place of the options' `Display` output when scoring
+    /// them against the filter input. The rendered labels are not affected.
+    ///
+    /// When set, it must have the same length as the option list, otherwise
+    /// the prompt will fail with an `InquireError::InvalidConfiguration` error.
+    pub search_strings: Option<Vec<String>>,
+
     /// Whether the current filter typed by the user is kept or cleaned after a selection is made.
     pub keep_filter: bool,
 
+    /// Whether the prompt accepts an empty list of options, displaying
+    /// [`empty_options_message`](Self::empty_options_message) in its place and
+    /// returning an empty selection on submission.
+    ///
+    /// When false, an empty list of options makes the prompt fail with an
+    /// `InquireError::InvalidConfiguration` error.
+    pub allow_empty_options: bool,
+
+    /// Message displayed in place of the options when the list of options is
+    /// empty and [`allow_empty_options`](Self::allow_empty_options) is set.
+    pub empty_options_message: &'a str,
+
+    /// Whether the selected options are returned in the order the user checked them,
+    /// instead of the order of the option list.
+    pub preserve_selection_order: bool,
+
+    /// Function returning the URL of an option, which is then rendered as a
+    /// hyperlink if enabled in the render config. Set by [`with_links`](Self::with_links).
+    pub option_url: Option<fn(&T) -> Option<&str>>,
+
+    /// Function styling the label of each option, receiving whether it is
+    /// highlighted. The highlighted option style of the render config is
+    /// applied on top of it.
+    pub option_style: Option<OptionStyle<'a, T>>,
+
+    /// Callback receiving an announcement of the state of the prompt, e.g.
+    /// for a screen reader, whenever it changes.
+    pub accessibility_announcer: Option<AccessibilityAnnouncer<'a>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: MultiOptionFormatter<'a, T>,
 
@@ -206,6 +302,14 @@ where
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
 
+    /// Default cursor behaviour when moving past the first or last option.
+    /// Defaults to true.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
+    /// Default mouse support behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_MOUSE_SUPPORT: bool = false;
+
     /// Default filter input enabled behaviour.
     /// Defaults to true.
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
@@ -213,9 +317,18 @@ where
     /// Default behavior of keeping or cleaning the current filter value.
     pub const DEFAULT_KEEP_FILTER: bool = true;
 
+    /// Default behavior of accepting an empty list of options.
+    pub const DEFAULT_ALLOW_EMPTY_OPTIONS: bool = false;
+
+    /// Default message displayed in place of an empty list of options.
+    pub const DEFAULT_EMPTY_OPTIONS_MESSAGE: &'a str = "No options available";
+
+    /// Default behavior of returning selections in the order they were checked.
+    pub const DEFAULT_PRESERVE_SELECTION_ORDER: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
-        Some("↑↓ to move, space to select one, → to all, ← to none, type to filter");
+        Some("↑↓ to move, space to select one, → to all, ← to none, ctrl+r to invert, ctrl+t to toggle filtered, type to filter");
 
     /// Creates a [MultiSelect] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
@@ -223,15 +336,28 @@ where
             message,
             options,
             default: None,
+            mandatory: vec![],
             help_message: Self::DEFAULT_HELP_MESSAGE,
             page_size: Self::DEFAULT_PAGE_SIZE,
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             starting_filter_input: None,
+            filter_placeholder: None,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
+            mouse_support: Self::DEFAULT_MOUSE_SUPPORT,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
             keep_filter: Self::DEFAULT_KEEP_FILTER,
+            allow_empty_options: Self::DEFAULT_ALLOW_EMPTY_OPTIONS,
+            empty_options_message: Self::DEFAULT_EMPTY_OPTIONS_MESSAGE,
+            preserve_selection_order: Self::DEFAULT_PRESERVE_SELECTION_ORDER,
             scorer: Self::DEFAULT_SCORER,
+            highlighting_scorer: None,
+            search_strings: None,
+            default_ordering: None,
+            option_url: None,
+            option_style: None,
+            accessibility_announcer: None,
             formatter: Self::DEFAULT_FORMATTER,
             validator: None,
             render_config: get_configuration(),
@@ -251,6 +377,12 @@ where
     }
 
     /// Sets the page size.
+    ///
+    /// A page size of `0` fits the page to the height of the terminal, minus the
+    /// rows used by the rest of the prompt, recomputing it on every render so that
+    /// it follows terminal resizes. At least one option is displayed, and the
+    /// [default page size](Self::DEFAULT_PAGE_SIZE) is used when the terminal
+    /// height is unknown.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
@@ -268,18 +400,152 @@ where
         self
     }
 
+    /// Sets whether the prompt accepts an empty list of options, e.g. for
+    /// optional steps whose options are computed at runtime. When allowed, a
+    /// placeholder message is displayed in place of the options and submitting
+    /// returns an empty selection.
+    pub fn with_empty_options_allowed(mut self, allow_empty_options: bool) -> Self {
+        self.allow_empty_options = allow_empty_options;
+        self
+    }
+
+    /// Sets the message displayed in place of an empty list of options.
+    pub fn with_empty_options_message(mut self, message: &'a str) -> Self {
+        self.empty_options_message = message;
+        self
+    }
+
+    /// Returns the selected options in the order the user checked them, instead of
+    /// sorted by their index in the option list, which is the default behavior.
+    ///
+    /// Unchecking and checking an option again moves it to the end of the order.
+    /// Options selected by default come first, in the order their indexes were given,
+    /// and selecting all options appends them in the order they are displayed.
+    ///
+    /// The same order is used for the options passed to the validator and formatter.
+    pub fn with_preserve_selection_order(mut self) -> Self {
+        self.preserve_selection_order = true;
+        self
+    }
+
     /// Sets the scoring function.
     pub fn with_scorer(mut self, scorer: Scorer<'a, T>) -> Self {
         self.scorer = scorer;
         self
     }
 
+    /// Sets a scoring function that also reports the parts of each option
+    /// matching the filter input, which are then highlighted. It is used
+    /// instead of the scoring function.
+    ///
+    /// With the `fuzzy` feature,
+    /// [`FUZZY_HIGHLIGHTING_SCORER`](MatchingStrategy::FUZZY_HIGHLIGHTING_SCORER)
+    /// highlights the fuzzy-matched characters.
+    pub fn with_highlighting_scorer(mut self, scorer: HighlightingScorer<'a, T>) -> Self {
+        self.highlighting_scorer = Some(scorer);
+        self
+    }
+
+    /// Sets the strategy used to match options against the filter input,
+    /// replacing the scoring function.
+    pub fn with_matching_strategy(mut self, strategy: MatchingStrategy<'a, T>) -> Self {
+        self.scorer = strategy.scorer();
+        self
+    }
+
+    /// Sets the order in which options are displayed while the filter input is
+    /// empty, e.g. most recently used first. Options comparing equal keep their
+    /// relative order.
+    ///
+    /// The ordering does not affect the ranking of options once the user types a
+    /// filter, which is computed by the scorer.
+    pub fn with_default_ordering(mut self, ordering: OptionOrdering<'a, T>) -> Self {
+        self.default_ordering = Some(ordering);
+        self
+    }
+
+    /// Sets the strings matched against the filter input instead of the
+    /// options' `Display` output, e.g. to leave out decorations such as emoji.
+    ///
+    /// There must be exactly one search string per option, in the same order.
+    pub fn with_search_strings(mut self, search_strings: Vec<String>) -> Self {
+        self.search_strings = Some(search_strings);
+        self
+    }
+
+    /// Renders options that have an URL as clickable hyperlinks, in terminals
+    /// that support them.
+    ///
+    /// Hyperlinks are only emitted when enabled in the render config, see
+    /// [`RenderConfig::with_hyperlinks`](crate::ui::RenderConfig::with_hyperlinks).
+    pub fn with_links(mut self) -> Self
+    where
+        T: Linkable,
+    {
+        self.option_url = Some(T::url);
+        self
+    }
+
+    /// Sets a function styling the label of each option from its value, e.g. to
+    /// render errors in red, called on every render with the option and whether
+    /// it is highlighted.
+    ///
+    /// The returned style sheet replaces the option style of the render config,
+    /// and the highlighted option style is applied on top of it.
+    pub fn with_option_style(mut self, option_style: OptionStyle<'a, T>) -> Self {
+        self.option_style = Some(option_style);
+        self
+    }
+
+    /// Sets a callback receiving an announcement of the state of the prompt
+    /// whenever it changes, e.g. when the highlighted option does, so that a
+    /// screen reader integration or a text-to-speech engine can speak it.
+    ///
+    /// Announcements look like `MultiSelect, 3 of 12, highlighted: Banana, checked, 2 selected, filter: ba`.
+    pub fn with_accessibility_announcer(mut self, announcer: AccessibilityAnnouncer<'a>) -> Self {
+        self.accessibility_announcer = Some(announcer);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: MultiOptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Formatter printing the string value of the selected options, separated
+    /// by commas, like [DEFAULT_FORMATTER](Self::DEFAULT_FORMATTER), as long as
+    /// there are at most `threshold` of them. Past that, it only prints how many
+    /// options are selected, e.g. `50 selected`, so that long answers do not
+    /// overflow the terminal.
+    ///
+    /// Formatters are borrowed by the prompt, so the returned one must be stored
+    /// and passed by reference to [with_formatter](Self::with_formatter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use inquire::list_option::ListOption;
+    /// use inquire::MultiSelect;
+    ///
+    /// let formatter = MultiSelect::<&str>::count_formatter(2);
+    ///
+    /// let mut ans = vec![ListOption::new(0, &"New York"), ListOption::new(3, &"Seattle")];
+    /// assert_eq!(String::from("New York, Seattle"), formatter(&ans));
+    ///
+    /// ans.push(ListOption::new(7, &"Vancouver"));
+    /// assert_eq!(String::from("3 selected"), formatter(&ans));
+    ///
+    /// let cities = vec!["New York", "Seattle", "Vancouver"];
+    /// let prompt = MultiSelect::new("Cities:", cities).with_formatter(&formatter);
+    /// ```
+    pub fn count_formatter(threshold: usize) -> impl Fn(&[ListOption<&T>]) -> String + Clone {
+        move |ans| match ans.len() {
+            len if len > threshold => format!("{} selected", len),
+            _ => Self::DEFAULT_FORMATTER(ans),
+        }
+    }
+
     /// Sets the validator to apply to the user input. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as limiting the number
     /// of selections.
@@ -302,6 +568,66 @@ where
         self
     }
 
+    /// Sets the options equal to any of `values` to be selected by default,
+    /// e.g. the options selected the last time the prompt was displayed.
+    ///
+    /// Values that are not equal to any option are ignored. See
+    /// [`try_with_default_values`](Self::try_with_default_values) to fail instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::MultiSelect;
+    ///
+    /// let last_choices = ["Lemon", "Pear"];
+    /// let options = vec!["Banana", "Apple", "Lemon", "Pear"];
+    ///
+    /// let ans = MultiSelect::new("What are your favorite fruits?", options)
+    ///     .with_default_values(&last_choices)
+    ///     .prompt();
+    /// ```
+    pub fn with_default_values(mut self, values: &[T]) -> Self
+    where
+        T: PartialEq,
+    {
+        self.default = Some(self.indexes_of(values).into_iter().flatten().collect());
+        self
+    }
+
+    /// Sets the options equal to any of `values` to be selected by default,
+    /// returning an `InquireError::InvalidConfiguration` error when one of the
+    /// values is not equal to any option.
+    ///
+    /// See [`with_default_values`](Self::with_default_values) to ignore missing values instead.
+    pub fn try_with_default_values(mut self, values: &[T]) -> InquireResult<Self>
+    where
+        T: PartialEq,
+    {
+        let default = self
+            .indexes_of(values)
+            .into_iter()
+            .collect::<Option<Vec<usize>>>()
+            .ok_or_else(|| {
+                InquireError::InvalidConfiguration(
+                    "Default values must be among the options".into(),
+                )
+            })?;
+
+        self.default = Some(default);
+        Ok(self)
+    }
+
+    /// Index of the first option equal to each value, if any.
+    fn indexes_of(&self, values: &[T]) -> Vec<Option<usize>>
+    where
+        T: PartialEq,
+    {
+        values
+            .iter()
+            .map(|value| self.options.iter().position(|option| option == value))
+            .collect()
+    }
+
     /// Sets all options to be selected by default.
     /// This overrides any previously set default and is equivalent to calling
     /// `with_default` with a slice containing all indexes for the given
@@ -311,6 +637,22 @@ where
         self
     }
 
+    /// Sets the indexes of options that are selected from the start and can
+    /// not be unchecked, e.g. components that are always installed.
+    ///
+    /// Unlike options selected with [`with_default`](Self::with_default),
+    /// mandatory options are always part of the answer: trying to uncheck
+    /// them only displays a message saying they are required. They are
+    /// rendered with the [`mandatory_option`](crate::ui::RenderConfig::mandatory_option)
+    /// style sheet of the render config.
+    ///
+    /// The values should be valid indexes for the given option list, otherwise
+    /// the prompt fails with an `InquireError::InvalidConfiguration` error.
+    pub fn with_mandatory(mut self, mandatory: &[usize]) -> Self {
+        self.mandatory = mandatory.to_vec();
+        self
+    }
+
     /// Sets the starting cursor index.
     ///
     /// This index might be overridden if the `reset_cursor` option is set to true (default)
@@ -326,6 +668,13 @@ where
         self
     }
 
+    /// Sets the placeholder displayed in the filter input while it is empty,
+    /// hinting that typing filters the options.
+    pub fn with_filter_placeholder(mut self, filter_placeholder: &'a str) -> Self {
+        self.filter_placeholder = Some(filter_placeholder);
+        self
+    }
+
     /// Sets the reset_cursor behaviour. Defaults to true.
     ///
     /// When there's an input change that results in a different list of options being displayed,
@@ -335,6 +684,27 @@ where
         self
     }
 
+    /// Sets whether the cursor wraps around the list of options. Defaults to true.
+    ///
+    /// When disabled, moving up from the first option or down from the last one
+    /// does nothing, instead of jumping to the other end of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Enables mouse support: scrolling the mouse wheel moves the cursor and
+    /// clicking an option toggles its selection.
+    ///
+    /// The mouse is captured while the prompt is displayed, which prevents
+    /// selecting text in the terminal with it. Only supported by the
+    /// `crossterm` back-end, and clicks assume each option takes a single row,
+    /// which might not hold when long options wrap around.
+    pub fn with_mouse_support(mut self) -> Self {
+        self.mouse_support = true;
+        self
+    }
+
     /// Disables the filter input, which means the user will not be able to filter the options
     /// by typing.
     ///
@@ -358,6 +728,56 @@ where
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the prefix of the highlighted option and its style sheet for this
+    /// prompt only, keeping the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_cursor_glyph(mut self, glyph: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_highlighted_option_prefix(glyph);
+        self
+    }
+
+    /// Sets the checkbox of selected options and its style sheet for this
+    /// prompt only, keeping the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this checkbox.
+    pub fn with_checked_glyph(mut self, glyph: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_selected_checkbox(glyph);
+        self
+    }
+
+    /// Sets the checkbox of unselected options and its style sheet for this
+    /// prompt only, keeping the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this checkbox.
+    pub fn with_unchecked_glyph(mut self, glyph: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_unselected_checkbox(glyph);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -377,6 +797,54 @@ where
         }
     }
 
+    /// Checks that the configuration of the prompt is valid, returning the
+    /// `InquireError::InvalidConfiguration` error the prompt would fail with
+    /// otherwise, e.g. when there are no options or the starting cursor is
+    /// out-of-bounds.
+    ///
+    /// The terminal is not touched, so this can be used to validate prompts at
+    /// startup. Prompting methods call it before acquiring the terminal.
+    pub fn validate_config(&self) -> InquireResult<()> {
+        if self.options.is_empty() && !self.allow_empty_options {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if let Some(&i) = self
+            .default
+            .iter()
+            .flatten()
+            .chain(&self.mandatory)
+            .find(|&&i| i >= self.options.len())
+        {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Index {} is out-of-bounds for length {} of options",
+                i,
+                self.options.len()
+            )));
+        }
+
+        if self.starting_cursor >= self.options.len() && !self.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                self.starting_cursor,
+                self.options.len()
+            )));
+        }
+
+        match &self.search_strings {
+            Some(search_strings) if search_strings.len() != self.options.len() => {
+                Err(InquireError::InvalidConfiguration(format!(
+                    "Length {} of search strings does not match length {} of options",
+                    search_strings.len(),
+                    self.options.len()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -412,15 +880,39 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<Vec<ListOption<T>>> {
+        self.validate_config()?;
+
+        let (input_reader, terminal) = get_default_terminal()?;
+        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+        MultiSelectPrompt::new(self)?
+            .prompt_with_render_callback(&mut backend, record_answer)
+            .map(|answer| answer.selected)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Returns a [`MultiSelection`] containing both the options selected by the
+    /// user and the ones left unselected, along with their original indexes.
+    pub fn prompt_with_counts(self) -> InquireResult<MultiSelection<T>> {
+        self.validate_config()?;
+
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        MultiSelectPrompt::new(self)?.prompt_with_render_callback(&mut backend, record_answer)
     }
 
-    pub(crate) fn prompt_with_backend<B: MultiSelectBackend>(
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: MultiSelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<Vec<ListOption<T>>> {
-        MultiSelectPrompt::new(self)?.prompt(backend)
+        MultiSelectPrompt::new(self)?
+            .prompt(backend)
+            .map(|answer| answer.selected)
     }
 }
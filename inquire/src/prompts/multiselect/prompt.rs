@@ -1,75 +1,99 @@
-use std::{cmp::Reverse, collections::BTreeSet, fmt::Display};
+use std::{
+    cell::Cell,
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    ops::{Range, RangeInclusive},
+};
 
 use crate::{
+    accessibility::{describe_options, AccessibleState, Announcer},
     error::InquireResult,
     formatter::MultiOptionFormatter,
     input::{Input, InputActionResult},
     list_option::ListOption,
+    matching::{ranges_in_label, score_option},
     prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::MultiSelectBackend,
-    utils::paginate,
-    validator::{ErrorMessage, MultiOptionValidator, Validation},
-    InquireError, MultiSelect,
+    type_aliases::{HighlightingScorer, OptionOrdering, OptionStyle, Scorer},
+    ui::{overlay_style, Hyperlink, MultiSelectBackend, StyleSheet},
+    utils::{fit_page_size, paginate, Page},
+    validator::{AnswerWarning, ErrorMessage, MultiOptionValidator, Validation},
+    MultiSelect, MultiSelection,
 };
 
 use super::{action::MultiSelectPromptAction, config::MultiSelectConfig};
 
+/// Message displayed when the user tries to uncheck a mandatory option.
+const MANDATORY_OPTION_MESSAGE: &str = "This option is required";
+
 pub struct MultiSelectPrompt<'a, T> {
     message: &'a str,
     config: MultiSelectConfig,
     options: Vec<T>,
     string_options: Vec<String>,
     help_message: Option<&'a str>,
+    empty_options_message: &'a str,
     cursor_index: usize,
+    /// Position, in the filtered list, where the range being selected starts.
+    anchor: Option<usize>,
     checked: BTreeSet<usize>,
+    selection_order: Vec<usize>,
+    mandatory: BTreeSet<usize>,
+    /// Whether the last action tried to uncheck a mandatory option.
+    mandatory_notice: bool,
     input: Option<Input>,
     scored_options: Vec<usize>,
+    /// Filter input of the last scorer run and the indexes of the options
+    /// it matched, in ascending order.
+    last_filter: Option<(String, Vec<usize>)>,
     scorer: Scorer<'a, T>,
+    highlighting_scorer: Option<HighlightingScorer<'a, T>>,
+    /// Byte ranges of the string values of the options that matched the
+    /// current filter input, as reported by the highlighting scorer.
+    match_highlights: HashMap<usize, Vec<Range<usize>>>,
+    default_ordering: Option<OptionOrdering<'a, T>>,
     formatter: MultiOptionFormatter<'a, T>,
+    option_url: Option<fn(&T) -> Option<&str>>,
+    option_style: Option<OptionStyle<'a, T>>,
     validator: Option<Box<dyn MultiOptionValidator<T>>>,
     error: Option<ErrorMessage>,
+    warning: Option<AnswerWarning<Vec<usize>>>,
+    /// Row of the prompt where the first displayed option was last rendered.
+    first_option_row: Cell<u16>,
+    announcer: Announcer<'a>,
 }
 
 impl<'a, T> MultiSelectPrompt<'a, T>
 where
     T: Display,
 {
-    pub fn new(mso: MultiSelect<'a, T>) -> InquireResult<Self> {
-        if mso.options.is_empty() {
-            return Err(InquireError::InvalidConfiguration(
-                "Available options can not be empty".into(),
-            ));
+    pub fn new(mut mso: MultiSelect<'a, T>) -> InquireResult<Self> {
+        mso.validate_config()?;
+
+        let string_options = match mso.search_strings.take() {
+            Some(search_strings) => search_strings,
+            None => mso.options.iter().map(T::to_string).collect(),
+        };
+        let mut scored_options = (0..mso.options.len()).collect::<Vec<usize>>();
+        if let Some(ordering) = mso.default_ordering {
+            scored_options.sort_by(|&a, &b| ordering(&mso.options[a], &mso.options[b]));
         }
-        if let Some(default) = &mso.default {
-            for i in default {
-                if i >= &mso.options.len() {
-                    return Err(InquireError::InvalidConfiguration(format!(
-                        "Index {} is out-of-bounds for length {} of options",
-                        i,
-                        &mso.options.len()
-                    )));
-                }
+        let mut checked_options = BTreeSet::new();
+        let mut selection_order = vec![];
+        for &idx in mso.mandatory.iter().chain(mso.default.iter().flatten()) {
+            if idx < mso.options.len() && checked_options.insert(idx) {
+                selection_order.push(idx);
             }
         }
 
-        let string_options = mso.options.iter().map(T::to_string).collect();
-        let scored_options = (0..mso.options.len()).collect();
-        let checked_options = mso
-            .default
-            .as_ref()
-            .map(|d| {
-                d.iter()
-                    .cloned()
-                    .filter(|i| *i < mso.options.len())
-                    .collect()
-            })
-            .unwrap_or_default();
-
         let input = match mso.filter_input_enabled {
-            true => Some(Input::new_with(
-                mso.starting_filter_input.unwrap_or_default(),
-            )),
+            true => {
+                let input = Input::new_with(mso.starting_filter_input.unwrap_or_default());
+                Some(match mso.filter_placeholder {
+                    Some(placeholder) => input.with_placeholder(placeholder),
+                    None => input,
+                })
+            }
             false => None,
         };
 
@@ -79,17 +103,110 @@ where
             options: mso.options,
             string_options,
             scored_options,
+            last_filter: None,
             help_message: mso.help_message,
+            empty_options_message: mso.empty_options_message,
             cursor_index: mso.starting_cursor,
+            anchor: None,
             input,
             scorer: mso.scorer,
+            highlighting_scorer: mso.highlighting_scorer,
+            match_highlights: HashMap::new(),
+            default_ordering: mso.default_ordering,
             formatter: mso.formatter,
+            option_url: mso.option_url,
+            option_style: mso.option_style,
             validator: mso.validator,
             error: None,
+            warning: None,
             checked: checked_options,
+            selection_order,
+            mandatory: mso.mandatory.into_iter().collect(),
+            mandatory_notice: false,
+            first_option_row: Cell::new(0),
+            announcer: Announcer::new(mso.accessibility_announcer),
         })
     }
 
+    /// Passes the announcement of the current state to the accessibility
+    /// announcer, if any.
+    fn announce_changes(&mut self) {
+        if self.announcer.is_enabled() {
+            let announcement = self.announce();
+            self.announcer.update(announcement);
+        }
+    }
+
+    fn option_label(&self, index: usize) -> Hyperlink<'_, &T> {
+        let url = match self.option_url {
+            Some(option_url) if self.config.emit_hyperlinks => option_url(&self.options[index]),
+            _ => None,
+        };
+
+        Hyperlink::new(&self.options[index], url)
+    }
+
+    /// Styles of the options in the page, computed with the option style
+    /// function, if any, and with the mandatory option style sheet applied
+    /// on top of the mandatory ones.
+    fn page_option_styles<D>(&self, page: &Page<'_, ListOption<D>>) -> Option<Vec<StyleSheet>> {
+        let has_mandatory = page
+            .content
+            .iter()
+            .any(|option| self.mandatory.contains(&option.index));
+
+        if self.option_style.is_none() && !has_mandatory {
+            return None;
+        }
+
+        let styles = page
+            .content
+            .iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                let style = match self.option_style {
+                    Some(option_style) => {
+                        option_style(&self.options[option.index], page.cursor == Some(idx))
+                    }
+                    None => self.config.option,
+                };
+
+                match self.mandatory.contains(&option.index) {
+                    true => overlay_style(style, self.config.mandatory_option),
+                    false => style,
+                }
+            })
+            .collect();
+
+        Some(styles)
+    }
+
+    /// Ranges to highlight in each option of the page, shifted to where the
+    /// string value is displayed in labels with hyperlinks.
+    fn page_match_highlights<D: Display>(
+        &self,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Option<Vec<Vec<Range<usize>>>> {
+        if self.match_highlights.is_empty() {
+            return None;
+        }
+
+        let highlights = page
+            .content
+            .iter()
+            .map(|option| match self.match_highlights.get(&option.index) {
+                Some(ranges) => ranges_in_label(
+                    &option.value.to_string(),
+                    &self.string_options[option.index],
+                    ranges,
+                ),
+                None => vec![],
+            })
+            .collect();
+
+        Some(highlights)
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let new_position = if wrap {
             let after_wrap = qty.saturating_sub(self.cursor_index);
@@ -128,22 +245,145 @@ where
         }
     }
 
+    /// Moves the cursor to the option rendered at the clicked row and toggles
+    /// it, if the click landed on an option.
+    fn click_option(&mut self, row: u16) -> ActionResult {
+        let offset = match row.checked_sub(self.first_option_row.get()) {
+            Some(offset) => offset as usize,
+            None => return ActionResult::Clean,
+        };
+
+        let page = paginate(
+            self.config.page_size,
+            &self.scored_options,
+            Some(self.cursor_index),
+        );
+
+        if offset >= page.content.len() {
+            return ActionResult::Clean;
+        }
+
+        self.cursor_index = page.start + offset;
+        self.toggle_cursor_selection()
+    }
+
     fn toggle_cursor_selection(&mut self) -> ActionResult {
         let idx = match self.scored_options.get(self.cursor_index) {
             Some(val) => val,
             None => return ActionResult::Clean,
         };
 
-        if self.checked.contains(idx) {
-            self.checked.remove(idx);
+        let idx = *idx;
+
+        if self.mandatory.contains(&idx) {
+            self.mandatory_notice = true;
+        } else if self.checked.remove(&idx) {
+            self.selection_order.retain(|&i| i != idx);
         } else {
-            self.checked.insert(*idx);
+            self.checked.insert(idx);
+            self.selection_order.push(idx);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Unchecks the option, unless it is mandatory.
+    fn uncheck(&mut self, idx: usize) {
+        if !self.mandatory.contains(&idx) && self.checked.remove(&idx) {
+            self.selection_order.retain(|&i| i != idx);
+        }
+    }
+
+    fn select_all(&mut self) -> ActionResult {
+        self.clear_selections();
+        for &idx in &self.scored_options {
+            if self.checked.insert(idx) {
+                self.selection_order.push(idx);
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn clear_selections(&mut self) -> ActionResult {
+        let mandatory = &self.mandatory;
+        self.checked.retain(|idx| mandatory.contains(idx));
+        self.selection_order.retain(|idx| mandatory.contains(idx));
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn invert_selections(&mut self) -> ActionResult {
+        let unchecked = (0..self.options.len())
+            .filter(|idx| !self.checked.contains(idx))
+            .collect::<Vec<usize>>();
+
+        self.clear_selections();
+        for idx in unchecked {
+            self.checked.insert(idx);
+            self.selection_order.push(idx);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn toggle_visible_options(&mut self) -> ActionResult {
+        let all_checked = self
+            .scored_options
+            .iter()
+            .all(|idx| self.checked.contains(idx));
+
+        for idx in self.scored_options.clone() {
+            if all_checked {
+                self.uncheck(idx);
+            } else if self.checked.insert(idx) {
+                self.selection_order.push(idx);
+            }
         }
 
         ActionResult::NeedsRedraw
     }
 
-    fn clear_input_if_needed(&mut self, action: MultiSelectPromptAction) -> ActionResult {
+    /// Positions, in the filtered list, between the range anchor and the cursor.
+    fn anchored_range(&self) -> Option<RangeInclusive<usize>> {
+        self.anchor.map(|anchor| match anchor <= self.cursor_index {
+            true => anchor..=self.cursor_index,
+            false => self.cursor_index..=anchor,
+        })
+    }
+
+    fn toggle_range(&mut self) -> ActionResult {
+        let range = if let Some(range) = self.anchored_range() {
+            range
+        } else {
+            self.anchor = Some(self.cursor_index);
+            return ActionResult::NeedsRedraw;
+        };
+        self.anchor = None;
+
+        let in_range = self.scored_options.get(range).unwrap_or_default().to_vec();
+        let all_checked = in_range.iter().all(|idx| self.checked.contains(idx));
+
+        for idx in in_range {
+            if all_checked {
+                self.uncheck(idx);
+            } else if self.checked.insert(idx) {
+                self.selection_order.push(idx);
+            }
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    /// Indexes of the checked options, in the order they should be returned.
+    fn selected_indexes(&self) -> Vec<usize> {
+        match self.config.preserve_selection_order {
+            true => self.selection_order.clone(),
+            false => self.checked.iter().copied().collect(),
+        }
+    }
+
+    fn clear_input_if_needed(&mut self, action: &MultiSelectPromptAction) -> ActionResult {
         if self.config.keep_filter {
             return ActionResult::Clean;
         }
@@ -159,8 +399,17 @@ where
 
         match action {
             MultiSelectPromptAction::ToggleCurrentOption
+            | MultiSelectPromptAction::Click(_)
             | MultiSelectPromptAction::SelectAll
-            | MultiSelectPromptAction::ClearSelections => {
+            | MultiSelectPromptAction::ClearSelections
+            | MultiSelectPromptAction::InvertSelections
+            | MultiSelectPromptAction::ToggleVisibleOptions => {
+                input_ref.clear();
+                self.run_scorer();
+                ActionResult::NeedsRedraw
+            }
+            // the range was toggled, not just anchored.
+            MultiSelectPromptAction::ToggleRange if self.anchor.is_none() => {
                 input_ref.clear();
                 self.run_scorer();
                 ActionResult::NeedsRedraw
@@ -172,13 +421,9 @@ where
     fn validate_current_answer(&self) -> InquireResult<Validation> {
         if let Some(validator) = &self.validator {
             let selected_options = self
-                .options
-                .iter()
-                .enumerate()
-                .filter_map(|(idx, opt)| match &self.checked.contains(&idx) {
-                    true => Some(ListOption::new(idx, opt)),
-                    false => None,
-                })
+                .selected_indexes()
+                .into_iter()
+                .map(|idx| ListOption::new(idx, &self.options[idx]))
                 .collect::<Vec<_>>();
 
             let res = validator.validate(&selected_options)?;
@@ -188,21 +433,29 @@ where
         }
     }
 
-    fn get_final_answer(&mut self) -> Vec<ListOption<T>> {
-        let mut answer = vec![];
+    fn get_final_answer(&mut self) -> MultiSelection<T> {
+        let mut options = std::mem::take(&mut self.options)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+
+        let selected = self
+            .selected_indexes()
+            .into_iter()
+            .filter_map(|idx| options[idx].take().map(|value| ListOption::new(idx, value)))
+            .collect();
+
+        // selected options were taken out, leaving the unselected ones in place.
+        let unselected = options
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, value)| value.map(|value| ListOption::new(idx, value)))
+            .collect();
 
-        // by iterating in descending order, we can safely
-        // swap remove because the elements to the right
-        // that we did not remove will not matter anymore.
-        for index in self.checked.iter().rev() {
-            let index = *index;
-            let value = self.options.swap_remove(index);
-            let lo = ListOption::new(index, value);
-            answer.push(lo);
+        MultiSelection {
+            selected,
+            unselected,
         }
-        answer.reverse();
-
-        answer
     }
 
     fn run_scorer(&mut self) {
@@ -211,25 +464,57 @@ where
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+        // when the user only appended characters to the filter, options that
+        // did not match the previous input can not match the new one either.
+        let candidates = match self.last_filter.take() {
+            Some((last_input, matches)) if content.starts_with(&last_input) => matches,
+            _ => (0..self.options.len()).collect(),
+        };
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        self.match_highlights.clear();
+
+        let mut options = vec![];
+        for i in candidates {
+            let scored = score_option(
+                self.scorer,
+                self.highlighting_scorer,
+                content,
+                &self.options[i],
+                &self.string_options[i],
+                i,
+            );
+
+            if let Some((score, ranges)) = scored {
+                if !content.is_empty() && !ranges.is_empty() {
+                    self.match_highlights.insert(i, ranges);
+                }
+                options.push((i, score));
+            }
+        }
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        let matches = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+
+        let new_scored_options = match self.default_ordering {
+            Some(ordering) if content.is_empty() => {
+                let mut sorted = matches.clone();
+                sorted.sort_by(|&a, &b| ordering(&self.options[a], &self.options[b]));
+                sorted
+            }
+            _ => {
+                options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+                options.iter().map(|(idx, _)| *idx).collect()
+            }
+        };
+
+        self.last_filter = Some((content.to_owned(), matches));
 
         if self.scored_options == new_scored_options {
             return;
         }
 
         self.scored_options = new_scored_options;
+        // positions in the filtered list changed, the range is no longer meaningful.
+        self.anchor = None;
 
         if self.config.reset_cursor {
             let _ = self.update_cursor_position(0);
@@ -239,6 +524,31 @@ where
     }
 }
 
+impl<'a, T> AccessibleState for MultiSelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn announce(&self) -> String {
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+        let position = highlighted.map(|index| {
+            let label = self.options[index].to_string();
+            (self.cursor_index, self.scored_options.len(), label)
+        });
+
+        let mut details = vec![];
+        if let Some(index) = highlighted {
+            details.push(match self.checked.contains(&index) {
+                true => String::from("checked"),
+                false => String::from("not checked"),
+            });
+        }
+        details.push(format!("{} selected", self.checked.len()));
+
+        let filter = self.input.as_ref().map(Input::content);
+        describe_options("MultiSelect", position, &details, filter)
+    }
+}
+
 impl<'a, Backend, T> Prompt<Backend> for MultiSelectPrompt<'a, T>
 where
     Backend: MultiSelectBackend,
@@ -246,7 +556,7 @@ where
 {
     type Config = MultiSelectConfig;
     type InnerAction = MultiSelectPromptAction;
-    type Output = Vec<ListOption<T>>;
+    type Output = MultiSelection<T>;
 
     fn message(&self) -> &str {
         self.message
@@ -256,21 +566,35 @@ where
         &self.config
     }
 
-    fn format_answer(&self, answer: &Vec<ListOption<T>>) -> String {
-        let refs: Vec<ListOption<&T>> = answer.iter().map(ListOption::as_ref).collect();
+    fn mouse_support(&self) -> bool {
+        self.config.mouse_support
+    }
+
+    fn format_answer(&self, answer: &MultiSelection<T>) -> String {
+        let refs: Vec<ListOption<&T>> = answer.selected.iter().map(ListOption::as_ref).collect();
         (self.formatter)(&refs)
     }
 
-    fn setup(&mut self) -> InquireResult<()> {
+    fn setup(&mut self) -> InquireResult<ActionResult> {
         self.run_scorer();
-        Ok(())
+        self.announce_changes();
+        Ok(ActionResult::NeedsRedraw)
     }
 
-    fn submit(&mut self) -> InquireResult<Option<Vec<ListOption<T>>>> {
+    fn submit(&mut self) -> InquireResult<Option<MultiSelection<T>>> {
         let answer = match self.validate_current_answer()? {
             Validation::Valid => Some(self.get_final_answer()),
+            Validation::Warning(msg) => {
+                self.error = None;
+                let selected = self.selected_indexes();
+                match AnswerWarning::confirm(&mut self.warning, msg, selected) {
+                    true => Some(self.get_final_answer()),
+                    false => None,
+                }
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
+                self.warning = None;
                 None
             }
         };
@@ -279,9 +603,15 @@ where
     }
 
     fn handle(&mut self, action: MultiSelectPromptAction) -> InquireResult<ActionResult> {
-        let result = match action {
-            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+        // the notice is only displayed until the next action.
+        let notice_cleared = match std::mem::take(&mut self.mandatory_notice) {
+            true => ActionResult::NeedsRedraw,
+            false => ActionResult::Clean,
+        };
+
+        let result = match &action {
+            MultiSelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            MultiSelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
             MultiSelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
             MultiSelectPromptAction::PageDown => {
                 self.move_cursor_down(self.config.page_size, false)
@@ -289,20 +619,15 @@ where
             MultiSelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             MultiSelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
             MultiSelectPromptAction::ToggleCurrentOption => self.toggle_cursor_selection(),
-            MultiSelectPromptAction::SelectAll => {
-                self.checked.clear();
-                for idx in &self.scored_options {
-                    self.checked.insert(*idx);
-                }
-                ActionResult::NeedsRedraw
-            }
-            MultiSelectPromptAction::ClearSelections => {
-                self.checked.clear();
-                ActionResult::NeedsRedraw
-            }
+            MultiSelectPromptAction::SelectAll => self.select_all(),
+            MultiSelectPromptAction::ClearSelections => self.clear_selections(),
+            MultiSelectPromptAction::InvertSelections => self.invert_selections(),
+            MultiSelectPromptAction::ToggleVisibleOptions => self.toggle_visible_options(),
+            MultiSelectPromptAction::ToggleRange => self.toggle_range(),
+            MultiSelectPromptAction::Click(row) => self.click_option(*row),
             MultiSelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
-                    let result = input.handle(input_action);
+                    let result = input.handle(input_action.clone());
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
@@ -314,11 +639,43 @@ where
             },
         };
 
-        let result = self.clear_input_if_needed(action).merge(result);
+        let result = self
+            .clear_input_if_needed(&action)
+            .merge(result)
+            .merge(notice_cleared);
+
+        self.announce_changes();
 
         Ok(result)
     }
 
+    fn fit_to_terminal(&mut self, backend: &Backend) {
+        if !self.config.auto_page_size {
+            return;
+        }
+
+        let error_rows = self
+            .error
+            .as_ref()
+            .map(|error| backend.error_message_rows(error))
+            .unwrap_or(0);
+        let warning_rows = match &self.warning {
+            Some(warning) => backend.warning_message_rows(&warning.message),
+            None if self.mandatory_notice => {
+                backend.warning_message_rows(&ErrorMessage::Custom(MANDATORY_OPTION_MESSAGE.into()))
+            }
+            None => 0,
+        };
+        let help_rows = self
+            .help_message
+            .map(|help| backend.help_message_rows(help))
+            .unwrap_or(0);
+
+        // error or warning message, prompt line, help message and the empty line below the options
+        let reserved_rows = 2 + error_rows + warning_rows + help_rows;
+        self.config.page_size = fit_page_size(backend.terminal_height(), reserved_rows);
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -326,18 +683,46 @@ where
             backend.render_error_message(err)?;
         }
 
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        } else if self.mandatory_notice {
+            let notice = ErrorMessage::Custom(MANDATORY_OPTION_MESSAGE.into());
+            backend.render_warning_message(&notice)?;
+        }
+
         backend.render_multiselect_prompt(prompt, self.input.as_ref())?;
+        self.first_option_row.set(backend.frame_row());
+
+        // only reachable when empty options are explicitly allowed.
+        if self.options.is_empty() {
+            backend.render_empty_options(self.empty_options_message)?;
+
+            if let Some(help_message) = self.help_message {
+                backend.render_help_message(help_message)?;
+            }
+
+            return Ok(());
+        }
 
         let choices = self
             .scored_options
             .iter()
             .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            .map(|i| ListOption::new(i, self.option_label(i)))
+            .collect::<Vec<ListOption<Hyperlink<'_, &T>>>>();
 
         let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
 
-        backend.render_options(page, &self.checked)?;
+        let option_styles = self.page_option_styles(&page);
+        let highlights = self.page_match_highlights(&page);
+
+        backend.render_options(
+            page,
+            &self.checked,
+            self.anchored_range(),
+            option_styles.as_deref(),
+            highlights.as_deref(),
+        )?;
 
         if let Some(help_message) = self.help_message {
             backend.render_help_message(help_message)?;
@@ -1,11 +1,21 @@
 use crate::{
+    error::InquireError,
     formatter::MultiOptionFormatter,
     list_option::ListOption,
-    test::fake_backend,
-    ui::{Key, KeyModifiers},
-    MultiSelect,
+    prompts::test::fake_backend,
+    terminal::{
+        test::{MockTerminal, MockTerminalToken},
+        TerminalSize,
+    },
+    test::{FakeBackend, Token},
+    ui::{Backend, Color, Key, KeyModifiers, RenderConfig, StyleSheet, Styled},
+    validator::{ErrorMessage, MultiOptionValidator, Validation},
+    CustomUserError, MultiSelect, MultiSelection,
 };
 
+use super::prompt::MultiSelectPrompt;
+use crate::prompts::prompt::Prompt;
+
 #[test]
 /// Tests that a closure that actually closes on a variable can be used
 /// as a Select formatter.
@@ -255,3 +265,790 @@ fn keep_filter_false_should_reset_option_list() {
     let expected_answer = vec![ListOption::new(0, 1), ListOption::new(2, 3)];
     assert_eq!(expected_answer, ans);
 }
+
+#[test]
+fn search_strings_are_scored_instead_of_rendered_labels() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["🍎 Apple", "🍌 Banana"];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_scorer(&|input, _, string_value, _| {
+            string_value.to_lowercase().starts_with(input).then_some(0)
+        })
+        .with_search_strings(vec!["Apple".into(), "Banana".into()])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "🍌 Banana")], ans);
+}
+
+#[test]
+fn search_strings_length_mismatch_is_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_search_strings(vec!["one".into(), "two".into()])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn selections_are_returned_in_index_order_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Home,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = MultiSelect::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1), ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn preserve_selection_order_returns_selections_in_click_order() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        // unchecking and checking the first option moves it to the end
+        Key::Home,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_preserve_selection_order()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(2, 3),
+            ListOption::new(1, 2),
+            ListOption::new(0, 1)
+        ],
+        ans
+    );
+}
+
+#[test]
+fn preserve_selection_order_starts_with_default_order() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = MultiSelect::new("Question", options)
+        .with_default(&[2, 0])
+        .with_preserve_selection_order()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, 3), ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn cursor_wraps_around_by_default() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1), ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn cursor_stops_at_boundaries_without_wrap_around() {
+    let mut backend = fake_backend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .with_wrap_around(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1), ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn ctrl_r_inverts_all_selections() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, 2), ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn invert_includes_options_hidden_by_filter() {
+    let mut backend = fake_backend(vec![
+        Key::Char('3', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .with_keep_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, 1),
+            ListOption::new(1, 2),
+            ListOption::new(2, 3)
+        ],
+        ans
+    );
+}
+
+#[test]
+fn ctrl_t_toggles_only_filtered_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Char('t', KeyModifiers::CONTROL),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 12])
+        .with_keep_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(0, 1),
+            ListOption::new(1, 2),
+            ListOption::new(2, 12)
+        ],
+        ans
+    );
+}
+
+#[test]
+fn ctrl_t_deselects_filtered_options_when_all_are_selected() {
+    let mut backend = fake_backend(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Char('t', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 12])
+        .with_keep_filter(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn default_values_are_selected() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_default_values(&["c", "a", "z"])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(0, "a"), ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn missing_default_values_error_when_strict() {
+    let res =
+        MultiSelect::new("Question", vec!["a", "b", "c"]).try_with_default_values(&["a", "z"]);
+    assert!(matches!(res, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .try_with_default_values(&["b"])
+        .unwrap()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(vec![ListOption::new(1, "b")], ans);
+}
+
+#[test]
+fn zero_page_size_fits_terminal_height() {
+    let prompt = MultiSelect::new("Question", (1..=20).collect()).with_page_size(0);
+
+    let mut backend = Backend::new(
+        vec![
+            Key::PageDown(KeyModifiers::NONE),
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new().with_size(TerminalSize::new(80, 6)),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    // prompt line, default help message wrapped on two rows and the empty
+    // line below the options are reserved
+    assert_eq!(vec![ListOption::new(2, 3)], ans);
+}
+
+#[test]
+fn warning_is_confirmed_by_submitting_the_same_selection_again() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3])
+        .with_validator(|ans: &[ListOption<&i32>]| match ans.len() {
+            1 => Ok(Validation::Warning("Only one?".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1)], ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(
+            |frame| frame.has_token(&Token::WarningMessage(ErrorMessage::Custom(
+                "Only one?".into()
+            )))
+        ));
+}
+
+#[test]
+fn partitioned_answer_keeps_original_indexes() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["a", "b", "c", "d", "e"];
+    let prompt = MultiSelect::new("Question", options.clone());
+
+    let answer = MultiSelectPrompt::new(prompt)
+        .unwrap()
+        .prompt(&mut backend)
+        .unwrap();
+
+    let expected = MultiSelection {
+        selected: vec![ListOption::new(1, "b"), ListOption::new(3, "d")],
+        unselected: vec![
+            ListOption::new(0, "a"),
+            ListOption::new(2, "c"),
+            ListOption::new(4, "e"),
+        ],
+    };
+    assert_eq!(expected, answer);
+
+    for option in answer.selected.iter().chain(&answer.unselected) {
+        assert_eq!(options[option.index], option.value);
+    }
+}
+
+#[test]
+fn checkbox_glyphs_are_rendered() {
+    let prompt = MultiSelect::new("Question", vec!["a", "b"])
+        .with_render_config(RenderConfig::empty().with_selected_checkbox(Styled::new("●")))
+        .with_default(&[1])
+        .with_cursor_glyph(Styled::new("→"))
+        .with_checked_glyph(Styled::new("[x]"))
+        .with_unchecked_glyph(Styled::new("[ ]"));
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("→".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("[ ]".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("a".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("[x]".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("b".into())));
+}
+
+#[test]
+fn default_ordering_only_applies_to_empty_filter() {
+    let prompt = || {
+        MultiSelect::new("Question", vec!["apple", "banana", "cherry"])
+            .with_default_ordering(&|a, b| b.cmp(a))
+            .with_scorer(&|_, _, _, idx| Some(-(idx as i64)))
+    };
+
+    let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+    let _ = prompt().prompt_with_backend(&mut backend).unwrap();
+    assert!(backend.frames()[0].has_token(&Token::Option {
+        index: 2,
+        value: "cherry".into(),
+        highlighted: true,
+        checked: false,
+    }));
+
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(
+        vec!["apple"],
+        ans.into_iter().map(|o| o.value).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn range_anchor_toggles_every_option_up_to_the_cursor() {
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3, 4, 5])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            ListOption::new(1, 2),
+            ListOption::new(2, 3),
+            ListOption::new(3, 4)
+        ],
+        ans
+    );
+}
+
+#[test]
+fn range_anchor_extends_upwards_and_deselects_a_fully_checked_range() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Up(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3, 4, 5])
+        .with_starting_cursor(3)
+        .with_default(&[0, 2, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, 1)], ans);
+}
+
+#[test]
+fn filtering_clears_the_range_anchor() {
+    let mut backend = fake_backend(vec![
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Char('3', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec![1, 2, 3, 4, 5])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(2, 3), ListOption::new(3, 4)], ans);
+}
+
+#[test]
+fn option_style_receives_whether_the_option_is_highlighted() {
+    let prompt = MultiSelect::new("Question", vec!["a", "b"])
+        .with_render_config(RenderConfig::empty())
+        .with_option_style(&|_, highlighted| match highlighted {
+            true => StyleSheet::new().with_fg(Color::LightCyan),
+            false => StyleSheet::new().with_fg(Color::DarkGrey),
+        });
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("a".into()).with_fg(Color::LightCyan),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("b".into()).with_fg(Color::DarkGrey),
+    ));
+}
+
+#[test]
+fn validate_config_reports_invalid_configurations() {
+    assert!(MultiSelect::new("Question", vec![1, 2, 3])
+        .with_default(&[2])
+        .validate_config()
+        .is_ok());
+
+    let invalid = [
+        MultiSelect::new("Question", Vec::<i32>::new()),
+        MultiSelect::new("Question", vec![1, 2, 3]).with_default(&[3]),
+        MultiSelect::new("Question", vec![1, 2, 3]).with_starting_cursor(3),
+    ];
+
+    for prompt in invalid {
+        assert!(matches!(
+            prompt.validate_config(),
+            Err(InquireError::InvalidConfiguration(_))
+        ));
+    }
+}
+
+#[test]
+fn highlighting_scorer_emphasizes_matched_ranges() {
+    let prompt = MultiSelect::new("Question", vec!["Banana", "Apple"])
+        .with_render_config(
+            RenderConfig::empty().with_option_match(StyleSheet::new().with_fg(Color::LightGreen)),
+        )
+        .with_highlighting_scorer(&|input, _option, string_value, _idx| {
+            let start = string_value.find(input)?;
+            let matched = start..start + input.len();
+            Some((0, vec![matched]))
+        });
+
+    let mut backend = Backend::new(
+        vec![
+            Key::Char('p', KeyModifiers::NONE),
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(vec![ListOption::new(1, "Apple")], ans);
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("A".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("p".into()).with_fg(Color::LightGreen),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("ple".into())));
+}
+
+#[test]
+fn empty_options_can_be_submitted_when_allowed() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = MultiSelect::new("Question", Vec::<&str>::new())
+        .with_empty_options_allowed(true)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(Vec::<ListOption<&str>>::new(), ans);
+    assert!(backend.frames()[0].has_token(&Token::EmptyOptions(
+        MultiSelect::<&str>::DEFAULT_EMPTY_OPTIONS_MESSAGE.into()
+    )));
+}
+
+#[test]
+fn empty_options_ignore_navigation_and_toggles() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", Vec::<i32>::new())
+        .with_empty_options_allowed(true)
+        .with_empty_options_message("Nothing to install")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert!(ans.is_empty());
+    assert!(backend.frames()[0].has_token(&Token::EmptyOptions("Nothing to install".into())));
+}
+
+#[test]
+fn empty_options_are_rejected_by_default() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let res = MultiSelect::new("Question", Vec::<i32>::new()).prompt_with_backend(&mut backend);
+
+    assert!(matches!(res, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn clicking_options_toggles_them() {
+    let prompt = MultiSelect::new("Question", vec!["a", "b", "c"]).with_mouse_support();
+
+    let mut backend = Backend::new(
+        vec![
+            Key::Click { row: 2, column: 4 },
+            Key::Click { row: 3, column: 4 },
+            Key::Click { row: 2, column: 4 },
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new().with_cursor_row(0),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(vec![ListOption::new(2, "c")], ans);
+    assert!(backend.terminal_mut().mouse_capture);
+}
+
+#[test]
+fn mouse_wheel_moves_the_cursor() {
+    let mut backend = fake_backend(vec![
+        Key::ScrollDown,
+        Key::ScrollDown,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::ScrollUp,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b", "c"])
+        .with_mouse_support()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "b"), ListOption::new(2, "c")], ans);
+}
+
+#[test]
+fn filter_placeholder_is_only_rendered_with_empty_input() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    MultiSelect::new("Fruit:", vec!["Banana", "Apple"])
+        .with_filter_placeholder("Type to filter")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let placeholder = Token::Placeholder("Type to filter".into());
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&placeholder));
+    assert!(frames[1].has_token(&Token::Input("a".into())));
+    assert!(!frames[1].has_token(&placeholder));
+    assert!(frames[2].has_token(&placeholder));
+}
+
+#[derive(Clone)]
+struct AtLeast(usize);
+
+impl<T> MultiOptionValidator<T> for AtLeast {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        match input.len() >= self.0 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid(ErrorMessage::Default)),
+        }
+    }
+}
+
+#[test]
+fn cloned_prompt_keeps_boxed_struct_validator() {
+    let prompt = MultiSelect::new("Fruits:", vec!["Banana", "Apple"]).with_validator(AtLeast(1));
+    let copy = prompt.clone();
+
+    for prompt in [prompt, copy] {
+        let mut backend = FakeBackend::new(vec![
+            Key::Enter,
+            Key::Char(' ', KeyModifiers::NONE),
+            Key::Enter,
+        ]);
+
+        let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+        assert_eq!(vec![ListOption::new(0, "Banana")], ans);
+        assert!(backend.frames()[1].has_token(&Token::ErrorMessage(ErrorMessage::Default)));
+    }
+}
+
+#[test]
+fn mandatory_options_can_not_be_unchecked() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Components:", vec!["docs", "core", "extras"])
+        .with_mandatory(&[1])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(1, "core")], ans);
+
+    let notice = Token::WarningMessage(ErrorMessage::Custom("This option is required".into()));
+    let core = |checked: bool| Token::Option {
+        index: 1,
+        value: "core".into(),
+        highlighted: false,
+        checked,
+    };
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&core(true)));
+    assert!(!frames[1].has_token(&notice));
+    assert!(frames[2].has_token(&notice));
+    assert!(!frames[3].has_token(&notice));
+    assert!(frames[4].has_token(&core(true)));
+    assert!(frames[5].has_token(&core(true)));
+}
+
+#[test]
+fn mandatory_options_are_always_part_of_the_answer() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('t', KeyModifiers::CONTROL),
+        Key::Char('t', KeyModifiers::CONTROL),
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::End,
+        Key::Char(' ', KeyModifiers::SHIFT),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Components:", vec!["docs", "core", "extras"])
+        .with_mandatory(&[0, 1])
+        .with_default(&[2])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![ListOption::new(0, "docs"), ListOption::new(1, "core")],
+        ans
+    );
+}
+
+#[test]
+fn mandatory_options_are_rendered_with_their_style_sheet() {
+    let prompt = MultiSelect::new("Question", vec!["a", "b"])
+        .with_render_config(
+            RenderConfig::empty().with_mandatory_option(StyleSheet::new().with_fg(Color::DarkGrey)),
+        )
+        .with_mandatory(&[1]);
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("a".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("b".into()).with_fg(Color::DarkGrey),
+    ));
+}
+
+#[test]
+fn out_of_bounds_mandatory_option_is_an_invalid_configuration() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let ans = MultiSelect::new("Question", vec!["a", "b"])
+        .with_mandatory(&[2])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn accessibility_announcer_receives_state_changes() {
+    let announcements = std::cell::RefCell::new(vec![]);
+    let announcer = |announcement: &str| announcements.borrow_mut().push(announcement.to_owned());
+
+    let mut backend = FakeBackend::new(vec![
+        Key::Char(' ', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = MultiSelect::new("Question", vec!["Apple", "Banana"])
+        .with_accessibility_announcer(&announcer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(vec![ListOption::new(0, "Apple")], ans);
+    assert_eq!(
+        vec![
+            "MultiSelect, 1 of 2, highlighted: Apple, not checked, 0 selected",
+            "MultiSelect, 1 of 2, highlighted: Apple, checked, 1 selected",
+            "MultiSelect, 2 of 2, highlighted: Banana, not checked, 1 selected",
+        ],
+        *announcements.borrow()
+    );
+}
@@ -122,8 +122,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<NaiveDate>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(NaiveDate)` where NaiveDate's value is the date selected by the user. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(NaiveDate)` where NaiveDate's value is the date selected by the user. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -158,8 +158,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<f64>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(f64)` where f64 is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(f64)` where f64 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -193,8 +193,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<f32>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(f32)` where f32 is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(f32)` where f32 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -228,8 +228,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u64>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(u64)` where u64 is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u64)` where u64 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -263,8 +263,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u32>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(u32)` where u32 is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u32)` where u32 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -298,8 +298,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<usize>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(usize)` where usize is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(usize)` where usize is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
@@ -333,8 +333,8 @@ where
 /// # Returns
 ///
 /// * `InquireResult<u128>`: An enum that represents the result of the prompt operation. If the operation is successful,
-///     it returns `InquireResult::Ok(u128)` where u128 is the number parsed from the user's input. If the operation
-///     encounters an error, it returns `InquireResult::Err(InquireError)`.
+///   it returns `InquireResult::Ok(u128)` where u128 is the number parsed from the user's input. If the operation
+///   encounters an error, it returns `InquireResult::Err(InquireError)`.
 ///
 /// # Example
 ///
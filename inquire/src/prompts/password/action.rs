@@ -6,12 +6,16 @@ use crate::{
 use super::config::PasswordConfig;
 
 /// Set of actions for a PasswordPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PasswordPromptAction {
     /// Action on the value text input handler.
     ValueInput(InputAction),
     /// Toggles the display mode between plain text and the initial one.
     ToggleDisplayMode,
+    /// Replaces the input with a password suggested by the generator.
+    GenerateSuggestion,
+    /// Pastes the text content of the system clipboard into the active input.
+    PasteFromClipboard,
 }
 
 impl InnerAction for PasswordPromptAction {
@@ -24,6 +28,16 @@ impl InnerAction for PasswordPromptAction {
             {
                 Self::ToggleDisplayMode
             }
+            Key::Char('n' | 'N', m)
+                if m.contains(KeyModifiers::CONTROL) && config.enable_generator =>
+            {
+                Self::GenerateSuggestion
+            }
+            Key::Char('v' | 'V', m)
+                if m.contains(KeyModifiers::CONTROL) && config.clipboard_paste =>
+            {
+                Self::PasteFromClipboard
+            }
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::ValueInput(action),
                 None => return None,
@@ -32,4 +46,11 @@ impl InnerAction for PasswordPromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        match self {
+            Self::ValueInput(action) => Some(action),
+            Self::ToggleDisplayMode | Self::GenerateSuggestion | Self::PasteFromClipboard => None,
+        }
+    }
 }
@@ -5,15 +5,31 @@ use crate::{Password, PasswordDisplayMode};
 pub struct PasswordConfig {
     /// Whether to allow the user to toggle the display mode of the password.
     pub enable_display_toggle: bool,
+    /// Whether the display toggle only reveals the password while held down.
+    pub reveal_while_held: bool,
     /// The initial display mode of the password.
     pub display_mode: PasswordDisplayMode,
+    /// Whether to display the errors of all failing validators.
+    pub show_all_errors: bool,
+    /// Whether a password generator is set, enabling its hotkey.
+    pub enable_generator: bool,
+    /// Whether Ctrl+V pastes the content of the system clipboard.
+    pub clipboard_paste: bool,
+    /// Whether a generated password is revealed for a few seconds, which
+    /// requires the input reader to wait for keys with a timeout.
+    pub timed_reveal: bool,
 }
 
 impl From<&Password<'_>> for PasswordConfig {
     fn from(value: &Password<'_>) -> Self {
         Self {
             enable_display_toggle: value.enable_display_toggle,
+            reveal_while_held: value.reveal_while_held,
             display_mode: value.display_mode,
+            show_all_errors: value.show_all_errors,
+            enable_generator: value.generator.is_some(),
+            clipboard_paste: value.clipboard_paste,
+            timed_reveal: true,
         }
     }
 }
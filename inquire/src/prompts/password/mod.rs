@@ -12,8 +12,9 @@ use crate::{
     error::{InquireError, InquireResult},
     formatter::StringFormatter,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, PasswordBackend, RenderConfig},
+    terminal::{default_terminal_supports_timeouts, get_default_terminal, record_answer},
+    type_aliases::{DynamicHelpMessage, PasswordGenerator, PasswordVerifier, StringTransformer},
+    ui::{Backend, PasswordBackend, RenderConfig, Styled},
     validator::StringValidator,
 };
 
@@ -47,9 +48,11 @@ pub enum PasswordDisplayMode {
 /// - **Toggle display mode**: When enabling this feature by calling the `with_display_toggle_enabled()` method, you allow the user to toggle between the standard display mode set and the full display mode.
 ///   - If you have set the standard display mode to hidden (which is also the default) or masked, the user can press `Ctrl+R` to change the display mode to `Full`, and `Ctrl+R` again to change it back to the standard one.
 ///   - Obviously, if you have set the standard display mode to `Full`, pressing `Ctrl+R` won't cause any changes.
+///   - Alternatively, `with_reveal_while_held()` makes the input visible only while `Ctrl+R` is held down. See its documentation for the limitations of this mode.
 /// - **Confirmation**: By default, the password will have a confirmation flow where the user will be asked for the input twice and the two responses will be compared. If they differ, an error message is shown and the user is prompted again.
 ///   - By default, a "Confirmation:" message is shown for the confirmation prompts, but this can be modified by setting a custom confirmation message only shown the second time, using the `with_custom_confirmation_message()` method.
 ///   - If confirmation is not desired, it can be turned off using the `without_confirmation()` method.
+///   - When the expected password is already known, e.g. as a hash, a verifier can be set using the `with_verifier()` method. It replaces the confirmation flow with a single input checked by the verifier.
 /// - **Help message**: Message displayed at the line below the prompt.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 ///   - By default, it prints eight asterisk characters: `********`.
@@ -92,24 +95,54 @@ pub struct Password<'a> {
     /// Message to be presented to the user when confirming the input.
     pub custom_confirmation_message: Option<&'a str>,
 
-    /// Error to be presented to the user when password confirmation fails.
+    /// Error to be presented to the user when password confirmation or verification fails.
     pub custom_confirmation_error_message: Option<&'a str>,
 
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current input on every render.
+    ///
+    /// When set, it takes precedence over [`help_message`](Self::help_message),
+    /// which is only displayed when the function returns `None`.
+    pub dynamic_help_message: Option<DynamicHelpMessage<'a>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
+    /// Function normalizing the submitted input, producing the value that is
+    /// then validated and returned by the prompt.
+    ///
+    /// Unlike [`formatter`](Self::formatter), which only affects how the answer
+    /// is displayed, the transformer changes the answer itself.
+    pub transformer: Option<StringTransformer<'a>>,
+
     /// How the password input is displayed to the user.
     pub display_mode: PasswordDisplayMode,
 
     /// Whether to allow the user to toggle the display of the current password input by pressing the Ctrl+R hotkey.
     pub enable_display_toggle: bool,
 
+    /// Whether the display toggle reveals the password only while the hotkey is held down,
+    /// instead of switching the display mode until the hotkey is pressed again.
+    pub reveal_while_held: bool,
+
     /// Whether to ask for input twice to see if the provided passwords are the same.
+    ///
+    /// Ignored when a [`verifier`](Self::verifier) is set.
     pub enable_confirmation: bool,
 
+    /// Function checking whether the submitted password is correct, replacing
+    /// the confirmation flow with a single input.
+    ///
+    /// When it returns `false`, the confirmation error message is displayed
+    /// and the user is prompted again.
+    pub verifier: Option<PasswordVerifier<'a>>,
+
+    /// Function generating a password suggestion, which replaces the input when
+    /// the user presses the Ctrl+N hotkey.
+    pub generator: Option<PasswordGenerator<'a>>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -118,6 +151,14 @@ pub struct Password<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Whether to execute all validators and display the errors of every failing one,
+    /// each on its own line, instead of stopping at the first error.
+    pub show_all_errors: bool,
+
+    /// Whether pressing Ctrl+V pastes the text content of the system clipboard
+    /// into the input. Requires the `clipboard` feature.
+    pub clipboard_paste: bool,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -136,12 +177,18 @@ impl<'a> Password<'a> {
     /// Default validators added to the [Password] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
 
+    /// Default value for the show all errors variable.
+    pub const DEFAULT_SHOW_ALL_ERRORS: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
     /// Default value for the allow display toggle variable.
     pub const DEFAULT_ENABLE_DISPLAY_TOGGLE: bool = false;
 
+    /// Default value for the reveal while held variable.
+    pub const DEFAULT_REVEAL_WHILE_HELD: bool = false;
+
     /// Default value for the enable confirmation variable.
     pub const DEFAULT_ENABLE_CONFIRMATION: bool = true;
 
@@ -155,11 +202,18 @@ impl<'a> Password<'a> {
             custom_confirmation_message: None,
             custom_confirmation_error_message: None,
             enable_confirmation: Self::DEFAULT_ENABLE_CONFIRMATION,
+            verifier: None,
+            generator: None,
             enable_display_toggle: Self::DEFAULT_ENABLE_DISPLAY_TOGGLE,
+            reveal_while_held: Self::DEFAULT_REVEAL_WHILE_HELD,
             display_mode: Self::DEFAULT_DISPLAY_MODE,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            dynamic_help_message: None,
             formatter: Self::DEFAULT_FORMATTER,
+            transformer: None,
             validators: Self::DEFAULT_VALIDATORS,
+            show_all_errors: Self::DEFAULT_SHOW_ALL_ERRORS,
+            clipboard_paste: false,
             render_config: get_configuration(),
         }
     }
@@ -170,25 +224,85 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current input on every render.
+    ///
+    /// When the function returns `None`, the help message set in
+    /// [`with_help_message`](Self::with_help_message) is displayed, if any.
+    ///
+    /// The function receives the unmasked input, so it must not display it as is.
+    pub fn with_dynamic_help_message(mut self, help_message: DynamicHelpMessage<'a>) -> Self {
+        self.dynamic_help_message = Some(help_message);
+        self
+    }
+
     /// Sets the flag to enable display toggling.
     pub fn with_display_toggle_enabled(mut self) -> Self {
         self.enable_display_toggle = true;
         self
     }
 
+    /// Enables the display toggle hotkey (Ctrl+R) in press-and-hold mode: the password is
+    /// displayed in full only while the hotkey is held down, reverting to the standard
+    /// display mode when released.
+    ///
+    /// Terminals do not report when a key is released, so the release is inferred from
+    /// the key-repeat events stopping: the standard display mode is restored once no
+    /// repeat arrives for half a second. This means that a single press reveals the
+    /// password briefly, and that the reveal lasts slightly longer than the hold. If the
+    /// key-repeat delay of the terminal is longer than that, the password is hidden
+    /// again for a moment before the repeats start arriving.
+    ///
+    /// Hiding the password again requires waiting for keys with a timeout, which
+    /// only the `crossterm` backend supports: when the default terminal is provided
+    /// by another backend, the prompt fails with `InquireError::InvalidConfiguration`.
+    pub fn with_reveal_while_held(mut self) -> Self {
+        self.enable_display_toggle = true;
+        self.reveal_while_held = true;
+        self
+    }
+
     /// Disables the confirmation step of the prompt.
     pub fn without_confirmation(mut self) -> Self {
         self.enable_confirmation = false;
         self
     }
 
+    /// Sets a function verifying the submitted password, e.g. against a stored hash.
+    ///
+    /// The user is asked for the password once, and the verifier replaces the
+    /// confirmation flow: when set, it takes precedence over
+    /// [`enable_confirmation`](Self::enable_confirmation). If the verifier returns
+    /// `false`, the confirmation error message is displayed and the user is
+    /// prompted again.
+    pub fn with_verifier(mut self, verifier: PasswordVerifier<'a>) -> Self {
+        self.verifier = Some(verifier);
+        self
+    }
+
+    /// Sets a function generating a password, e.g. a random one, offered to the
+    /// user in flows setting a new password.
+    ///
+    /// Pressing Ctrl+N replaces the input with a generated password, which is
+    /// then submitted, confirmed and validated like a typed one. When the display
+    /// toggle is enabled, the generated password is displayed in full for a few
+    /// seconds so that the user can record it, e.g. to type it again when
+    /// confirmation is enabled.
+    ///
+    /// The timed reveal requires the `crossterm` backend: with the default terminal
+    /// of other backends, the generated password keeps the standard display mode
+    /// and can be revealed with the display toggle instead.
+    pub fn with_generator(mut self, generator: PasswordGenerator<'a>) -> Self {
+        self.generator = Some(generator);
+        self
+    }
+
     /// Sets the prompt message when asking for the password confirmation.
     pub fn with_custom_confirmation_message(mut self, message: &'a str) -> Self {
         self.custom_confirmation_message.replace(message);
         self
     }
 
-    /// Sets the prompt error message when password confirmation fails.
+    /// Sets the prompt error message when password confirmation or verification fails.
     pub fn with_custom_confirmation_error_message(mut self, message: &'a str) -> Self {
         self.custom_confirmation_error_message.replace(message);
         self
@@ -206,6 +320,18 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets a function normalizing the submitted input, e.g. trimming whitespace.
+    ///
+    /// On submission, the input is transformed first, then the transformed value
+    /// is validated and, if valid, returned as the answer. When confirmation is
+    /// enabled, both inputs are transformed before being compared. Unlike the formatter,
+    /// which only affects how the answer is displayed, the transformer changes
+    /// the answer itself.
+    pub fn with_transformer(mut self, transformer: StringTransformer<'a>) -> Self {
+        self.transformer = Some(transformer);
+        self
+    }
+
     /// Adds a validator to the collection of validators. You might want to use this feature
     /// in case you need to limit the user to specific choices, such as requiring
     /// special characters in the password.
@@ -236,6 +362,28 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Executes all validators on submission and displays the errors of every failing
+    /// one, each on its own line, instead of stopping at the first error.
+    pub fn with_all_errors_shown(mut self) -> Self {
+        self.show_all_errors = true;
+        self
+    }
+
+    /// Enables pasting the text content of the system clipboard with Ctrl+V,
+    /// e.g. a password copied from a password manager.
+    ///
+    /// The clipboard content is inserted at the cursor of the active input, be
+    /// it the password or its confirmation, and is never passed to the event
+    /// observer. The clipboard itself is left untouched, clearing it is up to
+    /// the application. When the clipboard is unavailable, an error message is
+    /// displayed and the input is left untouched.
+    #[cfg(feature = "clipboard")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "clipboard")))]
+    pub fn with_clipboard_paste(mut self) -> Self {
+        self.clipboard_paste = true;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -249,6 +397,26 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -269,15 +437,27 @@ impl<'a> Password<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
+        if self.reveal_while_held && !default_terminal_supports_timeouts() {
+            return Err(InquireError::InvalidConfiguration(
+                "Revealing the password while held requires the crossterm backend".into(),
+            ));
+        }
+
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        let mut prompt = PasswordPrompt::from(self);
+        if !default_terminal_supports_timeouts() {
+            prompt = prompt.without_timed_reveal();
+        }
+        prompt.prompt_with_render_callback(&mut backend, record_answer)
     }
 
-    pub(crate) fn prompt_with_backend<B: PasswordBackend>(
-        self,
-        backend: &mut B,
-    ) -> InquireResult<String> {
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: PasswordBackend>(self, backend: &mut B) -> InquireResult<String> {
         PasswordPrompt::from(self).prompt(backend)
     }
 }
@@ -1,15 +1,26 @@
+use std::time::{Duration, Instant};
+
 use crate::{
+    clipboard,
     error::InquireResult,
     formatter::StringFormatter,
     input::Input,
     prompts::prompt::{ActionResult, Prompt},
+    type_aliases::{DynamicHelpMessage, PasswordGenerator, PasswordVerifier, StringTransformer},
     ui::PasswordBackend,
-    validator::{ErrorMessage, StringValidator, Validation},
-    InquireError, Password, PasswordDisplayMode,
+    validator::{merge_validations, AnswerWarning, ErrorMessage, StringValidator, Validation},
+    InputAction, InquireError, Password, PasswordDisplayMode,
 };
 
 use super::{action::PasswordPromptAction, config::PasswordConfig};
 
+// Terminals don't report key releases, so a held key is considered released
+// once its key-repeat events stop arriving for this long.
+const REVEAL_HOLD_TIMEOUT: Duration = Duration::from_millis(500);
+
+// How long a generated password is displayed for the user to record it.
+const GENERATED_REVEAL_DURATION: Duration = Duration::from_secs(5);
+
 // Helper type for representing the password confirmation flow.
 struct PasswordConfirmation<'a> {
     // The message of the prompt.
@@ -22,22 +33,44 @@ struct PasswordConfirmation<'a> {
     pub input: Input,
 }
 
+// Helper type for representing the password verification flow.
+struct PasswordVerification<'a> {
+    // The function checking the submitted password.
+    pub verifier: PasswordVerifier<'a>,
+
+    // The error message of the prompt.
+    pub error_message: &'a str,
+}
+
 pub struct PasswordPrompt<'a> {
     message: &'a str,
     config: PasswordConfig,
     help_message: Option<&'a str>,
+    dynamic_help_message: Option<DynamicHelpMessage<'a>>,
     input: Input,
     current_mode: PasswordDisplayMode,
+    hide_at: Option<Instant>,
     confirmation: Option<PasswordConfirmation<'a>>, // if `None`, confirmation is disabled, `Some(_)` confirmation is enabled
     confirmation_stage: bool,
+    verification: Option<PasswordVerification<'a>>,
+    generator: Option<PasswordGenerator<'a>>,
     formatter: StringFormatter<'a>,
+    transformer: Option<StringTransformer<'a>>,
     validators: Vec<Box<dyn StringValidator>>,
     error: Option<ErrorMessage>,
+    warning: Option<AnswerWarning<String>>,
 }
 
 impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
     fn from(so: Password<'a>) -> Self {
-        let confirmation = match so.enable_confirmation {
+        let verification = so.verifier.map(|verifier| PasswordVerification {
+            verifier,
+            error_message: so
+                .custom_confirmation_error_message
+                .unwrap_or("The password is incorrect."),
+        });
+
+        let confirmation = match so.enable_confirmation && verification.is_none() {
             true => Some(PasswordConfirmation {
                 message: so.custom_confirmation_message.unwrap_or("Confirmation:"),
                 error_message: so
@@ -52,13 +85,19 @@ impl<'a> From<Password<'a>> for PasswordPrompt<'a> {
             message: so.message,
             config: (&so).into(),
             help_message: so.help_message,
+            dynamic_help_message: so.dynamic_help_message,
             current_mode: so.display_mode,
+            hide_at: None,
             confirmation,
             confirmation_stage: false,
+            verification,
+            generator: so.generator,
             formatter: so.formatter,
+            transformer: so.transformer,
             validators: so.validators,
             input: Input::new(),
             error: None,
+            warning: None,
         }
     }
 }
@@ -70,6 +109,21 @@ impl<'a> From<&'a str> for Password<'a> {
 }
 
 impl<'a> PasswordPrompt<'a> {
+    /// Keeps generated passwords in the standard display mode, for input
+    /// readers unable to wait for keys with a timeout, with which the reveal
+    /// would last until the end of the prompt.
+    pub fn without_timed_reveal(mut self) -> Self {
+        self.config.timed_reveal = false;
+        self
+    }
+
+    fn active_input(&self) -> &Input {
+        match &self.confirmation {
+            Some(c) if self.confirmation_stage => &c.input,
+            _ => &self.input,
+        }
+    }
+
     fn active_input_mut(&mut self) -> &mut Input {
         if let Some(c) = &mut self.confirmation {
             if self.confirmation_stage {
@@ -81,13 +135,50 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn toggle_display_mode(&mut self) -> ActionResult {
+        if self.config.reveal_while_held {
+            return self.reveal(REVEAL_HOLD_TIMEOUT);
+        }
+
+        self.hide_at = None;
+
         let new_mode = match self.current_mode {
             PasswordDisplayMode::Hidden | PasswordDisplayMode::Masked => PasswordDisplayMode::Full,
             PasswordDisplayMode::Full => self.config.display_mode,
         };
 
-        if new_mode != self.current_mode {
-            self.current_mode = new_mode;
+        self.set_display_mode(new_mode)
+    }
+
+    /// Displays the password in full, reverting to the standard display mode
+    /// once `duration` elapses.
+    fn reveal(&mut self, duration: Duration) -> ActionResult {
+        self.hide_at = Some(Instant::now() + duration);
+        self.set_display_mode(PasswordDisplayMode::Full)
+    }
+
+    fn generate_suggestion(&mut self) -> ActionResult {
+        let generator = match self.generator {
+            // the confirmation must be typed by the user
+            Some(generator) if !self.confirmation_stage => generator,
+            _ => return ActionResult::Clean,
+        };
+
+        self.input = Input::new_with(generator());
+
+        // a password revealed with the toggle stays revealed.
+        if self.config.enable_display_toggle
+            && self.config.timed_reveal
+            && self.current_mode != PasswordDisplayMode::Full
+        {
+            self.reveal(GENERATED_REVEAL_DURATION);
+        }
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn set_display_mode(&mut self, mode: PasswordDisplayMode) -> ActionResult {
+        if mode != self.current_mode {
+            self.current_mode = mode;
             ActionResult::NeedsRedraw
         } else {
             ActionResult::Clean
@@ -95,12 +186,17 @@ impl<'a> PasswordPrompt<'a> {
     }
 
     fn confirmation_step(&mut self) -> ConfirmationStepResult {
-        let cur_answer = self.cur_answer().to_owned();
+        let cur_answer = self.transform(self.cur_answer());
+        let confirmed_answer = self
+            .confirmation
+            .as_ref()
+            .map(|confirmation| self.transform(confirmation.input.content()));
+
         match &mut self.confirmation {
             None => ConfirmationStepResult::NoConfirmationRequired,
             Some(confirmation) => {
                 if self.confirmation_stage {
-                    if cur_answer == confirmation.input.content() {
+                    if Some(cur_answer) == confirmed_answer {
                         ConfirmationStepResult::ConfirmationValidated
                     } else {
                         self.confirmation_stage = false;
@@ -119,21 +215,26 @@ impl<'a> PasswordPrompt<'a> {
         }
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.cur_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
-            }
-        }
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
+        let results = self
+            .validators
+            .iter()
+            .map(|validator| validator.validate(answer));
 
-        Ok(Validation::Valid)
+        merge_validations(results, self.config.show_all_errors).map_err(InquireError::Custom)
     }
 
     fn cur_answer(&self) -> &str {
         self.input.content()
     }
+
+    /// Input after going through the transformer, if any.
+    fn transform(&self, input: &str) -> String {
+        match self.transformer {
+            Some(transformer) => transformer(input),
+            None => input.to_owned(),
+        }
+    }
 }
 
 impl<'a, Backend> Prompt<Backend> for PasswordPrompt<'a>
@@ -168,22 +269,58 @@ where
         Ok(true)
     }
 
-    fn submit(&mut self) -> InquireResult<Option<String>> {
-        if let Validation::Invalid(msg) = self.validate_current_answer()? {
-            self.error = Some(msg);
-            if self.config.display_mode == PasswordDisplayMode::Hidden {
-                self.input.clear();
+    fn observes_content(&self) -> bool {
+        false
+    }
+
+    fn tick_interval(&self) -> Option<Duration> {
+        self.hide_at
+            .map(|hide_at| hide_at.saturating_duration_since(Instant::now()))
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        match self.hide_at {
+            Some(hide_at) if Instant::now() >= hide_at => {
+                self.hide_at = None;
+                Ok(self.set_display_mode(self.config.display_mode))
             }
-            return Ok(None);
+            _ => Ok(ActionResult::Clean),
         }
+    }
 
-        let confirmation = self.confirmation_step();
+    fn submit(&mut self) -> InquireResult<Option<String>> {
+        let answer = self.transform(self.cur_answer());
+
+        match self.validate_answer(&answer)? {
+            Validation::Valid => {}
+            Validation::Warning(msg) => {
+                // the input is kept even when hidden, so that it can be submitted again
+                self.error = None;
+                if !AnswerWarning::confirm(&mut self.warning, msg, answer.clone()) {
+                    return Ok(None);
+                }
+            }
+            Validation::Invalid(msg) => {
+                self.error = Some(msg);
+                self.warning = None;
+                if self.config.display_mode == PasswordDisplayMode::Hidden {
+                    self.input.clear();
+                }
+                return Ok(None);
+            }
+        }
 
-        let cur_answer = self.cur_answer().to_owned();
+        if let Some(verification) = &self.verification {
+            if !(verification.verifier)(&answer) {
+                self.error = Some(ErrorMessage::Custom(verification.error_message.to_owned()));
+                self.input.clear();
+                return Ok(None);
+            }
+        }
 
-        let result = match confirmation {
+        let result = match self.confirmation_step() {
             ConfirmationStepResult::NoConfirmationRequired
-            | ConfirmationStepResult::ConfirmationValidated => Some(cur_answer),
+            | ConfirmationStepResult::ConfirmationValidated => Some(answer),
             ConfirmationStepResult::ConfirmationPending => None,
             ConfirmationStepResult::ConfirmationInvalidated(message) => {
                 self.error = Some(message);
@@ -201,6 +338,18 @@ where
                 self.active_input_mut().handle(input_action).into()
             }
             PasswordPromptAction::ToggleDisplayMode => self.toggle_display_mode(),
+            PasswordPromptAction::GenerateSuggestion => self.generate_suggestion(),
+            PasswordPromptAction::PasteFromClipboard => match clipboard::read_text() {
+                Ok(text) => {
+                    self.error = None;
+                    self.active_input_mut().handle(InputAction::Paste(text));
+                    ActionResult::NeedsRedraw
+                }
+                Err(message) => {
+                    self.error = Some(message);
+                    ActionResult::NeedsRedraw
+                }
+            },
         };
 
         Ok(result)
@@ -211,6 +360,10 @@ where
             backend.render_error_message(err)?;
         }
 
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        }
+
         match self.current_mode {
             PasswordDisplayMode::Hidden => {
                 backend.render_prompt(self.message)?;
@@ -250,7 +403,11 @@ where
             }
         }
 
-        if let Some(message) = self.help_message {
+        let dynamic_help_message = self
+            .dynamic_help_message
+            .and_then(|help_message| help_message(self.active_input().content()));
+
+        if let Some(message) = dynamic_help_message.as_deref().or(self.help_message) {
             backend.render_help_message(message)?;
         }
 
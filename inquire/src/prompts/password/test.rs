@@ -1,5 +1,8 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 use super::Password;
-use crate::ui::{Key, KeyModifiers};
+use crate::test::{render_frames, FakeBackend, Token};
+use crate::ui::{Key, KeyModifiers, RenderConfig};
 use crate::validator::{ErrorMessage, Validation};
 
 macro_rules! text_to_events {
@@ -254,3 +257,315 @@ password_test!(
     "anor",
     Password::new("").with_display_mode(crate::PasswordDisplayMode::Masked)
 );
+
+#[test]
+fn reveal_while_held_reverts_after_key_repeats_stop() {
+    let mut backend = FakeBackend::new_with_timeouts(vec![
+        Some(Key::Char('a', KeyModifiers::NONE)),
+        Some(Key::Char('r', KeyModifiers::CONTROL)),
+        Some(Key::Char('r', KeyModifiers::CONTROL)),
+        None,
+        Some(Key::Enter),
+    ]);
+
+    let ans = Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_reveal_while_held()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("a", ans);
+
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&Token::MaskedInput("a".into())));
+    assert!(frames[2].has_token(&Token::Input("a".into())));
+    assert!(frames[3].has_token(&Token::MaskedInput("a".into())));
+}
+
+#[test]
+fn display_toggle_persists_without_reveal_while_held() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::CONTROL),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_display_toggle_enabled()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ab", ans);
+
+    let frames = backend.frames();
+    assert!(frames[2].has_token(&Token::Input("a".into())));
+    assert!(frames[3].has_token(&Token::Input("ab".into())));
+}
+
+#[test]
+fn dynamic_help_message_receives_the_active_input() {
+    let mut events: Vec<Key> = text_to_events!("abc");
+    events.push(Key::Enter);
+    events.push(Key::Char('a', KeyModifiers::NONE));
+    events.push(Key::Backspace);
+    let confirmation: Vec<Key> = text_to_events!("abc");
+    events.extend(confirmation);
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    Password::new("Password:")
+        .with_dynamic_help_message(&|input| Some(format!("{} characters", input.chars().count())))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert!(frames[3].has_token(&Token::HelpMessage("3 characters".into())));
+    // confirmation stage starts with an empty input
+    assert!(frames[4].has_token(&Token::HelpMessage("0 characters".into())));
+    assert!(frames[5].has_token(&Token::HelpMessage("1 characters".into())));
+}
+
+#[test]
+fn verifier_replaces_confirmation_and_rejects_wrong_passwords() {
+    let mut events: Vec<Key> = text_to_events!("wrong");
+    events.push(Key::Enter);
+    let correct: Vec<Key> = text_to_events!("hunter2");
+    events.extend(correct);
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Password::new("Current password:")
+        .with_verifier(&|password| password == "hunter2")
+        .with_custom_confirmation_error_message("Wrong password.")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("hunter2", ans);
+
+    let frames = backend.frames();
+    let error = Token::ErrorMessage(ErrorMessage::Custom("Wrong password.".into()));
+    assert!(frames.iter().any(|frame| frame.has_token(&error)));
+    assert!(!frames
+        .iter()
+        .any(|frame| frame.has_token(&Token::Prompt("Confirmation:".into()))));
+}
+
+#[test]
+fn transformer_applies_to_both_inputs_when_confirming() {
+    let mut events: Vec<Key> = text_to_events!("secret  ");
+    events.push(Key::Enter);
+    let confirmation: Vec<Key> = text_to_events!("secret");
+    events.extend(confirmation);
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Password::new("Password:")
+        .with_transformer(&|input| input.trim_end().to_owned())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("secret", ans);
+}
+
+password_test!(
+    ctrl_u_clears_input,
+    {
+        let mut events: Vec<Key> = text_to_events!("secret");
+        events.push(Key::Char('u', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("new\n"));
+        events
+    },
+    "new",
+    Password::new("").without_confirmation()
+);
+
+#[test]
+fn generated_password_goes_through_confirmation() {
+    let mut events = vec![Key::Char('n', KeyModifiers::CONTROL), Key::Enter];
+    events.append(&mut text_to_events!("wrong"));
+    events.push(Key::Enter);
+    events.push(Key::Char('n', KeyModifiers::CONTROL));
+    events.push(Key::Enter);
+    // the generator is not available while confirming
+    events.push(Key::Char('n', KeyModifiers::CONTROL));
+    events.append(&mut text_to_events!("s3cr3t"));
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Password::new("")
+        .with_generator(&|| String::from("s3cr3t"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("s3cr3t", ans);
+    assert!(backend
+        .frames()
+        .iter()
+        .any(
+            |frame| frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+                "The answers don't match.".into()
+            )))
+        ));
+}
+
+#[test]
+fn generated_password_is_validated() {
+    let mut events = vec![Key::Char('n', KeyModifiers::CONTROL), Key::Enter];
+    events.append(&mut text_to_events!("long enough"));
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_generator(&|| String::from("short"))
+        .with_validator(|ans: &str| match ans.len() {
+            0..=5 => Ok(Validation::Invalid("Too short".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("shortlong enough", ans);
+}
+
+#[test]
+fn generated_password_is_revealed_when_display_toggle_is_enabled() {
+    let events = vec![Key::Char('n', KeyModifiers::CONTROL), Key::Enter];
+
+    let mut backend = FakeBackend::new(events.clone());
+    Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_display_toggle_enabled()
+        .with_generator(&|| String::from("s3cr3t"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert!(backend.frames()[1].has_token(&Token::Input("s3cr3t".into())));
+
+    let mut backend = FakeBackend::new(events);
+    Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_generator(&|| String::from("s3cr3t"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert!(backend.frames()[1].has_token(&Token::MaskedInput("s3cr3t".into())));
+}
+
+#[test]
+fn generated_password_is_not_revealed_without_timed_reveal() {
+    use super::prompt::PasswordPrompt;
+    use crate::prompts::prompt::Prompt;
+
+    let events = vec![Key::Char('n', KeyModifiers::CONTROL), Key::Enter];
+    let prompt = Password::new("")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_display_toggle_enabled()
+        .with_generator(&|| String::from("s3cr3t"));
+
+    let mut backend = FakeBackend::new(events);
+    PasswordPrompt::from(prompt)
+        .without_timed_reveal()
+        .prompt(&mut backend)
+        .unwrap();
+
+    assert!(backend.frames()[1].has_token(&Token::MaskedInput("s3cr3t".into())));
+}
+
+#[test]
+fn masked_input_has_one_mask_per_grapheme() {
+    let password = "a👨\u{200d}👩\u{200d}👧🇧🇷e\u{301}";
+    assert_eq!(4, password.graphemes(true).count());
+
+    let prompt = Password::new("Password:")
+        .without_confirmation()
+        .with_display_mode(crate::PasswordDisplayMode::Masked)
+        .with_render_config(RenderConfig::empty());
+
+    let frames = render_frames(prompt, text_to_events!(password)).unwrap();
+
+    assert_eq!("? Password: ****", frames.last().unwrap());
+}
+
+#[test]
+#[cfg(not(feature = "clipboard"))]
+fn clipboard_paste_reports_error_without_clipboard_feature() {
+    let mut events: Vec<Key> = text_to_events!("ab");
+    events.push(Key::Char('v', KeyModifiers::CONTROL));
+    events.push(Key::Enter);
+
+    let mut prompt = Password::new("").without_confirmation();
+    prompt.clipboard_paste = true;
+
+    let mut backend = FakeBackend::new(events);
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("ab", ans);
+    assert!(backend.frames()[3].has_token(&Token::ErrorMessage(
+        "Could not paste from the clipboard: the `clipboard` feature is disabled".into()
+    )));
+}
+
+#[test]
+fn clipboard_paste_is_not_passed_to_the_event_observer() {
+    let mut events: Vec<Key> = text_to_events!("ab");
+    events.push(Key::Char('v', KeyModifiers::CONTROL));
+    events.push(Key::Enter);
+
+    let mut prompt = Password::new("").without_confirmation();
+    prompt.clipboard_paste = true;
+
+    crate::clipboard::test::stub_next_read("s3cr3t");
+    let mut backend = FakeBackend::new(events);
+    let (ans, observed) =
+        crate::config::test::observe_events(|| prompt.prompt_with_backend(&mut backend));
+
+    assert_eq!("abs3cr3t", ans.unwrap());
+    assert_eq!(
+        vec![
+            (Key::Char('*', KeyModifiers::NONE), None),
+            (Key::Char('*', KeyModifiers::NONE), None),
+            (Key::Char('*', KeyModifiers::NONE), None),
+            (Key::Enter, None),
+        ],
+        observed
+    );
+}
+
+#[test]
+fn typed_and_pasted_content_is_not_passed_to_the_event_observer() {
+    let events = vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('B', KeyModifiers::SHIFT),
+        Key::Paste(String::from("s3cr3t")),
+        Key::Backspace,
+        Key::Enter,
+    ];
+
+    let mut backend = FakeBackend::new(events);
+    let (ans, observed) = crate::config::test::observe_events(|| {
+        Password::new("")
+            .without_confirmation()
+            .prompt_with_backend(&mut backend)
+    });
+
+    assert_eq!("aBs3cr3", ans.unwrap());
+    assert_eq!(
+        vec![
+            (Key::Char('*', KeyModifiers::NONE), None),
+            (Key::Char('*', KeyModifiers::NONE), None),
+            (Key::Paste(String::new()), None),
+            (Key::Backspace, None),
+            (Key::Enter, None),
+        ],
+        observed
+    );
+}
@@ -1,8 +1,19 @@
 //! Definitions of common behavior shared amongst all different prompt types.
 
-use crate::{error::InquireResult, input::InputActionResult, ui::CommonBackend, InquireError};
+use std::time::{Duration, Instant};
 
-use super::action::{Action, InnerAction};
+use crate::{
+    config::{notify_event_observer, redact_content},
+    error::InquireResult,
+    input::InputActionResult,
+    ui::{CommonBackend, Key},
+    InquireError,
+};
+
+use super::{
+    action::{Action, InnerAction},
+    timeout::{PromptTimeout, TimeoutAction},
+};
 
 /// Represents the result of an action on the prompt.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -14,11 +25,16 @@ pub enum ActionResult {
     /// The action either didn't result in a state change or the state
     /// change does not require a redraw.
     Clean,
+
+    /// The action completed the user input, which should be submitted
+    /// right away as if the user had pressed the submit key.
+    NeedsSubmit,
 }
 
 impl ActionResult {
     pub fn merge(self, other: Self) -> Self {
         match (self, other) {
+            (Self::NeedsSubmit, _) | (_, Self::NeedsSubmit) => Self::NeedsSubmit,
             (Self::NeedsRedraw, _) | (_, Self::NeedsRedraw) => Self::NeedsRedraw,
             (Self::Clean, Self::Clean) => Self::Clean,
         }
@@ -26,7 +42,7 @@ impl ActionResult {
 
     /// Returns whether the action requires a redraw.
     pub fn needs_redraw(&self) -> bool {
-        matches!(self, Self::NeedsRedraw)
+        matches!(self, Self::NeedsRedraw | Self::NeedsSubmit)
     }
 }
 
@@ -61,6 +77,22 @@ where
     /// actions to the prompt.
     fn config(&self) -> &Self::Config;
 
+    /// Parses a key event into a prompt action.
+    ///
+    /// Defaults to [`Action::from_key`], which reserves some keys, e.g. `enter`,
+    /// for actions common to all prompts. Prompts might override this to take
+    /// over such keys.
+    fn action_from_key(&self, key: Key) -> Option<Action<Self::InnerAction>> {
+        Action::from_key(key, self.config())
+    }
+
+    /// Key replacing the default submit keys, e.g. `enter`, when set.
+    ///
+    /// The keys cancelling or interrupting the prompt are not affected.
+    fn submit_key(&self) -> Option<&Key> {
+        None
+    }
+
     /// Hook called when a prompt is finished. Returns a string
     /// to be rendered to the user as the final submission to the prompt.
     ///
@@ -71,8 +103,11 @@ where
 
     /// Hook called when a prompt is first started, before the first
     /// draw happens.
-    fn setup(&mut self) -> InquireResult<()> {
-        Ok(())
+    ///
+    /// Returns [`ActionResult::NeedsSubmit`] when the answer is already known
+    /// and should be submitted without waiting for user input.
+    fn setup(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::NeedsRedraw)
     }
 
     /// Hook called when an input to cancel the prompt is triggered.
@@ -104,6 +139,48 @@ where
     /// to the prompt, which will then be submitted to this method just the same.
     fn handle(&mut self, action: Self::InnerAction) -> InquireResult<ActionResult>;
 
+    /// Interval in which the prompt wants to be woken up by [`tick`](Self::tick)
+    /// when no key events arrive, e.g. to animate a loading indicator.
+    ///
+    /// When `None`, the prompt blocks until the next key event.
+    fn tick_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Hook called when [`tick_interval`](Self::tick_interval) elapses without
+    /// any key events. Returns whether the prompt must be re-rendered.
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        Ok(ActionResult::Clean)
+    }
+
+    /// Inactivity timeout of the prompt, after which it is resolved without
+    /// any further user input.
+    fn timeout(&self) -> Option<PromptTimeout> {
+        None
+    }
+
+    /// Hook called when the prompt times out with [`TimeoutAction::SubmitDefault`],
+    /// right before the answer is submitted. Should discard the current input
+    /// so that the default value is submitted.
+    fn restore_default(&mut self) {}
+
+    /// Whether the content typed in the prompt can be passed to the global
+    /// event observer. When `false`, character and paste keys are redacted and
+    /// input actions are withheld, e.g. for passwords.
+    fn observes_content(&self) -> bool {
+        true
+    }
+
+    /// Whether mouse events are reported to the prompt as keys, e.g. to scroll
+    /// through a list of options.
+    fn mouse_support(&self) -> bool {
+        false
+    }
+
+    /// Hook called before every rendering of the prompt UI with the backend it
+    /// is rendered to, e.g. to fit the displayed options to the terminal height.
+    fn fit_to_terminal(&mut self, _backend: &Backend) {}
+
     /// Hook called for the rendering of the prompt UI.
     ///
     /// The implementation should **not** call neither `frame_setup` or
@@ -115,45 +192,118 @@ where
     ///
     /// This should not be reimplemented by types that implement this trait,
     /// unless the situation really warrants it.
-    fn prompt(mut self, backend: &mut Backend) -> InquireResult<Self::Output> {
-        self.setup()?;
+    fn prompt(self, backend: &mut Backend) -> InquireResult<Self::Output> {
+        self.prompt_with_render_callback(backend, |_, _| {})
+    }
+
+    /// Same flow as [`prompt`](Self::prompt), calling `on_answer` with the
+    /// prompt message and the formatted answer once the latter is rendered.
+    fn prompt_with_render_callback<F>(
+        mut self,
+        backend: &mut Backend,
+        mut on_answer: F,
+    ) -> InquireResult<Self::Output>
+    where
+        F: FnMut(&str, &str),
+    {
+        let mut last_handle = self.setup()?;
+
+        if self.mouse_support() {
+            backend.enable_mouse_capture()?;
+        }
 
-        let mut last_handle = ActionResult::NeedsRedraw;
+        let mut last_input_at = Instant::now();
         let final_answer = loop {
+            if last_handle == ActionResult::NeedsSubmit {
+                if let Some(answer) = self.submit()? {
+                    break answer;
+                }
+                last_handle = ActionResult::NeedsRedraw;
+            }
+
             if last_handle.needs_redraw() {
+                self.fit_to_terminal(backend);
+
                 backend.frame_setup()?;
                 self.render(backend)?;
                 backend.frame_finish(false)?;
                 last_handle = ActionResult::Clean;
             }
 
-            let key = backend.read_key()?;
-            let action = Action::from_key(key, self.config());
+            let timeout_left = self
+                .timeout()
+                .map(|timeout| timeout.duration.saturating_sub(last_input_at.elapsed()));
 
-            if let Some(action) = action {
-                last_handle = match action {
-                    Action::Submit => {
-                        if let Some(answer) = self.submit()? {
-                            break answer;
-                        }
-                        ActionResult::NeedsRedraw
+            let wait = match (self.tick_interval(), timeout_left) {
+                (Some(interval), Some(left)) => Some(interval.min(left)),
+                (interval, left) => interval.or(left),
+            };
+
+            let key = match wait {
+                Some(wait) => backend.read_key_timeout(wait)?,
+                None => Some(backend.read_key()?),
+            };
+
+            last_handle = match key {
+                Some(key) => {
+                    last_input_at = Instant::now();
+
+                    let action = match (self.submit_key(), self.action_from_key(key.clone())) {
+                        (_, action @ Some(Action::Cancel | Action::Interrupt)) => action,
+                        (Some(submit_key), _) if *submit_key == key => Some(Action::Submit),
+                        (Some(_), Some(Action::Submit)) => None,
+                        (_, action) => action,
+                    };
+
+                    let input_action = match &action {
+                        Some(Action::Inner(inner_action)) => inner_action.input_action(),
+                        _ => None,
+                    };
+                    match self.observes_content() {
+                        true => notify_event_observer(key, input_action),
+                        false => notify_event_observer(redact_content(key), None),
                     }
-                    Action::Cancel => {
-                        let pre_cancel_result = self.pre_cancel()?;
-
-                        if pre_cancel_result {
-                            backend.frame_setup()?;
-                            backend.render_canceled_prompt(self.message())?;
-                            backend.frame_finish(true)?;
-                            return Err(InquireError::OperationCanceled);
+
+                    match action {
+                        Some(Action::Submit) => ActionResult::NeedsSubmit,
+                        Some(Action::Cancel) => {
+                            let pre_cancel_result = self.pre_cancel()?;
+
+                            if pre_cancel_result {
+                                backend.frame_setup()?;
+                                backend.render_canceled_prompt(self.message())?;
+                                backend.frame_finish(true)?;
+                                return Err(InquireError::OperationCanceled);
+                            }
+
+                            ActionResult::NeedsRedraw
                         }
+                        Some(Action::Interrupt) => return Err(InquireError::OperationInterrupted),
+                        Some(Action::Inner(inner_action)) => self.handle(inner_action)?,
+                        None => last_handle,
+                    }
+                }
+                None => match self.timeout() {
+                    Some(timeout) if last_input_at.elapsed() >= timeout.duration => {
+                        last_input_at = Instant::now();
 
-                        ActionResult::NeedsRedraw
+                        match timeout.action {
+                            TimeoutAction::Cancel => {
+                                backend.frame_setup()?;
+                                backend.render_canceled_prompt(self.message())?;
+                                backend.frame_finish(true)?;
+                                return Err(InquireError::OperationCanceled);
+                            }
+                            TimeoutAction::SubmitDefault => {
+                                self.restore_default();
+                                ActionResult::NeedsSubmit
+                            }
+                            TimeoutAction::SubmitCurrent => ActionResult::NeedsSubmit,
+                        }
                     }
-                    Action::Interrupt => return Err(InquireError::OperationInterrupted),
-                    Action::Inner(inner_action) => self.handle(inner_action)?,
-                };
-            }
+                    _ => self.tick()?,
+                },
+            };
         };
 
         let formatted = self.format_answer(&final_answer);
@@ -162,6 +312,8 @@ where
         backend.render_prompt_with_answer(self.message(), &formatted)?;
         backend.frame_finish(true)?;
 
+        on_answer(self.message(), &formatted);
+
         Ok(final_answer)
     }
 }
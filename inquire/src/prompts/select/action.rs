@@ -6,7 +6,7 @@ use crate::{
 use super::config::SelectConfig;
 
 /// Set of actions for a SelectPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SelectPromptAction {
     /// Action on the value text input handler.
     FilterInput(InputAction),
@@ -22,6 +22,14 @@ pub enum SelectPromptAction {
     MoveToStart,
     /// Moves the cursor to the end of the list.
     MoveToEnd,
+    /// Expands the highlighted option, when displaying options as a tree.
+    Expand,
+    /// Collapses the highlighted option, or moves the cursor to its parent
+    /// when it is not expanded, when displaying options as a tree.
+    Collapse,
+    /// Selects the option rendered at the given row of the prompt, where the
+    /// mouse was clicked.
+    Click(u16),
 }
 
 impl InnerAction for SelectPromptAction {
@@ -32,6 +40,8 @@ impl InnerAction for SelectPromptAction {
             let action = match key {
                 Key::Char('k', KeyModifiers::NONE) => Some(Self::MoveUp),
                 Key::Char('j', KeyModifiers::NONE) => Some(Self::MoveDown),
+                Key::Char('l', KeyModifiers::NONE) if config.tree_mode => Some(Self::Expand),
+                Key::Char('h', KeyModifiers::NONE) if config.tree_mode => Some(Self::Collapse),
                 _ => None,
             };
 
@@ -41,14 +51,26 @@ impl InnerAction for SelectPromptAction {
         }
 
         let action = match key {
-            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => Self::MoveUp,
+            Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) | Key::ScrollUp => {
+                Self::MoveUp
+            }
             Key::PageUp(_) => Self::PageUp,
             Key::Home => Self::MoveToStart,
 
-            Key::Down(KeyModifiers::NONE) | Key::Char('n', KeyModifiers::CONTROL) => Self::MoveDown,
+            Key::Down(KeyModifiers::NONE)
+            | Key::Char('n', KeyModifiers::CONTROL)
+            | Key::ScrollDown => Self::MoveDown,
             Key::PageDown(_) => Self::PageDown,
             Key::End => Self::MoveToEnd,
 
+            Key::Right(KeyModifiers::NONE) if config.tree_mode => Self::Expand,
+            Key::Left(KeyModifiers::NONE) if config.tree_mode => Self::Collapse,
+
+            Key::Left(KeyModifiers::NONE) if config.inline_cycle => Self::MoveUp,
+            Key::Right(KeyModifiers::NONE) if config.inline_cycle => Self::MoveDown,
+
+            Key::Click { row, .. } => Self::Click(row),
+
             key => match InputAction::from_key(key, &()) {
                 Some(action) => Self::FilterInput(action),
                 None => return None,
@@ -57,4 +79,11 @@ impl InnerAction for SelectPromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        match self {
+            Self::FilterInput(action) => Some(action),
+            _ => None,
+        }
+    }
 }
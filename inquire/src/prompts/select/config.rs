@@ -7,16 +7,41 @@ pub struct SelectConfig {
     pub vim_mode: bool,
     /// Page size of the list of options.
     pub page_size: usize,
+    /// Whether the page size is computed from the height of the terminal.
+    pub auto_page_size: bool,
     /// Whether to reset the cursor to the first option on filter input change.
     pub reset_cursor: bool,
+    /// Whether options that have an URL are rendered as hyperlinks.
+    pub emit_hyperlinks: bool,
+    /// Whether the cursor wraps around when moving past the first or last option.
+    pub wrap_around: bool,
+    /// Whether the options are displayed as a tree.
+    pub tree_mode: bool,
+    /// Whether mouse events are reported to the prompt.
+    pub mouse_support: bool,
+    /// Whether the highlighted option is displayed inline, cycled with the
+    /// left and right arrows.
+    pub inline_cycle: bool,
+    /// Whether the only option matching the filter input is submitted right away.
+    pub autoselect_on_single_match: bool,
 }
 
 impl<T> From<&Select<'_, T>> for SelectConfig {
     fn from(value: &Select<'_, T>) -> Self {
         Self {
             vim_mode: value.vim_mode,
-            page_size: value.page_size,
+            page_size: match value.page_size {
+                0 => crate::config::DEFAULT_PAGE_SIZE,
+                page_size => page_size,
+            },
+            auto_page_size: value.page_size == 0,
             reset_cursor: value.reset_cursor,
+            wrap_around: value.wrap_around,
+            emit_hyperlinks: value.render_config.emit_hyperlinks,
+            tree_mode: value.tree_levels.is_some(),
+            mouse_support: value.mouse_support,
+            inline_cycle: value.inline_cycle,
+            autoselect_on_single_match: value.autoselect_on_single_match,
         }
     }
 }
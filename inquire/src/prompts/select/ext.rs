@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use crate::Select;
+
+/// Extension trait to build a [`Select`] prompt out of any collection of
+/// displayable items.
+///
+/// The collection is consumed: its items are collected into the `Vec` of
+/// options owned by the prompt. Call it on `.iter()` to select among
+/// references instead.
+///
+/// # Example
+///
+/// ```
+/// use inquire::SelectExt;
+///
+/// let languages = vec!["Rust", "C", "Go"];
+///
+/// let prompt = languages.iter().select("Favorite language:").with_page_size(2);
+///
+/// assert_eq!(3, prompt.options.len());
+/// assert_eq!(2, prompt.page_size);
+/// ```
+pub trait SelectExt: IntoIterator + Sized
+where
+    Self::Item: Display,
+{
+    /// Creates a [`Select`] prompt with the given message and the items of
+    /// this collection as options, in iteration order.
+    fn select(self, message: &str) -> Select<'_, Self::Item> {
+        Select::new(message, self.into_iter().collect())
+    }
+}
+
+impl<I> SelectExt for I
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+}
@@ -1,22 +1,29 @@
 mod action;
 mod config;
+mod ext;
 mod prompt;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
+mod tree;
 
 pub use action::*;
+pub use ext::*;
 use std::fmt::Display;
 
 use crate::{
     config::get_configuration,
     error::{InquireError, InquireResult},
     formatter::OptionFormatter,
-    list_option::ListOption,
+    list_option::{Linkable, ListOption, OptionProvider, TreeLevel, TreeOption},
+    matching::MatchingStrategy,
     prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    type_aliases::Scorer,
-    ui::{Backend, RenderConfig, SelectBackend},
+    terminal::{get_default_terminal, record_answer},
+    type_aliases::{
+        AccessibilityAnnouncer, HighlightingScorer, OptionDescription, OptionOrdering, OptionStyle,
+        OptionSuffix, Scorer,
+    },
+    ui::{Backend, RenderConfig, SelectBackend, Styled},
 };
 
 use self::prompt::SelectPrompt;
@@ -50,6 +57,7 @@ static DEFAULT_MATCHER: Lazy<SkimMatcherV2> = Lazy::new(|| SkimMatcherV2::defaul
 /// - **Page size**: Number of options displayed at once, 7 by default.
 /// - **Display option indexes**: On long lists, it might be helpful to display the indexes of the options to the user. Via the `RenderConfig`, you can set the display mode of the indexes as a prefix of an option. The default configuration is `None`, to not render any index when displaying the options.
 /// - **Scorer function**: Function that defines the order of options and if displayed as all.
+/// - **Tree mode**: Options created with [`Select::new_tree`] are displayed as a tree, see its docs.
 ///
 /// # Example
 ///
@@ -81,6 +89,8 @@ pub struct Select<'a, T> {
     pub help_message: Option<&'a str>,
 
     /// Page size of the options displayed to the user.
+    ///
+    /// When `0`, the page size is fit to the height of the terminal.
     pub page_size: usize,
 
     /// Whether vim mode is enabled. When enabled, the user can
@@ -93,22 +103,114 @@ pub struct Select<'a, T> {
     /// Starting filter input
     pub starting_filter_input: Option<&'a str>,
 
+    /// Placeholder displayed in the filter input while it is empty.
+    pub filter_placeholder: Option<&'a str>,
+
     /// Reset cursor position to first option on filter input change.
     /// Defaults to true.
     pub reset_cursor: bool,
 
+    /// Whether moving the cursor past the last option goes back to the first
+    /// one, and vice-versa.
+    ///
+    /// Defaults to true.
+    pub wrap_around: bool,
+
     /// Whether to allow the option list to be filtered by user input or not.
     ///
     /// Defaults to true.
     pub filter_input_enabled: bool,
 
+    /// Whether typing moves the cursor to the next option starting with the
+    /// typed characters. Only applies when the filter input is disabled.
+    ///
+    /// Defaults to false.
+    pub typeahead_enabled: bool,
+
+    /// Whether the mouse wheel moves the cursor and clicking an option
+    /// selects it. Only supported by the `crossterm` back-end.
+    ///
+    /// Defaults to false.
+    pub mouse_support: bool,
+
+    /// Whether the highlighted option is displayed inline, next to the prompt
+    /// message, instead of in a list of options.
+    ///
+    /// Defaults to false.
+    pub inline_cycle: bool,
+
+    /// Whether the only option matching the filter input, e.g. the starting
+    /// one, is submitted right away.
+    ///
+    /// Defaults to false.
+    pub autoselect_on_single_match: bool,
+
     /// Function called with the current user input to score the provided
     /// options.
+    ///
+    /// When the user appends characters to the filter input, only the options
+    /// matched by the previous input are scored again, so an option that does
+    /// not match an input must not match any input starting with it either.
     pub scorer: Scorer<'a, T>,
 
+    /// Function scoring the options like [`scorer`](Self::scorer), also
+    /// reporting the parts of each option that matched the filter input so they
+    /// are highlighted with the
+    /// [`option_match`](crate::ui::RenderConfig::option_match) style.
+    ///
+    /// When set, it is used instead of the scorer.
+    pub highlighting_scorer: Option<HighlightingScorer<'a, T>>,
+
+    /// Function ordering the options displayed while the filter input is empty,
+    /// e.g. most recently used first. Once the user types a filter, options are
+    /// ranked by the scorer instead.
+    pub default_ordering: Option<OptionOrdering<'a, T>>,
+
+    /// Strings used in place of the options' `Display` output when scoring
+    /// them against the filter input. The rendered labels are not affected.
+    ///
+    /// When set, it must have the same length as the option list, otherwise
+    /// the prompt will fail with an `InquireError::InvalidConfiguration` error.
+    pub search_strings: Option<Vec<String>>,
+
+    /// Function returning the URL of an option, which is then rendered as a
+    /// hyperlink if enabled in the render config. Set by [`with_links`](Self::with_links).
+    pub option_url: Option<fn(&T) -> Option<&str>>,
+
+    /// Function describing the highlighted option, whose result is displayed in
+    /// the help area in place of [`help_message`](Self::help_message).
+    pub option_description: Option<OptionDescription<'a, T>>,
+
+    /// Function styling the label of each option, receiving whether it is
+    /// highlighted. The highlighted option style of the render config is
+    /// applied on top of it.
+    pub option_style: Option<OptionStyle<'a, T>>,
+
+    /// Callback receiving an announcement of the state of the prompt, e.g.
+    /// for a screen reader, whenever it changes.
+    pub accessibility_announcer: Option<AccessibilityAnnouncer<'a>>,
+
+    /// Function returning the secondary text of each option, rendered
+    /// right-aligned on its row.
+    pub option_suffix: Option<OptionSuffix<'a, T>>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: OptionFormatter<'a, T>,
 
+    /// Depth and expansion state of each option when they are displayed as a
+    /// tree, see [`Select::new_tree`].
+    ///
+    /// When set, it must have the same length as the option list, otherwise
+    /// the prompt will fail with an `InquireError::InvalidConfiguration` error.
+    pub tree_levels: Option<Vec<TreeLevel>>,
+
+    /// Source of options fetched on demand, see [`Select::new_from_provider`].
+    ///
+    /// When set, `options` must be empty, and tree levels and search strings
+    /// can not be used, otherwise the prompt will fail with an
+    /// `InquireError::InvalidConfiguration` error.
+    pub option_provider: Option<Box<dyn OptionProvider<T> + 'a>>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -191,14 +293,38 @@ where
     /// Defaults to true.
     pub const DEFAULT_RESET_CURSOR: bool = true;
 
+    /// Default cursor behaviour when moving past the first or last option.
+    /// Defaults to true.
+    pub const DEFAULT_WRAP_AROUND: bool = true;
+
     /// Default filter input enabled behaviour.
     /// Defaults to true.
     pub const DEFAULT_FILTER_INPUT_ENABLED: bool = true;
 
+    /// Default type-ahead enabled behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_TYPEAHEAD_ENABLED: bool = false;
+
+    /// Default mouse support behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_MOUSE_SUPPORT: bool = false;
+
+    /// Default inline cycle behaviour.
+    /// Defaults to false.
+    pub const DEFAULT_INLINE_CYCLE: bool = false;
+
+    /// Default behaviour of autoselecting the only option matching the filter.
+    /// Defaults to false.
+    pub const DEFAULT_AUTOSELECT_ON_SINGLE_MATCH: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> =
         Some("↑↓ to move, enter to select, type to filter");
 
+    /// Default help message of prompts created with [`Select::new_tree`].
+    pub const DEFAULT_TREE_HELP_MESSAGE: Option<&'a str> =
+        Some("↑↓ to move, →← to expand/collapse, enter to select, type to filter");
+
     /// Creates a [Select] with the provided message and options, along with default configuration values.
     pub fn new(message: &'a str, options: Vec<T>) -> Self {
         Self {
@@ -209,11 +335,115 @@ where
             vim_mode: Self::DEFAULT_VIM_MODE,
             starting_cursor: Self::DEFAULT_STARTING_CURSOR,
             reset_cursor: Self::DEFAULT_RESET_CURSOR,
+            wrap_around: Self::DEFAULT_WRAP_AROUND,
             filter_input_enabled: Self::DEFAULT_FILTER_INPUT_ENABLED,
+            typeahead_enabled: Self::DEFAULT_TYPEAHEAD_ENABLED,
+            mouse_support: Self::DEFAULT_MOUSE_SUPPORT,
+            inline_cycle: Self::DEFAULT_INLINE_CYCLE,
+            autoselect_on_single_match: Self::DEFAULT_AUTOSELECT_ON_SINGLE_MATCH,
             scorer: Self::DEFAULT_SCORER,
+            highlighting_scorer: None,
+            search_strings: None,
+            default_ordering: None,
+            option_url: None,
+            option_description: None,
+            option_style: None,
+            accessibility_announcer: None,
+            option_suffix: None,
             formatter: Self::DEFAULT_FORMATTER,
+            tree_levels: None,
+            option_provider: None,
             render_config: get_configuration(),
             starting_filter_input: None,
+            filter_placeholder: None,
+        }
+    }
+
+    /// Creates a [Select] whose options are displayed as a tree, e.g. a settings
+    /// menu with nested categories.
+    ///
+    /// Options are listed in depth-first order: the children of an option are the
+    /// options right after it with a greater depth. Pressing the right arrow key
+    /// expands the highlighted option and the left arrow key collapses it, or moves
+    /// the cursor to its parent when it is not expanded. Navigation skips the
+    /// descendants of collapsed options.
+    ///
+    /// While the filter input is not empty, the tree is flattened: all matching
+    /// options are displayed along with their ancestors, in tree order.
+    ///
+    /// The returned value can be any option of the tree, not only leaves, and its
+    /// index is relative to the provided list. The starting cursor is relative to
+    /// the options initially displayed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{list_option::TreeOption, Select};
+    ///
+    /// let options = vec![
+    ///     TreeOption::new("Display", 0).with_expanded(true),
+    ///     TreeOption::new("Resolution", 1),
+    ///     TreeOption::new("Brightness", 1),
+    ///     TreeOption::new("Sound", 0),
+    ///     TreeOption::new("Output device", 1),
+    /// ];
+    ///
+    /// let setting = Select::new_tree("Which setting do you want to change?", options).prompt();
+    /// ```
+    pub fn new_tree(message: &'a str, options: Vec<TreeOption<T>>) -> Self {
+        let (options, tree_levels) = options
+            .into_iter()
+            .map(|option| (option.value, option.level))
+            .unzip();
+
+        Self {
+            help_message: Self::DEFAULT_TREE_HELP_MESSAGE,
+            tree_levels: Some(tree_levels),
+            ..Self::new(message, options)
+        }
+    }
+
+    /// Creates a [Select] whose options are fetched on demand from `provider`,
+    /// e.g. when they come from a paginated API and can not be loaded upfront.
+    ///
+    /// Options are fetched a page at a time, as the user scrolls, and kept until
+    /// the filter input changes. The filter input is passed as is to the provider,
+    /// which is then responsible for filtering the options: the scorer is not used.
+    ///
+    /// As the total number of options is only known once the provider returns a
+    /// partial page, the cursor only wraps around after all options have been
+    /// fetched, and jumping to the end moves to the last fetched option.
+    ///
+    /// The index of the returned option is its position in the list fetched with
+    /// the final filter input.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::{list_option::OptionProvider, Select};
+    ///
+    /// #[derive(Clone)]
+    /// struct Numbers;
+    ///
+    /// impl OptionProvider<u64> for Numbers {
+    ///     fn fetch(&mut self, offset: usize, limit: usize, filter: &str) -> Vec<u64> {
+    ///         (0..)
+    ///             .filter(|n: &u64| n.to_string().contains(filter))
+    ///             .skip(offset)
+    ///             .take(limit)
+    ///             .collect()
+    ///     }
+    /// }
+    ///
+    /// let number = Select::new_from_provider("Pick a number:", Numbers).prompt();
+    /// ```
+    pub fn new_from_provider<P>(message: &'a str, provider: P) -> Self
+    where
+        P: OptionProvider<T> + 'a,
+    {
+        Self {
+            option_provider: Some(Box::new(provider)),
+            ..Self::new(message, vec![])
         }
     }
 
@@ -230,6 +460,12 @@ where
     }
 
     /// Sets the page size.
+    ///
+    /// A page size of `0` fits the page to the height of the terminal, minus the
+    /// rows used by the rest of the prompt, recomputing it on every render so that
+    /// it follows terminal resizes. At least one option is displayed, and the
+    /// [default page size](Self::DEFAULT_PAGE_SIZE) is used when the terminal
+    /// height is unknown.
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
@@ -247,6 +483,104 @@ where
         self
     }
 
+    /// Sets a scoring function that also reports the parts of each option
+    /// matching the filter input, which are then highlighted. It is used
+    /// instead of the scoring function.
+    ///
+    /// With the `fuzzy` feature,
+    /// [`FUZZY_HIGHLIGHTING_SCORER`](MatchingStrategy::FUZZY_HIGHLIGHTING_SCORER)
+    /// highlights the fuzzy-matched characters.
+    pub fn with_highlighting_scorer(mut self, scorer: HighlightingScorer<'a, T>) -> Self {
+        self.highlighting_scorer = Some(scorer);
+        self
+    }
+
+    /// Sets the strategy used to match options against the filter input,
+    /// replacing the scoring function.
+    pub fn with_matching_strategy(mut self, strategy: MatchingStrategy<'a, T>) -> Self {
+        self.scorer = strategy.scorer();
+        self
+    }
+
+    /// Sets the order in which options are displayed while the filter input is
+    /// empty, e.g. most recently used first. Options comparing equal keep their
+    /// relative order.
+    ///
+    /// The ordering does not affect the ranking of options once the user types a
+    /// filter, which is computed by the scorer.
+    ///
+    /// Note: options displayed as a tree or fetched from an option provider
+    /// are always displayed in their own order.
+    pub fn with_default_ordering(mut self, ordering: OptionOrdering<'a, T>) -> Self {
+        self.default_ordering = Some(ordering);
+        self
+    }
+
+    /// Sets the strings matched against the filter input instead of the
+    /// options' `Display` output, e.g. to leave out decorations such as emoji.
+    ///
+    /// There must be exactly one search string per option, in the same order.
+    pub fn with_search_strings(mut self, search_strings: Vec<String>) -> Self {
+        self.search_strings = Some(search_strings);
+        self
+    }
+
+    /// Renders options that have an URL as clickable hyperlinks, in terminals
+    /// that support them.
+    ///
+    /// Hyperlinks are only emitted when enabled in the render config, see
+    /// [`RenderConfig::with_hyperlinks`](crate::ui::RenderConfig::with_hyperlinks).
+    pub fn with_links(mut self) -> Self
+    where
+        T: Linkable,
+    {
+        self.option_url = Some(T::url);
+        self
+    }
+
+    /// Sets a function describing the highlighted option, called on every render.
+    ///
+    /// Its result is displayed in the help area, overriding the static help
+    /// message, e.g. to show what each command of a menu does. When it returns
+    /// `None`, the help message set in [`with_help_message`](Self::with_help_message)
+    /// is displayed, if any.
+    pub fn with_option_description(mut self, description: OptionDescription<'a, T>) -> Self {
+        self.option_description = Some(description);
+        self
+    }
+
+    /// Sets a function styling the label of each option from its value, e.g. to
+    /// render errors in red, called on every render with the option and whether
+    /// it is highlighted.
+    ///
+    /// The returned style sheet replaces the option style of the render config,
+    /// and the highlighted option style is applied on top of it.
+    pub fn with_option_style(mut self, option_style: OptionStyle<'a, T>) -> Self {
+        self.option_style = Some(option_style);
+        self
+    }
+
+    /// Sets a callback receiving an announcement of the state of the prompt
+    /// whenever it changes, e.g. when the highlighted option does, so that a
+    /// screen reader integration or a text-to-speech engine can speak it.
+    ///
+    /// Announcements look like `Select, 3 of 12, highlighted: Banana, filter: ba`.
+    pub fn with_accessibility_announcer(mut self, announcer: AccessibilityAnnouncer<'a>) -> Self {
+        self.accessibility_announcer = Some(announcer);
+        self
+    }
+
+    /// Sets a function returning the secondary text of each option, e.g. the
+    /// version and size of a package, called on every render.
+    ///
+    /// The text is rendered right-aligned within the width of the terminal, on
+    /// the same row as the option. When the row is too narrow, the text is
+    /// truncated with an ellipsis, or omitted if there is no room left at all.
+    pub fn with_option_suffix(mut self, option_suffix: OptionSuffix<'a, T>) -> Self {
+        self.option_suffix = Some(option_suffix);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: OptionFormatter<'a, T>) -> Self {
         self.formatter = formatter;
@@ -262,12 +596,80 @@ where
         self
     }
 
+    /// Sets the starting cursor to the first option equal to `value`, e.g. the
+    /// option selected the last time the prompt was displayed.
+    ///
+    /// When no option is equal to `value`, the starting cursor is left unchanged.
+    /// See [`try_with_starting_value`](Self::try_with_starting_value) to fail instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inquire::Select;
+    ///
+    /// let last_choice = "Lemon";
+    /// let options = vec!["Banana", "Apple", "Lemon", "Pear"];
+    ///
+    /// let ans = Select::new("What's your favorite fruit?", options)
+    ///     .with_starting_value(&last_choice)
+    ///     .prompt();
+    /// ```
+    pub fn with_starting_value(mut self, value: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        if let Some(position) = self.position_of(value) {
+            self.starting_cursor = position;
+        }
+        self
+    }
+
+    /// Sets the starting cursor to the first option equal to `value`, returning
+    /// an `InquireError::InvalidConfiguration` error when no option is equal to it.
+    ///
+    /// See [`with_starting_value`](Self::with_starting_value) to ignore missing values instead.
+    pub fn try_with_starting_value(mut self, value: &T) -> InquireResult<Self>
+    where
+        T: PartialEq,
+    {
+        match self.position_of(value) {
+            Some(position) => {
+                self.starting_cursor = position;
+                Ok(self)
+            }
+            None => Err(InquireError::InvalidConfiguration(
+                "Starting value is not one of the options".into(),
+            )),
+        }
+    }
+
+    /// Position of the first option equal to `value` among the displayed options,
+    /// which are only the visible ones in tree prompts.
+    fn position_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        match &self.tree_levels {
+            Some(tree) => tree::visible_options(tree)
+                .into_iter()
+                .position(|i| self.options.get(i) == Some(value)),
+            None => self.options.iter().position(|option| option == value),
+        }
+    }
+
     /// Sets the starting filter input
     pub fn with_starting_filter_input(mut self, starting_filter_input: &'a str) -> Self {
         self.starting_filter_input = Some(starting_filter_input);
         self
     }
 
+    /// Sets the placeholder displayed in the filter input while it is empty,
+    /// hinting that typing filters the options.
+    pub fn with_filter_placeholder(mut self, filter_placeholder: &'a str) -> Self {
+        self.filter_placeholder = Some(filter_placeholder);
+        self
+    }
+
     /// Sets the reset_cursor behaviour. Defaults to true.
     ///
     /// When there's an input change that results in a different list of options being displayed,
@@ -277,6 +679,15 @@ where
         self
     }
 
+    /// Sets whether the cursor wraps around the list of options. Defaults to true.
+    ///
+    /// When disabled, moving up from the first option or down from the last one
+    /// does nothing, instead of jumping to the other end of the list.
+    pub fn with_wrap_around(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
     /// Disables the filter input, which means the user will not be able to filter the options
     /// by typing.
     ///
@@ -287,6 +698,55 @@ where
         self
     }
 
+    /// Enables type-ahead: typing jumps the cursor to the next option whose
+    /// string value starts with the typed characters, ignoring case.
+    ///
+    /// Characters typed in quick succession are combined into a single prefix,
+    /// which is reset after one second without typing. Like in native list boxes,
+    /// repeatedly pressing the same letter cycles through the matching options.
+    ///
+    /// Type-ahead only takes effect when filtering is disabled with
+    /// [`without_filtering`](Self::without_filtering).
+    pub fn with_typeahead(mut self) -> Self {
+        self.typeahead_enabled = true;
+        self
+    }
+
+    /// Enables mouse support: scrolling the mouse wheel moves the cursor and
+    /// clicking an option selects it right away.
+    ///
+    /// The mouse is captured while the prompt is displayed, which prevents
+    /// selecting text in the terminal with it. Only supported by the
+    /// `crossterm` back-end, and clicks assume each option takes a single row,
+    /// which might not hold when long options wrap around.
+    pub fn with_mouse_support(mut self) -> Self {
+        self.mouse_support = true;
+        self
+    }
+
+    /// Displays the prompt in a single line, as in `? Color: < red >`, where
+    /// the left and right arrows cycle through the options instead of moving
+    /// through a vertical list of them.
+    ///
+    /// Filtering is disabled and the help message is not displayed in this
+    /// mode, which is not compatible with tree options created with
+    /// [`new_tree`](Self::new_tree).
+    pub fn with_inline_cycle(mut self) -> Self {
+        self.inline_cycle = true;
+        self
+    }
+
+    /// Submits the only option matching the filter input right away, without
+    /// further interaction, which makes disambiguation prompts trivial when
+    /// the starting filter input already narrows the options down to one.
+    ///
+    /// This applies whenever the filter input, starting or typed, leaves
+    /// exactly one option. An empty filter input never autoselects.
+    pub fn with_autoselect_on_single_match(mut self) -> Self {
+        self.autoselect_on_single_match = true;
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -300,6 +760,110 @@ where
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the prefix of the highlighted option and its style sheet for this
+    /// prompt only, keeping the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_cursor_glyph(mut self, glyph: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_highlighted_option_prefix(glyph);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
+    /// Checks that the configuration of the prompt is valid, returning the
+    /// `InquireError::InvalidConfiguration` error the prompt would fail with
+    /// otherwise, e.g. when there are no options or the starting cursor is
+    /// out-of-bounds.
+    ///
+    /// The terminal is not touched, so this can be used to validate prompts at
+    /// startup. Prompting methods call it before acquiring the terminal.
+    pub fn validate_config(&self) -> InquireResult<()> {
+        if self.option_provider.is_some() {
+            if !self.options.is_empty() {
+                return Err(InquireError::InvalidConfiguration(
+                    "Options can not be set along with an option provider".into(),
+                ));
+            }
+            if self.tree_levels.is_some() {
+                return Err(InquireError::InvalidConfiguration(
+                    "Tree levels can not be set along with an option provider".into(),
+                ));
+            }
+            if self.search_strings.is_some() {
+                return Err(InquireError::InvalidConfiguration(
+                    "Search strings can not be set along with an option provider".into(),
+                ));
+            }
+
+            return Ok(());
+        }
+
+        if self.options.is_empty() {
+            return Err(InquireError::InvalidConfiguration(
+                "Available options can not be empty".into(),
+            ));
+        }
+
+        if self.inline_cycle && self.tree_levels.is_some() {
+            return Err(InquireError::InvalidConfiguration(
+                "Tree levels can not be displayed in inline cycle mode".into(),
+            ));
+        }
+
+        let visible_options = match &self.tree_levels {
+            Some(tree) if tree.len() != self.options.len() => {
+                return Err(InquireError::InvalidConfiguration(format!(
+                    "Length {} of tree levels does not match length {} of options",
+                    tree.len(),
+                    self.options.len()
+                )));
+            }
+            Some(tree) => {
+                tree::validate(tree)?;
+                tree::visible_options(tree).len()
+            }
+            None => self.options.len(),
+        };
+
+        if self.starting_cursor >= visible_options {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Starting cursor index {} is out-of-bounds for length {} of options",
+                self.starting_cursor, visible_options
+            )));
+        }
+
+        match &self.search_strings {
+            Some(search_strings) if search_strings.len() != self.options.len() => {
+                Err(InquireError::InvalidConfiguration(format!(
+                    "Length {} of search strings does not match length {} of options",
+                    search_strings.len(),
+                    self.options.len()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -331,12 +895,47 @@ where
     /// Returns a [`ListOption`](crate::list_option::ListOption) containing
     /// the index of the selection and the owned object selected by the user.
     pub fn raw_prompt(self) -> InquireResult<ListOption<T>> {
+        self.raw_prompt_with_render_callback(|_, _| {})
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Once the user submits, `on_answer` is called with the prompt message
+    /// and the formatted answer exactly as rendered on the final line, which
+    /// is useful to log or replay the session. It is not called when the
+    /// prompt is canceled or interrupted.
+    pub fn prompt_with_render_callback<F>(self, on_answer: F) -> InquireResult<T>
+    where
+        F: FnMut(&str, &str),
+    {
+        self.raw_prompt_with_render_callback(on_answer)
+            .map(|op| op.value)
+    }
+
+    fn raw_prompt_with_render_callback<F>(self, on_answer: F) -> InquireResult<ListOption<T>>
+    where
+        F: FnMut(&str, &str),
+    {
+        self.validate_config()?;
+
+        let mut on_answer = on_answer;
+        let on_answer = |message: &str, answer: &str| {
+            record_answer(message, answer);
+            on_answer(message, answer);
+        };
+
         let (input_reader, terminal) = get_default_terminal()?;
         let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        SelectPrompt::new(self)?.prompt_with_render_callback(&mut backend, on_answer)
     }
 
-    pub(crate) fn prompt_with_backend<B: SelectBackend>(
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: SelectBackend>(
         self,
         backend: &mut B,
     ) -> InquireResult<ListOption<T>> {
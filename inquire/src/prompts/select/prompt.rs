@@ -1,18 +1,78 @@
-use std::{cmp::Reverse, fmt::Display};
+use std::{
+    cell::Cell,
+    cmp::Reverse,
+    collections::HashMap,
+    fmt::Display,
+    ops::Range,
+    time::{Duration, Instant},
+};
 
 use crate::{
+    accessibility::{describe_options, AccessibleState, Announcer},
     error::InquireResult,
     formatter::OptionFormatter,
     input::{Input, InputActionResult},
-    list_option::ListOption,
+    list_option::{ListOption, OptionProvider, TreeLevel},
+    matching::{ranges_in_label, score_option},
     prompts::prompt::{ActionResult, Prompt},
-    type_aliases::Scorer,
-    ui::SelectBackend,
-    utils::paginate,
-    InquireError, Select,
+    type_aliases::{
+        HighlightingScorer, OptionDescription, OptionOrdering, OptionStyle, OptionSuffix, Scorer,
+    },
+    ui::{Hyperlink, SelectBackend, StyleSheet},
+    utils::{fit_page_size, paginate, Page},
+    InputAction, Select,
+};
+
+use super::{
+    action::SelectPromptAction,
+    config::SelectConfig,
+    tree::{self, TreeLabel},
 };
 
-use super::{action::SelectPromptAction, config::SelectConfig};
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Characters typed in quick succession to jump to a matching option.
+struct TypeAhead {
+    buffer: String,
+    last_keystroke: Instant,
+}
+
+impl TypeAhead {
+    fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            last_keystroke: Instant::now(),
+        }
+    }
+
+    /// Appends the character to the buffer, starting a new one if the
+    /// previous keystroke is too old.
+    fn push(&mut self, c: char) {
+        if self.last_keystroke.elapsed() > TYPEAHEAD_TIMEOUT {
+            self.buffer.clear();
+        }
+
+        self.last_keystroke = Instant::now();
+        self.buffer.extend(c.to_lowercase());
+    }
+
+    /// Prefix to look for. Typing the same letter repeatedly cycles through
+    /// the options starting with it, instead of looking for e.g. "aaa".
+    fn prefix(&self) -> &str {
+        match self.buffer.chars().next() {
+            Some(first) if self.is_cycling() => &self.buffer[..first.len_utf8()],
+            _ => &self.buffer,
+        }
+    }
+
+    fn is_cycling(&self) -> bool {
+        let mut chars = self.buffer.chars();
+        match chars.next() {
+            Some(first) => chars.all(|c| c == first),
+            None => true,
+        }
+    }
+}
 
 pub struct SelectPrompt<'a, T> {
     message: &'a str,
@@ -20,58 +80,205 @@ pub struct SelectPrompt<'a, T> {
     options: Vec<T>,
     string_options: Vec<String>,
     scored_options: Vec<usize>,
+    /// Filter input of the last scorer run and the indexes of the options
+    /// it matched, in ascending order.
+    last_filter: Option<(String, Vec<usize>)>,
     help_message: Option<&'a str>,
     cursor_index: usize,
     input: Option<Input>,
+    typeahead: Option<TypeAhead>,
     scorer: Scorer<'a, T>,
+    highlighting_scorer: Option<HighlightingScorer<'a, T>>,
+    /// Byte ranges of the string values of the options that matched the
+    /// current filter input, as reported by the highlighting scorer.
+    match_highlights: HashMap<usize, Vec<Range<usize>>>,
+    default_ordering: Option<OptionOrdering<'a, T>>,
     formatter: OptionFormatter<'a, T>,
+    option_url: Option<fn(&T) -> Option<&str>>,
+    option_description: Option<OptionDescription<'a, T>>,
+    option_style: Option<OptionStyle<'a, T>>,
+    option_suffix: Option<OptionSuffix<'a, T>>,
+    tree: Option<Vec<TreeLevel>>,
+    provider: Option<Box<dyn OptionProvider<T> + 'a>>,
+    /// Whether all options are loaded, which is only false while the provider,
+    /// if any, might have more options to fetch.
+    fetched_all: bool,
+    /// Row of the prompt where the first displayed option was last rendered.
+    first_option_row: Cell<u16>,
+    announcer: Announcer<'a>,
 }
 
 impl<'a, T> SelectPrompt<'a, T>
 where
     T: Display,
 {
-    pub fn new(so: Select<'a, T>) -> InquireResult<Self> {
-        if so.options.is_empty() {
-            return Err(InquireError::InvalidConfiguration(
-                "Available options can not be empty".into(),
-            ));
-        }
+    pub fn new(mut so: Select<'a, T>) -> InquireResult<Self> {
+        so.validate_config()?;
 
-        if so.starting_cursor >= so.options.len() {
-            return Err(InquireError::InvalidConfiguration(format!(
-                "Starting cursor index {} is out-of-bounds for length {} of options",
-                so.starting_cursor,
-                &so.options.len()
-            )));
+        if so.option_provider.is_some() {
+            return Ok(Self::new_from_provider(so));
         }
 
-        let string_options = so.options.iter().map(T::to_string).collect();
-        let scored_options = (0..so.options.len()).collect();
+        // computed before taking the tree levels, which enable tree mode.
+        let config = SelectConfig::from(&so);
 
-        let input = match so.filter_input_enabled {
-            true => Some(Input::new_with(
-                so.starting_filter_input.unwrap_or_default(),
-            )),
-            false => None,
+        let tree = so.tree_levels.take();
+
+        let scored_options = if let Some(tree) = &tree {
+            tree::visible_options(tree)
+        } else {
+            let mut scored_options = (0..so.options.len()).collect::<Vec<usize>>();
+            if let Some(ordering) = so.default_ordering {
+                scored_options.sort_by(|&a, &b| ordering(&so.options[a], &so.options[b]));
+            }
+            scored_options
+        };
+
+        let string_options = match so.search_strings.take() {
+            Some(search_strings) => search_strings,
+            None => so.options.iter().map(T::to_string).collect(),
+        };
+        let input = Self::filter_input(&so);
+
+        let typeahead = match (so.filter_input_enabled, so.typeahead_enabled) {
+            (false, true) => Some(TypeAhead::new()),
+            _ => None,
         };
 
         Ok(Self {
             message: so.message,
-            config: (&so).into(),
+            config,
             options: so.options,
             string_options,
             scored_options,
+            last_filter: None,
             help_message: so.help_message,
             cursor_index: so.starting_cursor,
             input,
+            typeahead,
             scorer: so.scorer,
+            highlighting_scorer: so.highlighting_scorer,
+            match_highlights: HashMap::new(),
+            default_ordering: so.default_ordering,
             formatter: so.formatter,
+            option_url: so.option_url,
+            option_description: so.option_description,
+            option_style: so.option_style,
+            option_suffix: so.option_suffix,
+            tree,
+            provider: None,
+            fetched_all: true,
+            first_option_row: Cell::new(0),
+            announcer: Announcer::new(so.accessibility_announcer),
         })
     }
 
+    fn filter_input(so: &Select<'a, T>) -> Option<Input> {
+        if !so.filter_input_enabled || so.inline_cycle {
+            return None;
+        }
+
+        let input = Input::new_with(so.starting_filter_input.unwrap_or_default());
+        Some(match so.filter_placeholder {
+            Some(placeholder) => input.with_placeholder(placeholder),
+            None => input,
+        })
+    }
+
+    fn new_from_provider(so: Select<'a, T>) -> Self {
+        let input = Self::filter_input(&so);
+
+        let typeahead = match (so.filter_input_enabled, so.typeahead_enabled) {
+            (false, true) => Some(TypeAhead::new()),
+            _ => None,
+        };
+
+        // options are only fetched on setup, the starting cursor is then
+        // clamped to the fetched ones.
+        Self {
+            message: so.message,
+            config: SelectConfig::from(&so),
+            options: vec![],
+            string_options: vec![],
+            scored_options: vec![],
+            last_filter: None,
+            help_message: so.help_message,
+            cursor_index: so.starting_cursor,
+            input,
+            typeahead,
+            scorer: so.scorer,
+            highlighting_scorer: so.highlighting_scorer,
+            match_highlights: HashMap::new(),
+            default_ordering: so.default_ordering,
+            formatter: so.formatter,
+            option_url: so.option_url,
+            option_description: so.option_description,
+            option_style: so.option_style,
+            option_suffix: so.option_suffix,
+            tree: None,
+            provider: so.option_provider,
+            fetched_all: false,
+            first_option_row: Cell::new(0),
+            announcer: Announcer::new(so.accessibility_announcer),
+        }
+    }
+
+    /// Fetches pages of options from the provider until the option at `position`
+    /// and the page after it are loaded, or the provider runs out of options.
+    fn fetch_options_until(&mut self, position: usize) {
+        let provider = match self.provider.as_mut() {
+            Some(provider) => provider,
+            None => return,
+        };
+        let filter = match &self.input {
+            Some(input) => input.content(),
+            None => "",
+        };
+        let limit = self.config.page_size.max(1);
+
+        while !self.fetched_all && self.options.len() <= position.saturating_add(limit) {
+            let offset = self.options.len();
+            let page = provider.fetch(offset, limit, filter);
+
+            self.fetched_all = page.len() < limit;
+            self.string_options.extend(page.iter().map(T::to_string));
+            self.scored_options.extend(offset..offset + page.len());
+            self.options.extend(page);
+        }
+    }
+
+    /// Discards the fetched options when the filter input changes, as the
+    /// provider is the one filtering them, and fetches the first pages again.
+    fn refetch_options(&mut self) {
+        let content = match &self.input {
+            Some(input) => input.content(),
+            None => "",
+        };
+
+        let is_first_fetch = match &self.last_filter {
+            Some((last_input, _)) if last_input == content => return,
+            Some(_) => false,
+            None => true,
+        };
+
+        self.last_filter = Some((content.to_owned(), vec![]));
+        self.options.clear();
+        self.string_options.clear();
+        self.scored_options.clear();
+        self.fetched_all = false;
+
+        if self.config.reset_cursor && !is_first_fetch {
+            self.cursor_index = 0;
+        }
+
+        self.fetch_options_until(self.cursor_index);
+        self.cursor_index = self
+            .cursor_index
+            .min(self.scored_options.len().saturating_sub(1));
+    }
+
     fn move_cursor_up(&mut self, qty: usize, wrap: bool) -> ActionResult {
-        let new_position = if wrap {
+        let new_position = if wrap && self.fetched_all {
             let after_wrap = qty.saturating_sub(self.cursor_index);
             self.cursor_index
                 .checked_sub(qty)
@@ -86,10 +293,14 @@ where
     fn move_cursor_down(&mut self, qty: usize, wrap: bool) -> ActionResult {
         let mut new_position = self.cursor_index.saturating_add(qty);
 
+        // at most one page past the fetched options is loaded, so that jumping
+        // to the end does not go through the whole provider.
+        self.fetch_options_until(new_position.min(self.options.len()));
+
         if new_position >= self.scored_options.len() {
             new_position = if self.scored_options.is_empty() {
                 0
-            } else if wrap {
+            } else if wrap && self.fetched_all {
                 new_position % self.scored_options.len()
             } else {
                 self.scored_options.len().saturating_sub(1)
@@ -108,6 +319,211 @@ where
         }
     }
 
+    /// Moves the cursor to the option rendered at the clicked row, returning
+    /// whether the click landed on an option.
+    fn click_option(&mut self, row: u16) -> bool {
+        if self.config.inline_cycle {
+            return false;
+        }
+
+        let offset = match row.checked_sub(self.first_option_row.get()) {
+            Some(offset) => offset as usize,
+            None => return false,
+        };
+
+        let page = paginate(
+            self.config.page_size,
+            &self.scored_options,
+            Some(self.cursor_index),
+        );
+
+        if offset >= page.content.len() {
+            return false;
+        }
+
+        self.cursor_index = page.start + offset;
+        true
+    }
+
+    fn jump_to_typed_prefix(&mut self, c: char) -> ActionResult {
+        let typeahead = match self.typeahead.as_mut() {
+            Some(typeahead) => typeahead,
+            None => return ActionResult::Clean,
+        };
+
+        typeahead.push(c);
+        let prefix = typeahead.prefix().to_owned();
+
+        // when cycling, the current option is skipped so that the cursor moves on
+        // to the next match. otherwise, the current option is still a candidate as
+        // the user might be refining the prefix it already matches.
+        let skip = usize::from(typeahead.is_cycling());
+        let len = self.scored_options.len();
+
+        let target = (0..len)
+            .map(|offset| (self.cursor_index + skip + offset) % len)
+            .find(|&position| {
+                let index = self.scored_options[position];
+                self.string_options[index]
+                    .to_lowercase()
+                    .starts_with(&prefix)
+            });
+
+        match target {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    fn option_label(&self, index: usize) -> Hyperlink<'_, &T> {
+        let url = match self.option_url {
+            Some(option_url) if self.config.emit_hyperlinks => option_url(&self.options[index]),
+            _ => None,
+        };
+
+        Hyperlink::new(&self.options[index], url)
+    }
+
+    fn is_filtering(&self) -> bool {
+        matches!(&self.input, Some(input) if !input.content().is_empty())
+    }
+
+    /// Passes the announcement of the current state to the accessibility
+    /// announcer, if any.
+    fn announce_changes(&mut self) {
+        if self.announcer.is_enabled() {
+            let announcement = self.announce();
+            self.announcer.update(announcement);
+        }
+    }
+
+    /// Whether the only option matching the filter input should be submitted
+    /// right away.
+    fn autoselects(&self) -> bool {
+        self.config.autoselect_on_single_match
+            && self.is_filtering()
+            && self.fetched_all
+            && self.scored_options.len() == 1
+    }
+
+    fn expand_highlighted_option(&mut self) -> ActionResult {
+        let index = match self.scored_options.get(self.cursor_index) {
+            Some(index) if !self.is_filtering() => *index,
+            _ => return ActionResult::Clean,
+        };
+
+        let tree = match self.tree.as_mut() {
+            Some(tree) if tree::has_children(tree, index) && !tree[index].expanded => tree,
+            _ => return ActionResult::Clean,
+        };
+
+        tree[index].expanded = true;
+        self.scored_options = tree::visible_options(tree);
+
+        ActionResult::NeedsRedraw
+    }
+
+    fn collapse_highlighted_option(&mut self) -> ActionResult {
+        let index = match self.scored_options.get(self.cursor_index) {
+            Some(index) if !self.is_filtering() => *index,
+            _ => return ActionResult::Clean,
+        };
+
+        let tree = match self.tree.as_mut() {
+            Some(tree) => tree,
+            None => return ActionResult::Clean,
+        };
+
+        if tree::has_children(tree, index) && tree[index].expanded {
+            // the highlighted option keeps its position, as only the options
+            // after it are hidden.
+            tree[index].expanded = false;
+            self.scored_options = tree::visible_options(tree);
+
+            return ActionResult::NeedsRedraw;
+        }
+
+        let parent_position = tree::parent(tree, index)
+            .and_then(|parent| self.scored_options.iter().position(|&i| i == parent));
+
+        match parent_position {
+            Some(position) => self.update_cursor_position(position),
+            None => ActionResult::Clean,
+        }
+    }
+
+    /// Styles of the options in the page, computed with the option style
+    /// function, if any.
+    fn page_option_styles<D>(&self, page: &Page<'_, ListOption<D>>) -> Option<Vec<StyleSheet>> {
+        let option_style = self.option_style?;
+
+        let styles = page
+            .content
+            .iter()
+            .enumerate()
+            .map(|(idx, option)| {
+                option_style(&self.options[option.index], page.cursor == Some(idx))
+            })
+            .collect();
+
+        Some(styles)
+    }
+
+    fn page_option_suffixes<D>(
+        &self,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Option<Vec<Option<String>>> {
+        let option_suffix = self.option_suffix?;
+
+        let suffixes = page
+            .content
+            .iter()
+            .map(|option| option_suffix(&self.options[option.index]))
+            .collect();
+
+        Some(suffixes)
+    }
+
+    /// Ranges to highlight in each option of the page, shifted to where the
+    /// string value is displayed in labels with tree branches or hyperlinks.
+    fn page_match_highlights<D: Display>(
+        &self,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Option<Vec<Vec<Range<usize>>>> {
+        if self.match_highlights.is_empty() {
+            return None;
+        }
+
+        let highlights = page
+            .content
+            .iter()
+            .map(|option| match self.match_highlights.get(&option.index) {
+                Some(ranges) => ranges_in_label(
+                    &option.value.to_string(),
+                    &self.string_options[option.index],
+                    ranges,
+                ),
+                None => vec![],
+            })
+            .collect();
+
+        Some(highlights)
+    }
+
+    fn highlighted_option(&self) -> Option<&T> {
+        self.scored_options
+            .get(self.cursor_index)
+            .map(|i| &self.options[*i])
+    }
+
+    /// Description of the highlighted option, displayed instead of the help message.
+    fn highlighted_description(&self) -> Option<String> {
+        match (self.option_description, self.highlighted_option()) {
+            (Some(description), Some(option)) => description(option),
+            _ => None,
+        }
+    }
+
     fn has_answer_highlighted(&mut self) -> bool {
         self.scored_options.get(self.cursor_index).is_some()
     }
@@ -123,24 +539,65 @@ where
     }
 
     fn run_scorer(&mut self) {
+        if self.provider.is_some() {
+            self.refetch_options();
+            return;
+        }
+
         let content = match &self.input {
             Some(input) => input.content(),
             None => return,
         };
 
-        let mut options = self
-            .options
-            .iter()
-            .enumerate()
-            .filter_map(|(i, opt)| {
-                (self.scorer)(content, opt, self.string_options.get(i).unwrap(), i)
-                    .map(|score| (i, score))
-            })
-            .collect::<Vec<(usize, i64)>>();
+        // when the user only appended characters to the filter, options that
+        // did not match the previous input can not match the new one either.
+        let candidates = match self.last_filter.take() {
+            Some((last_input, matches)) if content.starts_with(&last_input) => matches,
+            _ => (0..self.options.len()).collect(),
+        };
 
-        options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+        self.match_highlights.clear();
+
+        let mut options = vec![];
+        for i in candidates {
+            let scored = score_option(
+                self.scorer,
+                self.highlighting_scorer,
+                content,
+                &self.options[i],
+                &self.string_options[i],
+                i,
+            );
+
+            if let Some((score, ranges)) = scored {
+                if !content.is_empty() && !ranges.is_empty() {
+                    self.match_highlights.insert(i, ranges);
+                }
+                options.push((i, score));
+            }
+        }
+
+        let matches = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+
+        // trees are displayed in their own order rather than by score, and
+        // only flattened once there is something to filter by.
+        let new_scored_options = match &self.tree {
+            Some(tree) if content.is_empty() => tree::visible_options(tree),
+            Some(tree) => tree::matches_with_ancestors(tree, &matches),
+            None => match self.default_ordering {
+                Some(ordering) if content.is_empty() => {
+                    let mut sorted = matches.clone();
+                    sorted.sort_by(|&a, &b| ordering(&self.options[a], &self.options[b]));
+                    sorted
+                }
+                _ => {
+                    options.sort_unstable_by_key(|(_idx, score)| Reverse(*score));
+                    options.iter().map(|(idx, _)| *idx).collect()
+                }
+            },
+        };
 
-        let new_scored_options = options.iter().map(|(idx, _)| *idx).collect::<Vec<usize>>();
+        self.last_filter = Some((content.to_owned(), matches));
 
         if self.scored_options == new_scored_options {
             return;
@@ -156,6 +613,32 @@ where
     }
 }
 
+impl<'a, T> AccessibleState for SelectPrompt<'a, T>
+where
+    T: Display,
+{
+    fn announce(&self) -> String {
+        let highlighted = self.scored_options.get(self.cursor_index).copied();
+        let position = highlighted.map(|index| {
+            let label = self.options[index].to_string();
+            (self.cursor_index, self.scored_options.len(), label)
+        });
+
+        let mut details = vec![];
+        if let (Some(tree), Some(index)) = (&self.tree, highlighted) {
+            if tree::has_children(tree, index) && !self.is_filtering() {
+                details.push(match tree[index].expanded {
+                    true => String::from("expanded"),
+                    false => String::from("collapsed"),
+                });
+            }
+        }
+
+        let filter = self.input.as_ref().map(Input::content);
+        describe_options("Select", position, &details, filter)
+    }
+}
+
 impl<'a, Backend, T> Prompt<Backend> for SelectPrompt<'a, T>
 where
     Backend: SelectBackend,
@@ -173,13 +656,22 @@ where
         &self.config
     }
 
+    fn mouse_support(&self) -> bool {
+        self.config.mouse_support
+    }
+
     fn format_answer(&self, answer: &ListOption<T>) -> String {
         (self.formatter)(answer.as_ref())
     }
 
-    fn setup(&mut self) -> InquireResult<()> {
+    fn setup(&mut self) -> InquireResult<ActionResult> {
         self.run_scorer();
-        Ok(())
+        self.announce_changes();
+
+        match self.autoselects() {
+            true => Ok(ActionResult::NeedsSubmit),
+            false => Ok(ActionResult::NeedsRedraw),
+        }
     }
 
     fn submit(&mut self) -> InquireResult<Option<ListOption<T>>> {
@@ -193,12 +685,18 @@ where
 
     fn handle(&mut self, action: SelectPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            SelectPromptAction::MoveUp => self.move_cursor_up(1, true),
-            SelectPromptAction::MoveDown => self.move_cursor_down(1, true),
+            SelectPromptAction::MoveUp => self.move_cursor_up(1, self.config.wrap_around),
+            SelectPromptAction::MoveDown => self.move_cursor_down(1, self.config.wrap_around),
             SelectPromptAction::PageUp => self.move_cursor_up(self.config.page_size, false),
             SelectPromptAction::PageDown => self.move_cursor_down(self.config.page_size, false),
             SelectPromptAction::MoveToStart => self.move_cursor_up(usize::MAX, false),
             SelectPromptAction::MoveToEnd => self.move_cursor_down(usize::MAX, false),
+            SelectPromptAction::Expand => self.expand_highlighted_option(),
+            SelectPromptAction::Collapse => self.collapse_highlighted_option(),
+            SelectPromptAction::Click(row) => match self.click_option(row) {
+                true => ActionResult::NeedsSubmit,
+                false => ActionResult::Clean,
+            },
 
             SelectPromptAction::FilterInput(input_action) => match self.input.as_mut() {
                 Some(input) => {
@@ -206,34 +704,107 @@ where
 
                     if let InputActionResult::ContentChanged = result {
                         self.run_scorer();
+
+                        if self.autoselects() {
+                            return Ok(ActionResult::NeedsSubmit);
+                        }
                     }
 
                     result.into()
                 }
-                None => ActionResult::Clean,
+                None => match input_action {
+                    InputAction::Write(c) => self.jump_to_typed_prefix(c),
+                    _ => ActionResult::Clean,
+                },
             },
         };
 
+        self.announce_changes();
+
         Ok(result)
     }
 
+    fn fit_to_terminal(&mut self, backend: &Backend) {
+        if !self.config.auto_page_size {
+            return;
+        }
+
+        // prompt line, help message and the empty line below the options
+        let description = self.highlighted_description();
+        let help_rows = description
+            .as_deref()
+            .or(self.help_message)
+            .map(|help| backend.help_message_rows(help))
+            .unwrap_or(0);
+        let reserved_rows = 2 + help_rows;
+        self.config.page_size = fit_page_size(backend.terminal_height(), reserved_rows);
+        self.fetch_options_until(self.cursor_index);
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
-        backend.render_select_prompt(prompt, self.input.as_ref())?;
+        if self.config.inline_cycle {
+            match self.scored_options.get(self.cursor_index) {
+                Some(&index) => {
+                    let option = ListOption::new(index, self.option_label(index));
+                    backend.render_inline_option(prompt, &option)?;
+                }
+                None => backend.render_select_prompt(prompt, None)?,
+            }
 
-        let choices = self
-            .scored_options
-            .iter()
-            .cloned()
-            .map(|i| ListOption::new(i, self.options.get(i).unwrap()))
-            .collect::<Vec<ListOption<&T>>>();
+            return Ok(());
+        }
 
-        let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+        backend.render_select_prompt(prompt, self.input.as_ref())?;
+        self.first_option_row.set(backend.frame_row());
+
+        if let Some(tree) = &self.tree {
+            let flattened = self.is_filtering();
+            let choices = self
+                .scored_options
+                .iter()
+                .cloned()
+                .map(|i| {
+                    ListOption::new(i, TreeLabel::new(tree, i, self.option_label(i), flattened))
+                })
+                .collect::<Vec<ListOption<TreeLabel<Hyperlink<'_, &T>>>>>();
+
+            let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+            let option_styles = self.page_option_styles(&page);
+            let highlights = self.page_match_highlights(&page);
+            let suffixes = self.page_option_suffixes(&page);
+
+            backend.render_options(
+                page,
+                option_styles.as_deref(),
+                highlights.as_deref(),
+                suffixes.as_deref(),
+            )?;
+        } else {
+            let choices = self
+                .scored_options
+                .iter()
+                .cloned()
+                .map(|i| ListOption::new(i, self.option_label(i)))
+                .collect::<Vec<ListOption<Hyperlink<'_, &T>>>>();
+
+            let page = paginate(self.config.page_size, &choices, Some(self.cursor_index));
+            let option_styles = self.page_option_styles(&page);
+            let highlights = self.page_match_highlights(&page);
+            let suffixes = self.page_option_suffixes(&page);
+
+            backend.render_options(
+                page,
+                option_styles.as_deref(),
+                highlights.as_deref(),
+                suffixes.as_deref(),
+            )?;
+        }
 
-        backend.render_options(page)?;
+        let description = self.highlighted_description();
 
-        if let Some(help_message) = self.help_message {
+        if let Some(help_message) = description.as_deref().or(self.help_message) {
             backend.render_help_message(help_message)?;
         }
 
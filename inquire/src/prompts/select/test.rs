@@ -1,8 +1,16 @@
+use super::prompt::SelectPrompt;
 use crate::{
+    error::InquireError,
     formatter::OptionFormatter,
-    list_option::ListOption,
-    test::fake_backend,
-    ui::{Key, KeyModifiers},
+    list_option::{ListOption, OptionProvider, TreeOption},
+    matching::MatchingStrategy,
+    prompts::{prompt::Prompt, test::fake_backend},
+    terminal::{
+        test::{MockTerminal, MockTerminalToken},
+        TerminalSize,
+    },
+    test::{render_frames, FakeBackend, Token},
+    ui::{Attributes, Backend, Color, Key, KeyModifiers, RenderConfig, StyleSheet, Styled},
     Select,
 };
 
@@ -142,3 +150,1190 @@ fn chars_do_not_affect_prompt_without_filtering() {
 
     assert_eq!(ListOption::new(0, "Banana"), ans);
 }
+
+#[test]
+fn fake_backend_records_rendered_options() {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Banana", "Apple", "Strawberry"];
+
+    let ans = Select::new("Question", options)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+
+    let frames = backend.frames();
+    assert!(frames[1].has_token(&Token::Option {
+        index: 1,
+        value: "Apple".into(),
+        highlighted: true,
+        checked: false,
+    }));
+    assert!(frames[2].has_token(&Token::AnsweredPrompt("Question".into(), "Apple".into())));
+}
+
+#[test]
+fn search_strings_are_scored_instead_of_rendered_labels() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["🍎 Apple", "🍌 Banana"];
+
+    let ans = Select::new("Question", options)
+        .with_scorer(&|input, _, string_value, _| {
+            string_value.to_lowercase().starts_with(input).then_some(0)
+        })
+        .with_search_strings(vec!["Apple".into(), "Banana".into()])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "🍌 Banana"), ans);
+
+    let frames = backend.frames();
+    assert!(frames[2].has_token(&Token::Option {
+        index: 1,
+        value: "🍌 Banana".into(),
+        highlighted: true,
+        checked: false,
+    }));
+}
+
+#[test]
+fn search_strings_length_mismatch_is_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![1, 2, 3];
+
+    let ans = Select::new("Question", options)
+        .with_search_strings(vec!["one".into(), "two".into()])
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn typeahead_jumps_to_options_by_prefix() {
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('l', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Apple", "Banana", "Blueberry", "Cherry"];
+
+    let ans = Select::new("Question", options)
+        .without_filtering()
+        .with_typeahead()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Blueberry"), ans);
+}
+
+#[test]
+fn typeahead_repeated_letter_cycles_through_matches() {
+    let mut backend = fake_backend(vec![
+        Key::Char('B', KeyModifiers::SHIFT),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Apple", "Banana", "Blueberry", "Cherry"];
+
+    let ans = Select::new("Question", options)
+        .without_filtering()
+        .with_typeahead()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Banana"), ans);
+}
+
+#[test]
+fn typeahead_is_ignored_when_filtering_is_enabled() {
+    let mut backend = fake_backend(vec![Key::Char('c', KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Apple", "Banana", "Cherry"];
+
+    let ans = Select::new("Question", options)
+        .with_typeahead()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Cherry"), ans);
+}
+
+#[test]
+fn matching_strategy_substring_keeps_original_order() {
+    let mut backend = fake_backend(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('n', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["Pineapple", "Mango", "Banana"];
+
+    let ans = Select::new("Question", options)
+        .with_matching_strategy(MatchingStrategy::Substring)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Mango"), ans);
+}
+
+#[test]
+fn matching_strategy_custom_uses_provided_scorer() {
+    let mut backend = fake_backend(vec![Key::Char('x', KeyModifiers::NONE), Key::Enter]);
+
+    let options = vec!["Pineapple", "Mango", "Banana"];
+
+    let ans = Select::new("Question", options)
+        .with_matching_strategy(MatchingStrategy::Custom(&|_, _, _, idx| Some(idx as i64)))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Banana"), ans);
+}
+
+#[test]
+fn scrollbar_is_rendered_for_paginated_options() {
+    let prompt = Select::new("Question", (1..=10).collect())
+        .with_page_size(5)
+        .with_render_config(RenderConfig::empty().with_scrollbar(true));
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("1".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("█".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("2".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("░".into())));
+}
+
+#[test]
+fn render_callback_receives_message_and_formatted_answer() {
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let formatter: OptionFormatter<'_, &str> = &|ans| format!("#{} {}", ans.index, ans.value);
+    let prompt = Select::new("Question", vec!["a", "b", "c"]).with_formatter(formatter);
+
+    let mut rendered = vec![];
+    let ans = SelectPrompt::new(prompt)
+        .unwrap()
+        .prompt_with_render_callback(&mut backend, |message, answer| {
+            rendered.push((message.to_owned(), answer.to_owned()));
+        })
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+    assert_eq!(vec![("Question".to_owned(), "#1 b".to_owned())], rendered);
+}
+
+#[test]
+fn appending_to_filter_only_rescores_previous_matches() {
+    let calls = std::cell::Cell::new(0usize);
+    let scorer = |input: &str, _: &&str, string_value: &str, _: usize| {
+        calls.set(calls.get() + 1);
+        string_value.contains(input).then_some(0)
+    };
+
+    let mut backend = fake_backend(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char('e', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["abc", "bad", "bed", "cab", "dog"])
+        .with_scorer(&scorer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "bed"), ans);
+    // 5 on setup, 5 for "b", 4 for "ba", 5 for "b" again and 4 for "be"
+    assert_eq!(23, calls.get());
+}
+
+fn settings_tree() -> Vec<TreeOption<&'static str>> {
+    vec![
+        TreeOption::new("Display", 0),
+        TreeOption::new("Resolution", 1),
+        TreeOption::new("Brightness", 1),
+        TreeOption::new("Sound", 0).with_expanded(true),
+        TreeOption::new("Volume", 1),
+    ]
+}
+
+fn rendered_options(frame: &crate::test::Frame) -> Vec<(usize, String)> {
+    frame
+        .tokens()
+        .iter()
+        .filter_map(|token| match token {
+            Token::Option { index, value, .. } => Some((*index, value.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn tree_navigation_skips_collapsed_descendants() {
+    let mut backend = FakeBackend::new(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Select::new_tree("Settings", settings_tree())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, "Sound"), ans);
+    assert_eq!(
+        vec![
+            (0, "▸ Display".to_owned()),
+            (3, "▾ Sound".to_owned()),
+            (4, "    Volume".to_owned()),
+        ],
+        rendered_options(&backend.frames()[0])
+    );
+}
+
+#[test]
+fn tree_right_and_left_expand_and_collapse_options() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Left(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new_tree("Settings", settings_tree())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // the first left moves from "Brightness" to its parent, the second one collapses it.
+    assert_eq!(ListOption::new(0, "Display"), ans);
+
+    let frames = backend.frames();
+    assert_eq!(
+        vec![
+            (0, "▾ Display".to_owned()),
+            (1, "    Resolution".to_owned()),
+            (2, "    Brightness".to_owned()),
+            (3, "▾ Sound".to_owned()),
+            (4, "    Volume".to_owned()),
+        ],
+        rendered_options(&frames[1])
+    );
+    assert_eq!(
+        vec![
+            (0, "▸ Display".to_owned()),
+            (3, "▾ Sound".to_owned()),
+            (4, "    Volume".to_owned()),
+        ],
+        rendered_options(&frames[5])
+    );
+}
+
+#[test]
+fn tree_filtering_shows_matches_with_their_ancestors() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('b', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+        Key::Char('i', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new_tree("Settings", settings_tree())
+        .with_matching_strategy(MatchingStrategy::Substring)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Brightness"), ans);
+    assert_eq!(
+        vec![
+            (0, "▾ Display".to_owned()),
+            (2, "    Brightness".to_owned())
+        ],
+        rendered_options(&backend.frames()[3])
+    );
+}
+
+#[test]
+fn tree_levels_skipping_depths_are_invalid_configuration() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+
+    let options = vec![TreeOption::new(1, 0), TreeOption::new(2, 2)];
+
+    let ans = Select::new_tree("Question", options).prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn cursor_wraps_around_by_default() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(2, 3), ans);
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_starting_cursor(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(0, 1), ans);
+}
+
+#[test]
+fn cursor_stops_at_boundaries_without_wrap_around() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_wrap_around(false)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(0, 1), ans);
+
+    let mut backend = fake_backend(vec![Key::Down(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Question", vec![1, 2, 3])
+        .with_wrap_around(false)
+        .with_starting_cursor(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(2, 3), ans);
+}
+
+struct Doc(&'static str, Option<&'static str>);
+
+impl std::fmt::Display for Doc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl crate::list_option::Linkable for Doc {
+    fn url(&self) -> Option<&str> {
+        self.1
+    }
+}
+
+fn docs() -> Vec<Doc> {
+    vec![
+        Doc("inquire", Some("https://docs.rs/inquire")),
+        Doc("std", None),
+    ]
+}
+
+#[test]
+fn options_with_url_are_rendered_as_hyperlinks() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    Select::new("Docs", docs())
+        .with_links()
+        .with_render_config(RenderConfig::default().with_hyperlinks(true))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let options = rendered_options(&backend.frames()[0]);
+    assert_eq!(
+        vec![
+            (
+                0,
+                "\x1b]8;;https://docs.rs/inquire\x07inquire\x1b]8;;\x07".to_owned()
+            ),
+            (1, "std".to_owned()),
+        ],
+        options
+    );
+}
+
+#[test]
+fn hyperlinks_are_not_emitted_unless_enabled_in_render_config() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    Select::new("Docs", docs())
+        .with_links()
+        .with_render_config(RenderConfig::default())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let options = rendered_options(&backend.frames()[0]);
+    assert_eq!(
+        vec![(0, "inquire".to_owned()), (1, "std".to_owned())],
+        options
+    );
+}
+
+/// Provider of the numbers up to `len`, recording the fetch calls.
+#[derive(Clone)]
+struct Numbers {
+    len: usize,
+    calls: std::rc::Rc<std::cell::RefCell<Vec<(usize, usize, String)>>>,
+}
+
+impl Numbers {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            calls: Default::default(),
+        }
+    }
+}
+
+impl OptionProvider<usize> for Numbers {
+    fn fetch(&mut self, offset: usize, limit: usize, filter: &str) -> Vec<usize> {
+        self.calls
+            .borrow_mut()
+            .push((offset, limit, filter.to_owned()));
+
+        (0..self.len)
+            .filter(|n| n.to_string().contains(filter))
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+}
+
+#[test]
+fn provider_is_fetched_one_page_ahead() {
+    let provider = Numbers::new(100);
+    let calls = provider.calls.clone();
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new_from_provider("Question", provider)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, 0), ans);
+    assert_eq!(
+        vec![(0, 2, String::new()), (2, 2, String::new())],
+        *calls.borrow()
+    );
+}
+
+#[test]
+fn provider_is_fetched_while_scrolling() {
+    let provider = Numbers::new(100);
+    let calls = provider.calls.clone();
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = Select::new_from_provider("Question", provider)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(3, 3), ans);
+    assert_eq!(3, calls.borrow().len());
+}
+
+#[test]
+fn provider_receives_filter_input() {
+    let provider = Numbers::new(100);
+    let calls = provider.calls.clone();
+
+    let mut backend = fake_backend(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('7', KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = Select::new_from_provider("Question", provider)
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    // 7, 17, 27...
+    assert_eq!(ListOption::new(1, 17), ans);
+    assert!(calls.borrow().contains(&(0, 2, String::from("7"))));
+}
+
+#[test]
+fn provider_cursor_only_wraps_around_once_all_options_are_fetched() {
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new_from_provider("Question", Numbers::new(100))
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(0, 0), ans);
+
+    let mut backend = fake_backend(vec![Key::Up(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new_from_provider("Question", Numbers::new(3))
+        .with_page_size(2)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(2, 2), ans);
+}
+
+#[test]
+fn provider_can_not_be_combined_with_options() {
+    let mut select = Select::new_from_provider("Question", Numbers::new(3));
+    select.options = vec![1];
+
+    let ans = select.prompt_with_backend(&mut fake_backend(vec![Key::Enter]));
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn starting_value_sets_starting_cursor() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_starting_value(&"b")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn missing_starting_value_is_ignored() {
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_starting_cursor(2)
+        .with_starting_value(&"z")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+#[test]
+fn missing_starting_value_errors_when_strict() {
+    let res = Select::new("Question", vec!["a", "b", "c"]).try_with_starting_value(&"z");
+    assert!(matches!(res, Err(InquireError::InvalidConfiguration(_))));
+
+    let mut backend = fake_backend(vec![Key::Enter]);
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .try_with_starting_value(&"c")
+        .unwrap()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+    assert_eq!(ListOption::new(2, "c"), ans);
+}
+
+fn page_down_answer_with_terminal_height(height: u16) -> i32 {
+    let prompt = Select::new("Question", (1..=20).collect()).with_page_size(0);
+
+    let mut backend = Backend::new(
+        vec![Key::PageDown(KeyModifiers::NONE), Key::Enter].into_iter(),
+        MockTerminal::new().with_size(TerminalSize::new(80, height)),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap().value
+}
+
+#[test]
+fn zero_page_size_fits_terminal_height() {
+    // prompt line, help message and the empty line below the options are reserved
+    assert_eq!(3, page_down_answer_with_terminal_height(5));
+    assert_eq!(11, page_down_answer_with_terminal_height(13));
+}
+
+#[test]
+fn zero_page_size_reserves_the_rows_of_wrapped_help_messages() {
+    let help = "a long help message ".repeat(6);
+    let prompt = Select::new("Question", (1..=20).collect())
+        .with_page_size(0)
+        .with_help_message(&help);
+
+    let mut backend = Backend::new(
+        vec![Key::PageDown(KeyModifiers::NONE), Key::Enter].into_iter(),
+        MockTerminal::new().with_size(TerminalSize::new(80, 13)),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    // the help message is wrapped on two rows, leaving 9 for the options
+    assert_eq!(10, prompt.prompt_with_backend(&mut backend).unwrap().value);
+}
+
+#[test]
+fn zero_page_size_displays_at_least_one_option() {
+    assert_eq!(2, page_down_answer_with_terminal_height(2));
+}
+
+#[test]
+fn zero_page_size_defaults_when_terminal_height_is_unknown() {
+    let mut backend = FakeBackend::new(vec![Key::PageDown(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Question", (1..=20).collect())
+        .with_page_size(0)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(1 + Select::<i32>::DEFAULT_PAGE_SIZE as i32, ans.value);
+}
+
+#[test]
+fn option_description_follows_the_highlighted_option() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let options = vec!["build", "test", "clean"];
+
+    Select::new("Command", options)
+        .with_help_message("pick a command")
+        .with_option_description(&|command| match *command {
+            "build" => Some(String::from("Compile the package")),
+            "test" => Some(String::from("Run the tests")),
+            _ => None,
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::HelpMessage("Compile the package".into())));
+    assert!(frames[1].has_token(&Token::HelpMessage("Run the tests".into())));
+    assert!(frames[2].has_token(&Token::HelpMessage("pick a command".into())));
+}
+
+#[test]
+fn cursor_glyph_is_rendered() {
+    let prompt = Select::new("Question", vec!["a", "b"])
+        .with_render_config(RenderConfig::empty())
+        .with_cursor_glyph(Styled::new("→"));
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("→".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("a".into())));
+}
+
+#[test]
+fn default_ordering_only_applies_to_empty_filter() {
+    let prompt = || {
+        Select::new("Question", vec!["apple", "banana", "cherry"])
+            .with_default_ordering(&|a, b| b.cmp(a))
+            .with_scorer(&|_, _, _, idx| Some(-(idx as i64)))
+    };
+
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(2, "cherry"), ans);
+
+    // the order is restored once the filter is cleared
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(1, "banana"), ans);
+
+    let mut backend = FakeBackend::new(vec![Key::Char('x', KeyModifiers::NONE), Key::Enter]);
+    let ans = prompt().prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(0, "apple"), ans);
+}
+
+#[test]
+fn option_style_is_applied_under_the_highlight_style() {
+    let prompt = Select::new("Question", vec!["error", "warning", "info"])
+        .with_render_config(
+            RenderConfig::empty()
+                .with_selected_option(Some(StyleSheet::new().with_attr(Attributes::BOLD))),
+        )
+        .with_option_style(&|option, highlighted| match (*option, highlighted) {
+            ("error", _) => StyleSheet::new().with_fg(Color::LightRed),
+            ("warning", false) => StyleSheet::new().with_fg(Color::LightYellow),
+            _ => StyleSheet::new(),
+        });
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("error".into())
+            .with_fg(Color::LightRed)
+            .with_attr(Attributes::BOLD),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("warning".into()).with_fg(Color::LightYellow),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("info".into())));
+}
+
+#[test]
+fn validate_config_reports_invalid_configurations() {
+    assert!(Select::new("Question", vec![1, 2, 3])
+        .validate_config()
+        .is_ok());
+
+    let empty = Select::new("Question", Vec::<i32>::new());
+    assert!(matches!(
+        empty.validate_config(),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+
+    let out_of_bounds = Select::new("Question", vec![1, 2, 3]).with_starting_cursor(3);
+    assert!(matches!(
+        out_of_bounds.validate_config(),
+        Err(InquireError::InvalidConfiguration(_))
+    ));
+}
+
+#[test]
+fn highlighting_scorer_emphasizes_matched_ranges() {
+    let prompt = Select::new("Question", vec!["Banana", "Apple"])
+        .with_render_config(
+            RenderConfig::empty().with_option_match(StyleSheet::new().with_attr(Attributes::BOLD)),
+        )
+        .with_highlighting_scorer(&|input, _option, string_value, _idx| {
+            let start = string_value.find(input)?;
+            let matched = start..start + input.len();
+            Some((0, vec![matched]))
+        });
+
+    let mut backend = Backend::new(
+        vec![
+            Key::Char('a', KeyModifiers::NONE),
+            Key::Char('n', KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(0, "Banana"), ans);
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("B".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("an".into()).with_attr(Attributes::BOLD),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("ana".into())));
+}
+
+#[test]
+fn highlighting_scorer_emphasizes_matched_ranges_in_filtered_trees() {
+    let prompt = Select::new_tree("Settings", settings_tree())
+        .with_render_config(
+            RenderConfig::empty().with_option_match(StyleSheet::new().with_attr(Attributes::BOLD)),
+        )
+        .with_highlighting_scorer(&|input, _option, string_value, _idx| {
+            let start = string_value.find(input)?;
+            let matched = start..start + input.len();
+            Some((0, vec![matched]))
+        });
+
+    let mut backend = Backend::new(
+        vec![
+            Key::Char('u', KeyModifiers::NONE),
+            Key::Char('m', KeyModifiers::NONE),
+            Key::Down(KeyModifiers::NONE),
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+    assert_eq!(ListOption::new(4, "Volume"), ans);
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("    Vol".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(
+        Styled::new("um".into()).with_attr(Attributes::BOLD),
+    ));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("e".into())));
+}
+
+#[test]
+fn highlighting_scorer_filters_options() {
+    let mut backend = fake_backend(vec![
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["Banana", "Apple"])
+        .with_highlighting_scorer(&|input, _option, string_value, _idx| {
+            let start = string_value.find(input)?;
+            let matched = start..start + input.len();
+            Some((0, vec![matched]))
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apple"), ans);
+}
+
+fn render_with_suffix(width: u16, option: &str, suffix: &str) -> MockTerminal {
+    let suffix = suffix.to_owned();
+    let option_suffix = move |_: &&str| Some(suffix.clone());
+
+    let prompt = Select::new("Package", vec![option])
+        .with_option_suffix(&option_suffix)
+        .with_render_config(RenderConfig::empty());
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new().with_size(TerminalSize::new(width, 10)),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    std::mem::replace(backend.terminal_mut(), MockTerminal::new())
+}
+
+#[test]
+fn option_suffix_is_right_aligned() {
+    let mut terminal = render_with_suffix(30, "serde", "v1.0");
+
+    // "> serde" takes 7 columns, the suffix ends right before the last one.
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("serde".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new(" ".repeat(18))));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("v1.0".into())));
+}
+
+#[test]
+fn option_suffix_is_truncated_when_row_is_too_narrow() {
+    let mut terminal = render_with_suffix(24, "package", "v1.2.3 (2MB) extra");
+
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("package".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new(" ".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("v1.2.3 (2MB)…".into())));
+}
+
+#[test]
+fn mouse_wheel_moves_the_cursor() {
+    let mut backend = fake_backend(vec![
+        Key::ScrollDown,
+        Key::ScrollDown,
+        Key::ScrollUp,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["a", "b", "c"])
+        .with_mouse_support()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+}
+
+#[test]
+fn clicking_an_option_selects_it() {
+    let prompt = Select::new("Question", vec!["a", "b", "c"]).with_mouse_support();
+
+    // the prompt starts at the 10th row of the screen, its options below it.
+    let mut backend = Backend::new(
+        vec![Key::Click { row: 12, column: 4 }].into_iter(),
+        MockTerminal::new().with_cursor_row(10),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(1, "b"), ans);
+    assert!(backend.terminal_mut().mouse_capture);
+}
+
+#[test]
+fn clicks_outside_of_the_options_are_ignored() {
+    let prompt = Select::new("Question", vec!["a", "b", "c"]).with_mouse_support();
+
+    let mut backend = Backend::new(
+        vec![
+            Key::Click { row: 5, column: 4 },
+            Key::Click { row: 10, column: 4 },
+            Key::Click { row: 30, column: 4 },
+            Key::Enter,
+        ]
+        .into_iter(),
+        MockTerminal::new().with_cursor_row(10),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), ans);
+}
+
+#[test]
+fn clicks_are_ignored_when_the_prompt_position_is_unknown() {
+    let prompt = Select::new("Question", vec!["a", "b", "c"]).with_mouse_support();
+
+    let mut backend = Backend::new(
+        vec![Key::Click { row: 2, column: 4 }, Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!(ListOption::new(0, "a"), ans);
+}
+
+#[test]
+fn clicks_account_for_the_displayed_page() {
+    let mut backend = FakeBackend::new(vec![Key::End, Key::Click { row: 1, column: 0 }]);
+
+    let ans = Select::new("Question", (0..10).collect())
+        .with_page_size(3)
+        .with_mouse_support()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(7, 7), ans);
+}
+
+#[test]
+fn mouse_is_not_captured_by_default() {
+    let prompt = Select::new("Question", vec!["a", "b", "c"]);
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert!(!backend.terminal_mut().mouse_capture);
+}
+
+#[test]
+fn inline_cycle_renders_a_single_line() {
+    let prompt = Select::new("Color:", vec!["red", "green", "blue"])
+        .with_inline_cycle()
+        .with_render_config(RenderConfig::empty());
+
+    let frames = render_frames(prompt, vec![Key::Right(KeyModifiers::NONE), Key::Enter]).unwrap();
+
+    assert_eq!(
+        vec!["? Color: < red >", "? Color: < green >", "? Color: green"],
+        frames
+    );
+}
+
+#[test]
+fn inline_cycle_arrows_cycle_with_wraparound() {
+    let mut backend = FakeBackend::new(vec![Key::Left(KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Color:", vec!["red", "green", "blue"])
+        .with_inline_cycle()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "blue"), ans);
+
+    let mut backend = FakeBackend::new(vec![
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Right(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+    let ans = Select::new("Color:", vec!["red", "green", "blue"])
+        .with_inline_cycle()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "green"), ans);
+}
+
+#[test]
+fn inline_cycle_disables_filtering() {
+    let mut backend = FakeBackend::new(vec![Key::Char('b', KeyModifiers::NONE), Key::Enter]);
+    let ans = Select::new("Color:", vec!["red", "green", "blue"])
+        .with_inline_cycle()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(0, "red"), ans);
+    assert_eq!(
+        &[
+            Token::Prompt("Color:".into()),
+            Token::Option {
+                index: 0,
+                value: "red".into(),
+                highlighted: true,
+                checked: false,
+            },
+        ],
+        backend.frames()[0].tokens()
+    );
+}
+
+#[test]
+fn inline_cycle_is_incompatible_with_tree_options() {
+    let mut backend = FakeBackend::new(vec![Key::Enter]);
+
+    let options = vec![TreeOption::new(1, 0), TreeOption::new(2, 1)];
+    let ans = Select::new_tree("Question", options)
+        .with_inline_cycle()
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::InvalidConfiguration(_))));
+}
+
+#[test]
+fn filter_placeholder_is_only_rendered_with_empty_input() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    Select::new("Fruit:", vec!["Banana", "Apple"])
+        .with_filter_placeholder("Type to filter")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let placeholder = Token::Placeholder("Type to filter".into());
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&placeholder));
+    assert!(frames[1].has_token(&Token::Input("a".into())));
+    assert!(!frames[1].has_token(&placeholder));
+    assert!(frames[2].has_token(&placeholder));
+}
+
+#[test]
+fn autoselect_submits_single_match_of_starting_filter_without_keys() {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let ans = Select::new("Question", vec!["Apple", "Banana", "Cherry"])
+        .with_starting_filter_input("ch")
+        .with_autoselect_on_single_match()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Cherry"), ans);
+}
+
+#[test]
+fn autoselect_submits_once_typed_filter_leaves_single_match() {
+    let mut backend = FakeBackend::new(vec![
+        Key::Char('a', KeyModifiers::NONE),
+        Key::Char('p', KeyModifiers::NONE),
+        Key::Char('r', KeyModifiers::NONE),
+    ]);
+
+    let ans = Select::new("Question", vec!["Apple", "Apricot", "Banana"])
+        .with_autoselect_on_single_match()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(1, "Apricot"), ans);
+}
+
+#[test]
+fn single_match_is_not_submitted_without_autoselect() {
+    let mut backend = FakeBackend::new(vec![]);
+
+    let result = Select::new("Question", vec!["Apple", "Banana", "Cherry"])
+        .with_starting_filter_input("ch")
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::IO(_))));
+}
+
+#[test]
+fn autoselect_ignores_empty_filter_with_a_single_option() {
+    let mut backend = FakeBackend::new(vec![Key::Char('x', KeyModifiers::NONE), Key::Backspace]);
+
+    let result = Select::new("Question", vec!["Apple"])
+        .with_autoselect_on_single_match()
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(result, Err(InquireError::IO(_))));
+}
+
+#[test]
+fn accessibility_announcer_receives_state_changes() {
+    let announcements = std::cell::RefCell::new(vec![]);
+    let announcer = |announcement: &str| announcements.borrow_mut().push(announcement.to_owned());
+
+    let mut backend = FakeBackend::new(vec![
+        Key::Down(KeyModifiers::NONE),
+        Key::Char('c', KeyModifiers::NONE),
+        Key::Char('x', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Enter,
+    ]);
+
+    let ans = Select::new("Question", vec!["Apple", "Banana", "Cherry"])
+        .with_accessibility_announcer(&announcer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(ListOption::new(2, "Cherry"), ans);
+    assert_eq!(
+        vec![
+            "Select, 1 of 3, highlighted: Apple",
+            "Select, 2 of 3, highlighted: Banana",
+            "Select, 1 of 1, highlighted: Cherry, filter: c",
+            "Select, no matching options, filter: cx",
+            "Select, 1 of 1, highlighted: Cherry, filter: c",
+        ],
+        *announcements.borrow()
+    );
+}
+
+#[test]
+fn accessibility_announcer_describes_tree_expansion() {
+    let announcements = std::cell::RefCell::new(vec![]);
+    let announcer = |announcement: &str| announcements.borrow_mut().push(announcement.to_owned());
+
+    let mut backend = FakeBackend::new(vec![Key::Right(KeyModifiers::NONE), Key::Enter]);
+
+    Select::new_tree("Settings", settings_tree())
+        .with_accessibility_announcer(&announcer)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            "Select, 1 of 3, highlighted: Display, collapsed",
+            "Select, 1 of 5, highlighted: Display, expanded",
+        ],
+        *announcements.borrow()
+    );
+}
@@ -0,0 +1,173 @@
+//! Helpers to navigate the options of a Select prompt displayed as a tree,
+//! where the children of an option are the options right after it with a
+//! greater depth.
+
+use std::fmt::{self, Display};
+
+use crate::{error::InquireResult, list_option::TreeLevel, InquireError};
+
+const EXPANDED_MARKER: &str = "▾ ";
+const COLLAPSED_MARKER: &str = "▸ ";
+const LEAF_MARKER: &str = "  ";
+const INDENTATION: &str = "  ";
+
+/// Checks that the first option is at the top level and that no option is
+/// more than one level deeper than the one before it.
+pub fn validate(tree: &[TreeLevel]) -> InquireResult<()> {
+    let mut max_depth = 0;
+
+    for (index, level) in tree.iter().enumerate() {
+        if level.depth > max_depth {
+            return Err(InquireError::InvalidConfiguration(format!(
+                "Option {} has depth {}, but it can be at most {}",
+                index, level.depth, max_depth
+            )));
+        }
+
+        max_depth = level.depth + 1;
+    }
+
+    Ok(())
+}
+
+pub fn has_children(tree: &[TreeLevel], index: usize) -> bool {
+    match tree.get(index + 1) {
+        Some(next) => next.depth > tree[index].depth,
+        None => false,
+    }
+}
+
+pub fn parent(tree: &[TreeLevel], index: usize) -> Option<usize> {
+    let depth = tree[index].depth;
+
+    (0..index).rev().find(|&i| tree[i].depth < depth)
+}
+
+/// Indexes of the options whose ancestors are all expanded.
+pub fn visible_options(tree: &[TreeLevel]) -> Vec<usize> {
+    let mut visible = vec![];
+    let mut collapsed_depth = None;
+
+    for (index, level) in tree.iter().enumerate() {
+        match collapsed_depth {
+            Some(depth) if level.depth > depth => continue,
+            _ => collapsed_depth = None,
+        }
+
+        visible.push(index);
+
+        if !level.expanded {
+            collapsed_depth = Some(level.depth);
+        }
+    }
+
+    visible
+}
+
+/// Indexes of the matched options along with all of their ancestors,
+/// regardless of whether they are expanded, in tree order.
+pub fn matches_with_ancestors(tree: &[TreeLevel], matches: &[usize]) -> Vec<usize> {
+    let mut included = vec![false; tree.len()];
+
+    for &index in matches {
+        let mut current = Some(index);
+
+        while let Some(index) = current {
+            if included[index] {
+                break;
+            }
+
+            included[index] = true;
+            current = parent(tree, index);
+        }
+    }
+
+    (0..tree.len()).filter(|&i| included[i]).collect()
+}
+
+/// Option value indented according to its depth, prefixed by a marker
+/// telling whether it is expanded, collapsed or a leaf.
+pub struct TreeLabel<D> {
+    value: D,
+    depth: usize,
+    marker: &'static str,
+}
+
+impl<D> TreeLabel<D> {
+    pub fn new(tree: &[TreeLevel], index: usize, value: D, flattened: bool) -> Self {
+        let level = tree[index];
+        let marker = match (has_children(tree, index), level.expanded || flattened) {
+            (false, _) => LEAF_MARKER,
+            (true, true) => EXPANDED_MARKER,
+            (true, false) => COLLAPSED_MARKER,
+        };
+
+        Self {
+            value,
+            depth: level.depth,
+            marker,
+        }
+    }
+}
+
+impl<D> Display for TreeLabel<D>
+where
+    D: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for _ in 0..self.depth {
+            f.write_str(INDENTATION)?;
+        }
+
+        write!(f, "{}{}", self.marker, self.value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree(levels: &[(usize, bool)]) -> Vec<TreeLevel> {
+        levels
+            .iter()
+            .map(|&(depth, expanded)| TreeLevel { depth, expanded })
+            .collect()
+    }
+
+    #[test]
+    fn collapsed_options_hide_their_descendants() {
+        let tree = tree(&[
+            (0, true),
+            (1, false),
+            (2, false),
+            (2, false),
+            (1, true),
+            (2, false),
+            (0, false),
+        ]);
+
+        assert_eq!(vec![0, 1, 4, 5, 6], visible_options(&tree));
+    }
+
+    #[test]
+    fn matches_are_listed_with_their_ancestors() {
+        let tree = tree(&[
+            (0, false),
+            (1, false),
+            (2, false),
+            (1, false),
+            (0, false),
+            (1, false),
+        ]);
+
+        assert_eq!(vec![0, 1, 2, 4, 5], matches_with_ancestors(&tree, &[2, 5]));
+        assert_eq!(vec![0, 3], matches_with_ancestors(&tree, &[0, 3]));
+    }
+
+    #[test]
+    fn options_can_not_skip_levels() {
+        assert!(validate(&tree(&[(0, false), (1, false), (2, false), (0, false)])).is_ok());
+        assert!(validate(&tree(&[(1, false)])).is_err());
+        assert!(validate(&tree(&[(0, false), (2, false)])).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{ui::Key, ui::RenderConfig, Confirm, CustomType, MultiSelect, Password, Select, Text};
+
+/// Configuration shared by every prompt created from it, such as the render
+/// config theming all the prompts of an application.
+///
+/// This is the recommended way to theme an application that displays several
+/// prompts: each prompt builder returned by the session is pre-configured, so
+/// there is no need to repeat `.with_render_config(...)` on every prompt, and
+/// it can still be customized further like any other builder.
+///
+/// Unlike [`set_global_render_config`](crate::set_global_render_config), the
+/// configuration is scoped to the session, so different parts of an application,
+/// or a library, can use different sessions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inquire::{ui::{Color, RenderConfig, Styled}, PromptSession};
+///
+/// let theme = RenderConfig::default().with_prompt_prefix(Styled::new("$").with_fg(Color::LightRed));
+/// let session = PromptSession::new(theme).with_vim_mode(true);
+///
+/// let name = session.text("What is your name?").prompt()?;
+/// let language = session
+///     .select("Favorite language:", vec!["Rust", "Go", "Zig"])
+///     .with_help_message("Pick one!")
+///     .prompt()?;
+/// let subscribe = session.confirm("Subscribe to the newsletter?").prompt()?;
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PromptSession<'a> {
+    /// Render config applied to every prompt.
+    pub render_config: RenderConfig<'a>,
+
+    /// Whether [`Select`] and [`MultiSelect`] prompts use vim-style keybindings.
+    pub vim_mode: bool,
+
+    /// Key submitting [`Text`] and `Editor` prompts instead of the default one, when set.
+    pub submit_key: Option<Key>,
+}
+
+impl<'a> PromptSession<'a> {
+    /// Creates a [PromptSession] applying the given render config to its prompts.
+    pub fn new(render_config: RenderConfig<'a>) -> Self {
+        Self {
+            render_config,
+            vim_mode: false,
+            submit_key: None,
+        }
+    }
+
+    /// Sets whether [`Select`] and [`MultiSelect`] prompts use vim-style keybindings.
+    pub fn with_vim_mode(mut self, vim_mode: bool) -> Self {
+        self.vim_mode = vim_mode;
+        self
+    }
+
+    /// Sets the key submitting [`Text`] and `Editor` prompts instead of the default one.
+    pub fn with_submit_key(mut self, key: Key) -> Self {
+        self.submit_key = Some(key);
+        self
+    }
+
+    /// Creates a [Text] prompt with the configuration of the session.
+    pub fn text(&self, message: &'a str) -> Text<'a> {
+        let text = Text::new(message).with_render_config(self.render_config);
+
+        match &self.submit_key {
+            Some(key) => text.with_submit_key(key.clone()),
+            None => text,
+        }
+    }
+
+    /// Creates a [Password] prompt with the configuration of the session.
+    pub fn password(&self, message: &'a str) -> Password<'a> {
+        Password::new(message).with_render_config(self.render_config)
+    }
+
+    /// Creates a [Confirm] prompt with the configuration of the session.
+    pub fn confirm(&self, message: &'a str) -> Confirm<'a> {
+        Confirm::new(message).with_render_config(self.render_config)
+    }
+
+    /// Creates a [CustomType] prompt with the configuration of the session.
+    pub fn custom_type<T>(&self, message: &'a str) -> CustomType<'a, T>
+    where
+        T: Clone + FromStr + ToString,
+    {
+        CustomType::new(message).with_render_config(self.render_config)
+    }
+
+    /// Creates a [Select] prompt with the configuration of the session.
+    pub fn select<T: Display>(&self, message: &'a str, options: Vec<T>) -> Select<'a, T> {
+        Select::new(message, options)
+            .with_render_config(self.render_config)
+            .with_vim_mode(self.vim_mode)
+    }
+
+    /// Creates a [MultiSelect] prompt with the configuration of the session.
+    pub fn multi_select<T: Display>(
+        &self,
+        message: &'a str,
+        options: Vec<T>,
+    ) -> MultiSelect<'a, T> {
+        MultiSelect::new(message, options)
+            .with_render_config(self.render_config)
+            .with_vim_mode(self.vim_mode)
+    }
+
+    /// Creates a [DateSelect](crate::DateSelect) prompt with the configuration of the session.
+    #[cfg(feature = "date")]
+    pub fn date_select(&self, message: &'a str) -> crate::DateSelect<'a> {
+        crate::DateSelect::new(message).with_render_config(self.render_config)
+    }
+
+    /// Creates an [Editor](crate::Editor) prompt with the configuration of the session.
+    #[cfg(feature = "editor")]
+    pub fn editor(&self, message: &'a str) -> crate::Editor<'a> {
+        let editor = crate::Editor::new(message).with_render_config(self.render_config);
+
+        match &self.submit_key {
+            Some(key) => editor.with_submit_key(key.clone()),
+            None => editor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        test::FakeBackend,
+        ui::{Key, KeyModifiers, RenderConfig, Styled},
+    };
+
+    use super::PromptSession;
+
+    #[test]
+    fn prompts_are_created_with_the_session_configuration() {
+        let render_config = RenderConfig::empty().with_prompt_prefix(Styled::new("$"));
+        let session = PromptSession::new(render_config).with_vim_mode(true);
+
+        assert_eq!(
+            "$",
+            session.text("Name:").render_config.prompt_prefix.content
+        );
+        assert_eq!(
+            "$",
+            session.confirm("Sure?").render_config.prompt_prefix.content
+        );
+        assert!(session.select("Color:", vec!["red"]).vim_mode);
+        assert!(session.multi_select("Colors:", vec!["red"]).vim_mode);
+    }
+
+    #[test]
+    fn text_prompts_are_submitted_with_the_session_submit_key() {
+        let save = Key::Char('s', KeyModifiers::CONTROL);
+        let session = PromptSession::new(RenderConfig::empty()).with_submit_key(save.clone());
+
+        let mut keys = Key::char_keys_from_str("hi");
+        keys.push(Key::Enter);
+        keys.push(Key::Char('!', KeyModifiers::NONE));
+        keys.push(save);
+        let mut backend = FakeBackend::new(keys);
+
+        let ans = session
+            .text("Greeting:")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        assert_eq!("hi!", ans);
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
 use super::config::TextConfig;
 
 /// Set of actions for a TextPrompt.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(clippy::enum_variant_names)]
 pub enum TextPromptAction {
     /// Action on the value text input handler.
@@ -21,13 +21,20 @@ pub enum TextPromptAction {
     MoveToSuggestionPageDown,
     /// When a suggestion list exists, autocompletes the text input with the current suggestion.
     UseCurrentSuggestion,
+    /// Pastes the text content of the system clipboard into the input.
+    PasteFromClipboard,
 }
 
 impl InnerAction for TextPromptAction {
     type Config = TextConfig;
 
-    fn from_key(key: Key, _config: &TextConfig) -> Option<Self> {
+    fn from_key(key: Key, config: &TextConfig) -> Option<Self> {
         let action = match key {
+            Key::Char('v' | 'V', m)
+                if m.contains(KeyModifiers::CONTROL) && config.clipboard_paste =>
+            {
+                Self::PasteFromClipboard
+            }
             Key::Up(KeyModifiers::NONE) | Key::Char('p', KeyModifiers::CONTROL) => {
                 Self::MoveToSuggestionAbove
             }
@@ -48,4 +55,11 @@ impl InnerAction for TextPromptAction {
 
         Some(action)
     }
+
+    fn input_action(&self) -> Option<&InputAction> {
+        match self {
+            Self::ValueInput(action) => Some(action),
+            _ => None,
+        }
+    }
 }
@@ -5,12 +5,18 @@ use crate::Text;
 pub struct TextConfig {
     /// Page size of the suggestion list, if it exists.
     pub page_size: usize,
+    /// Whether to display the errors of all failing validators.
+    pub show_all_errors: bool,
+    /// Whether Ctrl+V pastes the content of the system clipboard.
+    pub clipboard_paste: bool,
 }
 
 impl From<&Text<'_>> for TextConfig {
     fn from(value: &Text<'_>) -> Self {
         Self {
             page_size: value.page_size,
+            show_all_errors: value.show_all_errors,
+            clipboard_paste: value.clipboard_paste,
         }
     }
 }
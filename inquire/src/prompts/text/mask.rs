@@ -0,0 +1,161 @@
+/// Input mask applied to the text input, e.g. `(###) ###-####`.
+///
+/// Each `#` in the mask is a slot accepting a single ASCII digit and each `A`
+/// is a slot accepting a single alphabetic character. Any other character is a
+/// literal, inserted automatically as the user types.
+#[derive(Copy, Clone, Debug)]
+pub struct InputMask<'a> {
+    mask: &'a str,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MaskSlot {
+    Digit,
+    Letter,
+    Literal(char),
+}
+
+impl MaskSlot {
+    fn from_char(c: char) -> Self {
+        match c {
+            '#' => Self::Digit,
+            'A' => Self::Letter,
+            c => Self::Literal(c),
+        }
+    }
+
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Self::Digit => c.is_ascii_digit(),
+            Self::Letter => c.is_alphabetic(),
+            Self::Literal(literal) => c == literal,
+        }
+    }
+}
+
+impl<'a> InputMask<'a> {
+    pub fn new(mask: &'a str) -> Self {
+        Self { mask }
+    }
+
+    fn slot(&self, index: usize) -> Option<MaskSlot> {
+        self.mask.chars().nth(index).map(MaskSlot::from_char)
+    }
+
+    /// Returns the content after typing `c` at its end, or `None` if the mask
+    /// does not accept the character at this position.
+    ///
+    /// Literals preceding the next slot are inserted automatically. Typing a
+    /// literal explicitly is also accepted, so pasted or pre-formatted values
+    /// go through the mask unchanged.
+    pub fn write(&self, content: &str, c: char) -> Option<String> {
+        let mut result = content.to_owned();
+        let mut index = content.chars().count();
+
+        loop {
+            let slot = self.slot(index)?;
+
+            if slot.accepts(c) {
+                result.push(c);
+                return Some(result);
+            }
+
+            match slot {
+                MaskSlot::Literal(literal) => result.push(literal),
+                MaskSlot::Digit | MaskSlot::Letter => return None,
+            }
+
+            index += 1;
+        }
+    }
+
+    /// Applies every character of `text` through the mask, skipping the ones
+    /// that are not accepted.
+    pub fn write_str(&self, content: &str, text: &str) -> String {
+        text.chars().fold(content.to_owned(), |acc, c| {
+            self.write(&acc, c).unwrap_or(acc)
+        })
+    }
+
+    /// Returns the content after removing its last typed character, along
+    /// with any literals left dangling at its end.
+    pub fn backspace(&self, content: &str) -> String {
+        let mut chars: Vec<char> = content.chars().collect();
+        chars.pop();
+
+        while let Some(last) = chars.len().checked_sub(1) {
+            match self.slot(last) {
+                Some(MaskSlot::Literal(_)) => chars.pop(),
+                Some(MaskSlot::Digit | MaskSlot::Letter) | None => break,
+            };
+        }
+
+        chars.into_iter().collect()
+    }
+
+    /// Strips the literals from the content, keeping only the characters typed
+    /// into the mask slots.
+    pub fn unmask(&self, content: &str) -> String {
+        content
+            .chars()
+            .zip(self.mask.chars().map(MaskSlot::from_char))
+            .filter(|(_, slot)| !matches!(slot, MaskSlot::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputMask;
+
+    const PHONE: InputMask<'static> = InputMask {
+        mask: "(###) ###-####",
+    };
+
+    #[test]
+    fn leading_literals_are_inserted_before_the_first_slot() {
+        assert_eq!(Some("(1".to_owned()), PHONE.write("", '1'));
+    }
+
+    #[test]
+    fn literals_between_slots_are_inserted() {
+        assert_eq!(Some("(123) 4".to_owned()), PHONE.write("(123", '4'));
+    }
+
+    #[test]
+    fn typing_a_literal_explicitly_is_accepted() {
+        assert_eq!(Some("(123)".to_owned()), PHONE.write("(123", ')'));
+        assert_eq!(Some("(".to_owned()), PHONE.write("", '('));
+    }
+
+    #[test]
+    fn characters_rejected_by_the_slot_are_ignored() {
+        assert_eq!(None, PHONE.write("(12", 'a'));
+        assert_eq!(None, InputMask::new("AA-##").write("A", '1'));
+    }
+
+    #[test]
+    fn full_mask_rejects_more_input() {
+        assert_eq!(None, PHONE.write("(123) 456-7890", '1'));
+    }
+
+    #[test]
+    fn write_str_skips_rejected_characters() {
+        assert_eq!("(123) 456-7", PHONE.write_str("", "12x3 4.56-7"));
+    }
+
+    #[test]
+    fn backspace_removes_dangling_literals() {
+        assert_eq!("(123", PHONE.backspace("(123) 4"));
+        assert_eq!("(123) 456", PHONE.backspace("(123) 456-7"));
+        assert_eq!("", PHONE.backspace("(1"));
+        assert_eq!("", PHONE.backspace(""));
+    }
+
+    #[test]
+    fn unmask_keeps_only_slot_characters() {
+        assert_eq!("1234567", PHONE.unmask("(123) 456-7"));
+        assert_eq!("", PHONE.unmask(""));
+    }
+}
@@ -1,21 +1,33 @@
 mod action;
 mod config;
+mod mask;
 mod prompt;
+mod suggester;
 #[cfg(test)]
 #[cfg(feature = "crossterm")]
 mod test;
 
 pub use action::*;
 
+use std::{
+    io::{self, BufRead, Write},
+    time::Duration,
+};
+
 use crate::{
     autocompletion::Autocomplete,
     config::get_configuration,
-    error::{InquireError, InquireResult},
-    formatter::{StringFormatter, DEFAULT_STRING_FORMATTER},
-    prompts::prompt::Prompt,
-    terminal::get_default_terminal,
-    ui::{Backend, RenderConfig, TextBackend},
-    validator::StringValidator,
+    counter::CounterKind,
+    error::{CustomUserError, InquireError, InquireResult},
+    formatter::{StringFormatter, StringFormatterEx, DEFAULT_STRING_FORMATTER},
+    non_interactive::{
+        line_output, prompt_lines, read_line, resolve_message, NonInteractiveFallback,
+    },
+    prompts::{ensure_timeout_supported, prompt::Prompt, PromptTimeout, TimeoutAction},
+    terminal::{get_default_terminal, is_dumb_terminal, record_answer, stdin_is_tty},
+    type_aliases::{DynamicHelpMessage, StringTransformer},
+    ui::{Backend, Key, RenderConfig, Styled, TextBackend},
+    validator::{merge_validations, PromptContext, StringValidator, Validation},
 };
 
 use self::prompt::TextPrompt;
@@ -37,6 +49,8 @@ const DEFAULT_HELP_MESSAGE_WITH_AC: &str = "↑↓ to move, tab to autocomplete,
 /// - **Validators**: Custom validators to the user's input, displaying an error message if the input does not pass the requirements.
 /// - **Formatter**: Custom formatter in case you need to pre-process the user input before showing it as the final answer.
 /// - **Suggester**: Custom function that returns a list of input suggestions based on the current text input. See more on "Autocomplete" below.
+/// - **History**: Previously submitted values the user can recall with the `up` and `down` keys.
+/// - **Non-interactive fallback**: How to answer the prompt when stdin is not an interactive terminal.
 ///
 /// ## Default behaviors
 ///
@@ -88,18 +102,71 @@ pub struct Text<'a> {
     /// Default value, returned when the user input is empty.
     pub default: Option<&'a str>,
 
+    /// Whether the default value is used as the initial value of the text
+    /// input, when no initial value is set.
+    pub default_as_initial: bool,
+
     /// Short hint that describes the expected value of the input.
     pub placeholder: Option<&'a str>,
 
+    /// Mask the input is typed into, e.g. `(###) ###-####`.
+    ///
+    /// Each `#` accepts a single digit and each `A` a single letter. Any other
+    /// character is a literal, inserted automatically as the user types.
+    pub input_mask: Option<&'a str>,
+
+    /// Whether the answer of a masked input holds only the characters typed
+    /// into the mask slots, dropping the literals.
+    pub unmasked_answer: bool,
+
     /// Help message to be presented to the user.
     pub help_message: Option<&'a str>,
 
+    /// Function computing the help message from the current input on every render.
+    ///
+    /// When set, it takes precedence over [`help_message`](Self::help_message),
+    /// which is only displayed when the function returns `None`.
+    pub dynamic_help_message: Option<DynamicHelpMessage<'a>>,
+
+    /// Counters of the length of the input displayed below it, updated on
+    /// every keystroke.
+    pub counters: Vec<CounterKind>,
+
     /// Function that formats the user input and presents it to the user as the final rendering of the prompt.
     pub formatter: StringFormatter<'a>,
 
+    /// Function that formats the final answer knowing whether it came from the default value.
+    ///
+    /// When set, it takes precedence over [`formatter`](Self::formatter).
+    pub formatter_ex: Option<StringFormatterEx<'a>>,
+
+    /// Function normalizing the submitted input, producing the value that is
+    /// then validated and returned by the prompt.
+    ///
+    /// Unlike [`formatter`](Self::formatter), which only affects how the answer
+    /// is displayed, the transformer changes the answer itself.
+    pub transformer: Option<StringTransformer<'a>>,
+
     /// Autocompleter responsible for handling suggestions and input completions.
     pub autocompleter: Option<Box<dyn Autocomplete>>,
 
+    /// Autocompleter whose suggestions are computed in a background thread,
+    /// displaying a loading indicator in the meantime.
+    ///
+    /// When set, it takes precedence over [`autocompleter`](Self::autocompleter).
+    pub async_autocompleter: Option<Box<dyn Autocomplete + Send>>,
+
+    /// Whether the remainder of the first suggestion is displayed after the
+    /// text input, see [`with_inline_suggestion`](Self::with_inline_suggestion).
+    pub inline_suggestion: bool,
+
+    /// Previously submitted values, ordered from oldest to newest, recalled
+    /// into the text input with the `up` and `down` keys.
+    pub history: Vec<String>,
+
+    /// Key submitting the answer instead of `enter`, when set.
+    pub submit_key: Option<Key>,
+
     /// Collection of validators to apply to the user input.
     ///
     /// Validators are executed in the order they are stored, stopping at and displaying to the user
@@ -108,9 +175,25 @@ pub struct Text<'a> {
     /// The possible error is displayed to the user one line above the prompt.
     pub validators: Vec<Box<dyn StringValidator>>,
 
+    /// Whether to execute all validators and display the errors of every failing one,
+    /// each on its own line, instead of stopping at the first error.
+    pub show_all_errors: bool,
+
+    /// Whether pressing Ctrl+V pastes the text content of the system clipboard
+    /// into the input. Requires the `clipboard` feature.
+    pub clipboard_paste: bool,
+
     /// Page size of the suggestions displayed to the user, when applicable.
     pub page_size: usize,
 
+    /// Inactivity timeout after which the prompt is resolved without further input.
+    pub timeout: Option<PromptTimeout>,
+
+    /// How the prompt is answered when stdin is not an interactive terminal.
+    ///
+    /// When `None`, the prompt tries to acquire the terminal regardless.
+    pub non_interactive_fallback: Option<NonInteractiveFallback>,
+
     /// RenderConfig to apply to the rendered interface.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -132,6 +215,9 @@ impl<'a> Text<'a> {
     /// Default validators added to the [Text] prompt, none.
     pub const DEFAULT_VALIDATORS: Vec<Box<dyn StringValidator>> = vec![];
 
+    /// Default value for the show all errors variable.
+    pub const DEFAULT_SHOW_ALL_ERRORS: bool = false;
+
     /// Default help message.
     pub const DEFAULT_HELP_MESSAGE: Option<&'a str> = None;
 
@@ -142,11 +228,26 @@ impl<'a> Text<'a> {
             placeholder: None,
             initial_value: None,
             default: None,
+            default_as_initial: false,
+            input_mask: None,
+            unmasked_answer: false,
             help_message: Self::DEFAULT_HELP_MESSAGE,
+            dynamic_help_message: None,
+            counters: vec![],
             validators: Self::DEFAULT_VALIDATORS,
+            show_all_errors: Self::DEFAULT_SHOW_ALL_ERRORS,
+            clipboard_paste: false,
             formatter: Self::DEFAULT_FORMATTER,
+            formatter_ex: None,
+            transformer: None,
             page_size: Self::DEFAULT_PAGE_SIZE,
             autocompleter: None,
+            async_autocompleter: None,
+            inline_suggestion: false,
+            history: vec![],
+            submit_key: None,
+            timeout: None,
+            non_interactive_fallback: None,
             render_config: get_configuration(),
         }
     }
@@ -157,6 +258,15 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets a function computing the help message from the current input on every render.
+    ///
+    /// When the function returns `None`, the help message set in
+    /// [`with_help_message`](Self::with_help_message) is displayed, if any.
+    pub fn with_dynamic_help_message(mut self, help_message: DynamicHelpMessage<'a>) -> Self {
+        self.dynamic_help_message = Some(help_message);
+        self
+    }
+
     /// Sets the initial value of the prompt's text input.
     ///
     /// If you want to set a default value for the prompt, returned when the user's submission is empty, see [`with_default`].
@@ -173,12 +283,41 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Prefills the text input with the default value, if any, letting the user
+    /// edit it or accept it as is. An initial value set with
+    /// [`with_initial_value`](Self::with_initial_value) takes precedence.
+    pub fn with_default_as_initial(mut self) -> Self {
+        self.default_as_initial = true;
+        self
+    }
+
     /// Sets the placeholder.
     pub fn with_placeholder(mut self, placeholder: &'a str) -> Self {
         self.placeholder = Some(placeholder);
         self
     }
 
+    /// Sets a mask for the input, such as `(###) ###-####` for phone numbers.
+    ///
+    /// Each `#` accepts a single digit and each `A` a single letter; characters
+    /// rejected by the mask are ignored. Any other character of the mask is a
+    /// literal, inserted automatically as the user types and removed along with
+    /// the preceding character on backspace.
+    ///
+    /// The answer includes the literals, see
+    /// [`with_unmasked_answer`](Self::with_unmasked_answer) to drop them.
+    pub fn with_input_mask(mut self, mask: &'a str) -> Self {
+        self.input_mask = Some(mask);
+        self
+    }
+
+    /// Makes the answer of a masked input hold only the characters typed into
+    /// the mask slots, e.g. `5551234567` instead of `(555) 123-4567`.
+    pub fn with_unmasked_answer(mut self) -> Self {
+        self.unmasked_answer = true;
+        self
+    }
+
     /// Sets a new autocompleter
     pub fn with_autocomplete<AC>(mut self, ac: AC) -> Self
     where
@@ -188,12 +327,99 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets a new autocompleter whose suggestions are computed in a background thread.
+    ///
+    /// Useful when [`get_suggestions`](Autocomplete::get_suggestions) is expensive, e.g.
+    /// when it hits a network API. While suggestions are being computed, the prompt
+    /// stays responsive and displays a loading indicator next to the text input,
+    /// configurable through [`RenderConfig::spinner_frames`]. When the input changes
+    /// before the suggestions arrive, the stale request is discarded.
+    ///
+    /// Completions triggered by the autocompletion hotkey are still computed on the
+    /// main thread, sharing the same autocompleter instance.
+    ///
+    /// Note: the loading indicator is only animated with the `crossterm` terminal
+    /// back-end. Other back-ends wait for the next key press to update the prompt.
+    pub fn with_async_suggestions<AC>(mut self, ac: AC) -> Self
+    where
+        AC: Autocomplete + Send + 'static,
+    {
+        self.async_autocompleter = Some(Box::new(ac));
+        self
+    }
+
+    /// Displays the remainder of the first suggestion of the autocompleter, dimmed,
+    /// right after the text input, like the autosuggestions of the fish shell.
+    ///
+    /// The inline suggestion is only displayed while the cursor is at the end of
+    /// the input and the suggestion starts with the input. Pressing the right arrow
+    /// or the end key then accepts it, replacing the input with the suggestion.
+    /// The suggestion list and the autocompletion hotkey keep working as usual.
+    ///
+    /// The inline suggestion is rendered with the style sheet of placeholders.
+    pub fn with_inline_suggestion(mut self) -> Self {
+        self.inline_suggestion = true;
+        self
+    }
+
+    /// Sets the previously submitted values, ordered from oldest to newest,
+    /// that can be recalled into the text input like in a shell.
+    ///
+    /// Pressing `up` replaces the input with the previous entry and `down`
+    /// with the next one, restoring the in-progress input when moving past
+    /// the newest entry. When suggestions are displayed, `up` and `down` move
+    /// through them first, reaching the history from the top of the list.
+    pub fn with_history(mut self, history: Vec<String>) -> Self {
+        self.history = history;
+        self
+    }
+
+    /// Sets the key submitting the answer, e.g. `ctrl+s`, instead of `enter`,
+    /// which then no longer submits the prompt.
+    ///
+    /// The keys cancelling (`esc`) and interrupting (`ctrl+c`) the prompt are
+    /// not affected.
+    pub fn with_submit_key(mut self, key: Key) -> Self {
+        self.submit_key = Some(key);
+        self
+    }
+
     /// Sets the formatter.
     pub fn with_formatter(mut self, formatter: StringFormatter<'a>) -> Self {
         self.formatter = formatter;
         self
     }
 
+    /// Sets a formatter that also receives whether the answer is the default value,
+    /// i.e. whether the user submitted an empty input without picking a suggestion.
+    ///
+    /// When set, it takes precedence over the formatter set in [`with_formatter`](Self::with_formatter).
+    pub fn with_formatter_ex(mut self, formatter: StringFormatterEx<'a>) -> Self {
+        self.formatter_ex = Some(formatter);
+        self
+    }
+
+    /// Sets a function normalizing the submitted input, e.g. trimming whitespace.
+    ///
+    /// On submission, the input is transformed first, then the transformed value
+    /// is validated and, if valid, returned as the answer. Default values and
+    /// suggestions picked by the user are transformed as well. Unlike the formatter,
+    /// which only affects how the answer is displayed, the transformer changes
+    /// the answer itself.
+    pub fn with_transformer(mut self, transformer: StringTransformer<'a>) -> Self {
+        self.transformer = Some(transformer);
+        self
+    }
+
+    /// Adds a counter of the length of the input, e.g. `[142 chars]`, displayed
+    /// below it and updated on every keystroke.
+    ///
+    /// Several counters are displayed together, e.g. `[142 chars, 25 words]`.
+    pub fn with_counter(mut self, counter: CounterKind) -> Self {
+        self.counters.push(counter);
+        self
+    }
+
     /// Sets the page size
     pub fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
@@ -216,6 +442,42 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Adds a validator receiving the current value of the given context along
+    /// with the user input, e.g. to check the answer against the answers given
+    /// to previous prompts of a wizard.
+    ///
+    /// The context is read when the input is validated, so it can be updated
+    /// after this prompt is created, as long as it is before it is answered.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use inquire::{validator::{PromptContext, Validation}, Text};
+    ///
+    /// let context = PromptContext::new(String::new());
+    ///
+    /// let email = Text::new("Email:").prompt()?;
+    /// context.set(email);
+    ///
+    /// let _confirmation = Text::new("Confirm email:")
+    ///     .with_context_validator(&context, |input: &str, email: &String| {
+    ///         if input == email {
+    ///             Ok(Validation::Valid)
+    ///         } else {
+    ///             Ok(Validation::Invalid("The emails do not match".into()))
+    ///         }
+    ///     })
+    ///     .prompt()?;
+    /// # Ok::<(), inquire::InquireError>(())
+    /// ```
+    pub fn with_context_validator<C, F>(self, context: &PromptContext<C>, validator: F) -> Self
+    where
+        C: 'static,
+        F: Fn(&str, &C) -> Result<Validation, CustomUserError> + Clone + 'static,
+    {
+        self.with_validator(context.validator(validator))
+    }
+
     /// Adds the validators to the collection of validators in the order they are given.
     /// You might want to use this feature in case you need to require certain features
     /// from the user's answer, such as defining a limit of characters.
@@ -232,6 +494,47 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Executes all validators on submission and displays the errors of every failing
+    /// one, each on its own line, instead of stopping at the first error.
+    pub fn with_all_errors_shown(mut self) -> Self {
+        self.show_all_errors = true;
+        self
+    }
+
+    /// Enables pasting the text content of the system clipboard with Ctrl+V,
+    /// for terminals where the regular paste is not available.
+    ///
+    /// The clipboard content is inserted at the cursor as a single edit, with
+    /// line breaks replaced by spaces. When the clipboard is unavailable, an
+    /// error message is displayed and the input is left untouched.
+    #[cfg(feature = "clipboard")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "clipboard")))]
+    pub fn with_clipboard_paste(mut self) -> Self {
+        self.clipboard_paste = true;
+        self
+    }
+
+    /// Resolves the prompt with `action` when the user does not press any keys
+    /// for `duration`. The timer is reset on every key press.
+    ///
+    /// With [`TimeoutAction::Cancel`], the prompt returns `Err(InquireError::OperationCanceled)`,
+    /// just as when the user presses ESC.
+    ///
+    /// Timeouts require the `crossterm` backend, see [`PromptTimeout`].
+    pub fn with_timeout(mut self, duration: Duration, action: TimeoutAction) -> Self {
+        self.timeout = Some(PromptTimeout::new(duration, action));
+        self
+    }
+
+    /// Sets how the prompt is answered when stdin is not an interactive terminal,
+    /// e.g. when input is piped into the program in a CI environment.
+    ///
+    /// See [`NonInteractiveFallback`] for the available behaviors.
+    pub fn with_non_interactive_fallback(mut self, fallback: NonInteractiveFallback) -> Self {
+        self.non_interactive_fallback = Some(fallback);
+        self
+    }
+
     /// Sets the provided color theme to this prompt.
     ///
     /// Note: The default render config considers if the NO_COLOR environment variable
@@ -245,6 +548,26 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Sets the prompt prefix and its style sheet for this prompt only, keeping
+    /// the rest of its render config.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this prefix.
+    pub fn with_prompt_prefix(mut self, prompt_prefix: Styled<&'a str>) -> Self {
+        self.render_config = self.render_config.with_prompt_prefix(prompt_prefix);
+        self
+    }
+
+    /// Sets the position of this prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    ///
+    /// Note: calling [`with_render_config`](Self::with_render_config) afterwards
+    /// replaces the whole render config, including this step.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.render_config = self.render_config.with_step_indicator(current, total);
+        self
+    }
+
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     ///
@@ -265,15 +588,135 @@ impl<'a> Text<'a> {
     /// Parses the provided behavioral and rendering options and prompts
     /// the CLI user for input according to the defined rules.
     pub fn prompt(self) -> InquireResult<String> {
-        let (input_reader, terminal) = get_default_terminal()?;
-        let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
-        self.prompt_with_backend(&mut backend)
+        self.prompt_with_render_callback(|_, _| {})
     }
 
-    pub(crate) fn prompt_with_backend<B: TextBackend>(
-        self,
-        backend: &mut B,
-    ) -> InquireResult<String> {
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules, using `history`
+    /// as the recallable [history](Self::with_history) of the prompt.
+    ///
+    /// Once the user submits, the answer is appended to `history`, so calling
+    /// this method in a loop with the same vector behaves like a shell.
+    pub fn prompt_with_history(mut self, history: &mut Vec<String>) -> InquireResult<String> {
+        self.history = history.clone();
+        let answer = self.prompt()?;
+        history.push(answer.clone());
+        Ok(answer)
+    }
+
+    /// Parses the provided behavioral and rendering options and prompts
+    /// the CLI user for input according to the defined rules.
+    ///
+    /// Once the user submits, `on_answer` is called with the prompt message
+    /// and the formatted answer exactly as rendered on the final line, which
+    /// is useful to log or replay the session. It is not called when the
+    /// prompt is canceled or interrupted.
+    pub fn prompt_with_render_callback<F>(self, mut on_answer: F) -> InquireResult<String>
+    where
+        F: FnMut(&str, &str),
+    {
+        let (message, formatter, formatter_ex) = (self.message, self.formatter, self.formatter_ex);
+        let mut on_answer = |message: &str, answer: &str| {
+            record_answer(message, answer);
+            on_answer(message, answer);
+        };
+
+        let line_answer = match self.non_interactive_fallback {
+            Some(fallback) if !stdin_is_tty() => {
+                self.non_interactive_answer(fallback, &mut io::stdin().lock())
+            }
+            _ if is_dumb_terminal() => {
+                self.dumb_terminal_answer(&mut io::stdin().lock(), &mut line_output()?)
+            }
+            _ => {
+                ensure_timeout_supported(self.timeout.as_ref())?;
+                let (input_reader, terminal) = get_default_terminal()?;
+                let mut backend = Backend::new(input_reader, terminal, self.render_config)?;
+                return TextPrompt::from(self).prompt_with_render_callback(&mut backend, on_answer);
+            }
+        };
+
+        let (answer, used_default) = line_answer?;
+        let formatted = match formatter_ex {
+            Some(formatter_ex) => formatter_ex(&answer, used_default),
+            None => formatter(&answer),
+        };
+        on_answer(message, &formatted);
+        Ok(answer)
+    }
+
+    /// Prompts the user using the provided backend instead of the default terminal.
+    ///
+    /// This is mostly useful in automated tests, where the `FakeBackend` available
+    /// under the `test-util` feature can feed scripted key events to the prompt
+    /// and capture the rendered frames for assertions.
+    pub fn prompt_with_backend<B: TextBackend>(self, backend: &mut B) -> InquireResult<String> {
         TextPrompt::from(self).prompt(backend)
     }
+
+    fn non_interactive_answer<R: BufRead>(
+        self,
+        fallback: NonInteractiveFallback,
+        reader: &mut R,
+    ) -> InquireResult<(String, bool)> {
+        let line = match fallback {
+            NonInteractiveFallback::Error => return Err(InquireError::NotTTY),
+            NonInteractiveFallback::UseDefault if self.default.is_none() => {
+                return Err(InquireError::NotTTY)
+            }
+            // an empty line is answered with the default value
+            NonInteractiveFallback::UseDefault => String::new(),
+            NonInteractiveFallback::ReadLine => read_line(reader)?,
+        };
+
+        self.line_answer(line)?
+            .map_err(|message| InquireError::Custom(message.into()))
+    }
+
+    /// Line-based version of the prompt for terminals unable to move the cursor,
+    /// asking again until the answer is valid.
+    fn dumb_terminal_answer<R: BufRead, W: Write>(
+        self,
+        reader: &mut R,
+        writer: &mut W,
+    ) -> InquireResult<(String, bool)> {
+        prompt_lines(
+            reader,
+            writer,
+            &self.render_config,
+            self.message,
+            self.default,
+            |line| self.line_answer(line),
+        )
+    }
+
+    /// Transforms and validates a line typed as the answer, returning the error
+    /// message to display when it is invalid.
+    fn line_answer(&self, line: String) -> InquireResult<Result<(String, bool), String>> {
+        let (answer, used_default) = match self.default {
+            Some(default) if line.is_empty() => (default.to_owned(), true),
+            _ => (line, false),
+        };
+
+        let answer = match self.transformer {
+            Some(transformer) => transformer(&answer),
+            None => answer,
+        };
+
+        let results = self
+            .validators
+            .iter()
+            .map(|validator| validator.validate(&answer));
+
+        match merge_validations(results, self.show_all_errors) {
+            Ok(Validation::Valid | Validation::Warning(_)) => {}
+            Ok(Validation::Invalid(msg)) => {
+                let default_message = self.render_config.error_message.default_message;
+                return Ok(Err(resolve_message(msg, default_message)));
+            }
+            Err(err) => return Err(InquireError::Custom(err)),
+        }
+
+        Ok(Ok((answer, used_default)))
+    }
 }
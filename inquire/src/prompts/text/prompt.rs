@@ -1,37 +1,75 @@
-use std::cmp::min;
+use std::{cmp::min, time::Duration};
 
 use crate::{
     autocompletion::{NoAutoCompletion, Replacement},
+    clipboard,
+    counter::{format_counts, CounterKind},
     error::InquireResult,
-    formatter::StringFormatter,
-    input::{Input, InputActionResult},
+    formatter::{StringFormatter, StringFormatterEx},
+    input::{Input, InputActionResult, LineDirection, Magnitude},
     list_option::ListOption,
-    prompts::prompt::{ActionResult, Prompt},
-    ui::TextBackend,
+    prompts::{
+        prompt::{ActionResult, Prompt},
+        PromptTimeout,
+    },
+    type_aliases::{DynamicHelpMessage, StringTransformer},
+    ui::{Key, TextBackend},
     utils::paginate,
-    validator::{ErrorMessage, StringValidator, Validation},
-    Autocomplete, InquireError, Text,
+    validator::{merge_validations, AnswerWarning, ErrorMessage, StringValidator, Validation},
+    Autocomplete, CustomUserError, InputAction, InquireError, Text,
 };
 
-use super::{action::TextPromptAction, config::TextConfig, DEFAULT_HELP_MESSAGE_WITH_AC};
+use super::{
+    action::TextPromptAction, config::TextConfig, mask::InputMask, suggester::AsyncSuggester,
+    DEFAULT_HELP_MESSAGE_WITH_AC,
+};
+
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
 
 pub struct TextPrompt<'a> {
     message: &'a str,
     config: TextConfig,
     default: Option<&'a str>,
     help_message: Option<&'a str>,
+    dynamic_help_message: Option<DynamicHelpMessage<'a>>,
+    counters: Vec<CounterKind>,
     input: Input,
+    input_mask: Option<InputMask<'a>>,
+    unmasked_answer: bool,
     formatter: StringFormatter<'a>,
+    formatter_ex: Option<StringFormatterEx<'a>>,
+    transformer: Option<StringTransformer<'a>>,
+    used_default: bool,
     validators: Vec<Box<dyn StringValidator>>,
+    timeout: Option<PromptTimeout>,
     error: Option<ErrorMessage>,
+    warning: Option<AnswerWarning<String>>,
     autocompleter: Box<dyn Autocomplete>,
+    suggestion_error: Option<ErrorMessage>,
+    async_suggester: Option<AsyncSuggester>,
+    inline_suggestion: bool,
+    spinner_frame: usize,
+    animations_enabled: bool,
     suggested_options: Vec<String>,
     suggestion_cursor_index: Option<usize>,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    history_draft: String,
+    submit_key: Option<Key>,
 }
 
 impl<'a> From<Text<'a>> for TextPrompt<'a> {
     fn from(so: Text<'a>) -> Self {
-        let input = Input::new_with(so.initial_value.unwrap_or_default());
+        let initial_value = match so.default_as_initial {
+            true => so.initial_value.or(so.default),
+            false => so.initial_value,
+        };
+        let input_mask = so.input_mask.map(InputMask::new);
+        let initial_value = initial_value.unwrap_or_default();
+        let input = match input_mask {
+            Some(mask) => Input::new_with(mask.write_str("", initial_value)),
+            None => Input::new_with(initial_value),
+        };
         let input = if let Some(placeholder) = so.placeholder {
             input.with_placeholder(placeholder)
         } else {
@@ -43,15 +81,33 @@ impl<'a> From<Text<'a>> for TextPrompt<'a> {
             config: (&so).into(),
             default: so.default,
             help_message: so.help_message,
+            dynamic_help_message: so.dynamic_help_message,
+            counters: so.counters,
             formatter: so.formatter,
+            formatter_ex: so.formatter_ex,
+            transformer: so.transformer,
+            used_default: false,
             autocompleter: so
                 .autocompleter
                 .unwrap_or_else(|| Box::<NoAutoCompletion>::default()),
+            suggestion_error: None,
+            async_suggester: so.async_autocompleter.map(AsyncSuggester::new),
+            inline_suggestion: so.inline_suggestion,
+            spinner_frame: 0,
+            animations_enabled: so.render_config.animations_enabled,
             input,
+            input_mask,
+            unmasked_answer: so.unmasked_answer,
             error: None,
+            warning: None,
             suggestion_cursor_index: None,
             suggested_options: vec![],
+            history: so.history,
+            history_index: None,
+            history_draft: String::new(),
+            submit_key: so.submit_key,
             validators: so.validators,
+            timeout: so.timeout,
         }
     }
 }
@@ -63,11 +119,39 @@ impl<'a> From<&'a str> for Text<'a> {
 }
 
 impl<'a> TextPrompt<'a> {
-    fn update_suggestions(&mut self) -> InquireResult<()> {
-        self.suggested_options = self.autocompleter.get_suggestions(self.input.content())?;
+    fn update_suggestions(&mut self) {
+        if let Some(suggester) = &mut self.async_suggester {
+            suggester.request(self.input.content());
+            return;
+        }
+
+        let suggestions = self.autocompleter.get_suggestions(self.input.content());
+        self.set_suggestions(suggestions);
+    }
+
+    /// Replaces the suggestions with the ones returned by the autocompleter.
+    ///
+    /// An error does not abort the prompt: it is displayed like a validation
+    /// error, without suggestions, until the next successful update.
+    fn set_suggestions(&mut self, suggestions: Result<Vec<String>, CustomUserError>) {
+        match suggestions {
+            Ok(suggestions) => {
+                self.suggested_options = suggestions;
+                self.suggestion_error = None;
+            }
+            Err(err) => {
+                self.suggested_options = vec![];
+                self.suggestion_error = Some(ErrorMessage::Custom(err.to_string()));
+            }
+        }
         self.suggestion_cursor_index = None;
+    }
 
-        Ok(())
+    fn is_loading_suggestions(&self) -> bool {
+        self.async_suggester
+            .as_ref()
+            .map(|suggester| suggester.is_pending())
+            .unwrap_or(false)
     }
 
     fn get_highlighted_suggestion(&self) -> Option<&str> {
@@ -79,6 +163,35 @@ impl<'a> TextPrompt<'a> {
         }
     }
 
+    /// Remainder of the first suggestion displayed after the input, when the
+    /// cursor is at its end and the suggestion extends it.
+    fn inline_suggestion_suffix(&self) -> Option<&str> {
+        if !self.inline_suggestion
+            || self.input.is_empty()
+            || self.input.cursor() != self.input.length()
+            || self.is_loading_suggestions()
+        {
+            return None;
+        }
+
+        self.suggested_options
+            .first()
+            .and_then(|suggestion| suggestion.strip_prefix(self.input.content()))
+            .filter(|suffix| !suffix.is_empty())
+    }
+
+    fn accept_inline_suggestion(&mut self) -> InquireResult<ActionResult> {
+        let suggestion = match self.inline_suggestion_suffix() {
+            Some(suffix) => format!("{}{}", self.input.content(), suffix),
+            None => return Ok(ActionResult::Clean),
+        };
+
+        self.input = Input::new_with(suggestion);
+        self.update_suggestions();
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
     fn move_cursor_up(&mut self, qty: usize) -> ActionResult {
         let new_cursor_index = match self.suggestion_cursor_index {
             None => None,
@@ -119,18 +232,85 @@ impl<'a> TextPrompt<'a> {
 
     fn use_current_suggestion(&mut self) -> InquireResult<ActionResult> {
         let suggestion = self.get_highlighted_suggestion().map(|s| s.to_owned());
-        match self
-            .autocompleter
-            .get_completion(self.input.content(), suggestion)?
-        {
-            Replacement::Some(value) => {
+        let input = self.input.content();
+        let cursor = self.input.cursor();
+        let replacement = match &mut self.async_suggester {
+            Some(suggester) => suggester.get_completion_at(input, cursor, suggestion),
+            None => self
+                .autocompleter
+                .get_completion_at(input, cursor, suggestion),
+        };
+
+        match replacement {
+            Ok(Replacement::Some(value)) => {
                 self.input = Input::new_with(value);
+                self.update_suggestions();
+                Ok(ActionResult::NeedsRedraw)
+            }
+            Ok(Replacement::None) => Ok(ActionResult::Clean),
+            Err(err) => {
+                self.suggestion_error = Some(ErrorMessage::Custom(err.to_string()));
                 Ok(ActionResult::NeedsRedraw)
             }
-            Replacement::None => Ok(ActionResult::Clean),
         }
     }
 
+    /// Replaces the content of the input, keeping its placeholder.
+    fn replace_input(&mut self, content: String) {
+        let mut input = Input::new_with(content);
+        if let Some(placeholder) = self.input.placeholder() {
+            input = input.with_placeholder(placeholder);
+        }
+        self.input = input;
+    }
+
+    /// Recalls the history entry preceding the current one, saving the
+    /// in-progress input when leaving it.
+    fn move_history_back(&mut self) -> InquireResult<ActionResult> {
+        let index = match self.history_index {
+            None if self.history.is_empty() => return Ok(ActionResult::Clean),
+            None => {
+                self.history_draft = self.input.content().to_owned();
+                self.history.len() - 1
+            }
+            Some(0) => return Ok(ActionResult::Clean),
+            Some(index) => index - 1,
+        };
+
+        self.history_index = Some(index);
+        self.replace_input(self.history[index].clone());
+        self.update_suggestions();
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    /// Recalls the history entry following the current one, restoring the
+    /// in-progress input when moving past the newest entry.
+    fn move_history_forward(&mut self) -> InquireResult<ActionResult> {
+        let content = match self.history_index {
+            None => return Ok(ActionResult::Clean),
+            Some(index) if index + 1 < self.history.len() => {
+                self.history_index = Some(index + 1);
+                self.history[index + 1].clone()
+            }
+            Some(_) => {
+                self.history_index = None;
+                std::mem::take(&mut self.history_draft)
+            }
+        };
+
+        self.replace_input(content);
+        self.update_suggestions();
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn is_default_answer(&self) -> bool {
+        self.get_highlighted_suggestion().is_none()
+            && self.input.content().is_empty()
+            && self.default.is_some()
+    }
+
     fn get_current_answer(&self) -> &str {
         // If there is a highlighted suggestion, assume user wanted it as
         // the answer.
@@ -148,16 +328,77 @@ impl<'a> TextPrompt<'a> {
         self.input.content()
     }
 
-    fn validate_current_answer(&self) -> InquireResult<Validation> {
-        for validator in &self.validators {
-            match validator.validate(self.get_current_answer()) {
-                Ok(Validation::Valid) => {}
-                Ok(Validation::Invalid(msg)) => return Ok(Validation::Invalid(msg)),
-                Err(err) => return Err(InquireError::Custom(err)),
+    /// Current answer after going through the transformer, if any.
+    fn get_final_answer(&self) -> String {
+        let answer = self.get_current_answer();
+
+        let unmasked;
+        let answer = match self.input_mask {
+            Some(mask) if self.unmasked_answer && !self.is_default_answer() => {
+                unmasked = mask.unmask(answer);
+                &unmasked
             }
+            _ => answer,
+        };
+
+        match self.transformer {
+            Some(transformer) => transformer(answer),
+            None => answer.to_owned(),
+        }
+    }
+
+    /// Applies an input action to the value of the prompt, going through the
+    /// input mask when there is one, and refreshes the suggestions when the
+    /// content changes.
+    fn handle_value_input(&mut self, input_action: InputAction) -> ActionResult {
+        let result = match self.input_mask {
+            Some(mask) => self.handle_masked_input(mask, input_action),
+            None => self.input.handle(input_action),
+        };
+
+        if let InputActionResult::ContentChanged = result {
+            // an edited history entry becomes the in-progress input.
+            self.history_index = None;
+            self.update_suggestions();
         }
 
-        Ok(Validation::Valid)
+        result.into()
+    }
+
+    /// Handles an input action going through the mask: characters are written
+    /// at the end of the input only, and deletions remove whole mask slots.
+    fn handle_masked_input(
+        &mut self,
+        mask: InputMask<'a>,
+        action: InputAction,
+    ) -> InputActionResult {
+        let content = self.input.content();
+
+        let new_content = match action {
+            InputAction::Write(c) => mask.write(content, c),
+            InputAction::Paste(text) => Some(mask.write_str(content, &text)),
+            InputAction::Delete(Magnitude::Line, LineDirection::Left) => Some(String::new()),
+            InputAction::Delete(_, LineDirection::Left) => Some(mask.backspace(content)),
+            InputAction::Delete(_, LineDirection::Right) | InputAction::MoveCursor(_, _) => None,
+        };
+
+        let new_content = match new_content {
+            Some(new_content) if new_content != content => new_content,
+            _ => return InputActionResult::Clean,
+        };
+
+        self.replace_input(new_content);
+
+        InputActionResult::ContentChanged
+    }
+
+    fn validate_answer(&self, answer: &str) -> InquireResult<Validation> {
+        let results = self
+            .validators
+            .iter()
+            .map(|validator| validator.validate(answer));
+
+        merge_validations(results, self.config.show_all_errors).map_err(InquireError::Custom)
     }
 }
 
@@ -177,19 +418,36 @@ where
         &self.config
     }
 
+    fn submit_key(&self) -> Option<&Key> {
+        self.submit_key.as_ref()
+    }
+
     fn format_answer(&self, answer: &String) -> String {
-        (self.formatter)(answer)
+        match self.formatter_ex {
+            Some(formatter) => formatter(answer, self.used_default),
+            None => (self.formatter)(answer),
+        }
     }
 
-    fn setup(&mut self) -> InquireResult<()> {
-        self.update_suggestions()
+    fn setup(&mut self) -> InquireResult<ActionResult> {
+        self.update_suggestions();
+        Ok(ActionResult::NeedsRedraw)
     }
 
     fn submit(&mut self) -> InquireResult<Option<String>> {
-        let result = match self.validate_current_answer()? {
-            Validation::Valid => Some(self.get_current_answer().to_owned()),
+        self.used_default = self.is_default_answer();
+
+        let answer = self.get_final_answer();
+
+        let result = match self.validate_answer(&answer)? {
+            Validation::Valid => Some(answer),
+            Validation::Warning(msg) => {
+                self.error = None;
+                AnswerWarning::confirm(&mut self.warning, msg, answer.clone()).then_some(answer)
+            }
             Validation::Invalid(msg) => {
                 self.error = Some(msg);
+                self.warning = None;
                 None
             }
         };
@@ -199,35 +457,80 @@ where
 
     fn handle(&mut self, action: TextPromptAction) -> InquireResult<ActionResult> {
         let result = match action {
-            TextPromptAction::ValueInput(input_action) => {
-                let result = self.input.handle(input_action);
-
-                if let InputActionResult::ContentChanged = result {
-                    self.update_suggestions()?;
-                }
-
-                result.into()
+            // moving the cursor right while at the end of the input would be
+            // a no-op, so it accepts the inline suggestion instead.
+            TextPromptAction::ValueInput(InputAction::MoveCursor(
+                Magnitude::Char | Magnitude::Line,
+                LineDirection::Right,
+            )) if self.inline_suggestion_suffix().is_some() => self.accept_inline_suggestion()?,
+            TextPromptAction::ValueInput(input_action) => self.handle_value_input(input_action),
+            TextPromptAction::MoveToSuggestionAbove if self.suggestion_cursor_index.is_none() => {
+                self.move_history_back()?
             }
             TextPromptAction::MoveToSuggestionAbove => self.move_cursor_up(1),
+            TextPromptAction::MoveToSuggestionBelow if self.history_index.is_some() => {
+                self.move_history_forward()?
+            }
             TextPromptAction::MoveToSuggestionBelow => self.move_cursor_down(1),
             TextPromptAction::MoveToSuggestionPageUp => self.move_cursor_up(self.config.page_size),
             TextPromptAction::MoveToSuggestionPageDown => {
                 self.move_cursor_down(self.config.page_size)
             }
-            TextPromptAction::UseCurrentSuggestion => {
-                let result = self.use_current_suggestion()?;
-
-                if let ActionResult::NeedsRedraw = result {
-                    self.update_suggestions()?;
+            TextPromptAction::UseCurrentSuggestion => self.use_current_suggestion()?,
+            TextPromptAction::PasteFromClipboard => match clipboard::read_text() {
+                Ok(text) => {
+                    self.error = None;
+                    self.handle_value_input(InputAction::Paste(text))
+                        .merge(ActionResult::NeedsRedraw)
                 }
-
-                result
-            }
+                Err(message) => {
+                    self.error = Some(message);
+                    ActionResult::NeedsRedraw
+                }
+            },
         };
 
         Ok(result)
     }
 
+    fn tick_interval(&self) -> Option<Duration> {
+        if self.is_loading_suggestions() {
+            Some(SPINNER_INTERVAL)
+        } else {
+            None
+        }
+    }
+
+    fn tick(&mut self) -> InquireResult<ActionResult> {
+        let suggester = match &mut self.async_suggester {
+            Some(suggester) => suggester,
+            None => return Ok(ActionResult::Clean),
+        };
+
+        if let Some(suggestions) = suggester.poll() {
+            self.set_suggestions(suggestions);
+            return Ok(ActionResult::NeedsRedraw);
+        }
+
+        // the loading indicator is static without animations, there is
+        // nothing to redraw until the suggestions are ready.
+        if !self.animations_enabled {
+            return Ok(ActionResult::Clean);
+        }
+
+        self.spinner_frame = self.spinner_frame.wrapping_add(1);
+
+        Ok(ActionResult::NeedsRedraw)
+    }
+
+    fn timeout(&self) -> Option<PromptTimeout> {
+        self.timeout
+    }
+
+    fn restore_default(&mut self) {
+        self.input.clear();
+    }
+
     fn render(&self, backend: &mut Backend) -> InquireResult<()> {
         let prompt = &self.message;
 
@@ -235,7 +538,31 @@ where
             backend.render_error_message(err)?;
         }
 
-        backend.render_prompt(prompt, self.default, &self.input)?;
+        if let Some(warning) = &self.warning {
+            backend.render_warning_message(&warning.message)?;
+        }
+
+        if let Some(err) = &self.suggestion_error {
+            backend.render_suggestion_error(err)?;
+        }
+
+        if self.is_loading_suggestions() {
+            backend.render_prompt_with_spinner(
+                prompt,
+                self.default,
+                &self.input,
+                self.spinner_frame,
+            )?;
+        } else if let Some(suffix) = self.inline_suggestion_suffix() {
+            backend.render_prompt_with_inline_suggestion(
+                prompt,
+                self.default,
+                &self.input,
+                suffix,
+            )?;
+        } else {
+            backend.render_prompt(prompt, self.default, &self.input)?;
+        }
 
         let choices = self
             .suggested_options
@@ -252,12 +579,20 @@ where
 
         backend.render_suggestions(page)?;
 
-        if let Some(message) = self.help_message {
+        let dynamic_help_message = self
+            .dynamic_help_message
+            .and_then(|help_message| help_message(self.input.content()));
+
+        if let Some(message) = dynamic_help_message.as_deref().or(self.help_message) {
             backend.render_help_message(message)?;
         } else if !choices.is_empty() {
             backend.render_help_message(DEFAULT_HELP_MESSAGE_WITH_AC)?;
         }
 
+        if let Some(counts) = format_counts(&self.counters, |kind| self.input.count(kind)) {
+            backend.render_help_message(&counts)?;
+        }
+
         Ok(())
     }
 }
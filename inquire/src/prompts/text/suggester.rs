@@ -0,0 +1,106 @@
+use std::{
+    sync::{
+        mpsc::{channel, Receiver, Sender, TryRecvError},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+};
+
+use crate::{autocompletion::Replacement, Autocomplete, CustomUserError};
+
+type SharedAutocomplete = Arc<Mutex<Box<dyn Autocomplete + Send>>>;
+type SuggestionsResponse = (u64, Result<Vec<String>, CustomUserError>);
+
+fn lock(autocompleter: &SharedAutocomplete) -> MutexGuard<'_, Box<dyn Autocomplete + Send>> {
+    // a panic in the worker thread must not take the prompt down with it.
+    autocompleter
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Computes suggestions of an autocompleter in a background thread.
+///
+/// Requests are identified by an increasing id, so that only the response
+/// to the latest request is ever delivered, discarding stale ones.
+pub struct AsyncSuggester {
+    autocompleter: SharedAutocomplete,
+    requests: Sender<(u64, String)>,
+    responses: Receiver<SuggestionsResponse>,
+    last_request: u64,
+    pending: bool,
+}
+
+impl AsyncSuggester {
+    pub fn new(autocompleter: Box<dyn Autocomplete + Send>) -> Self {
+        let autocompleter = Arc::new(Mutex::new(autocompleter));
+        let (requests, worker_requests) = channel::<(u64, String)>();
+        let (worker_responses, responses) = channel();
+
+        let worker_autocompleter = autocompleter.clone();
+        thread::spawn(move || {
+            // the loop ends when the prompt drops its sender.
+            while let Ok(mut request) = worker_requests.recv() {
+                // skip requests made obsolete by newer ones.
+                while let Ok(newer) = worker_requests.try_recv() {
+                    request = newer;
+                }
+
+                let (id, input) = request;
+                let suggestions = lock(&worker_autocompleter).get_suggestions(&input);
+
+                if worker_responses.send((id, suggestions)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            autocompleter,
+            requests,
+            responses,
+            last_request: 0,
+            pending: false,
+        }
+    }
+
+    /// Requests suggestions for the given input, superseding any previous request.
+    pub fn request(&mut self, input: &str) {
+        self.last_request = self.last_request.wrapping_add(1);
+        self.pending = self
+            .requests
+            .send((self.last_request, input.into()))
+            .is_ok();
+    }
+
+    /// Whether the response to the latest request has not arrived yet.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Returns the suggestions for the latest request, if they already arrived.
+    pub fn poll(&mut self) -> Option<Result<Vec<String>, CustomUserError>> {
+        loop {
+            match self.responses.try_recv() {
+                Ok((id, suggestions)) if id == self.last_request => {
+                    self.pending = false;
+                    return Some(suggestions);
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    self.pending = false;
+                    return None;
+                }
+            }
+        }
+    }
+
+    pub fn get_completion_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        lock(&self.autocompleter).get_completion_at(input, cursor, highlighted_suggestion)
+    }
+}
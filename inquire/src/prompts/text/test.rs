@@ -1,6 +1,23 @@
-use super::Text;
-use crate::ui::{Key, KeyModifiers};
-use crate::validator::{ErrorMessage, Validation};
+use super::{prompt::TextPrompt, Text, TextPromptAction};
+use crate::counter::CounterKind;
+use crate::non_interactive::NonInteractiveFallback;
+use crate::prompts::prompt::{ActionResult, Prompt};
+use crate::terminal::{
+    test::{MockTerminal, MockTerminalToken},
+    TerminalSize,
+};
+use crate::test::{FakeBackend, Token};
+use crate::ui::{
+    Backend, Color, ColorDepth, CommonBackend, Key, KeyModifiers, RenderConfig, Styled,
+};
+use crate::validator::{ErrorMessage, PromptContext, StringValidator, Validation};
+use crate::{InputAction, InquireError, TimeoutAction};
+use std::sync::{
+    mpsc::{channel, Receiver, Sender},
+    Arc, Mutex,
+};
+use std::thread;
+use std::time::Duration;
 
 fn default<'a>() -> Text<'a> {
     Text::new("Question?")
@@ -111,3 +128,1180 @@ text_test!(
         _ => Ok(Validation::Invalid(ErrorMessage::Default)),
     })
 );
+
+#[derive(Clone)]
+struct CursorCompleter;
+
+impl crate::Autocomplete for CursorCompleter {
+    fn get_suggestions(&mut self, _: &str) -> Result<Vec<String>, crate::CustomUserError> {
+        Ok(vec![])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        _: Option<String>,
+    ) -> Result<crate::autocompletion::Replacement, crate::CustomUserError> {
+        Ok(None)
+    }
+
+    fn get_completion_at(
+        &mut self,
+        input: &str,
+        cursor: usize,
+        _: Option<String>,
+    ) -> Result<crate::autocompletion::Replacement, crate::CustomUserError> {
+        Ok(Some(format!("{input}@{cursor}")))
+    }
+}
+
+text_test!(
+    completion_receives_cursor_position,
+    {
+        let mut events = text_to_events!("foo bar");
+        events.extend(vec![Key::Left(KeyModifiers::NONE); 4]);
+        events.push(Key::Tab);
+        events.push(Key::Enter);
+        events
+    },
+    "foo bar@3",
+    Text::new("").with_autocomplete(CursorCompleter)
+);
+
+/// Suggests `{input}-suggestion` once the test drops the sender returned
+/// along with it, blocking the suggestions thread until then.
+#[derive(Clone)]
+struct GatedSuggester {
+    gate: Arc<Mutex<Receiver<()>>>,
+}
+
+impl GatedSuggester {
+    fn new() -> (Self, Sender<()>) {
+        let (sender, gate) = channel();
+        let suggester = Self {
+            gate: Arc::new(Mutex::new(gate)),
+        };
+        (suggester, sender)
+    }
+}
+
+impl crate::Autocomplete for GatedSuggester {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+        let _ = self.gate.lock().unwrap().recv();
+        Ok(vec![format!("{input}-suggestion")])
+    }
+
+    fn get_completion(
+        &mut self,
+        _: &str,
+        _: Option<String>,
+    ) -> Result<crate::autocompletion::Replacement, crate::CustomUserError> {
+        Ok(None)
+    }
+}
+
+fn render_frame(prompt: &TextPrompt<'_>, backend: &mut FakeBackend) {
+    backend.frame_setup().unwrap();
+    Prompt::render(prompt, backend).unwrap();
+    backend.frame_finish(false).unwrap();
+}
+
+#[test]
+fn async_suggestions_render_spinner_until_results_arrive() {
+    let (suggester, gate) = GatedSuggester::new();
+    let mut prompt = TextPrompt::from(Text::new("Question?").with_async_suggestions(suggester));
+    let mut backend = FakeBackend::new(vec![]);
+
+    Prompt::<FakeBackend>::setup(&mut prompt).unwrap();
+    for c in "ab".chars() {
+        let action = TextPromptAction::ValueInput(InputAction::Write(c));
+        Prompt::<FakeBackend>::handle(&mut prompt, action).unwrap();
+    }
+
+    let tick = Prompt::<FakeBackend>::tick(&mut prompt).unwrap();
+    assert_eq!(ActionResult::NeedsRedraw, tick);
+    render_frame(&prompt, &mut backend);
+
+    drop(gate);
+    while Prompt::<FakeBackend>::tick_interval(&prompt).is_some() {
+        Prompt::<FakeBackend>::tick(&mut prompt).unwrap();
+        thread::yield_now();
+    }
+    render_frame(&prompt, &mut backend);
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::Spinner));
+    assert!(!frames[1].has_token(&Token::Spinner));
+    assert!(frames[1].has_token(&Token::Option {
+        index: 0,
+        value: "ab-suggestion".into(),
+        highlighted: false,
+        checked: false,
+    }));
+}
+
+#[test]
+fn spinner_ticks_do_not_redraw_without_animations() {
+    let mut events: Vec<Option<Key>> = text_to_events!("a").into_iter().map(Some).collect();
+    events.extend(vec![None; 2]);
+    events.push(Some(Key::Enter));
+
+    let mut backend = FakeBackend::new_with_timeouts(events);
+
+    // the suggestions never arrive while the prompt is running.
+    let (suggester, _gate) = GatedSuggester::new();
+    let ans = Text::new("Question?")
+        .with_render_config(RenderConfig::empty().with_animations(false))
+        .with_async_suggestions(suggester)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("a", ans);
+
+    // initial render, input and answer, without frames for the ticks.
+    let frames = backend.frames();
+    assert_eq!(3, frames.len());
+    assert!(frames[1].has_token(&Token::Spinner));
+}
+
+#[test]
+fn step_indicator_is_rendered_before_the_message() {
+    let prompt = Text::new("Question?")
+        .with_render_config(RenderConfig::empty())
+        .with_step(2, 5);
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("?".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("[2/5]".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("Question?".into())));
+}
+
+#[test]
+fn custom_prompt_prefix_is_rendered() {
+    let prompt =
+        Text::new("Question?").with_prompt_prefix(Styled::new("!").with_fg(Color::DarkRed));
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    backend
+        .terminal_mut()
+        .find_and_expect_token(MockTerminalToken::Text(
+            Styled::new("!".into()).with_fg(Color::DarkRed),
+        ));
+}
+
+#[test]
+fn colors_are_downsampled_to_forced_color_depth() {
+    let prompt = Text::new("Question?").with_render_config(
+        RenderConfig::empty()
+            .with_prompt_prefix(Styled::new("!").with_fg(Color::rgb(250, 10, 10)))
+            .with_color_depth(ColorDepth::Ansi16),
+    );
+
+    let mut backend = Backend::new(
+        vec![Key::Enter].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    backend
+        .terminal_mut()
+        .find_and_expect_token(MockTerminalToken::Text(
+            Styled::new("!".into()).with_fg(Color::LightRed),
+        ));
+}
+
+#[test]
+fn non_interactive_fallback_reads_line_from_input() {
+    let mut input = "Bob\nignored\n".as_bytes();
+
+    let ans = Text::new("Question?")
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input)
+        .unwrap();
+
+    assert_eq!(("Bob".to_owned(), false), ans);
+}
+
+#[test]
+fn non_interactive_fallback_uses_default_on_empty_line() {
+    let mut input = "\r\n".as_bytes();
+
+    let ans = Text::new("Question?")
+        .with_default("Alice")
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input)
+        .unwrap();
+
+    assert_eq!(("Alice".to_owned(), true), ans);
+}
+
+#[test]
+fn non_interactive_fallback_without_default_fails() {
+    let mut input = "".as_bytes();
+
+    let ans = Text::new("Question?")
+        .non_interactive_answer(NonInteractiveFallback::UseDefault, &mut input);
+    assert!(matches!(ans, Err(InquireError::NotTTY)));
+
+    let ans = Text::new("Question?")
+        .with_default("Alice")
+        .non_interactive_answer(NonInteractiveFallback::Error, &mut input);
+    assert!(matches!(ans, Err(InquireError::NotTTY)));
+}
+
+#[test]
+fn non_interactive_fallback_applies_validators() {
+    let mut input = "a\n".as_bytes();
+
+    let ans = Text::new("Question?")
+        .with_validator(|ans: &str| match ans.len() {
+            1 => Ok(Validation::Invalid("Too short".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input);
+
+    match ans {
+        Err(InquireError::Custom(err)) => assert_eq!("Too short", err.to_string()),
+        _ => panic!("Expected a validation error"),
+    }
+}
+
+#[test]
+fn dumb_terminal_asks_again_until_answer_is_valid() {
+    let mut input = "a\nabc\n".as_bytes();
+    let mut output = vec![];
+
+    let ans = Text::new("Question?")
+        .with_validator(|ans: &str| match ans.len() {
+            1 => Ok(Validation::Invalid("Too short".into())),
+            _ => Ok(Validation::Valid),
+        })
+        .dumb_terminal_answer(&mut input, &mut output)
+        .unwrap();
+
+    assert_eq!(("abc".to_owned(), false), ans);
+    assert_eq!(
+        "? Question? # Too short\n? Question? ",
+        String::from_utf8(output).unwrap()
+    );
+}
+
+#[test]
+fn dumb_terminal_displays_and_uses_default() {
+    let mut input = "\n".as_bytes();
+    let mut output = vec![];
+
+    let ans = Text::new("Question?")
+        .with_default("Alice")
+        .dumb_terminal_answer(&mut input, &mut output)
+        .unwrap();
+
+    assert_eq!(("Alice".to_owned(), true), ans);
+    assert_eq!("? Question? (Alice) ", String::from_utf8(output).unwrap());
+}
+
+#[test]
+fn render_callback_receives_message_and_formatted_answer() {
+    let mut events = text_to_events!("bob");
+    events.push(Key::Enter);
+    let mut backend = crate::prompts::test::fake_backend(events);
+
+    let prompt = Text::new("Name?").with_formatter(&|ans| ans.to_uppercase());
+
+    let mut rendered = vec![];
+    let ans = TextPrompt::from(prompt)
+        .prompt_with_render_callback(&mut backend, |message, answer| {
+            rendered.push(format!("{message} {answer}"));
+        })
+        .unwrap();
+
+    assert_eq!("bob", ans);
+    assert_eq!(vec!["Name? BOB".to_owned()], rendered);
+}
+
+text_test!(
+    pasted_line_breaks_do_not_submit,
+    vec![
+        Key::Paste(String::from("line one\nline two\n")),
+        Key::Char('!', KeyModifiers::NONE),
+        Key::Enter,
+    ],
+    "line one line two!"
+);
+
+#[test]
+fn formatter_ex_knows_when_default_was_used() {
+    let formatter = &|ans: &str, used_default: bool| match used_default {
+        true => format!("{ans} (default)"),
+        false => ans.to_owned(),
+    };
+
+    for (events, expected) in [
+        (vec![Key::Enter], "Lisbon (default)"),
+        (text_to_events!("Lisbon\n"), "Lisbon"),
+    ] {
+        let mut backend = crate::prompts::test::fake_backend(events);
+        let prompt = Text::new("City?")
+            .with_default("Lisbon")
+            .with_formatter_ex(formatter);
+
+        let mut rendered = vec![];
+        let ans = TextPrompt::from(prompt)
+            .prompt_with_render_callback(&mut backend, |_, answer| {
+                rendered.push(answer.to_owned());
+            })
+            .unwrap();
+
+        assert_eq!("Lisbon", ans);
+        assert_eq!(vec![expected.to_owned()], rendered);
+    }
+}
+
+#[test]
+fn dynamic_help_message_is_computed_from_current_input() {
+    let mut events = text_to_events!("ab");
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    Text::new("Question?")
+        .with_help_message("static")
+        .with_dynamic_help_message(&|input| match input.len() {
+            0 => None,
+            len => Some(format!("{} characters left", 5 - len)),
+        })
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&Token::HelpMessage("static".into())));
+    assert!(frames[1].has_token(&Token::HelpMessage("4 characters left".into())));
+    assert!(frames[2].has_token(&Token::HelpMessage("3 characters left".into())));
+}
+
+const TIMEOUT: Duration = Duration::from_millis(10);
+
+#[test]
+fn timeout_submits_current_input() {
+    let mut events: Vec<Option<Key>> = text_to_events!("ab").into_iter().map(Some).collect();
+    events.push(None);
+
+    let mut backend = FakeBackend::new_with_timeouts(events);
+
+    let ans = Text::new("Question?")
+        .with_default("default")
+        .with_timeout(TIMEOUT, TimeoutAction::SubmitCurrent)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ab", ans);
+}
+
+#[test]
+fn timeout_submits_default_discarding_input() {
+    let mut events: Vec<Option<Key>> = text_to_events!("ab").into_iter().map(Some).collect();
+    events.push(None);
+
+    let mut backend = FakeBackend::new_with_timeouts(events);
+
+    let ans = Text::new("Question?")
+        .with_default("default")
+        .with_timeout(TIMEOUT, TimeoutAction::SubmitDefault)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("default", ans);
+}
+
+#[test]
+fn timeout_cancels_prompt() {
+    let mut backend = FakeBackend::new_with_timeouts(vec![None]);
+
+    let ans = Text::new("Question?")
+        .with_timeout(TIMEOUT, TimeoutAction::Cancel)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+    assert!(backend
+        .frames()
+        .last()
+        .unwrap()
+        .has_token(&Token::CanceledPrompt("Question?".into())));
+}
+
+#[test]
+fn timeout_resubmits_after_validation_error() {
+    let mut backend =
+        FakeBackend::new_with_timeouts(vec![None, Some(Key::Char('a', KeyModifiers::NONE)), None]);
+
+    let ans = Text::new("Question?")
+        .with_validator(crate::required!())
+        .with_timeout(TIMEOUT, TimeoutAction::SubmitCurrent)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("a", ans);
+    assert!(backend.frames()[1]
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::ErrorMessage(_))));
+}
+
+fn fruit_suggester(input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+    Ok(["banana", "bandana", "cherry"]
+        .iter()
+        .filter(|fruit| fruit.starts_with(input))
+        .map(|fruit| String::from(*fruit))
+        .collect())
+}
+
+fn has_inline_suggestion(frame: &crate::test::Frame) -> bool {
+    frame
+        .tokens()
+        .iter()
+        .any(|t| matches!(t, Token::InlineSuggestion(_)))
+}
+
+#[test]
+fn inline_suggestion_is_rendered_and_accepted_with_right_arrow() {
+    let mut events = text_to_events!("ba");
+    events.push(Key::Right(KeyModifiers::NONE));
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(fruit_suggester)
+        .with_inline_suggestion()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("banana", ans);
+
+    let frames = backend.frames();
+    assert!(frames[2].has_token(&Token::Input("ba".into())));
+    assert!(frames[2].has_token(&Token::InlineSuggestion("nana".into())));
+    assert!(frames[3].has_token(&Token::Input("banana".into())));
+    assert!(!has_inline_suggestion(&frames[3]));
+}
+
+#[test]
+fn inline_suggestion_is_accepted_with_end_key() {
+    let mut events = text_to_events!("c");
+    events.push(Key::End);
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(fruit_suggester)
+        .with_inline_suggestion()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("cherry", ans);
+}
+
+#[test]
+fn inline_suggestion_is_not_part_of_the_input() {
+    let mut events = text_to_events!("ba");
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(fruit_suggester)
+        .with_inline_suggestion()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ba", ans);
+}
+
+#[test]
+fn inline_suggestion_is_hidden_when_cursor_is_not_at_the_end() {
+    let mut events = text_to_events!("ba");
+    events.push(Key::Left(KeyModifiers::NONE));
+    events.push(Key::Right(KeyModifiers::NONE));
+    events.extend(text_to_events!("x"));
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(fruit_suggester)
+        .with_inline_suggestion()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("bax", ans);
+
+    let frames = backend.frames();
+    assert!(!has_inline_suggestion(&frames[3]));
+    assert!(has_inline_suggestion(&frames[4]));
+    assert!(!has_inline_suggestion(&frames[5]));
+}
+
+#[test]
+fn inline_suggestion_is_disabled_by_default() {
+    let mut events = text_to_events!("ba");
+    events.push(Key::Right(KeyModifiers::NONE));
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(fruit_suggester)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("ba", ans);
+    assert!(!backend.frames().iter().any(has_inline_suggestion));
+}
+
+fn expect_cursor_column(input: Vec<Key>, terminal_size: TerminalSize, line: &str, col: u16) {
+    let prompt = Text::new("Question?").with_render_config(RenderConfig::empty());
+
+    let mut backend = Backend::new(
+        input.into_iter().chain(vec![Key::Enter]),
+        MockTerminal::new().with_size(terminal_size),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new(line.into())));
+    terminal.find_and_expect_token(MockTerminalToken::CursorRight(col));
+}
+
+#[test]
+fn cursor_accounts_for_wide_characters() {
+    let mut input = text_to_events!("你好");
+    input.push(Key::Left(KeyModifiers::NONE));
+
+    // "? Question? " takes 12 columns, "你" takes 2 more
+    expect_cursor_column(input, TerminalSize::new(80, 40), "好", 14);
+}
+
+#[test]
+fn cursor_accounts_for_combining_marks() {
+    let mut input = text_to_events!("a\u{0301}b");
+    input.push(Key::Left(KeyModifiers::NONE));
+
+    // "? Question? " takes 12 columns, "a\u{0301}" takes a single one
+    expect_cursor_column(input, TerminalSize::new(80, 40), "b", 13);
+}
+
+#[test]
+fn cursor_accounts_for_wide_characters_wrapped_to_next_line() {
+    // "? Question? 你" takes 14 of 15 columns, so "好" is wrapped to the next line
+    expect_cursor_column(
+        text_to_events!("你好你好"),
+        TerminalSize::new(15, 40),
+        "好你好",
+        6,
+    );
+}
+
+fn long_answer_warning(ans: &str) -> Result<Validation, crate::CustomUserError> {
+    match ans.len() > 3 {
+        true => Ok(Validation::Warning("That's long".into())),
+        false => Ok(Validation::Valid),
+    }
+}
+
+#[test]
+fn warning_is_displayed_and_confirmed_by_submitting_again() {
+    let mut events = text_to_events!("long");
+    events.push(Key::Enter);
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Text::new("Question?")
+        .with_validator(long_answer_warning)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("long", ans);
+
+    let warning = Token::WarningMessage(ErrorMessage::Custom("That's long".into()));
+    let warned_frames = backend
+        .frames()
+        .iter()
+        .filter(|frame| frame.has_token(&warning))
+        .count();
+    assert_eq!(1, warned_frames);
+}
+
+#[test]
+fn changed_answer_is_validated_again_after_warning() {
+    let mut events = text_to_events!("long");
+    events.push(Key::Enter);
+    events.extend(text_to_events!("er"));
+    events.push(Key::Enter);
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Text::new("Question?")
+        .with_validator(long_answer_warning)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("longer", ans);
+    // warned for "long", then for "longer" before the last submission
+    assert_eq!(backend.frames().len(), 1 + 4 + 1 + 2 + 1 + 1);
+}
+
+#[test]
+fn invalid_answer_takes_precedence_over_warning() {
+    let mut events = text_to_events!("long");
+    events.push(Key::Enter);
+    events.push(Key::Enter);
+    events.push(Key::Escape);
+
+    let mut backend = FakeBackend::new(events);
+    let res = Text::new("Question?")
+        .with_validator(long_answer_warning)
+        .with_validator(|_: &str| Ok(Validation::Invalid("Never".into())))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(res, Err(InquireError::OperationCanceled)));
+    assert!(!backend.frames().iter().any(|frame| frame
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::WarningMessage(_)))));
+}
+
+#[test]
+fn warning_is_rendered_with_warning_style() {
+    let prompt = Text::new("Question?")
+        .with_validator(long_answer_warning)
+        .with_render_config(RenderConfig::default_colored());
+
+    let mut events = text_to_events!("long");
+    events.push(Key::Enter);
+    events.push(Key::Enter);
+
+    let mut backend = Backend::new(
+        events.into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    prompt.prompt_with_backend(&mut backend).unwrap();
+
+    backend
+        .terminal_mut()
+        .find_and_expect_token(MockTerminalToken::Text(
+            Styled::new("!".into()).with_fg(Color::LightYellow),
+        ));
+}
+
+#[test]
+fn all_errors_are_displayed_when_enabled() {
+    let mut events = text_to_events!("a");
+    events.push(Key::Enter);
+    events.push(Key::Escape);
+
+    let prompt = || {
+        Text::new("Question?")
+            .with_validator(|_: &str| Ok(Validation::Invalid("Too short".into())))
+            .with_validator(|_: &str| Ok(Validation::Valid))
+            .with_validator(|_: &str| Ok(Validation::Invalid(ErrorMessage::Default)))
+    };
+
+    let mut backend = FakeBackend::new(events.clone());
+    let _ = prompt().prompt_with_backend(&mut backend);
+    assert!(backend.frames()[2].has_token(&Token::ErrorMessage("Too short".into())));
+
+    let mut backend = FakeBackend::new(events);
+    let _ = prompt()
+        .with_all_errors_shown()
+        .prompt_with_backend(&mut backend);
+    assert!(
+        backend.frames()[2].has_token(&Token::ErrorMessage(ErrorMessage::Multiple(vec![
+            "Too short".into(),
+            ErrorMessage::Default,
+        ])))
+    );
+}
+
+#[test]
+fn each_error_is_rendered_on_its_own_line() {
+    let prompt = Text::new("Question?")
+        .with_validator(|_: &str| Ok(Validation::Invalid("Too short".into())))
+        .with_validator(|_: &str| Ok(Validation::Invalid("No digits".into())))
+        .with_all_errors_shown()
+        .with_render_config(RenderConfig::empty());
+
+    let mut backend = Backend::new(
+        vec![Key::Enter, Key::Escape].into_iter(),
+        MockTerminal::new(),
+        prompt.render_config,
+    )
+    .unwrap();
+
+    let _ = prompt.prompt_with_backend(&mut backend);
+
+    let terminal = backend.terminal_mut();
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("#".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("Too short".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("#".into())));
+    terminal.find_and_expect_token(MockTerminalToken::Text(Styled::new("No digits".into())));
+}
+
+#[test]
+fn non_interactive_fallback_reports_all_errors_when_enabled() {
+    let mut input = "a\n".as_bytes();
+    let res = Text::new("Question?")
+        .with_validator(|_: &str| Ok(Validation::Invalid("Too short".into())))
+        .with_validator(|_: &str| Ok(Validation::Invalid("No digits".into())))
+        .with_all_errors_shown()
+        .non_interactive_answer(NonInteractiveFallback::ReadLine, &mut input);
+
+    match res {
+        Err(InquireError::Custom(err)) => assert_eq!("Too short\nNo digits", err.to_string()),
+        _ => panic!("expected custom error, got {:?}", res),
+    }
+}
+
+#[test]
+fn event_observer_receives_keys_and_input_actions() {
+    let mut keys = text_to_events!("☃");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+    let (ans, events) = crate::config::test::observe_events(|| {
+        Text::new("Question?").prompt_with_backend(&mut backend)
+    });
+
+    assert_eq!("☃", ans.unwrap());
+    assert_eq!(
+        vec![
+            (
+                Key::Char('☃', KeyModifiers::NONE),
+                Some(InputAction::Write('☃'))
+            ),
+            (Key::Enter, None),
+        ],
+        events
+    );
+}
+
+#[test]
+fn transformer_runs_before_validation() {
+    let mut events = text_to_events!("  Alice ");
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Text::new("Username?")
+        .with_transformer(&|input| input.trim().to_lowercase())
+        .with_validator(|input: &str| match input == "alice" {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("Not normalized".into())),
+        })
+        .with_formatter(&|answer| format!("@{answer}"))
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("alice", ans);
+    let frames = backend.frames();
+    assert!(frames
+        .last()
+        .unwrap()
+        .has_token(&Token::AnsweredPrompt("Username?".into(), "@alice".into())));
+}
+
+#[test]
+fn context_validator_reads_the_context_when_validating() {
+    let context = PromptContext::new(String::new());
+
+    let mut events = text_to_events!("a@b.c");
+    events.push(Key::Enter);
+    events.push(Key::Char('o', KeyModifiers::NONE));
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let prompt = Text::new("Confirm email:").with_context_validator(
+        &context,
+        |input: &str, email: &String| match input == email {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid("The emails do not match".into())),
+        },
+    );
+
+    // set after the prompt is created, as it happens in a wizard.
+    context.set("a@b.co".into());
+
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("a@b.co", ans);
+    assert!(backend.frames().iter().any(|frame| {
+        frame.has_token(&Token::ErrorMessage(ErrorMessage::Custom(
+            "The emails do not match".into(),
+        )))
+    }));
+}
+
+#[test]
+fn submit_key_replaces_enter() {
+    let save = Key::Char('s', KeyModifiers::CONTROL);
+
+    let mut events = text_to_events!("ab");
+    events.push(Key::Enter);
+    events.push(Key::Char('c', KeyModifiers::NONE));
+    events.push(save.clone());
+
+    let mut backend = FakeBackend::new(events);
+    let ans = Text::new("Question?")
+        .with_submit_key(save)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("abc", ans);
+}
+
+#[test]
+fn submit_key_does_not_override_cancel_and_interrupt() {
+    let mut backend = FakeBackend::new(vec![Key::Escape]);
+    let ans = Text::new("Question?")
+        .with_submit_key(Key::Escape)
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationCanceled)));
+
+    let mut backend = FakeBackend::new(vec![Key::Char('c', KeyModifiers::CONTROL)]);
+    let ans = Text::new("Question?")
+        .with_submit_key(Key::Char('s', KeyModifiers::CONTROL))
+        .prompt_with_backend(&mut backend);
+
+    assert!(matches!(ans, Err(InquireError::OperationInterrupted)));
+}
+
+#[test]
+fn default_as_initial_prefills_the_input() {
+    let mut backend = FakeBackend::new(vec![Key::Char('!', KeyModifiers::NONE), Key::Enter]);
+
+    let ans = Text::new("Question?")
+        .with_default("Hello")
+        .with_default_as_initial()
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("Hello!", ans);
+    assert!(backend.frames()[0].has_token(&Token::Input("Hello".into())));
+}
+
+text_test!(
+    input_mask_inserts_literals,
+    text_to_events!("5551234567\n"),
+    "(555) 123-4567",
+    Text::new("Phone?").with_input_mask("(###) ###-####")
+);
+
+text_test!(
+    input_mask_ignores_rejected_characters,
+    text_to_events!("55a5-12b3\n"),
+    "(555) 123",
+    Text::new("Phone?").with_input_mask("(###) ###-####")
+);
+
+text_test!(
+    input_mask_unmasked_answer,
+    text_to_events!("5551234567\n"),
+    "5551234567",
+    Text::new("Phone?")
+        .with_input_mask("(###) ###-####")
+        .with_unmasked_answer()
+);
+
+text_test!(
+    input_mask_applies_to_initial_value,
+    vec![Key::Char('8', KeyModifiers::NONE), Key::Enter],
+    "(555) 8",
+    Text::new("Phone?")
+        .with_input_mask("(###) ###-####")
+        .with_initial_value("555")
+);
+
+#[test]
+fn input_mask_backspace_removes_literals() {
+    let mut events = text_to_events!("5551");
+    events.push(Key::Backspace);
+    events.push(Key::Char('9', KeyModifiers::NONE));
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Phone?")
+        .with_input_mask("(###) ###-####")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("(555) 9", ans);
+    assert!(backend.frames()[4].has_token(&Token::Input("(555) 1".into())));
+    assert!(backend.frames()[5].has_token(&Token::Input("(555".into())));
+}
+
+#[test]
+fn input_mask_backspace_clears_leading_literal() {
+    let events = vec![
+        Key::Char('1', KeyModifiers::NONE),
+        Key::Backspace,
+        Key::Char('2', KeyModifiers::NONE),
+        Key::Enter,
+    ];
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Phone?")
+        .with_input_mask("(###) ###-####")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("(2", ans);
+    assert!(backend.frames()[2].has_token(&Token::Input("".into())));
+}
+
+#[test]
+fn input_mask_ignores_cursor_movement() {
+    let mut events = text_to_events!("12");
+    events.push(Key::Left(KeyModifiers::NONE));
+    events.push(Key::Char('3', KeyModifiers::NONE));
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Code?")
+        .with_input_mask("##-##")
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("12-3", ans);
+}
+
+fn history() -> Vec<String> {
+    vec!["first".to_owned(), "second".to_owned(), "third".to_owned()]
+}
+
+#[test]
+fn up_recalls_history_entries_from_newest() {
+    let events = vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Enter,
+    ];
+
+    let mut backend = FakeBackend::new(events);
+    let ans = default()
+        .with_history(history())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("second", ans);
+}
+
+#[test]
+fn up_stops_at_oldest_history_entry() {
+    let mut events = vec![Key::Up(KeyModifiers::NONE); 5];
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = default()
+        .with_history(history())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("first", ans);
+}
+
+#[test]
+fn down_past_newest_history_entry_restores_input() {
+    let mut events = text_to_events!("draft");
+    events.extend(vec![
+        Key::Up(KeyModifiers::NONE),
+        Key::Up(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Down(KeyModifiers::NONE),
+        Key::Enter,
+    ]);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = default()
+        .with_history(history())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("draft", ans);
+}
+
+#[test]
+fn edited_history_entry_is_submitted() {
+    let mut events = vec![Key::Up(KeyModifiers::NONE)];
+    events.extend(text_to_events!("!"));
+    events.push(Key::Down(KeyModifiers::NONE));
+    events.push(Key::Enter);
+
+    let mut backend = FakeBackend::new(events);
+    let ans = default()
+        .with_history(history())
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("third!", ans);
+}
+
+text_test!(
+    ctrl_w_deletes_previous_word,
+    {
+        let mut events = text_to_events!("hello world");
+        events.push(Key::Char('w', KeyModifiers::CONTROL));
+        events.append(&mut text_to_events!("there\n"));
+        events
+    },
+    "hello there"
+);
+
+fn failing_suggester(input: &str) -> Result<Vec<String>, crate::CustomUserError> {
+    match input.contains('!') {
+        true => Err("Suggestions are unavailable".into()),
+        false => Ok(vec![format!("{}?", input)]),
+    }
+}
+
+#[test]
+fn suggestion_errors_are_rendered_and_prompt_keeps_running() {
+    let mut events = text_to_events!("a!");
+    events.push(Key::Backspace);
+    events.push(Key::Enter);
+    let mut backend = FakeBackend::new(events);
+
+    let ans = Text::new("Question?")
+        .with_autocomplete(failing_suggester)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("a", ans);
+
+    let error = Token::SuggestionError(ErrorMessage::Custom("Suggestions are unavailable".into()));
+    let frames = backend.frames();
+    assert!(!frames[1].has_token(&error));
+    assert!(frames[2].has_token(&error));
+    assert!(!frames[2]
+        .tokens()
+        .iter()
+        .any(|token| matches!(token, Token::Option { .. })));
+    assert!(!frames[3].has_token(&error));
+}
+
+#[derive(Clone)]
+struct MinLength(usize);
+
+impl StringValidator for MinLength {
+    fn validate(&self, input: &str) -> Result<Validation, crate::CustomUserError> {
+        match input.chars().count() >= self.0 {
+            true => Ok(Validation::Valid),
+            false => Ok(Validation::Invalid(ErrorMessage::Default)),
+        }
+    }
+}
+
+#[test]
+fn cloned_prompt_keeps_boxed_struct_validator() {
+    let prompt = Text::new("Name:").with_validator(MinLength(3));
+    let copy = prompt.clone();
+
+    for prompt in [prompt, copy] {
+        let mut keys = Key::char_keys_from_str("ab");
+        keys.push(Key::Enter);
+        keys.push(Key::Char('c', KeyModifiers::NONE));
+        keys.push(Key::Enter);
+        let mut backend = FakeBackend::new(keys);
+
+        assert_eq!("abc", prompt.prompt_with_backend(&mut backend).unwrap());
+        assert!(backend.frames()[3].has_token(&Token::ErrorMessage(ErrorMessage::Default)));
+    }
+}
+
+#[test]
+fn counters_are_updated_on_every_keystroke_with_multibyte_input() {
+    let mut keys = Key::char_keys_from_str("añ 👍🏽");
+    keys.push(Key::Enter);
+    let mut backend = FakeBackend::new(keys);
+
+    let ans = Text::new("Bio:")
+        .with_counter(CounterKind::Chars)
+        .with_counter(CounterKind::Words)
+        .with_counter(CounterKind::Bytes)
+        .with_counter(CounterKind::GraphemeClusters)
+        .prompt_with_backend(&mut backend)
+        .unwrap();
+
+    assert_eq!("añ 👍🏽", ans);
+
+    let counts = |text: &str| Token::HelpMessage(text.into());
+    let frames = backend.frames();
+    assert!(frames[0].has_token(&counts("0 chars, 0 words, 0 bytes, 0 graphemes")));
+    assert!(frames[1].has_token(&counts("1 char, 1 word, 1 byte, 1 grapheme")));
+    assert!(frames[2].has_token(&counts("2 chars, 1 word, 3 bytes, 2 graphemes")));
+    assert!(frames[4].has_token(&counts("4 chars, 1 word, 8 bytes, 4 graphemes")));
+    assert!(frames[5].has_token(&counts("5 chars, 1 word, 12 bytes, 4 graphemes")));
+}
+
+#[test]
+#[cfg(not(feature = "clipboard"))]
+fn clipboard_paste_reports_error_without_clipboard_feature() {
+    let mut events = text_to_events!("ab");
+    events.push(Key::Char('v', KeyModifiers::CONTROL));
+    events.push(Key::Enter);
+
+    let mut prompt = Text::new("Question?");
+    prompt.clipboard_paste = true;
+
+    let mut backend = FakeBackend::new(events);
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("ab", ans);
+    assert!(backend.frames()[3].has_token(&Token::ErrorMessage(
+        "Could not paste from the clipboard: the `clipboard` feature is disabled".into()
+    )));
+}
+
+#[test]
+fn clipboard_paste_inserts_content_without_line_breaks_in_one_change() {
+    use std::sync::{Arc, Mutex};
+
+    let mut events = text_to_events!("ab");
+    events.push(Key::Char('v', KeyModifiers::CONTROL));
+    events.push(Key::Enter);
+
+    let suggested = Arc::new(Mutex::new(vec![]));
+    let recorder = Arc::clone(&suggested);
+    let mut prompt = Text::new("Question?").with_autocomplete(
+        move |input: &str| -> Result<Vec<String>, crate::CustomUserError> {
+            recorder.lock().unwrap().push(input.to_owned());
+            Ok(vec![])
+        },
+    );
+    prompt.clipboard_paste = true;
+
+    crate::clipboard::test::stub_next_read("one\r\ntwo\nthree\n");
+    let mut backend = FakeBackend::new(events);
+    let ans = prompt.prompt_with_backend(&mut backend).unwrap();
+
+    assert_eq!("abone two three", ans);
+    let suggested = suggested.lock().unwrap();
+    let pasted = suggested.iter().position(|input| input == "ab").unwrap() + 1;
+    assert_eq!(["abone two three"], suggested[pasted..]);
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use crate::{
+    error::{InquireError, InquireResult},
+    terminal::default_terminal_supports_timeouts,
+};
+
+/// Inactivity timeout of a prompt, resolving it automatically when the user
+/// does not press any keys for the configured duration.
+///
+/// The timer is reset on every key press.
+///
+/// Waiting for keys with a timeout requires the `crossterm` backend: when the
+/// default terminal is provided by another backend, prompts with a timeout fail
+/// with [`InquireError::InvalidConfiguration`]. Custom input readers passed to
+/// `prompt_with_backend` must implement
+/// [`InputReader::read_key_timeout`](crate::ui::InputReader::read_key_timeout),
+/// otherwise they block until a key is pressed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PromptTimeout {
+    /// Duration of inactivity after which the prompt is resolved.
+    pub duration: Duration,
+
+    /// How the prompt is resolved once the timeout elapses.
+    pub action: TimeoutAction,
+}
+
+impl PromptTimeout {
+    /// Creates a timeout resolving the prompt with `action` after `duration` of inactivity.
+    pub fn new(duration: Duration, action: TimeoutAction) -> Self {
+        Self { duration, action }
+    }
+}
+
+/// Fails with [`InquireError::InvalidConfiguration`] when a timeout is set but
+/// the default terminal is not able to wait for keys with a timeout.
+pub(crate) fn ensure_timeout_supported(timeout: Option<&PromptTimeout>) -> InquireResult<()> {
+    if timeout.is_some() && !default_terminal_supports_timeouts() {
+        return Err(InquireError::InvalidConfiguration(
+            "Prompt timeouts require the crossterm backend".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// How a prompt is resolved once its [`PromptTimeout`] elapses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TimeoutAction {
+    /// Discards the current input and submits the default value of the prompt,
+    /// as if the user had submitted an empty input.
+    SubmitDefault,
+
+    /// Submits the current input, as if the user had pressed the submit key.
+    SubmitCurrent,
+
+    /// Cancels the prompt, which then returns
+    /// [`InquireError::OperationCanceled`](crate::InquireError::OperationCanceled).
+    Cancel,
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{ensure_timeout_supported, PromptTimeout, TimeoutAction};
+
+    #[test]
+    fn timeouts_are_supported_by_the_crossterm_backend_only() {
+        let timeout = PromptTimeout::new(Duration::from_secs(1), TimeoutAction::Cancel);
+
+        assert!(ensure_timeout_supported(None).is_ok());
+        assert_eq!(
+            cfg!(feature = "crossterm"),
+            ensure_timeout_supported(Some(&timeout)).is_ok()
+        );
+    }
+}
@@ -12,6 +12,8 @@ use super::Terminal;
 #[derive(Clone)]
 pub struct ConsoleTerminal {
     term: Term,
+    /// Key read while finishing an escape sequence, returned by the next read.
+    pending_key: Option<Key>,
 }
 
 impl ConsoleTerminal {
@@ -19,14 +21,39 @@ impl ConsoleTerminal {
     pub fn new() -> Self {
         Self {
             term: Term::stderr(),
+            pending_key: None,
         }
     }
+
+    /// Reads the rest of a CSI sequence of a key pressed with modifiers, e.g.
+    /// `5A` of `\x1b[1;5A` for ctrl+up, whose start is `\x1b[1;`.
+    fn read_modified_key(&mut self, param: char) -> Result<crate::ui::Key> {
+        let term = &self.term;
+        let (key, interrupting_key) = finish_modified_key(param, || term.read_key())?;
+        self.pending_key = interrupting_key;
+
+        Ok(key)
+    }
 }
 
 impl InputReader for ConsoleTerminal {
     fn read_key(&mut self) -> InquireResult<crate::ui::Key> {
-        let key = self.term.read_key()?;
-        Ok(key.into())
+        let key = match self.pending_key.take() {
+            Some(key) => key,
+            None => self.term.read_key()?,
+        };
+
+        let key = match key {
+            // console only reads the first three characters of escape sequences,
+            // so the ones of keys pressed with modifiers are finished here
+            // instead of having their remaining characters read as other keys.
+            Key::UnknownEscSeq(seq) if seq.len() == 3 && seq[0] == '[' && seq[2] == ';' => {
+                self.read_modified_key(seq[1])?
+            }
+            key => key.into(),
+        };
+
+        Ok(key)
     }
 }
 
@@ -171,9 +198,173 @@ impl From<Key> for crate::ui::Key {
             Key::ArrowDown => Self::Down(KeyModifiers::empty()),
             Key::ArrowLeft => Self::Left(KeyModifiers::empty()),
             Key::ArrowRight => Self::Right(KeyModifiers::empty()),
+            Key::CtrlC => Self::Char('c', KeyModifiers::CONTROL),
+            Key::Char('\0') => Self::Char(' ', KeyModifiers::CONTROL),
+            Key::Char(c @ '\x01'..='\x1a') => {
+                Self::Char((c as u8 - 1 + b'a') as char, KeyModifiers::CONTROL)
+            }
             Key::Char(c) => Self::Char(c, KeyModifiers::empty()),
+            // escape followed by a character, as sent by terminals for alt+char.
+            Key::UnknownEscSeq(seq) if seq.len() == 1 => Self::Char(seq[0], KeyModifiers::ALT),
             #[allow(deprecated)]
             _ => Self::Any,
         }
     }
 }
+
+/// Finishes a CSI sequence of a key pressed with modifiers with the keys
+/// returned by `read_key`, until its final character.
+///
+/// A key other than a character interrupts the sequence: it is a separate key
+/// press, returned along with [`Key::Any`](crate::ui::Key::Any) for the
+/// unfinished sequence.
+fn finish_modified_key<F>(param: char, mut read_key: F) -> Result<(crate::ui::Key, Option<Key>)>
+where
+    F: FnMut() -> Result<Key>,
+{
+    let mut modifier = String::new();
+
+    loop {
+        match read_key()? {
+            Key::Char(c) if c.is_ascii_digit() => modifier.push(c),
+            Key::Char(c) => return Ok((modified_key(param, &modifier, c), None)),
+            #[allow(deprecated)]
+            key => return Ok((crate::ui::Key::Any, Some(key))),
+        }
+    }
+}
+
+/// Key of a CSI sequence with modifiers, `\x1b[{param};{modifier}{final_char}`,
+/// e.g. `\x1b[1;5A` for ctrl+up or `\x1b[6;2~` for shift+page down.
+fn modified_key(param: char, modifier: &str, final_char: char) -> crate::ui::Key {
+    use crate::ui::{Key, KeyModifiers};
+
+    // the modifier parameter is 1 plus the bitmask of the pressed modifiers.
+    let mask = modifier
+        .parse::<u8>()
+        .ok()
+        .and_then(|m| m.checked_sub(1))
+        .unwrap_or(0);
+
+    let mut modifiers = KeyModifiers::empty();
+    if mask & 1 != 0 {
+        modifiers |= KeyModifiers::SHIFT;
+    }
+    if mask & 2 != 0 {
+        modifiers |= KeyModifiers::ALT;
+    }
+    if mask & 4 != 0 {
+        modifiers |= KeyModifiers::CONTROL;
+    }
+    if mask & 8 != 0 {
+        modifiers |= KeyModifiers::META;
+    }
+
+    match (param, final_char) {
+        ('1', 'A') => Key::Up(modifiers),
+        ('1', 'B') => Key::Down(modifiers),
+        ('1', 'C') => Key::Right(modifiers),
+        ('1', 'D') => Key::Left(modifiers),
+        ('1', 'H') => Key::Home,
+        ('1', 'F') => Key::End,
+        ('3', '~') => Key::Delete(modifiers),
+        ('5', '~') => Key::PageUp(modifiers),
+        ('6', '~') => Key::PageDown(modifiers),
+        #[allow(deprecated)]
+        _ => Key::Any,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::ui::{Key, KeyModifiers};
+
+    use super::{finish_modified_key, modified_key};
+
+    #[test]
+    fn control_characters_are_mapped_to_control_keys() {
+        let key = |c| Key::from(console::Key::Char(c));
+
+        assert_eq!(Key::Char('n', KeyModifiers::CONTROL), key('\x0e'));
+        assert_eq!(Key::Char('r', KeyModifiers::CONTROL), key('\x12'));
+        assert_eq!(Key::Char(' ', KeyModifiers::CONTROL), key('\0'));
+        assert_eq!(Key::Char('N', KeyModifiers::NONE), key('N'));
+        assert_eq!(Key::Enter, key('\r'));
+        assert_eq!(Key::Tab, key('\t'));
+        assert_eq!(
+            Key::Char('c', KeyModifiers::CONTROL),
+            Key::from(console::Key::CtrlC)
+        );
+    }
+
+    #[test]
+    fn escape_followed_by_a_character_is_an_alt_key() {
+        let key = Key::from(console::Key::UnknownEscSeq(vec!['b']));
+
+        assert_eq!(Key::Char('b', KeyModifiers::ALT), key);
+    }
+
+    #[test]
+    fn modifiers_of_csi_sequences_are_decoded() {
+        assert_eq!(Key::Up(KeyModifiers::CONTROL), modified_key('1', "5", 'A'));
+        assert_eq!(
+            Key::Down(KeyModifiers::CONTROL),
+            modified_key('1', "5", 'B')
+        );
+        assert_eq!(Key::Left(KeyModifiers::SHIFT), modified_key('1', "2", 'D'));
+        assert_eq!(Key::Right(KeyModifiers::ALT), modified_key('1', "3", 'C'));
+        assert_eq!(
+            Key::Up(KeyModifiers::SHIFT | KeyModifiers::CONTROL),
+            modified_key('1', "6", 'A')
+        );
+        assert_eq!(Key::Home, modified_key('1', "5", 'H'));
+        assert_eq!(Key::End, modified_key('1', "2", 'F'));
+        assert_eq!(
+            Key::Delete(KeyModifiers::CONTROL),
+            modified_key('3', "5", '~')
+        );
+        assert_eq!(
+            Key::PageUp(KeyModifiers::SHIFT),
+            modified_key('5', "2", '~')
+        );
+        assert_eq!(
+            Key::PageDown(KeyModifiers::ALT),
+            modified_key('6', "3", '~')
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn unknown_csi_sequences_are_mapped_to_any() {
+        assert_eq!(Key::Any, modified_key('2', "5", '~'));
+        assert_eq!(Key::Any, modified_key('1', "5", 'P'));
+    }
+
+    #[test]
+    fn csi_sequences_are_finished_until_their_final_character() {
+        let finish = |param, keys: Vec<console::Key>| {
+            let mut keys = keys.into_iter();
+            finish_modified_key(param, || Ok(keys.next().unwrap())).unwrap()
+        };
+        let chars = |s: &str| s.chars().map(console::Key::Char).collect::<Vec<_>>();
+
+        assert_eq!(
+            (Key::Up(KeyModifiers::CONTROL), None),
+            finish('1', chars("5A"))
+        );
+        assert_eq!(
+            (Key::Delete(KeyModifiers::SHIFT | KeyModifiers::META), None),
+            finish('3', chars("10~"))
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn keys_interrupting_csi_sequences_are_kept() {
+        let mut keys = vec![console::Key::Char('5'), console::Key::Enter].into_iter();
+        let finished = finish_modified_key('1', || Ok(keys.next().unwrap())).unwrap();
+
+        assert_eq!((Key::Any, Some(console::Key::Enter)), finished);
+        assert_eq!(None, keys.next());
+    }
+}
@@ -1,29 +1,42 @@
-use std::io::{stderr, Result, Stderr, Write};
+use std::{
+    fs::File,
+    io::{stderr, Result, Stderr, Write},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
 
 use crossterm::{
     cursor,
-    event::{self, KeyCode, KeyEvent, KeyModifiers},
+    event::{self, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     queue,
     style::{Attribute, Color, Print, SetAttribute, SetBackgroundColor, SetForegroundColor},
     terminal::{self, ClearType},
     Command,
 };
 
+use once_cell::sync::Lazy;
+
 use crate::{
-    error::InquireResult,
-    ui::{Attributes, InputReader, Key, Styled},
+    config::get_output,
+    error::{InquireError, InquireResult},
+    ui::{Attributes, CursorShape, InputReader, Key, Styled},
 };
 
 use super::Terminal;
 
 enum IO {
     Std(Stderr),
+    File(File),
     #[allow(unused)]
     Test(Vec<u8>),
 }
 
 pub struct CrosstermTerminal {
     io: IO,
+    mouse_capture: bool,
 }
 
 pub struct CrosstermKeyReader;
@@ -37,25 +50,87 @@ impl CrosstermKeyReader {
 impl InputReader for CrosstermKeyReader {
     fn read_key(&mut self) -> InquireResult<Key> {
         loop {
-            if let event::Event::Key(key_event) = event::read()? {
-                return Ok(key_event.into());
+            if let Some(key) = key_from_event(event::read()?) {
+                return Ok(key);
             }
         }
     }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if !event::poll(remaining)? {
+                return Ok(None);
+            }
+
+            if let Some(key) = key_from_event(event::read()?) {
+                return Ok(Some(key));
+            }
+        }
+    }
+}
+
+/// Converts a terminal event into a key, if it is one prompts react to.
+///
+/// Clicks are reported with the absolute position on the screen, the backend
+/// then makes it relative to the prompt.
+fn key_from_event(event: event::Event) -> Option<Key> {
+    match event {
+        event::Event::Key(key_event) => Some(key_event.into()),
+        event::Event::Paste(text) => Some(Key::Paste(text)),
+        event::Event::Mouse(MouseEvent {
+            kind, row, column, ..
+        }) => match kind {
+            MouseEventKind::ScrollUp => Some(Key::ScrollUp),
+            MouseEventKind::ScrollDown => Some(Key::ScrollDown),
+            MouseEventKind::Down(MouseButton::Left) => Some(Key::Click { row, column }),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 impl CrosstermTerminal {
     pub fn new() -> InquireResult<Self> {
+        Self::new_with_io(IO::Std(stderr()))
+    }
+
+    /// Creates a terminal rendering to the given file, e.g. `/dev/tty`, instead of stderr.
+    ///
+    /// Keys are still read by crossterm from stdin, or from `/dev/tty` on Unix
+    /// when stdin is not a terminal.
+    pub fn new_with_file(file: File) -> InquireResult<Self> {
+        Self::new_with_io(IO::File(file))
+    }
+
+    fn new_with_io(mut io: IO) -> InquireResult<Self> {
         terminal::enable_raw_mode()?;
 
+        // without bracketed paste, line breaks in pasted text are read as
+        // enter key presses, submitting the prompt prematurely.
+        let _unused = match &mut io {
+            IO::Std(w) => queue!(w, event::EnableBracketedPaste),
+            IO::File(w) => queue!(w, event::EnableBracketedPaste),
+            IO::Test(_) => Ok(()),
+        };
+
+        // in-memory terminals are not restored when dropped, so they are not counted.
+        if !matches!(io, IO::Test(_)) {
+            LIVE_TERMINALS.fetch_add(1, Ordering::SeqCst);
+        }
+
         Ok(Self {
-            io: IO::Std(stderr()),
+            io,
+            mouse_capture: false,
         })
     }
 
     fn get_writer(&mut self) -> &mut dyn Write {
         match &mut self.io {
             IO::Std(w) => w,
+            IO::File(w) => w,
             IO::Test(w) => w,
         }
     }
@@ -182,15 +257,135 @@ impl Terminal for CrosstermTerminal {
     fn cursor_show(&mut self) -> Result<()> {
         self.write_command(cursor::Show)
     }
+
+    fn cursor_set_shape(&mut self, shape: CursorShape) -> Result<()> {
+        self.write_command(cursor::SetCursorShape(shape.into()))
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.mouse_capture = true;
+        self.write_command(event::EnableMouseCapture)
+    }
+
+    fn cursor_row(&mut self) -> Result<Option<u16>> {
+        if let IO::Test(_) = self.io {
+            return Ok(None);
+        }
+
+        self.flush()?;
+
+        // the query times out on terminals not answering it, which is not
+        // worth failing the prompt for.
+        Ok(cursor::position().ok().map(|(_, row)| row))
+    }
+
+    fn cursor_reset_shape(&mut self) -> Result<()> {
+        // crossterm has no command for the terminal's default shape,
+        // which is selected by the DECSCUSR sequence with parameter 0.
+        self.write_command(Print("\x1b[0 q"))
+    }
+}
+
+/// Number of [`CrosstermTerminal`]s alive, i.e. of prompts being displayed.
+static LIVE_TERMINALS: AtomicUsize = AtomicUsize::new(0);
+
+/// Leaves the alternate screen, if active, and leaves raw mode and shows the
+/// cursor when a prompt is being displayed.
+pub(crate) fn restore_after_panic() {
+    let mut output = prompt_output();
+
+    // the lock might be held by the panicking thread.
+    if let Ok(mut answers) = ALTERNATE_SCREEN_ANSWERS.try_lock() {
+        if answers.take().is_some() {
+            let _unused = queue!(output, terminal::LeaveAlternateScreen);
+        }
+    }
+
+    if LIVE_TERMINALS.load(Ordering::SeqCst) == 0 {
+        let _unused = output.flush();
+        return;
+    }
+
+    let _unused = queue!(
+        output,
+        event::DisableBracketedPaste,
+        event::DisableMouseCapture,
+        cursor::Show
+    )
+    .and_then(|_| output.flush());
+    let _unused = terminal::disable_raw_mode();
+}
+
+/// Answers of the prompts finished while the alternate screen is active, `None`
+/// when it is not.
+static ALTERNATE_SCREEN_ANSWERS: Lazy<Mutex<Option<Vec<String>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Writer prompts render to, i.e. the [global output](crate::set_global_output)
+/// when set or stderr otherwise, used to restore the terminal on the same output.
+pub(crate) fn prompt_output() -> Box<dyn Write> {
+    match get_output() {
+        Ok(Some(file)) => Box::new(file),
+        _ => Box::new(stderr()),
+    }
+}
+
+pub(crate) fn enter_alternate_screen(mut writer: &mut dyn Write) -> InquireResult<()> {
+    let mut answers = ALTERNATE_SCREEN_ANSWERS.lock().unwrap();
+    if answers.is_some() {
+        return Err(InquireError::InvalidConfiguration(
+            "The alternate screen is already active".into(),
+        ));
+    }
+
+    queue!(&mut writer, terminal::EnterAlternateScreen)?;
+    writer.flush()?;
+    *answers = Some(vec![]);
+
+    Ok(())
+}
+
+pub(crate) fn leave_alternate_screen(mut writer: &mut dyn Write, echo_answers: bool) -> Result<()> {
+    let answers = ALTERNATE_SCREEN_ANSWERS
+        .lock()
+        .unwrap()
+        .take()
+        .unwrap_or_default();
+
+    queue!(&mut writer, terminal::LeaveAlternateScreen)?;
+    if echo_answers {
+        for answer in answers {
+            writeln!(writer, "{answer}")?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Records the answer of a finished prompt, to be echoed to the primary screen
+/// when leaving the alternate one.
+pub(crate) fn record_answer(message: &str, answer: &str) {
+    if let Some(answers) = ALTERNATE_SCREEN_ANSWERS.lock().unwrap().as_mut() {
+        answers.push(format!("{message} {answer}"));
+    }
 }
 
 impl Drop for CrosstermTerminal {
     fn drop(&mut self) {
         let _unused = self.flush();
-        let _unused = match self.io {
-            IO::Std(_) => terminal::disable_raw_mode(),
-            IO::Test(_) => Ok(()),
+        if self.mouse_capture {
+            let _unused = self.write_command(event::DisableMouseCapture);
+        }
+        let _unused = match &mut self.io {
+            IO::Std(io) => queue!(io, event::DisableBracketedPaste).and_then(|_| io.flush()),
+            IO::File(io) => queue!(io, event::DisableBracketedPaste).and_then(|_| io.flush()),
+            IO::Test(_) => return,
         };
+
+        LIVE_TERMINALS.fetch_sub(1, Ordering::SeqCst);
+
+        // raw mode is disabled even if the output can no longer be written to,
+        // e.g. when dropped while unwinding from a panic.
+        let _unused = terminal::disable_raw_mode();
     }
 }
 
@@ -220,6 +415,16 @@ impl From<crate::ui::Color> for Color {
     }
 }
 
+impl From<CursorShape> for cursor::CursorShape {
+    fn from(shape: CursorShape) -> Self {
+        match shape {
+            CursorShape::Block => cursor::CursorShape::Block,
+            CursorShape::Underline => cursor::CursorShape::UnderScore,
+            CursorShape::Bar => cursor::CursorShape::Line,
+        }
+    }
+}
+
 impl From<KeyModifiers> for crate::ui::KeyModifiers {
     fn from(m: KeyModifiers) -> Self {
         let mut modifiers = Self::empty();
@@ -334,12 +539,14 @@ mod test {
         pub fn new_in_memory_output() -> Self {
             Self {
                 io: IO::Test(Vec::new()),
+                mouse_capture: false,
             }
         }
 
         pub fn get_buffer_content(&mut self) -> Vec<u8> {
             match &mut self.io {
                 IO::Std(_) => panic!("Cannot get write buffer from standard output"),
+                IO::File(_) => panic!("Cannot get write buffer from a file"),
                 IO::Test(w) => {
                     let mut buffer = Vec::new();
                     std::mem::swap(&mut buffer, w);
@@ -349,6 +556,67 @@ mod test {
         }
     }
 
+    #[test]
+    fn alternate_screen_echoes_answers_when_left() {
+        let mut output = Vec::new();
+
+        super::enter_alternate_screen(&mut output).unwrap();
+        assert!(super::enter_alternate_screen(&mut output).is_err());
+        super::record_answer("What's your name?", "Ferris");
+
+        let mut keys = crate::ui::Key::char_keys_from_str("fake");
+        keys.push(crate::ui::Key::Enter);
+        let mut backend = crate::test::FakeBackend::new(keys);
+        crate::Text::new("From a custom backend")
+            .prompt_with_backend(&mut backend)
+            .unwrap();
+
+        super::leave_alternate_screen(&mut output, true).unwrap();
+        super::record_answer("Not echoed", "answer");
+
+        let output = String::from_utf8(output).unwrap();
+        let entered = output.find("\x1b[?1049h").unwrap();
+        let left = output.find("\x1b[?1049l").unwrap();
+        let echoed = output.find("What's your name? Ferris\n").unwrap();
+        assert!(entered < left && left < echoed);
+        assert!(!output.contains("Not echoed"));
+        assert!(!output.contains("From a custom backend"));
+    }
+
+    #[test]
+    fn mouse_events_are_converted_to_keys() {
+        use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+        use crate::ui::Key;
+
+        let mouse = |kind| {
+            Event::Mouse(MouseEvent {
+                kind,
+                column: 4,
+                row: 7,
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        assert_eq!(
+            Some(Key::ScrollUp),
+            super::key_from_event(mouse(MouseEventKind::ScrollUp))
+        );
+        assert_eq!(
+            Some(Key::ScrollDown),
+            super::key_from_event(mouse(MouseEventKind::ScrollDown))
+        );
+        assert_eq!(
+            Some(Key::Click { row: 7, column: 4 }),
+            super::key_from_event(mouse(MouseEventKind::Down(MouseButton::Left)))
+        );
+        assert_eq!(
+            None,
+            super::key_from_event(mouse(MouseEventKind::Down(MouseButton::Right)))
+        );
+        assert_eq!(None, super::key_from_event(mouse(MouseEventKind::Moved)));
+    }
+
     #[test]
     fn writer() {
         let mut terminal = CrosstermTerminal::new_in_memory_output();
@@ -1,8 +1,8 @@
-use std::{fmt::Display, io::Result};
+use std::{ffi::OsStr, fmt::Display, io::Result};
 
 use crate::{
     error::InquireResult,
-    ui::{dimension::Dimension, InputReader, Styled},
+    ui::{dimension::Dimension, CursorShape, InputReader, Styled},
 };
 
 #[cfg(feature = "crossterm")]
@@ -20,6 +20,117 @@ pub mod console;
 #[cfg(test)]
 pub(crate) mod test;
 
+/// Installs a panic hook restoring the terminal before the panic message is
+/// printed, then running the previously installed hook.
+///
+/// Prompts already restore the terminal when dropped, including while unwinding
+/// from a panic raised in user callbacks such as validators or formatters.
+/// However, the panic message is printed before unwinding starts, while the
+/// terminal is still in raw mode, which garbles it. When panics abort instead
+/// of unwinding, the terminal is never restored at all. This hook leaves raw
+/// mode and shows the cursor as soon as a panic happens, covering both cases.
+///
+/// Panics raised while no prompt is displayed leave the terminal untouched,
+/// apart from restoring the primary screen if an
+/// [alternate screen](enter_alternate_screen) is active.
+///
+/// It is meant to be called once, at the start of the program.
+///
+/// # Examples
+///
+/// ```no_run
+/// inquire::install_panic_restore();
+///
+/// let name = inquire::Text::new("What's your name?").prompt();
+/// ```
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+pub fn install_panic_restore() {
+    let previous_hook = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        crossterm::restore_after_panic();
+        previous_hook(info);
+    }));
+}
+
+/// Switches to the alternate screen buffer of the terminal, in which prompts
+/// are rendered until the returned guard is dropped. The primary screen and its
+/// scrollback are then restored as they were, without any trace of the prompts.
+///
+/// Call [`AlternateScreen::with_answers_echoed`] on the guard to print the
+/// message and formatted answer of each prompt to the primary screen once it
+/// is restored. Prompts that were canceled, e.g. skipped by the user when run
+/// with `prompt_skippable`, have no answer and are not echoed.
+///
+/// Only one alternate screen can be active at a time, entering it again before
+/// the guard is dropped fails with [`InquireError::InvalidConfiguration`].
+///
+/// [`InquireError::InvalidConfiguration`]: crate::InquireError::InvalidConfiguration
+///
+/// # Examples
+///
+/// ```no_run
+/// let screen = inquire::enter_alternate_screen()?.with_answers_echoed();
+///
+/// let name = inquire::Text::new("What's your name?").prompt()?;
+/// let age = inquire::CustomType::<u32>::new("How old are you?").prompt_skippable()?;
+///
+/// // the primary screen is restored, with "What's your name? <name>" echoed.
+/// drop(screen);
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+pub fn enter_alternate_screen() -> InquireResult<AlternateScreen> {
+    crossterm::enter_alternate_screen(&mut crossterm::prompt_output())?;
+
+    Ok(AlternateScreen {
+        echo_answers: false,
+    })
+}
+
+/// Guard returned by [`enter_alternate_screen`], restoring the primary screen
+/// of the terminal when dropped.
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+#[derive(Debug)]
+#[must_use = "the primary screen is restored as soon as the guard is dropped"]
+pub struct AlternateScreen {
+    echo_answers: bool,
+}
+
+#[cfg(feature = "crossterm")]
+impl AlternateScreen {
+    /// Prints the message and formatted answer of each prompt answered in the
+    /// alternate screen to the primary one, once it is restored.
+    pub fn with_answers_echoed(mut self) -> Self {
+        self.echo_answers = true;
+        self
+    }
+}
+
+#[cfg(feature = "crossterm")]
+impl Drop for AlternateScreen {
+    fn drop(&mut self) {
+        let _unused =
+            crossterm::leave_alternate_screen(&mut crossterm::prompt_output(), self.echo_answers);
+    }
+}
+
+/// Records the answer of a prompt displayed on the default terminal, to be
+/// echoed once the primary screen is restored by an `AlternateScreen` guard.
+///
+/// Prompts call it from their `prompt()` methods only, so answers given
+/// through a custom backend are never echoed to the terminal.
+pub(crate) fn record_answer(message: &str, answer: &str) {
+    #[cfg(feature = "crossterm")]
+    crossterm::record_answer(message, answer);
+
+    #[cfg(not(feature = "crossterm"))]
+    let _unused = (message, answer);
+}
+
 pub type TerminalSize = Dimension;
 
 pub trait Terminal: Sized {
@@ -40,21 +151,155 @@ pub trait Terminal: Sized {
     #[allow(unused)]
     fn cursor_move_to_column(&mut self, idx: u16) -> Result<()>;
 
+    /// Changes the shape of the cursor, a no-op on terminals without support for it.
+    fn cursor_set_shape(&mut self, _shape: CursorShape) -> Result<()> {
+        Ok(())
+    }
+
+    /// Starts reporting mouse events as keys, a no-op on terminals without
+    /// support for it. Mouse capture is disabled when the terminal is dropped.
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Row of the cursor on the screen, `None` on terminals unable to query it.
+    fn cursor_row(&mut self) -> Result<Option<u16>> {
+        Ok(None)
+    }
+
+    /// Restores the default shape of the cursor, a no-op on terminals without support for it.
+    fn cursor_reset_shape(&mut self) -> Result<()> {
+        Ok(())
+    }
+
     fn flush(&mut self) -> Result<()>;
 }
 
+/// Returns whether stdin is attached to an interactive terminal.
+///
+/// The `console` backend is only able to check stdout, which is used instead.
+pub fn stdin_is_tty() -> bool {
+    #[cfg(feature = "crossterm")]
+    use ::crossterm::tty::IsTty;
+
+    #[cfg(feature = "crossterm")]
+    return std::io::stdin().is_tty();
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    return ::termion::is_tty(&std::io::stdin());
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    return ::console::user_attended();
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console")
+    ))]
+    false
+}
+
+/// Returns whether a user is typing in a terminal unable to move the cursor
+/// around, such as one with `TERM=dumb` or whose size can not be queried.
+///
+/// Text, Confirm and CustomType prompts check this before acquiring the
+/// default terminal, falling back to a plain line-based interaction instead of
+/// the frames drawn by the interactive [`Backend`](crate::ui::Backend).
+pub(crate) fn is_dumb_terminal() -> bool {
+    stdin_is_tty()
+        && is_dumb(
+            std::env::var_os("TERM").as_deref(),
+            terminal_size_available(),
+        )
+}
+
+/// Returns whether the input reader of the default terminal is able to wait for
+/// keys with a timeout, which only the crossterm one does. The termion and
+/// console readers block until a key is pressed.
+pub(crate) fn default_terminal_supports_timeouts() -> bool {
+    cfg!(feature = "crossterm")
+}
+
+fn is_dumb(term: Option<&OsStr>, size_available: bool) -> bool {
+    term == Some(OsStr::new("dumb")) || !size_available
+}
+
+fn terminal_size_available() -> bool {
+    #[cfg(feature = "crossterm")]
+    return ::crossterm::terminal::size().is_ok();
+
+    #[cfg(all(feature = "termion", not(feature = "crossterm")))]
+    return ::termion::terminal_size().is_ok();
+
+    #[cfg(all(
+        feature = "console",
+        not(feature = "termion"),
+        not(feature = "crossterm")
+    ))]
+    return ::console::Term::stderr().size_checked().is_some();
+
+    #[cfg(all(
+        not(feature = "crossterm"),
+        not(feature = "termion"),
+        not(feature = "console")
+    ))]
+    false
+}
+
+/// Fails with [`InquireError::NotTTY`] when there is no terminal to read keys
+/// from, instead of the opaque IO error raised later on when enabling raw mode.
+///
+/// When stdin is not a terminal, e.g. a pipe, crossterm reads keys from the
+/// controlling terminal at `/dev/tty` on Unix, so prompts still work as long as
+/// it can be opened.
+///
+/// [`InquireError::NotTTY`]: crate::InquireError::NotTTY
+#[cfg(feature = "crossterm")]
+fn ensure_input_terminal<F>(stdin_is_tty: bool, open_tty: F) -> InquireResult<()>
+where
+    F: FnOnce() -> Result<()>,
+{
+    if stdin_is_tty || !cfg!(unix) {
+        return Ok(());
+    }
+
+    open_tty().map_err(|_e| crate::InquireError::NotTTY)
+}
+
 pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)> {
+    #[cfg(feature = "crossterm")]
+    ensure_input_terminal(stdin_is_tty(), || {
+        std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/tty")
+            .map(drop)
+    })?;
+
     #[cfg(feature = "crossterm")]
     return Ok((
         crossterm::CrosstermKeyReader::new(),
-        crossterm::CrosstermTerminal::new()?,
+        match crate::config::get_output()? {
+            Some(file) => crossterm::CrosstermTerminal::new_with_file(file)?,
+            None => crossterm::CrosstermTerminal::new()?,
+        },
     ));
 
     #[cfg(all(feature = "termion", not(feature = "crossterm")))]
-    return Ok((
-        termion::TermionKeyReader::new()?,
-        termion::TermionTerminal::new()?,
-    ));
+    return match crate::config::get_output()? {
+        Some(file) => Ok((
+            termion::TermionKeyReader::new_with_file(file.try_clone()?)?,
+            termion::TermionTerminal::new_with_file(file)?,
+        )),
+        None => Ok((
+            termion::TermionKeyReader::new()?,
+            termion::TermionTerminal::new()?,
+        )),
+    };
 
     #[cfg(all(
         feature = "console",
@@ -83,3 +328,45 @@ pub fn get_default_terminal() -> InquireResult<(impl InputReader, impl Terminal)
         ))
     }
 }
+
+#[cfg(all(test, unix, feature = "crossterm"))]
+mod tests {
+    use std::{ffi::OsStr, io};
+
+    use crate::InquireError;
+
+    use super::{ensure_input_terminal, is_dumb};
+
+    #[test]
+    fn piped_stdin_without_controlling_terminal_is_not_a_tty() {
+        // ENXIO, returned when opening /dev/tty without a controlling terminal.
+        let result = ensure_input_terminal(false, || Err(io::Error::from_raw_os_error(6)));
+
+        assert!(matches!(result, Err(InquireError::NotTTY)));
+    }
+
+    #[test]
+    fn piped_stdin_with_controlling_terminal_is_accepted() {
+        assert!(ensure_input_terminal(false, || Ok(())).is_ok());
+    }
+
+    #[test]
+    fn interactive_stdin_is_accepted() {
+        let result = ensure_input_terminal(true, || unreachable!("stdin is a terminal"));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn dumb_term_is_detected() {
+        assert!(is_dumb(Some(OsStr::new("dumb")), true));
+        assert!(!is_dumb(Some(OsStr::new("xterm-256color")), true));
+        assert!(!is_dumb(None, true));
+    }
+
+    #[test]
+    fn unknown_terminal_size_is_dumb() {
+        assert!(is_dumb(Some(OsStr::new("xterm-256color")), false));
+        assert!(is_dumb(None, false));
+    }
+}
@@ -34,9 +34,13 @@ pub struct TermionKeyReader {
 impl TermionKeyReader {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
-        Ok(Self {
-            keys: termion::get_tty()?.keys(),
-        })
+        Self::new_with_file(termion::get_tty()?)
+    }
+
+    /// Creates a key reader reading from the given file, e.g. `/dev/tty`.
+    #[allow(unused)]
+    pub fn new_with_file(file: File) -> InquireResult<Self> {
+        Ok(Self { keys: file.keys() })
     }
 }
 
@@ -58,9 +62,14 @@ pub struct TermionTerminal<'a> {
 impl<'a> TermionTerminal<'a> {
     #[allow(unused)]
     pub fn new() -> InquireResult<Self> {
-        let tty = termion::get_tty()?;
-        let raw_terminal = tty.into_raw_mode()?;
-        let keys = raw_terminal.try_clone()?.keys();
+        Self::new_with_file(termion::get_tty()?)
+    }
+
+    /// Creates a terminal rendering to the given file, e.g. `/dev/tty`,
+    /// which is put in raw mode while the terminal is alive.
+    #[allow(unused)]
+    pub fn new_with_file(file: File) -> InquireResult<Self> {
+        let raw_terminal = file.into_raw_mode()?;
 
         Ok(Self {
             io: IO::TTY(raw_terminal),
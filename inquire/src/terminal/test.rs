@@ -1,13 +1,16 @@
 use std::{collections::VecDeque, fmt::Display};
 
-use crate::ui::{Key, Styled};
+use crate::ui::{CursorShape, Styled};
 
 use super::{Terminal, TerminalSize};
 
 pub struct MockTerminal {
     pub size: TerminalSize,
-    pub input: VecDeque<Key>,
     pub output: VecDeque<MockTerminalToken>,
+    /// Row of the cursor on the screen, only tracked when an origin is set
+    /// with [`with_cursor_row`](Self::with_cursor_row).
+    pub cursor_row: Option<u16>,
+    pub mouse_capture: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -22,6 +25,9 @@ pub enum MockTerminalToken {
     CursorLeft(u16),
     CursorRight(u16),
     CursorMoveToColumn(u16),
+    CursorSetShape(CursorShape),
+    CursorResetShape,
+    EnableMouseCapture,
 }
 
 impl<T> From<T> for MockTerminalToken
@@ -37,8 +43,20 @@ impl MockTerminal {
     pub fn new() -> Self {
         Self {
             size: TerminalSize::new(80, 40),
-            input: VecDeque::new(),
             output: VecDeque::new(),
+            cursor_row: None,
+            mouse_capture: false,
+        }
+    }
+
+    pub fn with_cursor_row(mut self, row: u16) -> Self {
+        self.cursor_row = Some(row);
+        self
+    }
+
+    fn move_cursor_row(&mut self, delta: i32) {
+        if let Some(row) = self.cursor_row.as_mut() {
+            *row = (i32::from(*row) + delta).max(0) as u16;
         }
     }
 
@@ -65,6 +83,7 @@ impl Terminal for MockTerminal {
 
     fn write<T: Display>(&mut self, val: T) -> std::io::Result<()> {
         let styled = Styled::new(format!("{}", val));
+        self.move_cursor_row(styled.content.matches('\n').count() as i32);
         let token = MockTerminalToken::Text(styled);
         self.output.push_back(token);
         Ok(())
@@ -102,12 +121,14 @@ impl Terminal for MockTerminal {
     }
 
     fn cursor_up(&mut self, cnt: u16) -> std::io::Result<()> {
+        self.move_cursor_row(-i32::from(cnt));
         let token = MockTerminalToken::CursorUp(cnt);
         self.output.push_back(token);
         Ok(())
     }
 
     fn cursor_down(&mut self, cnt: u16) -> std::io::Result<()> {
+        self.move_cursor_row(i32::from(cnt));
         let token = MockTerminalToken::CursorDown(cnt);
         self.output.push_back(token);
         Ok(())
@@ -131,6 +152,28 @@ impl Terminal for MockTerminal {
         Ok(())
     }
 
+    fn cursor_set_shape(&mut self, shape: CursorShape) -> std::io::Result<()> {
+        let token = MockTerminalToken::CursorSetShape(shape);
+        self.output.push_back(token);
+        Ok(())
+    }
+
+    fn cursor_reset_shape(&mut self) -> std::io::Result<()> {
+        let token = MockTerminalToken::CursorResetShape;
+        self.output.push_back(token);
+        Ok(())
+    }
+
+    fn enable_mouse_capture(&mut self) -> std::io::Result<()> {
+        self.mouse_capture = true;
+        self.output.push_back(MockTerminalToken::EnableMouseCapture);
+        Ok(())
+    }
+
+    fn cursor_row(&mut self) -> std::io::Result<Option<u16>> {
+        Ok(self.cursor_row)
+    }
+
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
@@ -0,0 +1,570 @@
+//! Headless backend to drive prompts in automated tests.
+//!
+//! Prompts usually require an interactive terminal to run, which makes it hard
+//! to unit test code that calls them. The [`FakeBackend`] defined here replaces
+//! the terminal with a scripted list of [`Key`] events and records every frame
+//! the prompt renders as a list of [`Token`]s, which can then be asserted against.
+//!
+//! This module is only available when the `test-util` feature is enabled.
+//!
+//! # Example
+//!
+//! ```
+//! use inquire::{
+//!     test::{FakeBackend, Token},
+//!     ui::{Key, KeyModifiers},
+//!     Text,
+//! };
+//!
+//! let mut backend = FakeBackend::new(vec![
+//!     Key::Char('h', KeyModifiers::NONE),
+//!     Key::Char('i', KeyModifiers::NONE),
+//!     Key::Enter,
+//! ]);
+//!
+//! let answer = Text::new("Greeting:").prompt_with_backend(&mut backend)?;
+//!
+//! assert_eq!("hi", answer);
+//!
+//! let last_frame = backend.frames().last().unwrap();
+//! assert!(last_frame.has_token(&Token::AnsweredPrompt("Greeting:".into(), "hi".into())));
+//! # Ok::<(), inquire::InquireError>(())
+//! ```
+
+mod snapshot;
+
+pub use snapshot::*;
+
+use std::{
+    collections::{BTreeSet, VecDeque},
+    fmt::Display,
+    io::Result,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    error::{InquireError, InquireResult},
+    input::Input,
+    list_option::ListOption,
+    ui::{
+        CommonBackend, CustomTypeBackend, InputReader, Key, MultiSelectBackend, PasswordBackend,
+        SelectBackend, TextBackend,
+    },
+    utils::Page,
+    validator::ErrorMessage,
+};
+
+/// Semantic unit rendered by a prompt into a [`Frame`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// Prompt message.
+    Prompt(String),
+    /// Default value displayed next to the prompt message.
+    DefaultValue(String),
+    /// Current content of the text input.
+    Input(String),
+    /// Placeholder displayed while the text input is empty.
+    Placeholder(String),
+    /// Loading indicator displayed while suggestions are being computed.
+    Spinner,
+    /// Current content of a text input that is rendered masked to the user.
+    MaskedInput(String),
+    /// Prompt message rendered after the prompt was canceled.
+    CanceledPrompt(String),
+    /// Prompt message and formatted answer rendered after a submission.
+    AnsweredPrompt(String, String),
+    /// Error message, e.g. from a failed validation.
+    ErrorMessage(ErrorMessage),
+    /// Warning message returned by a validation.
+    WarningMessage(ErrorMessage),
+    /// Error returned by the autocompleter of a Text prompt.
+    SuggestionError(ErrorMessage),
+    /// Help message.
+    HelpMessage(String),
+    /// Message displayed in place of an empty list of options.
+    EmptyOptions(String),
+    /// Remainder of the suggestion displayed inline after the text input.
+    InlineSuggestion(String),
+    /// Command used to open the external editor in Editor prompts.
+    EditorCommand(String),
+    /// Option of a list, such as the ones in Select prompts or the suggestions
+    /// of a Text prompt.
+    Option {
+        /// Index of the option relative to the original list.
+        index: usize,
+        /// Rendered value of the option.
+        value: String,
+        /// Whether the cursor is currently on this option.
+        highlighted: bool,
+        /// Whether the option is checked. Always `false` outside of MultiSelect prompts.
+        checked: bool,
+    },
+    /// Calendar rendered by DateSelect prompts.
+    #[cfg(feature = "date")]
+    Calendar {
+        /// Month being displayed.
+        month: chrono::Month,
+        /// Year being displayed.
+        year: i32,
+        /// First day of the week.
+        week_start: chrono::Weekday,
+        /// Date considered as today.
+        today: chrono::NaiveDate,
+        /// Date under the cursor.
+        selected_date: chrono::NaiveDate,
+        /// Minimum selectable date, if any.
+        min_date: Option<chrono::NaiveDate>,
+        /// Maximum selectable date, if any.
+        max_date: Option<chrono::NaiveDate>,
+        /// Dates displayed in the calendar that are disabled by the prompt's predicate.
+        disabled_dates: Vec<chrono::NaiveDate>,
+    },
+    /// ISO week number displayed before a calendar row, pushed once per row
+    /// after the calendar when week numbers are enabled.
+    #[cfg(feature = "date")]
+    WeekNumber(u32),
+    /// Time selector rendered by DateSelect prompts with time selection enabled.
+    #[cfg(feature = "date")]
+    TimeSelector {
+        /// Time of day under the cursor.
+        time: chrono::NaiveTime,
+        /// Whether the time selection stage is active.
+        active: bool,
+    },
+    /// Marks the end of the last frame of a prompt.
+    PromptEnd,
+}
+
+/// Collection of tokens rendered in a single draw of the prompt.
+#[derive(Default, Debug, Clone)]
+pub struct Frame {
+    content: Vec<Token>,
+}
+
+impl Frame {
+    /// Returns whether the frame contains the given token.
+    pub fn has_token(&self, token: &Token) -> bool {
+        self.content.iter().any(|t| t == token)
+    }
+
+    /// Tokens rendered in this frame, in order.
+    pub fn tokens(&self) -> &[Token] {
+        &self.content
+    }
+}
+
+/// Backend that reads keys from a scripted list of events and records
+/// the rendered frames instead of writing to a terminal.
+///
+/// When there are no more keys to be read, the prompt returns an
+/// [`InquireError::IO`] error with the [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof) kind.
+#[derive(Default, Debug, Clone)]
+pub struct FakeBackend {
+    input: VecDeque<Option<Key>>,
+    frames: Vec<Frame>,
+    cur_frame: Option<Frame>,
+}
+
+impl FakeBackend {
+    /// Creates a backend that feeds the given keys, in order, to the prompt.
+    pub fn new(input: Vec<Key>) -> Self {
+        Self::new_with_timeouts(input.into_iter().map(Some).collect())
+    }
+
+    /// Creates a backend that feeds the given events, in order, to the prompt.
+    ///
+    /// A `None` event simulates a period without key presses: prompts waiting
+    /// for a key with a timeout, e.g. to animate a loading indicator, are woken
+    /// up after the timeout elapses. Prompts blocking for the next key skip it.
+    pub fn new_with_timeouts(input: Vec<Option<Key>>) -> Self {
+        Self {
+            input: input.into(),
+            frames: vec![],
+            cur_frame: None,
+        }
+    }
+
+    /// Frames rendered so far, in order.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Keys that have not been read by the prompt yet.
+    pub fn remaining_input(&self) -> Vec<Key> {
+        self.input.iter().flatten().cloned().collect()
+    }
+
+    fn push_token(&mut self, token: Token) {
+        if let Some(frame) = self.cur_frame.as_mut() {
+            frame.content.push(token);
+        } else {
+            panic!("No frame to push token");
+        }
+    }
+
+    fn push_prompt_with_input(&mut self, prompt: &str, default: Option<&str>, input: &Input) {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        if let Some(default) = default {
+            self.push_token(Token::DefaultValue(default.to_string()));
+        }
+        self.push_token(Token::Input(input.content().to_string()));
+        if input.is_empty() {
+            if let Some(placeholder) = input.placeholder() {
+                self.push_token(Token::Placeholder(placeholder.to_string()));
+            }
+        }
+    }
+
+    fn push_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: Option<&BTreeSet<usize>>,
+    ) {
+        for (idx, option) in page.content.iter().enumerate() {
+            self.push_token(Token::Option {
+                index: option.index,
+                value: option.value.to_string(),
+                highlighted: page.cursor == Some(idx),
+                checked: checked
+                    .map(|checked| checked.contains(&option.index))
+                    .unwrap_or(false),
+            });
+        }
+    }
+}
+
+impl InputReader for FakeBackend {
+    fn read_key(&mut self) -> InquireResult<Key> {
+        loop {
+            match self.input.pop_front() {
+                Some(Some(key)) => return Ok(key),
+                Some(None) => {}
+                None => return Err(eof()),
+            }
+        }
+    }
+
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        match self.input.pop_front() {
+            Some(Some(key)) => Ok(Some(key)),
+            Some(None) => {
+                thread::sleep(timeout);
+                Ok(None)
+            }
+            None => Err(eof()),
+        }
+    }
+}
+
+fn eof() -> InquireError {
+    InquireError::IO(std::io::Error::new(
+        std::io::ErrorKind::UnexpectedEof,
+        "No more keys in input",
+    ))
+}
+
+impl CommonBackend for FakeBackend {
+    fn frame_setup(&mut self) -> Result<()> {
+        self.cur_frame = Some(Frame::default());
+        Ok(())
+    }
+
+    fn terminal_height(&self) -> Option<u16> {
+        None
+    }
+
+    fn error_message_rows(&self, _error: &ErrorMessage) -> usize {
+        1
+    }
+
+    fn warning_message_rows(&self, _warning: &ErrorMessage) -> usize {
+        1
+    }
+
+    fn help_message_rows(&self, _help: &str) -> usize {
+        1
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Counts the rows of the tokens rendered so far in the current frame,
+    /// the ones rendered next to the prompt message sharing its row.
+    fn frame_row(&self) -> u16 {
+        let frame = match &self.cur_frame {
+            Some(frame) => frame,
+            None => return 0,
+        };
+
+        frame
+            .content
+            .iter()
+            .filter(|token| {
+                !matches!(
+                    token,
+                    Token::DefaultValue(_)
+                        | Token::Input(_)
+                        | Token::Placeholder(_)
+                        | Token::Spinner
+                        | Token::MaskedInput(_)
+                        | Token::InlineSuggestion(_)
+                )
+            })
+            .count() as u16
+    }
+
+    fn frame_finish(&mut self, is_last_frame: bool) -> Result<()> {
+        if is_last_frame {
+            self.push_token(Token::PromptEnd);
+        }
+
+        if let Some(frame) = self.cur_frame.take() {
+            self.frames.push(frame);
+        } else {
+            panic!("No frame to finish");
+        }
+        Ok(())
+    }
+
+    fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.push_token(Token::CanceledPrompt(prompt.to_string()));
+        Ok(())
+    }
+
+    fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()> {
+        self.push_token(Token::AnsweredPrompt(
+            prompt.to_string(),
+            answer.to_string(),
+        ));
+        Ok(())
+    }
+
+    fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.push_token(Token::ErrorMessage(error.clone()));
+        Ok(())
+    }
+
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()> {
+        self.push_token(Token::WarningMessage(warning.clone()));
+        Ok(())
+    }
+
+    fn render_help_message(&mut self, help: &str) -> Result<()> {
+        self.push_token(Token::HelpMessage(help.to_string()));
+        Ok(())
+    }
+}
+
+impl TextBackend for FakeBackend {
+    fn render_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.push_prompt_with_input(prompt, default, cur_input);
+        Ok(())
+    }
+
+    fn render_prompt_with_spinner(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        _spinner_frame: usize,
+    ) -> Result<()> {
+        self.push_prompt_with_input(prompt, default, cur_input);
+        self.push_token(Token::Spinner);
+        Ok(())
+    }
+
+    fn render_prompt_with_inline_suggestion(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        inline_suggestion: &str,
+    ) -> Result<()> {
+        self.push_prompt_with_input(prompt, default, cur_input);
+        self.push_token(Token::InlineSuggestion(inline_suggestion.to_string()));
+        Ok(())
+    }
+
+    fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+        self.push_options(page, None);
+        Ok(())
+    }
+
+    fn render_suggestion_error(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.push_token(Token::SuggestionError(error.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "editor")]
+impl crate::ui::EditorBackend for FakeBackend {
+    fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        self.push_token(Token::EditorCommand(editor_command.to_string()));
+        Ok(())
+    }
+
+    fn render_inline_prompt(
+        &mut self,
+        prompt: &str,
+        input: &crate::input::MultilineInput,
+    ) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        self.push_token(Token::Input(input.content()));
+        Ok(())
+    }
+}
+
+impl SelectBackend for FakeBackend {
+    fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+        match cur_input {
+            Some(input) => self.push_prompt_with_input(prompt, None, input),
+            None => self.push_token(Token::Prompt(prompt.to_string())),
+        }
+        Ok(())
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        _option_styles: Option<&[crate::ui::StyleSheet]>,
+        _highlights: Option<&[Vec<std::ops::Range<usize>>]>,
+        _suffixes: Option<&[Option<String>]>,
+    ) -> Result<()> {
+        self.push_options(page, None);
+        Ok(())
+    }
+
+    fn render_inline_option<D: Display>(
+        &mut self,
+        prompt: &str,
+        option: &ListOption<D>,
+    ) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        self.push_token(Token::Option {
+            index: option.index,
+            value: option.value.to_string(),
+            highlighted: true,
+            checked: false,
+        });
+        Ok(())
+    }
+}
+
+impl MultiSelectBackend for FakeBackend {
+    fn render_multiselect_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()> {
+        match cur_input {
+            Some(input) => self.push_prompt_with_input(prompt, None, input),
+            None => self.push_token(Token::Prompt(prompt.to_string())),
+        }
+        Ok(())
+    }
+
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        checked: &BTreeSet<usize>,
+        _range: Option<std::ops::RangeInclusive<usize>>,
+        _option_styles: Option<&[crate::ui::StyleSheet]>,
+        _highlights: Option<&[Vec<std::ops::Range<usize>>]>,
+    ) -> Result<()> {
+        self.push_options(page, Some(checked));
+        Ok(())
+    }
+
+    fn render_empty_options(&mut self, message: &str) -> Result<()> {
+        self.push_token(Token::EmptyOptions(message.to_string()));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "date")]
+impl crate::ui::date::DateSelectBackend for FakeBackend {
+    fn render_calendar_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        Ok(())
+    }
+
+    fn render_calendar(
+        &mut self,
+        month: chrono::Month,
+        year: i32,
+        week_start: chrono::Weekday,
+        week_numbers: bool,
+        today: chrono::NaiveDate,
+        selected_date: chrono::NaiveDate,
+        min_date: Option<chrono::NaiveDate>,
+        max_date: Option<chrono::NaiveDate>,
+        is_disabled: &dyn Fn(chrono::NaiveDate) -> bool,
+    ) -> Result<()> {
+        let first_date = crate::date_utils::get_first_rendered_date(month, year, week_start);
+        let disabled_dates = std::iter::successors(Some(first_date), |date| date.succ_opt())
+            .take(6 * 7)
+            .filter(|date| is_disabled(*date))
+            .collect();
+
+        self.push_token(Token::Calendar {
+            month,
+            year,
+            week_start,
+            today,
+            selected_date,
+            min_date,
+            max_date,
+            disabled_dates,
+        });
+
+        if week_numbers {
+            let rows = std::iter::successors(Some(first_date), |date| {
+                date.checked_add_days(chrono::Days::new(7))
+            });
+            for row_start in rows.take(6) {
+                let week_number = crate::date_utils::get_week_number(row_start);
+                self.push_token(Token::WeekNumber(week_number));
+            }
+        }
+        Ok(())
+    }
+
+    fn render_time_selector(&mut self, time: chrono::NaiveTime, active: bool) -> Result<()> {
+        self.push_token(Token::TimeSelector { time, active });
+        Ok(())
+    }
+}
+
+impl CustomTypeBackend for FakeBackend {
+    fn render_prompt(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+    ) -> Result<()> {
+        self.push_prompt_with_input(prompt, default, cur_input);
+        Ok(())
+    }
+}
+
+impl PasswordBackend for FakeBackend {
+    fn render_prompt(&mut self, prompt: &str) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        Ok(())
+    }
+
+    fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.push_token(Token::Prompt(prompt.to_string()));
+        self.push_token(Token::MaskedInput(cur_input.content().to_string()));
+        Ok(())
+    }
+
+    fn render_prompt_with_full_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        self.push_prompt_with_input(prompt, None, cur_input);
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+use std::{cell::RefCell, fmt::Display, io::Result, rc::Rc};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+    ansi::AnsiStrippable,
+    error::{InquireError, InquireResult},
+    terminal::{Terminal, TerminalSize},
+    ui::{Backend, InputReader, Key, RenderConfig, Styled},
+    Confirm, CustomType, MultiSelect, Password, Select, Text,
+};
+
+use super::FakeBackend;
+
+/// Renders the prompt as it would be displayed in an 80x40 terminal while
+/// `keys` are pressed, returning the content of the screen after each frame.
+///
+/// Frames are plain text: styles and ANSI escape codes are stripped, as well
+/// as trailing whitespace. Consecutive identical frames are only returned once.
+///
+/// The prompt does not need to be answered: when the keys run out, the frames
+/// rendered so far are returned, which makes it possible to capture the prompt
+/// at any intermediate state. Any other error returned by the prompt, e.g. an
+/// invalid configuration, is forwarded.
+///
+/// This works well with snapshot testing libraries such as `insta`:
+///
+/// ```ignore
+/// let keys = vec![Key::Char('J', KeyModifiers::NONE)];
+/// let frames = render_frames(Text::new("Name:"), keys)?;
+///
+/// insta::assert_snapshot!(frames.last().unwrap());
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use inquire::{
+///     test::render_frames,
+///     ui::{Key, KeyModifiers, RenderConfig},
+///     Text,
+/// };
+///
+/// let prompt = Text::new("Greeting:")
+///     .with_help_message("say hi")
+///     .with_render_config(RenderConfig::empty());
+///
+/// let frames = render_frames(prompt, vec![Key::Char('h', KeyModifiers::NONE)])?;
+///
+/// assert_eq!("? Greeting: h\n[say hi]", frames.last().unwrap());
+/// # Ok::<(), inquire::InquireError>(())
+/// ```
+pub fn render_frames<'a, P>(prompt: P, keys: Vec<Key>) -> InquireResult<Vec<String>>
+where
+    P: RenderablePrompt<'a>,
+{
+    let frames = Rc::new(RefCell::new(vec![]));
+    let terminal = ScreenTerminal::new(Rc::clone(&frames));
+
+    let render_config = prompt.render_config();
+    let result = {
+        let mut backend = Backend::new(FakeBackend::new(keys), terminal, render_config)?;
+        prompt.run(&mut backend)
+    };
+
+    match result {
+        Err(InquireError::IO(err)) if err.kind() == std::io::ErrorKind::UnexpectedEof => {}
+        Err(err) => return Err(err),
+        Ok(()) => {}
+    }
+
+    let frames = frames.borrow().clone();
+    Ok(frames)
+}
+
+/// Prompt that can be rendered with [`render_frames`].
+pub trait RenderablePrompt<'a>: Sized {
+    #[doc(hidden)]
+    fn render_config(&self) -> RenderConfig<'a>;
+
+    #[doc(hidden)]
+    fn run<I, T>(self, backend: &mut Backend<'a, I, T>) -> InquireResult<()>
+    where
+        I: InputReader,
+        T: Terminal;
+}
+
+macro_rules! renderable_prompt {
+    ($prompt:ty $(, $generics:ident: $bound:path)?) => {
+        impl<'a $(, $generics: $bound)?> RenderablePrompt<'a> for $prompt {
+            fn render_config(&self) -> RenderConfig<'a> {
+                self.render_config
+            }
+
+            fn run<I, T>(self, backend: &mut Backend<'a, I, T>) -> InquireResult<()>
+            where
+                I: InputReader,
+                T: Terminal,
+            {
+                self.prompt_with_backend(backend).map(|_answer| ())
+            }
+        }
+    };
+}
+
+renderable_prompt!(Text<'a>);
+renderable_prompt!(Password<'a>);
+renderable_prompt!(Confirm<'a>);
+renderable_prompt!(CustomType<'a, V>, V: Clone);
+renderable_prompt!(Select<'a, V>, V: Display);
+renderable_prompt!(MultiSelect<'a, V>, V: Display);
+#[cfg(feature = "editor")]
+renderable_prompt!(crate::Editor<'a>);
+#[cfg(feature = "date")]
+renderable_prompt!(crate::DateSelect<'a>);
+
+/// Terminal emulating a screen, storing its content after each frame.
+struct ScreenTerminal {
+    rows: Vec<Vec<String>>,
+    row: usize,
+    col: usize,
+    frames: Rc<RefCell<Vec<String>>>,
+}
+
+impl ScreenTerminal {
+    fn new(frames: Rc<RefCell<Vec<String>>>) -> Self {
+        Self {
+            rows: vec![],
+            row: 0,
+            col: 0,
+            frames,
+        }
+    }
+
+    fn current_row(&mut self) -> &mut Vec<String> {
+        if self.rows.len() <= self.row {
+            self.rows.resize_with(self.row + 1, Vec::new);
+        }
+
+        &mut self.rows[self.row]
+    }
+
+    fn put_char(&mut self, c: char) {
+        let col = self.col;
+        let width = UnicodeWidthChar::width(c).unwrap_or(0);
+        let row = self.current_row();
+
+        // zero-width characters, such as combining marks, are part of the
+        // grapheme written in the previous cell.
+        if width == 0 {
+            if let Some(cell) = col.checked_sub(1).and_then(|prev| row.get_mut(prev)) {
+                cell.push(c);
+            }
+            return;
+        }
+
+        if row.len() < col + width {
+            row.resize(col + width, " ".to_owned());
+        }
+
+        row[col] = c.to_string();
+        for cell in &mut row[col + 1..col + width] {
+            cell.clear();
+        }
+
+        self.col += width;
+    }
+
+    fn screen(&self) -> String {
+        let mut lines: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| row.concat().trim_end().to_owned())
+            .collect();
+
+        while matches!(lines.last(), Some(line) if line.is_empty()) {
+            lines.pop();
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl Terminal for ScreenTerminal {
+    fn get_size(&self) -> Result<TerminalSize> {
+        Ok(TerminalSize::new(80, 40))
+    }
+
+    fn write<T: Display>(&mut self, val: T) -> Result<()> {
+        for c in val.to_string().ansi_stripped_chars() {
+            match c {
+                '\r' => self.col = 0,
+                '\n' => self.row += 1,
+                c => self.put_char(c),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_styled<T: Display>(&mut self, val: &Styled<T>) -> Result<()> {
+        self.write(&val.content)
+    }
+
+    fn clear_line(&mut self) -> Result<()> {
+        self.current_row().clear();
+        Ok(())
+    }
+
+    fn clear_until_new_line(&mut self) -> Result<()> {
+        let col = self.col;
+        self.current_row().truncate(col);
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_up(&mut self, cnt: u16) -> Result<()> {
+        self.row = self.row.saturating_sub(cnt as usize);
+        Ok(())
+    }
+
+    fn cursor_down(&mut self, cnt: u16) -> Result<()> {
+        self.row += cnt as usize;
+        Ok(())
+    }
+
+    fn cursor_left(&mut self, cnt: u16) -> Result<()> {
+        self.col = self.col.saturating_sub(cnt as usize);
+        Ok(())
+    }
+
+    fn cursor_right(&mut self, cnt: u16) -> Result<()> {
+        self.col += cnt as usize;
+        Ok(())
+    }
+
+    fn cursor_move_to_column(&mut self, idx: u16) -> Result<()> {
+        self.col = idx as usize;
+        Ok(())
+    }
+
+    /// Called once at the end of each frame, when the screen is up to date.
+    fn flush(&mut self) -> Result<()> {
+        let screen = self.screen();
+        let mut frames = self.frames.borrow_mut();
+
+        if frames.last() != Some(&screen) {
+            frames.push(screen);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        ui::{Key, KeyModifiers, RenderConfig},
+        Select, Text,
+    };
+
+    use super::render_frames;
+
+    #[test]
+    fn frames_are_rendered_as_plain_text() {
+        let prompt = Text::new("Name:")
+            .with_placeholder("John")
+            .with_render_config(RenderConfig::default_colored());
+
+        let frames = render_frames(prompt, vec![Key::Char('J', KeyModifiers::NONE)]).unwrap();
+
+        assert_eq!(vec!["? Name: John", "? Name: J"], frames);
+    }
+
+    #[test]
+    fn answered_prompt_is_the_last_frame() {
+        let prompt =
+            Select::new("Color:", vec!["red", "green"]).with_render_config(RenderConfig::empty());
+
+        let frames =
+            render_frames(prompt, vec![Key::Down(KeyModifiers::NONE), Key::Enter]).unwrap();
+
+        assert_eq!(
+            vec![
+                "? Color:\n> red\n  green\n[↑↓ to move, enter to select, type to filter]",
+                "? Color:\n  red\n> green\n[↑↓ to move, enter to select, type to filter]",
+                "? Color: green",
+            ],
+            frames
+        );
+    }
+
+    #[test]
+    fn shrinking_frames_clear_previous_lines() {
+        let prompt = Text::new("Name:")
+            .with_help_message("help")
+            .with_render_config(RenderConfig::empty());
+
+        let frames = render_frames(prompt, vec![Key::Enter]).unwrap();
+
+        assert_eq!(Some(&"? Name:".to_owned()), frames.last());
+    }
+
+    #[test]
+    fn left_margin_indents_every_line() {
+        let prompt = Select::new("Color:", vec!["red", "green"])
+            .with_render_config(RenderConfig::empty().with_left_margin(3));
+
+        let frames = render_frames(prompt, vec![]).unwrap();
+
+        assert_eq!(
+            vec!["   ? Color:\n   > red\n     green\n   [↑↓ to move, enter to select, type to filter]"],
+            frames
+        );
+    }
+}
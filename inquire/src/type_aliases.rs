@@ -1,7 +1,25 @@
 //! General type aliases.
 
+use std::ops::Range;
+
 use crate::error::CustomUserError;
 
+/// Type alias for the callback receiving the announcements of a prompt, e.g.
+/// to be spoken by a text-to-speech engine. It is called whenever the
+/// announcement changes, such as when the highlighted option does.
+///
+/// See [`AccessibleState`](crate::accessibility::AccessibleState) for the
+/// format of the announcements.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::AccessibilityAnnouncer;
+///
+/// let announcer: AccessibilityAnnouncer = &|announcement| eprintln!("{}", announcement);
+/// ```
+pub type AccessibilityAnnouncer<'a> = &'a dyn Fn(&str);
+
 /// Type alias to represent the function used to Score and filter options.
 ///
 /// The function receives:
@@ -73,6 +91,29 @@ use crate::error::CustomUserError;
 /// ```
 pub type Scorer<'a, T> = &'a dyn Fn(&str, &T, &str, usize) -> Option<i64>;
 
+/// Type alias to represent a [`Scorer`] that also reports which parts of the
+/// option matched the filter input, so they can be highlighted.
+///
+/// The function receives the same arguments as a [`Scorer`] and returns, along
+/// with the score, the byte ranges of the string value of the option that
+/// matched the input. The ranges must lie on character boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::HighlightingScorer;
+///
+/// let scorer: HighlightingScorer<str> = &|input, _option, string_value, _idx| {
+///     let start = string_value.to_lowercase().find(&input.to_lowercase())?;
+///     Some((0, vec![start..start + input.len()]))
+/// };
+///
+/// assert_eq!(None, scorer("sa", "New York", "New York", 0));
+/// assert_eq!(Some((0, vec![3..5])), scorer("sa", "Kansas", "Kansas", 1));
+/// ```
+pub type HighlightingScorer<'a, T> =
+    &'a dyn Fn(&str, &T, &str, usize) -> Option<(i64, Vec<Range<usize>>)>;
+
 /// Type alias to represent the function used to retrieve text input suggestions.
 /// The function receives the current input and should return a collection of strings
 /// containing the suggestions to be made to the user.
@@ -82,3 +123,168 @@ pub type Suggester<'a> = &'a dyn Fn(&str) -> Result<Vec<String>, CustomUserError
 /// The function receives the current input and should return the suggestion (if any)
 /// that will replace the current input.
 pub type Completer<'a> = &'a dyn Fn(&str) -> Result<Option<String>, CustomUserError>;
+
+/// Type alias to represent the function used to compute the help message of a prompt
+/// on every render, e.g. to show how many characters are left.
+///
+/// The function receives the current input and returns the help message to display,
+/// or `None` to display the static help message, if any.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::DynamicHelpMessage;
+///
+/// let help: DynamicHelpMessage = &|input| match 20usize.checked_sub(input.chars().count()) {
+///     Some(left) => Some(format!("{} characters left", left)),
+///     None => Some(String::from("Too long!")),
+/// };
+///
+/// assert_eq!(Some(String::from("15 characters left")), help("hello"));
+/// ```
+pub type DynamicHelpMessage<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Type alias to represent the function used to normalize the submitted input of
+/// [`Text`](crate::Text) and [`Password`](crate::Password) prompts.
+///
+/// The function receives the submitted input and returns the value that is then
+/// validated and returned by the prompt. Unlike formatters, which only affect how
+/// the answer is displayed, transformers change the answer itself.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::StringTransformer;
+///
+/// let transformer: StringTransformer = &|input| input.trim().to_lowercase();
+///
+/// assert_eq!(String::from("alice"), transformer("  Alice "));
+/// ```
+pub type StringTransformer<'a> = &'a dyn Fn(&str) -> String;
+
+/// Type alias to represent the function used to verify the input of a
+/// [`Password`](crate::Password) prompt, e.g. against a stored hash.
+///
+/// The function receives the submitted password and returns whether it is correct.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::PasswordVerifier;
+///
+/// let verifier: PasswordVerifier = &|password| password == "hunter2";
+///
+/// assert!(verifier("hunter2"));
+/// assert!(!verifier("hunter3"));
+/// ```
+pub type PasswordVerifier<'a> = &'a dyn Fn(&str) -> bool;
+
+/// Type alias to represent the function generating a password suggested to the
+/// user in a [`Password`](crate::Password) prompt, e.g. when setting a new one.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::PasswordGenerator;
+///
+/// let generator: PasswordGenerator = &|| String::from("correct-horse-battery-staple");
+///
+/// assert_eq!("correct-horse-battery-staple", generator());
+/// ```
+pub type PasswordGenerator<'a> = &'a dyn Fn() -> String;
+
+/// Type alias to represent the function used to order the options of a
+/// [`Select`](crate::Select) or [`MultiSelect`](crate::MultiSelect) prompt
+/// while the filter input is empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use inquire::type_aliases::OptionOrdering;
+///
+/// // shortest options first
+/// let ordering: OptionOrdering<&str> = &|a, b| a.len().cmp(&b.len());
+///
+/// assert_eq!(Ordering::Less, ordering(&"ab", &"abc"));
+/// ```
+pub type OptionOrdering<'a, T> = &'a dyn Fn(&T, &T) -> std::cmp::Ordering;
+
+/// Type alias to represent the function used to describe the highlighted option
+/// of a [`Select`](crate::Select) prompt in its help area.
+///
+/// The function receives the highlighted option and returns the description to
+/// display, or `None` to display the static help message, if any.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionDescription;
+///
+/// let description: OptionDescription<&str> = &|command| match *command {
+///     "build" => Some(String::from("Compile the current package")),
+///     _ => None,
+/// };
+///
+/// assert_eq!(Some(String::from("Compile the current package")), description(&"build"));
+/// assert_eq!(None, description(&"clean"));
+/// ```
+pub type OptionDescription<'a, T> = &'a dyn Fn(&T) -> Option<String>;
+
+/// Type alias to represent the function returning the secondary text of an
+/// option, rendered right-aligned on its row in a [`Select`](crate::Select)
+/// prompt.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionSuffix;
+///
+/// let suffix: OptionSuffix<(&str, &str)> = &|(_, version)| Some(format!("v{}", version));
+///
+/// assert_eq!(Some(String::from("v1.2.3")), suffix(&("serde", "1.2.3")));
+/// ```
+pub type OptionSuffix<'a, T> = &'a dyn Fn(&T) -> Option<String>;
+
+/// Type alias to represent the function used to style each option row of a
+/// [`Select`](crate::Select) or [`MultiSelect`](crate::MultiSelect) prompt.
+///
+/// The function receives the option and whether it is highlighted by the
+/// cursor, and returns the style sheet its label is rendered with. The style of
+/// highlighted options set in the render config is applied on top of it.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::type_aliases::OptionStyle;
+/// use inquire::ui::{Color, StyleSheet};
+///
+/// let style: OptionStyle<&str> = &|line, _highlighted| match *line {
+///     l if l.starts_with("error") => StyleSheet::new().with_fg(Color::LightRed),
+///     l if l.starts_with("warning") => StyleSheet::new().with_fg(Color::LightYellow),
+///     _ => StyleSheet::new(),
+/// };
+///
+/// assert_eq!(Some(Color::LightRed), style(&"error: disk full", false).fg);
+/// assert_eq!(None, style(&"info: all good", true).fg);
+/// ```
+pub type OptionStyle<'a, T> = &'a dyn Fn(&T, bool) -> crate::ui::StyleSheet;
+
+/// Type alias to represent the function used to disable dates in a
+/// [`DateSelect`](crate::DateSelect) prompt.
+///
+/// The function receives a date and returns whether it can not be selected.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{Datelike, NaiveDate, Weekday};
+/// use inquire::type_aliases::DisabledDatePredicate;
+///
+/// let weekends: DisabledDatePredicate = &|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+///
+/// assert!(weekends(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()));
+/// assert!(!weekends(NaiveDate::from_ymd_opt(2023, 1, 2).unwrap()));
+/// ```
+#[cfg(feature = "date")]
+pub type DisabledDatePredicate<'a> = &'a dyn Fn(chrono::NaiveDate) -> bool;
@@ -165,4 +165,263 @@ impl Color {
     pub fn rgb(r: u8, g: u8, b: u8) -> Color {
         Color::Rgb { r, g, b }
     }
+
+    /// Maps the color to the nearest one that can be displayed with the given
+    /// color depth. Colors already supported by the depth are returned as is.
+    ///
+    /// ```
+    /// # use inquire::ui::{Color, ColorDepth};
+    ///
+    /// assert_eq!(Color::rgb(250, 10, 10).downsample_to(ColorDepth::Ansi16), Color::LightRed);
+    /// assert_eq!(Color::rgb(255, 0, 0).downsample_to(ColorDepth::Ansi256), Color::AnsiValue(196));
+    /// assert_eq!(Color::rgb(1, 2, 3).downsample_to(ColorDepth::TrueColor), Color::rgb(1, 2, 3));
+    /// ```
+    pub fn downsample_to(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (Color::Rgb { r, g, b }, ColorDepth::Ansi256) => {
+                Color::AnsiValue(nearest_ansi256(r, g, b))
+            }
+            (Color::Rgb { r, g, b }, ColorDepth::Ansi16) => {
+                Color::from_ansi16(nearest_ansi16(r, g, b))
+            }
+            (Color::AnsiValue(v), ColorDepth::Ansi16) if v < 16 => Color::from_ansi16(v),
+            (Color::AnsiValue(v), ColorDepth::Ansi16) => {
+                let (r, g, b) = ansi256_to_rgb(v);
+                Color::from_ansi16(nearest_ansi16(r, g, b))
+            }
+            _ => self,
+        }
+    }
+
+    fn from_ansi16(index: u8) -> Color {
+        match index {
+            0 => Color::Black,
+            1 => Color::DarkRed,
+            2 => Color::DarkGreen,
+            3 => Color::DarkYellow,
+            4 => Color::DarkBlue,
+            5 => Color::DarkMagenta,
+            6 => Color::DarkCyan,
+            7 => Color::Grey,
+            8 => Color::DarkGrey,
+            9 => Color::LightRed,
+            10 => Color::LightGreen,
+            11 => Color::LightYellow,
+            12 => Color::LightBlue,
+            13 => Color::LightMagenta,
+            14 => Color::LightCyan,
+            _ => Color::White,
+        }
+    }
+}
+
+/// Amount of colors a terminal is able to display, used to downsample colors
+/// it does not support. See [`Color::downsample_to`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// The 16 named colors.
+    Ansi16,
+
+    /// The 256 colors of [`Color::AnsiValue`], along with the named ones.
+    Ansi256,
+
+    /// Any [`Color::Rgb`] color, also known as truecolor.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the current terminal from the `COLORTERM`
+    /// environment variable and the terminal name in `TERM`, as listed in the
+    /// terminfo database, e.g. `xterm-256color` or `xterm-direct`.
+    ///
+    /// Defaults to [`ColorDepth::Ansi16`] when the depth can not be determined,
+    /// except on Windows, whose terminals support truecolor since Windows 10.
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").ok();
+        let term = std::env::var("TERM").ok();
+
+        Self::from_env(colorterm.as_deref(), term.as_deref())
+    }
+
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if let Some("truecolor" | "24bit") = colorterm {
+            return Self::TrueColor;
+        }
+
+        match term {
+            Some(term) if term.ends_with("-direct") || term.contains("truecolor") => {
+                Self::TrueColor
+            }
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            None if cfg!(windows) => Self::TrueColor,
+            _ => Self::Ansi16,
+        }
+    }
+}
+
+/// Default RGB values of the 16 named colors, as used by xterm.
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (128, 0, 0),
+    (0, 128, 0),
+    (128, 128, 0),
+    (0, 0, 128),
+    (128, 0, 128),
+    (0, 128, 128),
+    (192, 192, 192),
+    (128, 128, 128),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (0, 0, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Intensities of each component in the 6x6x6 color cube of the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    (0..16u8)
+        .min_by_key(|&i| distance((r, g, b), ANSI16_PALETTE[usize::from(i)]))
+        .unwrap_or(0)
+}
+
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let nearest_level = |c: u8| {
+        (0..6u8)
+            .min_by_key(|&i| CUBE_LEVELS[usize::from(i)].abs_diff(c))
+            .unwrap_or(0)
+    };
+    let cube = 16 + 36 * nearest_level(r) + 6 * nearest_level(g) + nearest_level(b);
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 232 + gray_step;
+
+    let rgb = (r, g, b);
+    match distance(rgb, ansi256_to_rgb(gray)) < distance(rgb, ansi256_to_rgb(cube)) {
+        true => gray,
+        false => cube,
+    }
+}
+
+fn ansi256_to_rgb(value: u8) -> (u8, u8, u8) {
+    match value {
+        0..=15 => ANSI16_PALETTE[usize::from(value)],
+        16..=231 => {
+            let i = value - 16;
+            let level = |c: u8| CUBE_LEVELS[usize::from(c)];
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let c = 8 + 10 * (value - 232);
+            (c, c, c)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Color, ColorDepth};
+
+    #[test]
+    fn rgb_is_downsampled_to_nearest_ansi16_color() {
+        let table = [
+            ((0, 0, 0), 0),
+            ((140, 10, 10), 1),
+            ((10, 120, 20), 2),
+            ((130, 120, 0), 3),
+            ((0, 0, 110), 4),
+            ((120, 0, 140), 5),
+            ((0, 130, 120), 6),
+            ((200, 190, 185), 7),
+            ((110, 120, 125), 8),
+            ((250, 10, 10), 9),
+            ((20, 240, 20), 10),
+            ((255, 250, 40), 11),
+            ((30, 30, 230), 12),
+            ((240, 20, 250), 13),
+            ((10, 245, 255), 14),
+            ((250, 250, 250), 15),
+        ];
+
+        for ((r, g, b), index) in table {
+            assert_eq!(
+                Color::from_ansi16(index),
+                Color::rgb(r, g, b).downsample_to(ColorDepth::Ansi16),
+                "rgb({}, {}, {})",
+                r,
+                g,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn rgb_is_downsampled_to_nearest_ansi256_color() {
+        assert_eq!(
+            Color::AnsiValue(16),
+            Color::rgb(0, 0, 0).downsample_to(ColorDepth::Ansi256)
+        );
+        assert_eq!(
+            Color::AnsiValue(231),
+            Color::rgb(255, 255, 255).downsample_to(ColorDepth::Ansi256)
+        );
+        assert_eq!(
+            Color::AnsiValue(208),
+            Color::rgb(255, 135, 0).downsample_to(ColorDepth::Ansi256)
+        );
+        assert_eq!(
+            Color::AnsiValue(244),
+            Color::rgb(128, 128, 128).downsample_to(ColorDepth::Ansi256)
+        );
+    }
+
+    #[test]
+    fn ansi256_is_downsampled_to_ansi16() {
+        assert_eq!(
+            Color::LightRed,
+            Color::AnsiValue(9).downsample_to(ColorDepth::Ansi16)
+        );
+        assert_eq!(
+            Color::LightRed,
+            Color::AnsiValue(196).downsample_to(ColorDepth::Ansi16)
+        );
+        assert_eq!(
+            Color::DarkGrey,
+            Color::AnsiValue(244).downsample_to(ColorDepth::Ansi16)
+        );
+    }
+
+    #[test]
+    fn named_colors_are_kept() {
+        assert_eq!(
+            Color::LightCyan,
+            Color::LightCyan.downsample_to(ColorDepth::Ansi16)
+        );
+    }
+
+    #[test]
+    fn color_depth_is_detected_from_env() {
+        let cases = [
+            (Some("truecolor"), Some("xterm"), ColorDepth::TrueColor),
+            (Some("24bit"), None, ColorDepth::TrueColor),
+            (None, Some("xterm-direct"), ColorDepth::TrueColor),
+            (None, Some("xterm-256color"), ColorDepth::Ansi256),
+            (None, Some("screen-256color"), ColorDepth::Ansi256),
+            (None, Some("xterm"), ColorDepth::Ansi16),
+            (None, Some("dumb"), ColorDepth::Ansi16),
+        ];
+
+        for (colorterm, term, expected) in cases {
+            assert_eq!(expected, ColorDepth::from_env(colorterm, term));
+        }
+    }
 }
@@ -3,34 +3,84 @@ use bitflags::bitflags;
 // Using the same struct, but without importing, to cut prompts' direct dependencies to crossterm
 // https://github.com/crossterm-rs/crossterm/blob/e1260446e94e9a8f7809fef61dc1369b6f8d6e12/src/event.rs#L376-L385
 bitflags! {
+    /// Modifier keys held while a key was pressed.
     #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
     pub struct KeyModifiers: u8 {
+        /// Shift key.
         const SHIFT = 0b0000_0001;
+        /// Control key.
         const CONTROL = 0b0000_0010;
+        /// Alt key.
         const ALT = 0b0000_0100;
+        /// Super key.
         const SUPER = 0b0000_1000;
+        /// Hyper key.
         const HYPER = 0b0001_0000;
+        /// Meta key.
         const META = 0b0010_0000;
+        /// No modifiers.
         const NONE = 0b0000_0000;
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Key event read from the terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Key {
+    /// Escape key.
     Escape,
+    /// Enter key.
     Enter,
+    /// Backspace key.
     Backspace,
+    /// Tab key.
     Tab,
+    /// Delete key.
     Delete(KeyModifiers),
+    /// Home key.
     Home,
+    /// End key.
     End,
+    /// Page up key.
     PageUp(KeyModifiers),
+    /// Page down key.
     PageDown(KeyModifiers),
+    /// Up arrow key.
     Up(KeyModifiers),
+    /// Down arrow key.
     Down(KeyModifiers),
+    /// Left arrow key.
     Left(KeyModifiers),
+    /// Right arrow key.
     Right(KeyModifiers),
+    /// Character key, e.g. a letter, a digit or a symbol.
     Char(char, KeyModifiers),
+    /// Text pasted into the terminal at once.
+    ///
+    /// Only reported by terminal back-ends supporting bracketed paste,
+    /// currently `crossterm`. Elsewhere, pasted text arrives key by key.
+    Paste(String),
+    /// Mouse wheel scrolled up.
+    ///
+    /// Mouse events are only reported by the `crossterm` back-end, when mouse
+    /// support is enabled in the prompt, e.g. with `Select::with_mouse_support`.
+    ScrollUp,
+    /// Mouse wheel scrolled down.
+    ///
+    /// Mouse events are only reported by the `crossterm` back-end, when mouse
+    /// support is enabled in the prompt, e.g. with `Select::with_mouse_support`.
+    ScrollDown,
+    /// Left mouse button pressed.
+    ///
+    /// The row and column are relative to the top-left corner of the prompt.
+    /// Mouse events are only reported by the `crossterm` back-end, when mouse
+    /// support is enabled in the prompt, e.g. with `Select::with_mouse_support`.
+    Click {
+        /// Row of the click, 0 being the first row of the prompt.
+        row: u16,
+        /// Column of the click, 0 being the first column of the terminal.
+        column: u16,
+    },
+    /// Any other key not mapped by this library.
     #[deprecated(note = "If the key you want isn't mapped, please open a PR.")]
     Any,
 }
@@ -40,7 +90,7 @@ pub(crate) mod key_test {
     use super::{Key, KeyModifiers};
 
     impl Key {
-        pub fn char_keys_from_str(s: &str) -> Vec<Self> {
+        pub(crate) fn char_keys_from_str(s: &str) -> Vec<Self> {
             s.chars()
                 .map(|c| Key::Char(c, KeyModifiers::NONE))
                 .collect()
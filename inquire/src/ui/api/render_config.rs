@@ -1,6 +1,11 @@
 use std::env;
 
-use super::{Color, StyleSheet, Styled};
+use super::{Attributes, Color, ColorDepth, StyleSheet, Styled};
+
+const DEFAULT_SPINNER_FRAMES: &[&str] = &["-", "\\", "|", "/"];
+
+/// Environment variable disabling animations in the default render config.
+const NO_ANIMATIONS_ENV_VAR: &str = "INQUIRE_NO_ANIM";
 
 /// Rendering configuration that can be applied to a prompt.
 ///
@@ -19,6 +24,49 @@ use super::{Color, StyleSheet, Styled};
 /// let prompt_prefix = Styled::new("$").with_fg(Color::DarkRed);
 /// let mine = default.with_prompt_prefix(prompt_prefix);
 /// ```
+///
+/// Every `with_*` method takes and returns the configuration by value, so a
+/// whole theme can be built in a single expression:
+///
+/// ```
+/// use inquire::ui::{Attributes, Color, ErrorMessageRenderConfig, RenderConfig, StyleSheet, Styled};
+///
+/// let theme = RenderConfig::empty()
+///     .with_prompt_prefix(Styled::new("λ").with_fg(Color::LightMagenta))
+///     .with_prompt(StyleSheet::new().with_attr(Attributes::BOLD))
+///     .with_placeholder(StyleSheet::new().with_fg(Color::DarkGrey))
+///     .with_help_message(StyleSheet::new().with_fg(Color::DarkCyan))
+///     .with_password_mask('•')
+///     .with_error_message(
+///         ErrorMessageRenderConfig::empty()
+///             .with_prefix(Styled::new("✗").with_fg(Color::LightRed))
+///             .with_message(StyleSheet::new().with_fg(Color::LightRed))
+///             .with_default_message("That does not look right."),
+///     )
+///     .with_answer(StyleSheet::new().with_fg(Color::LightGreen));
+///
+/// assert_eq!('•', theme.password_mask);
+/// assert_eq!("That does not look right.", theme.error_message.default_message);
+/// ```
+///
+/// Render configurations are `Copy`: they only hold style sheets, characters
+/// and borrowed strings, so passing one by value to a prompt is a plain copy
+/// without any allocation. A configuration shared by many prompts, e.g. in a
+/// loop, can be passed by dereferencing it:
+///
+/// ```
+/// use inquire::{ui::RenderConfig, Text};
+///
+/// fn assert_copy<T: Copy>(_: &T) {}
+///
+/// let theme: &RenderConfig = &RenderConfig::default_colored();
+/// assert_copy(theme);
+///
+/// let prompts: Vec<Text> = ["Name:", "Email:", "Phone:"]
+///     .into_iter()
+///     .map(|message| Text::new(message).with_render_config(*theme))
+///     .collect();
+/// ```
 #[derive(Copy, Clone, Debug)]
 pub struct RenderConfig<'a> {
     /// Prefix added before prompts.
@@ -33,6 +81,16 @@ pub struct RenderConfig<'a> {
     /// and the prompt message.
     pub answered_prompt_prefix: Styled<&'a str>,
 
+    /// Position of the prompt in a sequence of prompts, as a `(current, total)`
+    /// pair, displayed between the prompt prefix and the prompt message.
+    ///
+    /// Note: the step is displayed as `[current/total]`, with a non-styled
+    /// space character added after it as a separator from the prompt message.
+    pub step_indicator: Option<(usize, usize)>,
+
+    /// Style sheet of the step indicator.
+    pub step_indicator_style: StyleSheet,
+
     /// Style of the prompt message, applicable to all prompt types.
     pub prompt: StyleSheet,
 
@@ -68,6 +126,31 @@ pub struct RenderConfig<'a> {
     /// a separator from the prompt message (or default value display).
     pub text_input: StyleSheet,
 
+    /// Frames of the loading indicator displayed next to text inputs while
+    /// suggestions are being computed in the background, cycled in order.
+    pub spinner_frames: &'a [&'a str],
+
+    /// Style sheet of the loading indicator.
+    ///
+    /// Note: a non-styled space character is added before the loading indicator
+    /// as a separator from the text input.
+    pub spinner: StyleSheet,
+
+    /// Whether animated elements, such as the loading indicator, are animated.
+    /// When disabled, they are rendered in a static state and prompts are not
+    /// redrawn only to animate them.
+    ///
+    /// The [default](RenderConfig::default) render config disables animations
+    /// when the `NO_COLOR` or the `INQUIRE_NO_ANIM` environment variable is set.
+    pub animations_enabled: bool,
+
+    /// Shape of the terminal cursor while a prompt is active.
+    ///
+    /// When set, the terminal's default cursor shape is restored once the prompt
+    /// finishes. Not every terminal back-end supports changing the cursor shape,
+    /// in which case this setting is ignored.
+    pub cursor_shape: Option<CursorShape>,
+
     /// Render configuration of final prompt answers (submissions).
     ///
     /// Note: a non-styled space character is added before the answer as
@@ -84,6 +167,14 @@ pub struct RenderConfig<'a> {
     /// Render configuration for error messages.
     pub error_message: ErrorMessageRenderConfig<'a>,
 
+    /// Render configuration for warning messages, returned by validators
+    /// through [`Validation::Warning`](crate::validator::Validation::Warning).
+    pub warning_message: ErrorMessageRenderConfig<'a>,
+
+    /// Render configuration for errors returned by the autocompleter of a
+    /// [`Text`](crate::Text) prompt, displayed above the prompt like validation errors.
+    pub suggestion_error: ErrorMessageRenderConfig<'a>,
+
     /// Prefix for the current highlighted option.
     ///
     /// Note: a space character will be added to separate the prefix
@@ -134,6 +225,40 @@ pub struct RenderConfig<'a> {
     /// a separator from the prefix.
     pub selected_option: Option<StyleSheet>,
 
+    /// Style sheet applied on top of the option style to the parts of an option
+    /// matching the filter input, as reported by a
+    /// [`HighlightingScorer`](crate::type_aliases::HighlightingScorer).
+    pub option_match: StyleSheet,
+
+    /// Style sheet applied on top of the option style to the mandatory options
+    /// of multi-select prompts, which can not be unchecked.
+    pub mandatory_option: StyleSheet,
+
+    /// Whether to draw a scrollbar on the right of option lists that do not
+    /// fit in a single page, indicating the position of the current page.
+    ///
+    /// The scrollbar is drawn with the style sheet of options.
+    pub show_scrollbar: bool,
+
+    /// Whether options that have an URL, see [`Linkable`](crate::list_option::Linkable),
+    /// are rendered as [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+    /// hyperlinks, which supporting terminals make clickable.
+    pub emit_hyperlinks: bool,
+
+    /// Color depth to render colors with, overriding the one detected from the
+    /// terminal by [`ColorDepth::detect`].
+    ///
+    /// Colors that can not be displayed with the color depth are replaced by
+    /// the nearest ones that can, see [`Color::downsample_to`].
+    pub force_color_depth: Option<ColorDepth>,
+
+    /// Number of columns left blank at the start of every rendered line,
+    /// indenting the whole prompt.
+    ///
+    /// The margin is subtracted from the width available to the prompt, so
+    /// content is wrapped before reaching the edge of the terminal.
+    pub left_margin: u16,
+
     /// Render configuration for calendar
 
     #[cfg(feature = "date")]
@@ -154,12 +279,20 @@ impl<'a> RenderConfig<'a> {
         Self {
             prompt_prefix: Styled::new("?"),
             answered_prompt_prefix: Styled::new("?"),
+            step_indicator: None,
+            step_indicator_style: StyleSheet::empty(),
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::empty(),
             help_message: StyleSheet::empty(),
             text_input: StyleSheet::empty(),
+            spinner_frames: DEFAULT_SPINNER_FRAMES,
+            spinner: StyleSheet::empty(),
+            animations_enabled: true,
+            cursor_shape: None,
             error_message: ErrorMessageRenderConfig::empty(),
+            warning_message: ErrorMessageRenderConfig::empty_warning(),
+            suggestion_error: ErrorMessageRenderConfig::empty_suggestion_error(),
             answer: StyleSheet::empty(),
             canceled_prompt_indicator: Styled::new("<canceled>"),
             password_mask: '*',
@@ -171,6 +304,12 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: None,
+            option_match: StyleSheet::empty(),
+            mandatory_option: StyleSheet::empty(),
+            show_scrollbar: false,
+            emit_hyperlinks: false,
+            force_color_depth: None,
+            left_margin: 0,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::empty(),
@@ -185,12 +324,20 @@ impl<'a> RenderConfig<'a> {
         Self {
             prompt_prefix: Styled::new("?").with_fg(Color::LightGreen),
             answered_prompt_prefix: Styled::new(">").with_fg(Color::LightGreen),
+            step_indicator: None,
+            step_indicator_style: StyleSheet::new().with_fg(Color::DarkGrey),
             prompt: StyleSheet::empty(),
             default_value: StyleSheet::empty(),
             placeholder: StyleSheet::new().with_fg(Color::DarkGrey),
             help_message: StyleSheet::empty().with_fg(Color::LightCyan),
             text_input: StyleSheet::empty(),
+            spinner_frames: DEFAULT_SPINNER_FRAMES,
+            spinner: StyleSheet::empty().with_fg(Color::LightCyan),
+            animations_enabled: true,
+            cursor_shape: None,
             error_message: ErrorMessageRenderConfig::default_colored(),
+            warning_message: ErrorMessageRenderConfig::default_colored_warning(),
+            suggestion_error: ErrorMessageRenderConfig::default_colored_suggestion_error(),
             password_mask: '*',
             answer: StyleSheet::empty().with_fg(Color::LightCyan),
             canceled_prompt_indicator: Styled::new("<canceled>").with_fg(Color::DarkRed),
@@ -202,6 +349,12 @@ impl<'a> RenderConfig<'a> {
             option_index_prefix: IndexPrefix::None,
             option: StyleSheet::empty(),
             selected_option: Some(StyleSheet::new().with_fg(Color::LightCyan)),
+            option_match: StyleSheet::new().with_attr(Attributes::BOLD),
+            mandatory_option: StyleSheet::new().with_attr(Attributes::ITALIC),
+            show_scrollbar: false,
+            emit_hyperlinks: false,
+            force_color_depth: None,
+            left_margin: 0,
 
             #[cfg(feature = "date")]
             calendar: calendar::CalendarRenderConfig::default_colored(),
@@ -223,12 +376,67 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the position of the prompt in a sequence of prompts, displayed
+    /// as `[current/total]` before the prompt message.
+    pub fn with_step_indicator(mut self, current: usize, total: usize) -> Self {
+        self.step_indicator = Some((current, total));
+        self
+    }
+
+    /// Sets the style sheet of the step indicator.
+    pub fn with_step_indicator_style(mut self, step_indicator_style: StyleSheet) -> Self {
+        self.step_indicator_style = step_indicator_style;
+        self
+    }
+
+    /// Sets the style sheet for prompt messages.
+    pub fn with_prompt(mut self, prompt: StyleSheet) -> Self {
+        self.prompt = prompt;
+        self
+    }
+
+    /// Sets the style sheet for placeholders.
+    pub fn with_placeholder(mut self, placeholder: StyleSheet) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Sets the character used to mask password inputs.
+    pub fn with_password_mask(mut self, password_mask: char) -> Self {
+        self.password_mask = password_mask;
+        self
+    }
+
     /// Sets style for text inputs.
     pub fn with_text_input(mut self, text_input: StyleSheet) -> Self {
         self.text_input = text_input;
         self
     }
 
+    /// Sets the frames of the loading indicator.
+    pub fn with_spinner_frames(mut self, spinner_frames: &'a [&'a str]) -> Self {
+        self.spinner_frames = spinner_frames;
+        self
+    }
+
+    /// Sets the style sheet for the loading indicator.
+    pub fn with_spinner(mut self, spinner: StyleSheet) -> Self {
+        self.spinner = spinner;
+        self
+    }
+
+    /// Sets whether animated elements, such as the loading indicator, are animated.
+    pub fn with_animations(mut self, animations_enabled: bool) -> Self {
+        self.animations_enabled = animations_enabled;
+        self
+    }
+
+    /// Sets the shape of the terminal cursor while prompts are active.
+    pub fn with_cursor_shape(mut self, cursor_shape: CursorShape) -> Self {
+        self.cursor_shape = Some(cursor_shape);
+        self
+    }
+
     /// Sets the style sheet for default values.
     pub fn with_default_value(mut self, default_value: StyleSheet) -> Self {
         self.default_value = default_value;
@@ -253,6 +461,18 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the render configuration for warning messages.
+    pub fn with_warning_message(mut self, warning_message: ErrorMessageRenderConfig<'a>) -> Self {
+        self.warning_message = warning_message;
+        self
+    }
+
+    /// Sets the render configuration for errors returned by autocompleters.
+    pub fn with_suggestion_error(mut self, suggestion_error: ErrorMessageRenderConfig<'a>) -> Self {
+        self.suggestion_error = suggestion_error;
+        self
+    }
+
     /// Sets the styled component for prefixes in highlighted options.
     pub fn with_highlighted_option_prefix(
         mut self,
@@ -304,6 +524,43 @@ impl<'a> RenderConfig<'a> {
         self
     }
 
+    /// Sets the style sheet for the parts of options matching the filter input.
+    pub fn with_option_match(mut self, option_match: StyleSheet) -> Self {
+        self.option_match = option_match;
+        self
+    }
+
+    /// Sets the style sheet applied to the mandatory options of multi-select prompts.
+    pub fn with_mandatory_option(mut self, mandatory_option: StyleSheet) -> Self {
+        self.mandatory_option = mandatory_option;
+        self
+    }
+
+    /// Sets whether to draw a scrollbar on the right of paginated option lists.
+    pub fn with_scrollbar(mut self, show_scrollbar: bool) -> Self {
+        self.show_scrollbar = show_scrollbar;
+        self
+    }
+
+    /// Sets whether to render options that have an URL as hyperlinks.
+    pub fn with_hyperlinks(mut self, emit_hyperlinks: bool) -> Self {
+        self.emit_hyperlinks = emit_hyperlinks;
+        self
+    }
+
+    /// Sets the color depth to render colors with, instead of the one detected
+    /// from the terminal.
+    pub fn with_color_depth(mut self, color_depth: ColorDepth) -> Self {
+        self.force_color_depth = Some(color_depth);
+        self
+    }
+
+    /// Sets the number of columns left blank at the start of every rendered line.
+    pub fn with_left_margin(mut self, left_margin: u16) -> Self {
+        self.left_margin = left_margin;
+        self
+    }
+
     /// Sets the indicator for canceled prompts.
     pub fn with_canceled_prompt_indicator(
         mut self,
@@ -330,10 +587,15 @@ impl<'a> RenderConfig<'a> {
 
 impl<'a> Default for RenderConfig<'a> {
     fn default() -> Self {
-        match env::var("NO_COLOR") {
+        let config = match env::var("NO_COLOR") {
             Ok(_) => Self::empty(),
             Err(_) => Self::default_colored(),
-        }
+        };
+
+        let no_animations =
+            env::var_os("NO_COLOR").is_some() || env::var_os(NO_ANIMATIONS_ENV_VAR).is_some();
+
+        config.with_animations(!no_animations)
     }
 }
 
@@ -373,6 +635,19 @@ pub enum IndexPrefix {
     ZeroPadded,
 }
 
+/// Shapes of the terminal cursor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CursorShape {
+    /// A block covering the whole character cell.
+    Block,
+
+    /// An underline below the character cell.
+    Underline,
+
+    /// A vertical bar on the left side of the character cell.
+    Bar,
+}
+
 /// Render configuration for error messages.
 #[derive(Copy, Clone, Debug)]
 pub struct ErrorMessageRenderConfig<'a> {
@@ -413,6 +688,46 @@ impl<'a> ErrorMessageRenderConfig<'a> {
         }
     }
 
+    /// Render configuration for warning messages in which no colors or attributes are applied.
+    pub fn empty_warning() -> Self {
+        Self {
+            prefix: Styled::new("!"),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty(),
+            default_message: "Please double-check your input. Submit it again to confirm.",
+        }
+    }
+
+    /// Render configuration for warning messages where default colors and attributes are applied.
+    pub fn default_colored_warning() -> Self {
+        Self {
+            prefix: Styled::new("!").with_fg(Color::LightYellow),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty().with_fg(Color::LightYellow),
+            default_message: "Please double-check your input. Submit it again to confirm.",
+        }
+    }
+
+    /// Render configuration for autocompleter errors in which no colors or attributes are applied.
+    pub fn empty_suggestion_error() -> Self {
+        Self {
+            prefix: Styled::new("#"),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty(),
+            default_message: "Suggestions could not be loaded.",
+        }
+    }
+
+    /// Render configuration for autocompleter errors where default colors and attributes are applied.
+    pub fn default_colored_suggestion_error() -> Self {
+        Self {
+            prefix: Styled::new("#").with_fg(Color::LightRed),
+            separator: StyleSheet::empty(),
+            message: StyleSheet::empty().with_fg(Color::DarkGrey),
+            default_message: "Suggestions could not be loaded.",
+        }
+    }
+
     /// Sets the prefix.
     pub fn with_prefix(mut self, prefix: Styled<&'a str>) -> Self {
         self.prefix = prefix;
@@ -433,6 +748,12 @@ impl<'a> ErrorMessageRenderConfig<'a> {
         self.message = message;
         self
     }
+
+    /// Sets the default message, used for validators that do not define custom messages.
+    pub fn with_default_message(mut self, default_message: &'a str) -> Self {
+        self.default_message = default_message;
+        self
+    }
 }
 
 #[cfg(feature = "date")]
@@ -473,6 +794,10 @@ pub mod calendar {
         /// Style sheet for dates that can not be selected due to the
         /// min/max settings.
         pub unavailable_date: StyleSheet,
+
+        /// Style sheet for the ISO week numbers displayed before each week row,
+        /// when enabled.
+        pub week_number: StyleSheet,
     }
 
     impl<'a> CalendarRenderConfig<'a> {
@@ -486,6 +811,7 @@ pub mod calendar {
                 today_date: StyleSheet::empty(),
                 different_month_date: StyleSheet::empty(),
                 unavailable_date: StyleSheet::empty(),
+                week_number: StyleSheet::empty(),
             }
         }
 
@@ -503,6 +829,7 @@ pub mod calendar {
                 today_date: StyleSheet::empty().with_fg(Color::LightGreen),
                 different_month_date: StyleSheet::empty().with_fg(Color::DarkGrey),
                 unavailable_date: StyleSheet::empty().with_fg(Color::DarkGrey),
+                week_number: StyleSheet::empty().with_fg(Color::DarkGrey),
             }
         }
 
@@ -511,5 +838,47 @@ pub mod calendar {
             self.prefix = prefix;
             self
         }
+
+        /// Sets the style sheet for the calendar header.
+        pub fn with_header(mut self, header: StyleSheet) -> Self {
+            self.header = header;
+            self
+        }
+
+        /// Sets the style sheet for the calendar week header.
+        pub fn with_week_header(mut self, week_header: StyleSheet) -> Self {
+            self.week_header = week_header;
+            self
+        }
+
+        /// Sets the style sheet for the currently selected date.
+        pub fn with_selected_date(mut self, selected_date: Option<StyleSheet>) -> Self {
+            self.selected_date = selected_date;
+            self
+        }
+
+        /// Sets the style sheet for today's date.
+        pub fn with_today_date(mut self, today_date: StyleSheet) -> Self {
+            self.today_date = today_date;
+            self
+        }
+
+        /// Sets the style sheet for dates of the previous or next months.
+        pub fn with_different_month_date(mut self, different_month_date: StyleSheet) -> Self {
+            self.different_month_date = different_month_date;
+            self
+        }
+
+        /// Sets the style sheet for dates that can not be selected.
+        pub fn with_unavailable_date(mut self, unavailable_date: StyleSheet) -> Self {
+            self.unavailable_date = unavailable_date;
+            self
+        }
+
+        /// Sets the style sheet for week numbers.
+        pub fn with_week_number(mut self, week_number: StyleSheet) -> Self {
+            self.week_number = week_number;
+            self
+        }
     }
 }
@@ -1,19 +1,33 @@
-use std::{collections::BTreeSet, fmt::Display, io::Result};
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    io::Result,
+    ops::{Range, RangeInclusive},
+    time::Duration,
+};
 
-use unicode_width::UnicodeWidthStr;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{
+    ansi::AnsiStrippable,
     error::InquireResult,
     input::Input,
     list_option::ListOption,
     terminal::Terminal,
-    ui::{IndexPrefix, Key, RenderConfig, Styled},
+    ui::{
+        dimension::{display_width, wrap_words, DEFAULT_WRAP_WIDTH},
+        ColorDepth, ErrorMessageRenderConfig, IndexPrefix, Key, RenderConfig, StyleSheet, Styled,
+    },
     utils::{int_log10, Page},
     validator::ErrorMessage,
 };
 
 use super::{frame_renderer::FrameRenderer, InputReader};
 
+const SCROLLBAR_THUMB: &str = "█";
+const SCROLLBAR_TRACK: &str = "░";
+
 pub trait CommonBackend: InputReader {
     fn frame_setup(&mut self) -> Result<()>;
     fn frame_finish(&mut self, is_last_frame: bool) -> Result<()>;
@@ -22,7 +36,25 @@ pub trait CommonBackend: InputReader {
     fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> Result<()>;
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()>;
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()>;
     fn render_help_message(&mut self, help: &str) -> Result<()>;
+
+    /// Height of the terminal, if it can be retrieved.
+    fn terminal_height(&self) -> Option<u16>;
+
+    /// Rows taken by the error message once wrapped to the terminal width.
+    fn error_message_rows(&self, error: &ErrorMessage) -> usize;
+    /// Rows taken by the warning message once wrapped to the terminal width.
+    fn warning_message_rows(&self, warning: &ErrorMessage) -> usize;
+    /// Rows taken by the help message once wrapped to the terminal width.
+    fn help_message_rows(&self, help: &str) -> usize;
+
+    /// Starts reporting mouse events as keys, if supported by the terminal.
+    fn enable_mouse_capture(&mut self) -> Result<()>;
+
+    /// Row of the frame being rendered that the next content is written to,
+    /// 0 being the first row of the prompt.
+    fn frame_row(&self) -> u16;
 }
 
 pub trait TextBackend: CommonBackend {
@@ -32,17 +64,48 @@ pub trait TextBackend: CommonBackend {
         default: Option<&str>,
         cur_input: &Input,
     ) -> Result<()>;
+    fn render_prompt_with_spinner(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        spinner_frame: usize,
+    ) -> Result<()>;
+    fn render_prompt_with_inline_suggestion(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        inline_suggestion: &str,
+    ) -> Result<()>;
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_suggestion_error(&mut self, error: &ErrorMessage) -> Result<()>;
 }
 
 #[cfg(feature = "editor")]
 pub trait EditorBackend: CommonBackend {
     fn render_prompt(&mut self, prompt: &str, editor_command: &str) -> Result<()>;
+    fn render_inline_prompt(
+        &mut self,
+        prompt: &str,
+        input: &crate::input::MultilineInput,
+    ) -> Result<()>;
 }
 
 pub trait SelectBackend: CommonBackend {
     fn render_select_prompt(&mut self, prompt: &str, cur_input: Option<&Input>) -> Result<()>;
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()>;
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        option_styles: Option<&[StyleSheet]>,
+        highlights: Option<&[Vec<Range<usize>>]>,
+        suffixes: Option<&[Option<String>]>,
+    ) -> Result<()>;
+    fn render_inline_option<D: Display>(
+        &mut self,
+        prompt: &str,
+        option: &ListOption<D>,
+    ) -> Result<()>;
 }
 
 pub trait MultiSelectBackend: CommonBackend {
@@ -51,7 +114,11 @@ pub trait MultiSelectBackend: CommonBackend {
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        range: Option<RangeInclusive<usize>>,
+        option_styles: Option<&[StyleSheet]>,
+        highlights: Option<&[Vec<Range<usize>>]>,
     ) -> Result<()>;
+    fn render_empty_options(&mut self, message: &str) -> Result<()>;
 }
 
 pub trait CustomTypeBackend: CommonBackend {
@@ -92,15 +159,33 @@ where
 {
     #[allow(clippy::large_types_passed_by_value)]
     pub fn new(input_reader: I, terminal: T, render_config: RenderConfig<'a>) -> Result<Self> {
-        let backend = Self {
+        let mut backend = Self {
             frame_renderer: FrameRenderer::new(terminal)?,
             input_reader,
             render_config,
         };
 
+        let color_depth = match backend.render_config.force_color_depth {
+            Some(color_depth) => color_depth,
+            None => ColorDepth::detect(),
+        };
+        backend.frame_renderer.set_color_depth(color_depth);
+        backend
+            .frame_renderer
+            .set_left_margin(backend.render_config.left_margin);
+
+        if let Some(shape) = backend.render_config.cursor_shape {
+            backend.frame_renderer.set_cursor_shape(shape)?;
+        }
+
         Ok(backend)
     }
 
+    #[cfg(test)]
+    pub fn terminal_mut(&mut self) -> &mut T {
+        self.frame_renderer.terminal_mut()
+    }
+
     fn print_option_prefix<D: Display>(
         &mut self,
         option_relative_index: usize,
@@ -126,24 +211,65 @@ where
         option_relative_index: usize,
         option: &ListOption<D>,
         page: &Page<'_, ListOption<D>>,
+        option_styles: Option<&[StyleSheet]>,
+        highlights: Option<&[Vec<Range<usize>>]>,
     ) -> Result<()> {
-        let stylesheet = if let Some(selected_option_style) = self.render_config.selected_option {
-            match page.cursor {
-                Some(cursor) if cursor == option_relative_index => selected_option_style,
-                _ => self.render_config.option,
+        let base = option_styles
+            .and_then(|styles| styles.get(option_relative_index))
+            .copied();
+
+        let stylesheet = match (base, self.render_config.selected_option, page.cursor) {
+            (Some(base), Some(selected), Some(cursor)) if cursor == option_relative_index => {
+                overlay_style(base, selected)
+            }
+            (None, Some(selected), Some(cursor)) if cursor == option_relative_index => selected,
+            (Some(base), _, _) => base,
+            (None, _, _) => self.render_config.option,
+        };
+
+        let ranges = match highlights.and_then(|h| h.get(option_relative_index)) {
+            Some(ranges) if !ranges.is_empty() => ranges,
+            _ => {
+                return self
+                    .frame_renderer
+                    .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
             }
-        } else {
-            self.render_config.option
         };
 
+        let value = option.value.to_string();
+        let match_stylesheet = overlay_style(stylesheet, self.render_config.option_match);
+
+        let mut last = 0;
+        for range in ranges {
+            let before = value.get(last..range.start);
+            let matched = value.get(range.clone());
+
+            if let (Some(before), Some(matched)) = (before, matched) {
+                self.frame_renderer
+                    .write_styled(Styled::new(before).with_style_sheet(stylesheet))?;
+                self.frame_renderer
+                    .write_styled(Styled::new(matched).with_style_sheet(match_stylesheet))?;
+                last = range.end;
+            }
+        }
+
+        let rest = value.get(last..).unwrap_or_default();
         self.frame_renderer
-            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))
+            .write_styled(Styled::new(rest).with_style_sheet(stylesheet))
     }
 
     fn print_option_index_prefix(&mut self, index: usize, max_index: usize) -> Option<Result<()>> {
+        self.format_option_index_prefix(index, max_index)
+            .map(|prefix| {
+                self.frame_renderer
+                    .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))
+            })
+    }
+
+    fn format_option_index_prefix(&self, index: usize, max_index: usize) -> Option<String> {
         let index = index.saturating_add(1);
 
-        let content = match self.render_config.option_index_prefix {
+        match self.render_config.option_index_prefix {
             IndexPrefix::None => None,
             IndexPrefix::Simple => Some(format!("{index})")),
             IndexPrefix::SpacePadded => {
@@ -154,12 +280,84 @@ where
                 let width = int_log10(max_index.saturating_add(1));
                 Some(format!("{index:0width$})"))
             }
+        }
+    }
+
+    /// Width of the parts of an option row that vary between options, used
+    /// to align the scrollbar on the right of the option list.
+    fn option_row_width<D: Display>(&self, option: &ListOption<D>, total: usize) -> usize {
+        let index_prefix_width = match self.format_option_index_prefix(option.index, total) {
+            Some(prefix) => prefix.width() + 1,
+            None => 0,
         };
 
-        content.map(|prefix| {
-            self.frame_renderer
-                .write_styled(Styled::new(prefix).with_style_sheet(self.render_config.option))
-        })
+        let value = option.value.to_string();
+        let value_width = value.ansi_stripped_chars().collect::<String>().width();
+
+        index_prefix_width + value_width
+    }
+
+    /// Padding required by each row of the page so that the scrollbar is
+    /// aligned, or `None` when no scrollbar should be drawn.
+    fn scrollbar_paddings<D: Display>(&self, page: &Page<'_, ListOption<D>>) -> Option<Vec<usize>> {
+        if !self.render_config.show_scrollbar || page.scrollbar_thumb().is_none() {
+            return None;
+        }
+
+        let widths: Vec<usize> = page
+            .content
+            .iter()
+            .map(|option| self.option_row_width(option, page.total))
+            .collect();
+        let max_width = widths.iter().copied().max().unwrap_or_default();
+
+        Some(widths.into_iter().map(|w| max_width - w).collect())
+    }
+
+    fn print_scrollbar<D: Display>(
+        &mut self,
+        option_relative_index: usize,
+        padding: usize,
+        page: &Page<'_, ListOption<D>>,
+    ) -> Result<()> {
+        let (position, length) = match page.scrollbar_thumb() {
+            Some(thumb) => thumb,
+            None => return Ok(()),
+        };
+
+        let symbol = if (position..position + length).contains(&option_relative_index) {
+            SCROLLBAR_THUMB
+        } else {
+            SCROLLBAR_TRACK
+        };
+
+        self.frame_renderer.write(" ".repeat(padding + 1))?;
+        self.frame_renderer
+            .write_styled(Styled::new(symbol).with_style_sheet(self.render_config.option))
+    }
+
+    /// Prints the suffix of an option right-aligned in the current line, keeping
+    /// `reserved` columns free at its end, and the last column of the terminal
+    /// free as writing into it makes some terminals wrap the line.
+    fn print_option_suffix(&mut self, suffix: Option<&str>, reserved: usize) -> Result<()> {
+        let suffix = suffix.unwrap_or_default();
+
+        let width = match self.frame_renderer.terminal_width() {
+            Some(width) => width as usize,
+            None if suffix.is_empty() => return Ok(()),
+            None => return self.frame_renderer.write(format!(" {suffix}")),
+        };
+
+        let used = self.frame_renderer.current_line_width() as usize;
+        let available = width.saturating_sub(used + reserved + 1);
+
+        // at least one space separates the suffix from the option.
+        let suffix = truncate_to_width(suffix, available.saturating_sub(1));
+        let padding = available.saturating_sub(suffix.width());
+
+        self.frame_renderer.write(" ".repeat(padding))?;
+        self.frame_renderer
+            .write_styled(Styled::new(suffix).with_style_sheet(self.render_config.option))
     }
 
     fn print_default_value(&mut self, value: &str) -> Result<()> {
@@ -174,8 +372,98 @@ where
 
         self.frame_renderer.write(" ")?;
 
+        if let Some((current, total)) = self.render_config.step_indicator {
+            let step = format!("[{current}/{total}]");
+            self.frame_renderer.write_styled(
+                Styled::new(step.as_str())
+                    .with_style_sheet(self.render_config.step_indicator_style),
+            )?;
+            self.frame_renderer.write(" ")?;
+        }
+
+        self.print_wrapped(prompt, self.render_config.prompt)
+    }
+
+    /// Prints an error or warning message returned by validators.
+    fn print_validation_message(
+        &mut self,
+        message: &ErrorMessage,
+        config: ErrorMessageRenderConfig<'_>,
+    ) -> Result<()> {
+        let message = match message {
+            ErrorMessage::Default => config.default_message,
+            ErrorMessage::Custom(msg) => msg,
+            ErrorMessage::Multiple(messages) => {
+                for message in messages {
+                    self.print_validation_message(message, config)?;
+                }
+                return Ok(());
+            }
+        };
+
+        self.frame_renderer.write_styled(config.prefix)?;
+
         self.frame_renderer
-            .write_styled(Styled::new(prompt).with_style_sheet(self.render_config.prompt))?;
+            .write_styled(Styled::new(" ").with_style_sheet(config.separator))?;
+
+        self.print_wrapped(message, config.message)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    /// Rows taken by an error or warning message printed by
+    /// [`print_validation_message`](Self::print_validation_message).
+    fn validation_message_rows(
+        &self,
+        message: &ErrorMessage,
+        config: ErrorMessageRenderConfig<'_>,
+    ) -> usize {
+        let message = match message {
+            ErrorMessage::Default => config.default_message,
+            ErrorMessage::Custom(msg) => msg,
+            ErrorMessage::Multiple(messages) => {
+                return messages
+                    .iter()
+                    .map(|message| self.validation_message_rows(message, config))
+                    .sum();
+            }
+        };
+
+        // the prefix is followed by a space separator.
+        let offset = display_width(config.prefix.content).saturating_add(1);
+        self.wrapped_rows(message, offset)
+    }
+
+    /// Rows taken by the text printed with [`print_wrapped`](Self::print_wrapped)
+    /// starting at column `offset`.
+    fn wrapped_rows(&self, text: &str, offset: u16) -> usize {
+        let width = self
+            .frame_renderer
+            .terminal_width()
+            .unwrap_or(DEFAULT_WRAP_WIDTH);
+
+        wrap_words(text, offset, width).len()
+    }
+
+    /// Writes the text breaking lines at word boundaries, so that it fits
+    /// the terminal width starting from the current column.
+    fn print_wrapped(&mut self, text: &str, style_sheet: StyleSheet) -> Result<()> {
+        let width = self
+            .frame_renderer
+            .terminal_width()
+            .unwrap_or(DEFAULT_WRAP_WIDTH);
+        let offset = self.frame_renderer.current_line_width();
+
+        for (idx, line) in wrap_words(text, offset, width).into_iter().enumerate() {
+            if idx > 0 {
+                self.new_line()?;
+            }
+
+            self.frame_renderer
+                .write_styled(Styled::new(line).with_style_sheet(style_sheet))?;
+        }
 
         Ok(())
     }
@@ -185,16 +473,21 @@ where
     }
 
     fn print_input(&mut self, input: &Input) -> Result<()> {
-        self.frame_renderer.write(" ")?;
+        self.print_input_with_inline_suggestion(input, None)
+    }
 
-        // The cursor is at the beginning of the input line.
-        // From here it's easier to mark the wanted cursor position
-        // (based on the underlying input struct), as it's a simple
-        // cur_pos + offset calculation.
-        self.frame_renderer
-            .mark_cursor_position(input.pre_cursor().width() as isize);
+    /// Prints the input followed by the dimmed remainder of the inline
+    /// suggestion, if any, which is not part of the input itself.
+    fn print_input_with_inline_suggestion(
+        &mut self,
+        input: &Input,
+        inline_suggestion: Option<&str>,
+    ) -> Result<()> {
+        self.frame_renderer.write(" ")?;
 
         if input.is_empty() {
+            self.frame_renderer.mark_cursor_position(0);
+
             match input.placeholder() {
                 Some("") | None => {}
                 Some(p) => self.frame_renderer.write_styled(
@@ -202,8 +495,37 @@ where
                 )?,
             }
         } else {
+            // The cursor is marked in between the content before and after it,
+            // so that its position accounts for the display width of the
+            // characters, e.g. wide CJK characters or combining marks, and for
+            // the line wrapping applied by the frame renderer.
+            let pre_cursor = input.pre_cursor();
+            let post_cursor = input.post_cursor();
+
+            if !pre_cursor.is_empty() {
+                self.frame_renderer.write_styled(
+                    Styled::new(pre_cursor).with_style_sheet(self.render_config.text_input),
+                )?;
+            }
+
+            // at the end of the input, the cursor is placed on the trailing space
+            let next_width = post_cursor
+                .graphemes(true)
+                .next()
+                .map(display_width)
+                .unwrap_or(1);
+            self.frame_renderer.mark_cursor_position_before(next_width);
+
+            if !post_cursor.is_empty() {
+                self.frame_renderer.write_styled(
+                    Styled::new(post_cursor).with_style_sheet(self.render_config.text_input),
+                )?;
+            }
+        }
+
+        if let Some(inline_suggestion) = inline_suggestion {
             self.frame_renderer.write_styled(
-                Styled::new(input.content()).with_style_sheet(self.render_config.text_input),
+                Styled::new(inline_suggestion).with_style_sheet(self.render_config.placeholder),
             )?;
         }
 
@@ -217,6 +539,27 @@ where
         Ok(())
     }
 
+    fn print_spinner(&mut self, input: &Input, frame: usize) -> Result<()> {
+        let frames = self.render_config.spinner_frames;
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        // print_input already adds a trailing space when the cursor
+        // is at the end of the input.
+        if input.cursor() != input.length() {
+            self.frame_renderer.write(' ')?;
+        }
+
+        let frame = match self.render_config.animations_enabled {
+            true => frame % frames.len(),
+            false => 0,
+        };
+
+        let token = Styled::new(frames[frame]).with_style_sheet(self.render_config.spinner);
+        self.frame_renderer.write_styled(token)
+    }
+
     fn print_prompt_with_input(
         &mut self,
         prompt: &str,
@@ -237,6 +580,26 @@ where
         Ok(())
     }
 
+    /// Makes the row of clicks, reported on the whole screen, relative to the
+    /// first row of the prompt.
+    ///
+    /// Clicks above the prompt, or when the position of the prompt on the
+    /// screen is unknown, are discarded.
+    fn relative_to_prompt(&mut self, key: Key) -> InquireResult<Option<Key>> {
+        let (row, column) = match key {
+            Key::Click { row, column } => (row, column),
+            key => return Ok(Some(key)),
+        };
+
+        let key = self
+            .frame_renderer
+            .frame_top_row()?
+            .and_then(|top| row.checked_sub(top))
+            .map(|row| Key::Click { row, column });
+
+        Ok(key)
+    }
+
     fn new_line(&mut self) -> Result<()> {
         self.frame_renderer.write("\n")?;
         Ok(())
@@ -256,6 +619,30 @@ where
         self.frame_renderer.finish_current_frame(is_last_frame)
     }
 
+    fn terminal_height(&self) -> Option<u16> {
+        self.frame_renderer.terminal_height()
+    }
+
+    fn error_message_rows(&self, error: &ErrorMessage) -> usize {
+        self.validation_message_rows(error, self.render_config.error_message)
+    }
+
+    fn warning_message_rows(&self, warning: &ErrorMessage) -> usize {
+        self.validation_message_rows(warning, self.render_config.warning_message)
+    }
+
+    fn help_message_rows(&self, help: &str) -> usize {
+        self.wrapped_rows(&format!("[{help}]"), 0)
+    }
+
+    fn enable_mouse_capture(&mut self) -> Result<()> {
+        self.frame_renderer.enable_mouse_capture()
+    }
+
+    fn frame_row(&self) -> u16 {
+        self.frame_renderer.current_frame_row()
+    }
+
     fn render_canceled_prompt(&mut self, prompt: &str) -> Result<()> {
         self.print_prompt(prompt)?;
 
@@ -283,36 +670,15 @@ where
     }
 
     fn render_error_message(&mut self, error: &ErrorMessage) -> Result<()> {
-        self.frame_renderer
-            .write_styled(self.render_config.error_message.prefix)?;
-
-        self.frame_renderer.write_styled(
-            Styled::new(" ").with_style_sheet(self.render_config.error_message.separator),
-        )?;
-
-        let message = match error {
-            ErrorMessage::Default => self.render_config.error_message.default_message,
-            ErrorMessage::Custom(msg) => msg,
-        };
-
-        self.frame_renderer.write_styled(
-            Styled::new(message).with_style_sheet(self.render_config.error_message.message),
-        )?;
-
-        self.new_line()?;
+        self.print_validation_message(error, self.render_config.error_message)
+    }
 
-        Ok(())
+    fn render_warning_message(&mut self, warning: &ErrorMessage) -> Result<()> {
+        self.print_validation_message(warning, self.render_config.warning_message)
     }
 
     fn render_help_message(&mut self, help: &str) -> Result<()> {
-        self.frame_renderer
-            .write_styled(Styled::new("[").with_style_sheet(self.render_config.help_message))?;
-
-        self.frame_renderer
-            .write_styled(Styled::new(help).with_style_sheet(self.render_config.help_message))?;
-
-        self.frame_renderer
-            .write_styled(Styled::new("]").with_style_sheet(self.render_config.help_message))?;
+        self.print_wrapped(&format!("[{help}]"), self.render_config.help_message)?;
 
         self.new_line()?;
 
@@ -334,18 +700,65 @@ where
         self.print_prompt_with_input(prompt, default, cur_input)
     }
 
+    fn render_prompt_with_spinner(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        spinner_frame: usize,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.print_input(cur_input)?;
+        self.print_spinner(cur_input, spinner_frame)?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
+    fn render_prompt_with_inline_suggestion(
+        &mut self,
+        prompt: &str,
+        default: Option<&str>,
+        cur_input: &Input,
+        inline_suggestion: &str,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        if let Some(default) = default {
+            self.frame_renderer.write(" ")?;
+            self.print_default_value(default)?;
+        }
+
+        self.print_input_with_inline_suggestion(cur_input, Some(inline_suggestion))?;
+
+        self.new_line()?;
+
+        Ok(())
+    }
+
     fn render_suggestions<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
             self.frame_renderer.write(" ")?;
-            self.print_option_value(idx, option, &page)?;
+            self.print_option_value(idx, option, &page, None, None)?;
 
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_suggestion_error(&mut self, error: &ErrorMessage) -> Result<()> {
+        self.print_validation_message(error, self.render_config.suggestion_error)
+    }
 }
 
 #[cfg(feature = "editor")]
@@ -367,6 +780,37 @@ where
 
         Ok(())
     }
+
+    fn render_inline_prompt(
+        &mut self,
+        prompt: &str,
+        input: &crate::input::MultilineInput,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        self.frame_renderer.write(" ")?;
+
+        let token =
+            Styled::new("[(ctrl+d) to submit]").with_style_sheet(self.render_config.editor_prompt);
+        self.frame_renderer.write_styled(token)?;
+
+        self.new_line()?;
+
+        for (row, line) in input.lines().iter().enumerate() {
+            if row == input.cursor_row() {
+                self.print_input(line)?;
+            } else {
+                self.frame_renderer.write(" ")?;
+                self.frame_renderer.write_styled(
+                    Styled::new(line.content()).with_style_sheet(self.render_config.text_input),
+                )?;
+            }
+
+            self.new_line()?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a, I, T> SelectBackend for Backend<'a, I, T>
@@ -382,7 +826,15 @@ where
         }
     }
 
-    fn render_options<D: Display>(&mut self, page: Page<'_, ListOption<D>>) -> Result<()> {
+    fn render_options<D: Display>(
+        &mut self,
+        page: Page<'_, ListOption<D>>,
+        option_styles: Option<&[StyleSheet]>,
+        highlights: Option<&[Vec<Range<usize>>]>,
+        suffixes: Option<&[Option<String>]>,
+    ) -> Result<()> {
+        let scrollbar_paddings = self.scrollbar_paddings(&page);
+
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
@@ -393,13 +845,50 @@ where
                 self.frame_renderer.write(" ")?;
             }
 
-            self.print_option_value(idx, option, &page)?;
+            self.print_option_value(idx, option, &page, option_styles, highlights)?;
+
+            // suffixes pad every row to the same width, so the scrollbar is
+            // already aligned after them.
+            let scrollbar_padding = match (suffixes, &scrollbar_paddings) {
+                (Some(suffixes), paddings) => {
+                    let reserved = if paddings.is_some() { 2 } else { 0 };
+                    let suffix = suffixes.get(idx).and_then(Option::as_deref);
+                    self.print_option_suffix(suffix, reserved)?;
+                    0
+                }
+                (None, Some(paddings)) => paddings[idx],
+                (None, None) => 0,
+            };
+
+            if scrollbar_paddings.is_some() {
+                self.print_scrollbar(idx, scrollbar_padding, &page)?;
+            }
 
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_inline_option<D: Display>(
+        &mut self,
+        prompt: &str,
+        option: &ListOption<D>,
+    ) -> Result<()> {
+        self.print_prompt(prompt)?;
+
+        let stylesheet = self
+            .render_config
+            .selected_option
+            .unwrap_or(self.render_config.option);
+
+        self.frame_renderer.write(" < ")?;
+        self.frame_renderer
+            .write_styled(Styled::new(&option.value).with_style_sheet(stylesheet))?;
+        self.frame_renderer.write(" >")?;
+
+        self.new_line()
+    }
 }
 
 impl<'a, I, T> MultiSelectBackend for Backend<'a, I, T>
@@ -419,7 +908,12 @@ where
         &mut self,
         page: Page<'_, ListOption<D>>,
         checked: &BTreeSet<usize>,
+        range: Option<RangeInclusive<usize>>,
+        option_styles: Option<&[StyleSheet]>,
+        highlights: Option<&[Vec<Range<usize>>]>,
     ) -> Result<()> {
+        let scrollbar_paddings = self.scrollbar_paddings(&page);
+
         for (idx, option) in page.content.iter().enumerate() {
             self.print_option_prefix(idx, &page)?;
 
@@ -435,32 +929,83 @@ where
                 false => self.render_config.unselected_checkbox,
             };
 
-            match (self.render_config.selected_option, page.cursor) {
-                (Some(stylesheet), Some(cursor)) if cursor == idx => checkbox.style = stylesheet,
-                _ => {}
+            let in_range = matches!(&range, Some(range) if range.contains(&(page.start + idx)));
+
+            if let Some(stylesheet) = self.render_config.selected_option {
+                if page.cursor == Some(idx) || in_range {
+                    checkbox.style = stylesheet;
+                }
             }
 
             self.frame_renderer.write_styled(checkbox)?;
 
             self.frame_renderer.write(" ")?;
 
-            self.print_option_value(idx, option, &page)?;
+            self.print_option_value(idx, option, &page, option_styles, highlights)?;
+
+            if let Some(paddings) = &scrollbar_paddings {
+                self.print_scrollbar(idx, paddings[idx], &page)?;
+            }
 
             self.new_line()?;
         }
 
         Ok(())
     }
+
+    fn render_empty_options(&mut self, message: &str) -> Result<()> {
+        self.frame_renderer
+            .write_styled(Styled::new(message).with_style_sheet(self.render_config.placeholder))?;
+
+        self.new_line()
+    }
+}
+
+/// Applies `top` over `base`: colors set in `top` replace the ones of `base`
+/// and the attributes of both are kept.
+pub(crate) fn overlay_style(base: StyleSheet, top: StyleSheet) -> StyleSheet {
+    StyleSheet {
+        fg: top.fg.or(base.fg),
+        bg: top.bg.or(base.bg),
+        att: base.att | top.att,
+    }
+}
+
+/// Truncates `text` to at most `max_width` columns, ending it with an ellipsis
+/// when anything was cut.
+fn truncate_to_width(text: &str, max_width: usize) -> String {
+    if text.width() <= max_width {
+        return text.to_owned();
+    }
+
+    let mut truncated = String::new();
+    let mut width = 0;
+
+    for c in text.chars() {
+        let char_width = UnicodeWidthChar::width(c).unwrap_or(0);
+        // one column is left for the ellipsis.
+        if width + char_width + 1 > max_width {
+            break;
+        }
+        width += char_width;
+        truncated.push(c);
+    }
+
+    if max_width > 0 {
+        truncated.push('…');
+    }
+
+    truncated
 }
 
 #[cfg(feature = "date")]
 pub mod date {
-    use std::{io::Result, ops::Sub};
+    use std::io::Result;
 
-    use chrono::{Datelike, Duration};
+    use chrono::Datelike;
 
     use crate::{
-        date_utils::get_start_date,
+        date_utils::{get_first_rendered_date, get_week_number},
         terminal::Terminal,
         ui::{InputReader, Styled},
     };
@@ -476,11 +1021,15 @@ pub mod date {
             month: chrono::Month,
             year: i32,
             week_start: chrono::Weekday,
+            week_numbers: bool,
             today: chrono::NaiveDate,
             selected_date: chrono::NaiveDate,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
+            is_disabled: &dyn Fn(chrono::NaiveDate) -> bool,
         ) -> Result<()>;
+
+        fn render_time_selector(&mut self, time: chrono::NaiveTime, active: bool) -> Result<()>;
     }
 
     impl<'a, I, T> DateSelectBackend for Backend<'a, I, T>
@@ -499,16 +1048,23 @@ pub mod date {
             month: chrono::Month,
             year: i32,
             week_start: chrono::Weekday,
+            week_numbers: bool,
             today: chrono::NaiveDate,
             selected_date: chrono::NaiveDate,
             min_date: Option<chrono::NaiveDate>,
             max_date: Option<chrono::NaiveDate>,
+            is_disabled: &dyn Fn(chrono::NaiveDate) -> bool,
         ) -> Result<()> {
+            // the week number column is two digits wide, followed by a space.
             macro_rules! write_prefix {
                 () => {{
                     self.frame_renderer
                         .write_styled(self.render_config.calendar.prefix)?;
-                    self.frame_renderer.write(" ")
+                    self.frame_renderer.write(" ")?;
+                    if week_numbers {
+                        self.frame_renderer.write("   ")?;
+                    }
+                    Ok::<(), std::io::Error>(())
                 }};
             }
 
@@ -544,23 +1100,20 @@ pub mod date {
             self.new_line()?;
 
             // print dates
-            let mut date_it = get_start_date(month, year);
-            // first date of week-line is possibly in the previous month
-            if date_it.weekday() == week_start {
-                date_it = date_it.sub(
-                    Duration::try_weeks(1).expect("overflow when calculating duration of 1 week"),
-                );
-            } else {
-                while date_it.weekday() != week_start {
-                    date_it = match date_it.pred_opt() {
-                        Some(date) => date,
-                        None => break,
-                    };
-                }
-            }
+            let mut date_it = get_first_rendered_date(month, year, week_start);
 
             for _ in 0..6 {
-                write_prefix!()?;
+                self.frame_renderer
+                    .write_styled(self.render_config.calendar.prefix)?;
+                self.frame_renderer.write(" ")?;
+
+                if week_numbers {
+                    let week_number = format!("{:2}", get_week_number(date_it));
+                    let week_number = Styled::new(week_number)
+                        .with_style_sheet(self.render_config.calendar.week_number);
+                    self.frame_renderer.write_styled(week_number)?;
+                    self.frame_renderer.write(" ")?;
+                }
 
                 for i in 0..7 {
                     if i > 0 {
@@ -597,6 +1150,10 @@ pub mod date {
                         }
                     }
 
+                    if is_disabled(date_it) {
+                        style_sheet = self.render_config.calendar.unavailable_date;
+                    }
+
                     let token = Styled::new(date).with_style_sheet(style_sheet);
                     self.frame_renderer.write_styled(token)?;
 
@@ -608,6 +1165,31 @@ pub mod date {
 
             Ok(())
         }
+
+        fn render_time_selector(&mut self, time: chrono::NaiveTime, active: bool) -> Result<()> {
+            self.frame_renderer
+                .write_styled(self.render_config.calendar.prefix)?;
+            self.frame_renderer.write(" ")?;
+
+            let label =
+                Styled::new("time").with_style_sheet(self.render_config.calendar.week_header);
+            self.frame_renderer.write_styled(label)?;
+            self.frame_renderer.write(" ")?;
+
+            let mut style_sheet = crate::ui::StyleSheet::empty();
+
+            if active {
+                self.frame_renderer.mark_cursor_position(0);
+                if let Some(custom_style_sheet) = self.render_config.calendar.selected_date {
+                    style_sheet = custom_style_sheet;
+                }
+            }
+
+            let token = Styled::new(time.format("%H:%M").to_string()).with_style_sheet(style_sheet);
+            self.frame_renderer.write_styled(token)?;
+
+            self.new_line()
+        }
     }
 }
 
@@ -638,6 +1220,8 @@ where
     }
 
     fn render_prompt_with_masked_input(&mut self, prompt: &str, cur_input: &Input) -> Result<()> {
+        // one mask per grapheme, so that multi-codepoint characters such as
+        // emoji ZWJ sequences keep the mask in sync with the cursor.
         let masked_string: String = (0..cur_input.length())
             .map(|_| self.render_config.password_mask)
             .collect();
@@ -658,185 +1242,19 @@ where
     T: Terminal,
 {
     fn read_key(&mut self) -> InquireResult<Key> {
-        self.input_reader.read_key()
-    }
-}
-
-#[cfg(test)]
-pub(crate) mod test {
-    use std::collections::VecDeque;
-
-    use chrono::{Month, NaiveDate, Weekday};
-
-    use crate::{
-        input::Input,
-        ui::{InputReader, Key},
-        validator::ErrorMessage,
-    };
-
-    use super::{CommonBackend, CustomTypeBackend};
-
-    #[derive(Debug, Clone, PartialEq)]
-    pub enum Token {
-        Prompt(String),
-        DefaultValue(String),
-        Input(Input),
-        CanceledPrompt(String),
-        AnsweredPrompt(String, String),
-        ErrorMessage(ErrorMessage),
-        HelpMessage(String),
-        Calendar {
-            month: Month,
-            year: i32,
-            week_start: Weekday,
-            today: NaiveDate,
-            selected_date: NaiveDate,
-            min_date: Option<NaiveDate>,
-            max_date: Option<NaiveDate>,
-        },
-        PromptEnd,
-    }
-
-    #[derive(Default, Debug, Clone)]
-    pub struct Frame {
-        content: Vec<Token>,
-    }
-
-    impl Frame {
-        pub fn has_token(&self, token: &Token) -> bool {
-            self.content.iter().any(|t| t == token)
-        }
-
-        pub fn tokens(&self) -> &[Token] {
-            &self.content
-        }
-    }
-
-    #[derive(Default, Debug, Clone)]
-    pub struct FakeBackend {
-        pub input: VecDeque<Key>,
-        pub frames: Vec<Frame>,
-        pub cur_frame: Option<Frame>,
-    }
+        loop {
+            let key = self.input_reader.read_key()?;
 
-    impl FakeBackend {
-        pub fn new(input: Vec<Key>) -> Self {
-            Self {
-                input: input.into(),
-                frames: vec![],
-                cur_frame: None,
+            if let Some(key) = self.relative_to_prompt(key)? {
+                return Ok(key);
             }
         }
-
-        fn push_token(&mut self, token: Token) {
-            if let Some(frame) = self.cur_frame.as_mut() {
-                frame.content.push(token);
-            } else {
-                panic!("No frame to push token");
-            }
-        }
-        pub fn frames(&self) -> &[Frame] {
-            &self.frames
-        }
-    }
-
-    impl InputReader for FakeBackend {
-        fn read_key(&mut self) -> crate::error::InquireResult<Key> {
-            self.input
-                .pop_front()
-                .ok_or(crate::error::InquireError::IO(std::io::Error::new(
-                    std::io::ErrorKind::UnexpectedEof,
-                    "No more keys in input",
-                )))
-        }
-    }
-
-    impl CommonBackend for FakeBackend {
-        fn frame_setup(&mut self) -> std::io::Result<()> {
-            self.cur_frame = Some(Frame::default());
-            Ok(())
-        }
-
-        fn frame_finish(&mut self, is_last_frame: bool) -> std::io::Result<()> {
-            if is_last_frame {
-                self.push_token(Token::PromptEnd);
-            }
-
-            if let Some(frame) = self.cur_frame.take() {
-                self.frames.push(frame);
-            } else {
-                panic!("No frame to finish");
-            }
-            Ok(())
-        }
-
-        fn render_canceled_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
-            self.push_token(Token::CanceledPrompt(prompt.to_string()));
-            Ok(())
-        }
-
-        fn render_prompt_with_answer(&mut self, prompt: &str, answer: &str) -> std::io::Result<()> {
-            self.push_token(Token::AnsweredPrompt(
-                prompt.to_string(),
-                answer.to_string(),
-            ));
-            Ok(())
-        }
-
-        fn render_error_message(&mut self, error: &ErrorMessage) -> std::io::Result<()> {
-            self.push_token(Token::ErrorMessage(error.clone()));
-            Ok(())
-        }
-
-        fn render_help_message(&mut self, help: &str) -> std::io::Result<()> {
-            self.push_token(Token::HelpMessage(help.to_string()));
-            Ok(())
-        }
-    }
-
-    #[cfg(feature = "date")]
-    impl crate::ui::date::DateSelectBackend for FakeBackend {
-        fn render_calendar_prompt(&mut self, prompt: &str) -> std::io::Result<()> {
-            self.push_token(Token::Prompt(prompt.to_string()));
-            Ok(())
-        }
-
-        fn render_calendar(
-            &mut self,
-            month: Month,
-            year: i32,
-            week_start: Weekday,
-            today: NaiveDate,
-            selected_date: NaiveDate,
-            min_date: Option<NaiveDate>,
-            max_date: Option<NaiveDate>,
-        ) -> std::io::Result<()> {
-            self.push_token(Token::Calendar {
-                month,
-                year,
-                week_start,
-                today,
-                selected_date,
-                min_date,
-                max_date,
-            });
-            Ok(())
-        }
     }
 
-    impl CustomTypeBackend for FakeBackend {
-        fn render_prompt(
-            &mut self,
-            prompt: &str,
-            default: Option<&str>,
-            cur_input: &Input,
-        ) -> std::io::Result<()> {
-            self.push_token(Token::Prompt(prompt.to_string()));
-            if let Some(default) = default {
-                self.push_token(Token::DefaultValue(default.to_string()));
-            }
-            self.push_token(Token::Input(cur_input.clone()));
-            Ok(())
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        match self.input_reader.read_key_timeout(timeout)? {
+            Some(key) => self.relative_to_prompt(key),
+            None => Ok(None),
         }
     }
 }
@@ -1,3 +1,7 @@
+use unicode_width::UnicodeWidthChar;
+
+use crate::ansi::{AnsiAware, AnsiAwareChar, AnsiStrippable};
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Dimension {
     width: u16,
@@ -17,3 +21,142 @@ impl Dimension {
         self.height
     }
 }
+
+/// Width assumed for word wrapping when the terminal size is unknown.
+pub const DEFAULT_WRAP_WIDTH: u16 = 80;
+
+/// Number of terminal columns taken by `text` once rendered.
+///
+/// Wide characters, e.g. CJK ideographs, take two columns, while combining marks
+/// and ANSI escape sequences take none.
+pub fn display_width(text: &str) -> u16 {
+    text.ansi_stripped_chars()
+        .map(|c| UnicodeWidthChar::width(c).unwrap_or(0) as u16)
+        .fold(0, u16::saturating_add)
+}
+
+/// Splits `text` into lines no wider than `width` columns, breaking lines at spaces.
+///
+/// `offset` is the number of columns already taken in the first line, e.g. by a prefix.
+/// Words wider than `width` are broken at the column limit, and ANSI escape sequences
+/// are not counted as taking any space.
+pub fn wrap_words(text: &str, offset: u16, width: u16) -> Vec<String> {
+    if width == 0 {
+        return vec![text.to_string()];
+    }
+
+    let mut lines = vec![];
+    let mut current_line = String::new();
+    let mut current_width = offset;
+
+    for (idx, paragraph) in text.split('\n').enumerate() {
+        if idx > 0 {
+            lines.push(std::mem::take(&mut current_line));
+            current_width = 0;
+        }
+
+        let mut is_line_start = true;
+
+        for word in paragraph.split(' ') {
+            let word_width = display_width(word);
+            let separator_width = if is_line_start { 0 } else { 1 };
+
+            if current_width
+                .saturating_add(separator_width)
+                .saturating_add(word_width)
+                > width
+            {
+                if word_width <= width {
+                    lines.push(std::mem::take(&mut current_line));
+                    current_width = 0;
+                } else {
+                    // the word does not fit in a line of its own, so it is
+                    // hard-broken starting right where the current line is.
+                    if !is_line_start {
+                        current_line.push(' ');
+                        current_width = current_width.saturating_add(1);
+                    }
+
+                    for piece in word.ansi_aware_chars() {
+                        match piece {
+                            AnsiAwareChar::AnsiEscapeSequence(seq) => current_line.push_str(seq),
+                            AnsiAwareChar::Char(c) => {
+                                let char_width = UnicodeWidthChar::width(c).unwrap_or(0) as u16;
+                                if current_width.saturating_add(char_width) > width {
+                                    lines.push(std::mem::take(&mut current_line));
+                                    current_width = 0;
+                                }
+                                current_line.push(c);
+                                current_width = current_width.saturating_add(char_width);
+                            }
+                        }
+                    }
+
+                    is_line_start = false;
+                    continue;
+                }
+            } else if !is_line_start {
+                current_line.push(' ');
+                current_width = current_width.saturating_add(1);
+            }
+
+            current_line.push_str(word);
+            current_width = current_width.saturating_add(word_width);
+            is_line_start = false;
+        }
+    }
+
+    lines.push(current_line);
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::wrap_words;
+
+    #[test]
+    fn short_text_is_not_wrapped() {
+        assert_eq!(
+            vec!["What is your name?"],
+            wrap_words("What is your name?", 2, 80)
+        );
+    }
+
+    #[test]
+    fn text_is_wrapped_at_word_boundaries() {
+        assert_eq!(
+            vec!["What is", "your name?"],
+            wrap_words("What is your name?", 2, 12)
+        );
+    }
+
+    #[test]
+    fn first_line_accounts_for_offset() {
+        assert_eq!(vec!["", "What is your"], wrap_words("What is your", 10, 12));
+        assert_eq!(vec!["What is", "your"], wrap_words("What is your", 5, 12));
+    }
+
+    #[test]
+    fn long_words_are_hard_broken() {
+        assert_eq!(
+            vec!["a abcdef", "ghijklmn", "op b"],
+            wrap_words("a abcdefghijklmnop b", 0, 8)
+        );
+    }
+
+    #[test]
+    fn line_breaks_are_kept() {
+        assert_eq!(
+            vec!["one two", "three"],
+            wrap_words("one two\nthree", 0, 80)
+        );
+    }
+
+    #[test]
+    fn ansi_escape_sequences_take_no_space() {
+        assert_eq!(
+            vec!["\x1b[1mbold\x1b[0m text"],
+            wrap_words("\x1b[1mbold\x1b[0m text", 0, 9)
+        );
+    }
+}
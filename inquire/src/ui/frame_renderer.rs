@@ -7,7 +7,7 @@ use fxhash::FxHasher;
 use unicode_width::UnicodeWidthChar;
 
 use super::dimension::Dimension;
-use super::{Position, Styled};
+use super::{ColorDepth, CursorShape, Position, Styled};
 use crate::ansi::{AnsiAware, AnsiAwareChar};
 use crate::terminal::{Terminal, TerminalSize};
 
@@ -103,19 +103,31 @@ impl FrameState {
     }
 
     pub fn mark_cursor_position(&mut self, offset: isize) {
-        let mut row = self.finished_rows.len() as u16;
-        let mut col = self.current_line_width;
+        let width = self.terminal_size.width().max(1);
+        let col = self.current_line_width.saturating_add(offset as u16);
 
-        col = col.saturating_add(offset as u16);
-
-        if col >= self.terminal_size.width() {
-            col -= self.terminal_size.width();
-            row += 1;
-        }
+        let row = (self.finished_rows.len() as u16).saturating_add(col / width);
+        let col = col % width;
 
         self.expected_cursor_position = Some(Position { row, col });
     }
 
+    /// Marks the cursor position right before content `next_width` columns wide
+    /// that is about to be written. When such content does not fit in the current
+    /// line, it is written in the next one, and so the cursor is placed there as well.
+    pub fn mark_cursor_position_before(&mut self, next_width: u16) {
+        let remaining_width_space = self
+            .terminal_size
+            .width()
+            .saturating_sub(self.current_line_width);
+
+        if next_width > remaining_width_space {
+            self.mark_cursor_position(remaining_width_space as isize);
+        } else {
+            self.mark_cursor_position(0);
+        }
+    }
+
     pub fn finish(&mut self) {
         self.finish_line();
     }
@@ -190,6 +202,9 @@ where
 {
     terminal: T,
     cursor_position: Position,
+    cursor_shape_changed: bool,
+    color_depth: ColorDepth,
+    left_margin: u16,
     state: RenderState,
 }
 
@@ -201,15 +216,108 @@ where
         Ok(Self {
             terminal,
             cursor_position: Position::default(),
+            cursor_shape_changed: false,
+            color_depth: ColorDepth::TrueColor,
+            left_margin: 0,
             state: RenderState::Initial,
         })
     }
 
+    #[cfg(test)]
+    pub fn terminal_mut(&mut self) -> &mut T {
+        &mut self.terminal
+    }
+
+    /// Width available for content in the terminal, that is, its width minus
+    /// the left margin, if it can be retrieved.
+    pub fn terminal_width(&self) -> Option<u16> {
+        self.terminal
+            .get_size()
+            .ok()
+            .map(|size| self.content_size(size).width())
+    }
+
+    /// Height of the terminal, if it can be retrieved.
+    pub fn terminal_height(&self) -> Option<u16> {
+        self.terminal.get_size().ok().map(|size| size.height())
+    }
+
+    /// Columns already taken in the line currently being written.
+    pub fn current_line_width(&self) -> u16 {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => current_frame.current_line_width,
+            RenderState::Rendered(_) | RenderState::Initial => 0,
+        }
+    }
+
+    /// Moves the cursor below the rendered frame, showing it and resetting its
+    /// shape if changed.
+    ///
+    /// Errors are ignored so that each step is attempted, as this runs on drop,
+    /// including when unwinding from a panic in the middle of a frame.
+    fn restore_terminal(&mut self) {
+        let _unused = self.move_cursor_to_end_position();
+        let _unused = self.terminal.cursor_show();
+        if self.cursor_shape_changed {
+            let _unused = self.terminal.cursor_reset_shape();
+        }
+        let _unused = self.terminal.flush();
+    }
+
+    pub fn set_cursor_shape(&mut self, shape: CursorShape) -> io::Result<()> {
+        self.cursor_shape_changed = true;
+        self.terminal.cursor_set_shape(shape)
+    }
+
+    /// Starts reporting mouse events as keys, if supported by the terminal.
+    pub fn enable_mouse_capture(&mut self) -> io::Result<()> {
+        self.terminal.enable_mouse_capture()
+    }
+
+    /// Row of the screen where the rendered frame starts, if the terminal is
+    /// able to report the position of the cursor.
+    pub fn frame_top_row(&mut self) -> io::Result<Option<u16>> {
+        let cursor_row = self.terminal.cursor_row()?;
+        Ok(cursor_row.and_then(|row| row.checked_sub(self.cursor_position.row)))
+    }
+
+    /// Row of the frame currently being rendered that is being written to.
+    pub fn current_frame_row(&self) -> u16 {
+        match &self.state {
+            RenderState::ActiveRender { current_frame, .. } => {
+                current_frame.finished_rows.len() as u16
+            }
+            RenderState::Rendered(_) | RenderState::Initial => 0,
+        }
+    }
+
+    /// Sets the color depth that colors of the written content are downsampled to.
+    pub fn set_color_depth(&mut self, color_depth: ColorDepth) {
+        self.color_depth = color_depth;
+    }
+
+    /// Sets the number of blank columns written before every rendered line.
+    pub fn set_left_margin(&mut self, left_margin: u16) {
+        self.left_margin = left_margin;
+    }
+
+    /// Size of the area of the terminal where content is laid out, to the
+    /// right of the left margin.
+    fn content_size(&self, terminal_size: TerminalSize) -> TerminalSize {
+        let width = terminal_size
+            .width()
+            .saturating_sub(self.left_margin)
+            .max(1);
+        TerminalSize::new(width, terminal_size.height())
+    }
+
     pub fn write(&mut self, value: impl Display) -> io::Result<()> {
         self.write_styled(Styled::new(value))
     }
 
     pub fn write_styled(&mut self, value: Styled<impl Display>) -> io::Result<()> {
+        let color_depth = self.color_depth;
+
         match &mut self.state {
             RenderState::Rendered(_) | RenderState::Initial => {}
             RenderState::ActiveRender { current_frame, .. } => {
@@ -219,7 +327,9 @@ where
                 // we pay a little bit in memory/cpu usage for this so we can
                 // calculate incremental rendering and cursor position on-the-fly.
                 let formatted = format!("{}", value.content);
-                let value = value.with_content(formatted);
+                let mut value = value.with_content(formatted);
+                value.style.fg = value.style.fg.map(|c| c.downsample_to(color_depth));
+                value.style.bg = value.style.bg.map(|c| c.downsample_to(color_depth));
 
                 current_frame.write(&value);
             }
@@ -237,6 +347,15 @@ where
         }
     }
 
+    pub fn mark_cursor_position_before(&mut self, next_width: u16) {
+        match &mut self.state {
+            RenderState::Rendered(_) | RenderState::Initial => {}
+            RenderState::ActiveRender { current_frame, .. } => {
+                current_frame.mark_cursor_position_before(next_width);
+            }
+        }
+    }
+
     pub fn start_frame(&mut self) -> io::Result<()> {
         let terminal_size = self.refresh_terminal_size();
 
@@ -291,6 +410,7 @@ where
             match (last_row, current_row) {
                 (Some(last_row), Some(current_row)) => {
                     if last_row.hash() != current_row.hash() {
+                        self.write_left_margin()?;
                         for styled in current_row.get_content() {
                             self.terminal.write_styled(styled)?;
                         }
@@ -301,6 +421,7 @@ where
                     self.terminal.clear_line()?;
                 }
                 (None, Some(current_row)) => {
+                    self.write_left_margin()?;
                     for styled in current_row.get_content() {
                         self.terminal.write_styled(styled)?;
                     }
@@ -328,7 +449,12 @@ where
         }
 
         if let Some(expected_cursor_position) = current_frame.expected_cursor_position {
-            self.move_cursor_to(expected_cursor_position)?;
+            self.move_cursor_to(Position {
+                row: expected_cursor_position.row,
+                col: expected_cursor_position
+                    .col
+                    .saturating_add(self.left_margin),
+            })?;
         }
 
         self.terminal.cursor_show()?;
@@ -339,6 +465,15 @@ where
         Ok(())
     }
 
+    fn write_left_margin(&mut self) -> io::Result<()> {
+        if self.left_margin > 0 {
+            self.terminal
+                .write(format_args!("{:1$}", "", self.left_margin as usize))?;
+        }
+
+        Ok(())
+    }
+
     fn move_cursor_to_end_position(&mut self) -> io::Result<()> {
         self.refresh_terminal_size();
 
@@ -411,6 +546,8 @@ where
             };
         }
 
+        let terminal_size = self.content_size(terminal_size);
+
         match &mut self.state {
             RenderState::Initial => {}
             RenderState::ActiveRender {
@@ -434,9 +571,7 @@ where
     T: Terminal,
 {
     fn drop(&mut self) {
-        let _unused = self.move_cursor_to_end_position();
-        let _unused = self.terminal.cursor_show();
-        let _unused = self.terminal.flush();
+        self.restore_terminal();
     }
 }
 
@@ -444,10 +579,14 @@ where
 mod test {
     use crate::{
         error::InquireResult,
-        terminal::{test::MockTerminal, TerminalSize},
+        terminal::{
+            test::{MockTerminal, MockTerminalToken},
+            TerminalSize,
+        },
+        ui::{CursorShape, Styled},
     };
 
-    use super::FrameRenderer;
+    use super::{FrameRenderer, FrameState};
 
     #[test]
     fn ensure_inline_ansi_codes_are_maintained() -> InquireResult<()> {
@@ -470,4 +609,86 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn left_margin_is_written_before_lines_and_added_to_cursor() -> InquireResult<()> {
+        let terminal = MockTerminal::new().with_size(TerminalSize::new(20, 20));
+        let mut renderer = FrameRenderer::new(terminal)?;
+        renderer.set_left_margin(4);
+
+        assert_eq!(Some(16), renderer.terminal_width());
+
+        renderer.start_frame()?;
+        renderer.write("Name:")?;
+        renderer.mark_cursor_position(0);
+        renderer.write("\nhelp")?;
+        renderer.finish_current_frame(false)?;
+
+        let terminal = &mut renderer.terminal;
+
+        terminal.find_and_expect_token("    ".into());
+        terminal.find_and_expect_token("Name:".into());
+        terminal.find_and_expect_token("\n".into());
+        terminal.find_and_expect_token("    ".into());
+        terminal.find_and_expect_token("help".into());
+        terminal.find_and_expect_token(MockTerminalToken::CursorUp(1));
+        terminal.find_and_expect_token(MockTerminalToken::CursorRight(9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_shape_is_forwarded_to_terminal() -> InquireResult<()> {
+        let mut renderer = FrameRenderer::new(MockTerminal::new())?;
+        renderer.set_cursor_shape(CursorShape::Block)?;
+
+        let terminal = &mut renderer.terminal;
+        terminal.find_and_expect_token(MockTerminalToken::CursorSetShape(CursorShape::Block));
+
+        Ok(())
+    }
+
+    #[test]
+    fn teardown_restores_the_cursor_in_the_middle_of_a_frame() -> InquireResult<()> {
+        let mut renderer = FrameRenderer::new(MockTerminal::new())?;
+        renderer.set_cursor_shape(CursorShape::Block)?;
+        renderer.start_frame()?;
+        renderer.write("Hello")?;
+        renderer.finish_current_frame(false)?;
+
+        // the state a panicking prompt leaves the renderer in when unwinding
+        renderer.start_frame()?;
+        renderer.write("World")?;
+        renderer.terminal.output.clear();
+
+        renderer.restore_terminal();
+
+        let terminal = &mut renderer.terminal;
+        terminal.find_and_expect_token(MockTerminalToken::CursorShow);
+        terminal.find_and_expect_token(MockTerminalToken::CursorResetShape);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_offsets_can_span_several_lines() {
+        let mut frame = FrameState::new(TerminalSize::new(5, 10));
+        frame.write(&Styled::new("abc"));
+        frame.mark_cursor_position(9);
+
+        let position = frame.expected_cursor_position.unwrap();
+        assert_eq!((2, 2), (position.row, position.col));
+    }
+
+    #[test]
+    fn cursor_is_wrapped_with_wide_characters_not_fitting_the_line() {
+        let mut frame = FrameState::new(TerminalSize::new(5, 10));
+        frame.write(&Styled::new("abcd"));
+        frame.mark_cursor_position_before(2);
+        frame.write(&Styled::new("你"));
+
+        let position = frame.expected_cursor_position.unwrap();
+        assert_eq!((1, 0), (position.row, position.col));
+        assert_eq!(1, frame.finished_rows.len());
+    }
 }
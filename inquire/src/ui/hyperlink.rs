@@ -0,0 +1,62 @@
+use std::fmt::{self, Display};
+
+/// Label rendered as an [OSC 8](https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda)
+/// hyperlink when it has an URL, or as is otherwise.
+///
+/// The escape sequences are zero-width, so they do not affect the width of
+/// the rendered label.
+pub struct Hyperlink<'u, D> {
+    label: D,
+    url: Option<&'u str>,
+}
+
+impl<'u, D> Hyperlink<'u, D> {
+    pub fn new(label: D, url: Option<&'u str>) -> Self {
+        Self { label, url }
+    }
+}
+
+impl<'u, D> Display for Hyperlink<'u, D>
+where
+    D: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let url = match self.url {
+            Some(url) => url,
+            None => return self.label.fmt(f),
+        };
+
+        f.write_str("\x1b]8;;")?;
+        // control characters would terminate the escape sequence early.
+        for c in url.chars().filter(|c| !c.is_control()) {
+            write!(f, "{c}")?;
+        }
+        write!(f, "\x07{}\x1b]8;;\x07", self.label)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hyperlink;
+    use crate::ansi::AnsiStrippable;
+
+    #[test]
+    fn url_is_wrapped_in_zero_width_escape_sequences() {
+        let link = Hyperlink::new("Docs", Some("https://docs.rs/inquire")).to_string();
+
+        assert_eq!("\x1b]8;;https://docs.rs/inquire\x07Docs\x1b]8;;\x07", link);
+        assert_eq!("Docs", link.ansi_stripped_chars().collect::<String>());
+    }
+
+    #[test]
+    fn control_characters_are_removed_from_url() {
+        let link = Hyperlink::new("Docs", Some("https://a\x07b\x1b.rs")).to_string();
+
+        assert_eq!("\x1b]8;;https://ab.rs\x07Docs\x1b]8;;\x07", link);
+    }
+
+    #[test]
+    fn label_without_url_is_rendered_as_is() {
+        assert_eq!("Docs", Hyperlink::new("Docs", None).to_string());
+    }
+}
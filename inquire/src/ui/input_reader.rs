@@ -1,7 +1,17 @@
+use std::time::Duration;
+
 use crate::error::InquireResult;
 
 use super::Key;
 
 pub trait InputReader: Sized {
     fn read_key(&mut self) -> InquireResult<Key>;
+
+    /// Waits up to `timeout` for a key event, returning `None` when none arrived in time.
+    ///
+    /// Readers that are not able to poll for events fall back to a blocking read.
+    fn read_key_timeout(&mut self, timeout: Duration) -> InquireResult<Option<Key>> {
+        let _ = timeout;
+        self.read_key().map(Some)
+    }
 }
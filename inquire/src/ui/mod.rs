@@ -4,9 +4,11 @@ mod api;
 mod backend;
 pub(crate) mod dimension;
 mod frame_renderer;
+mod hyperlink;
 mod input_reader;
 
 pub(crate) use backend::*;
+pub(crate) use hyperlink::Hyperlink;
 pub(crate) use input_reader::*;
 
 pub use api::*;
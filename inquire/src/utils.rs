@@ -17,6 +17,42 @@ pub struct Page<'a, T> {
 
     /// Total amount of elements in the original list of choices.
     pub total: usize,
+
+    /// Index of the first element of the page in the original list of choices.
+    pub start: usize,
+}
+
+impl<'a, T> Page<'a, T> {
+    /// Position and length, in rows of the page, of the thumb of a scrollbar
+    /// representing this page in the original list of choices.
+    ///
+    /// Returns `None` when every choice fits in the page.
+    pub fn scrollbar_thumb(&self) -> Option<(usize, usize)> {
+        let rows = self.content.len();
+
+        if rows == 0 || self.total <= rows {
+            return None;
+        }
+
+        let length = (rows * rows / self.total).max(1);
+        let free_rows = rows - length;
+
+        let position = if self.last {
+            free_rows
+        } else {
+            let position = (self.start * rows + self.total / 2) / self.total;
+            let position = position.min(free_rows.saturating_sub(1));
+
+            // keep the thumb off the top when there are choices above the page
+            if self.first || free_rows < 2 {
+                position
+            } else {
+                position.max(1)
+            }
+        };
+
+        Some((position, length))
+    }
 }
 
 pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<'_, T> {
@@ -65,6 +101,20 @@ pub fn paginate<T>(page_size: usize, choices: &[T], sel: Option<usize>) -> Page<
         content: &choices[start..end],
         cursor,
         total: choices.len(),
+        start,
+    }
+}
+
+/// Page size fitting the options in a terminal `terminal_height` rows tall,
+/// after the `reserved_rows` used by the rest of the prompt, e.g. its message.
+///
+/// At least one option is always displayed, and the
+/// [default page size](crate::config::DEFAULT_PAGE_SIZE) is used when the
+/// terminal height is unknown.
+pub fn fit_page_size(terminal_height: Option<u16>, reserved_rows: usize) -> usize {
+    match terminal_height {
+        Some(height) => usize::from(height).saturating_sub(reserved_rows).max(1),
+        None => crate::config::DEFAULT_PAGE_SIZE,
     }
 }
 
@@ -92,6 +142,7 @@ impl<'a, T> Debug for Page<'a, T> {
             .field("content", &format!("({} elements)", &self.content.len()))
             .field("cursor", &self.cursor)
             .field("total", &self.total)
+            .field("start", &self.start)
             .finish()
     }
 }
@@ -260,4 +311,59 @@ mod test {
         assert_eq!(false, page.last);
         assert_eq!(6, page.total);
     }
+
+    #[test]
+    fn scrollbar_thumb_is_none_when_choices_fit_in_page() {
+        let choices = ListOption::from_list(vec!["1", "2", "3"]);
+
+        let page = paginate(3, &choices, Some(1));
+
+        assert_eq!(None, page.scrollbar_thumb());
+    }
+
+    #[test]
+    fn scrollbar_thumb_follows_page_position() {
+        let choices = ListOption::from_list((0..20).collect());
+
+        assert_eq!(
+            Some((0, 1)),
+            paginate(5, &choices, Some(0)).scrollbar_thumb()
+        );
+        assert_eq!(
+            Some((2, 1)),
+            paginate(5, &choices, Some(10)).scrollbar_thumb()
+        );
+        assert_eq!(
+            Some((4, 1)),
+            paginate(5, &choices, Some(19)).scrollbar_thumb()
+        );
+    }
+
+    #[test]
+    fn scrollbar_thumb_stays_off_the_edges_in_the_middle_of_the_list() {
+        let choices = ListOption::from_list((0..100).collect());
+
+        assert_eq!(
+            Some((1, 1)),
+            paginate(5, &choices, Some(3)).scrollbar_thumb()
+        );
+        assert_eq!(
+            Some((3, 1)),
+            paginate(5, &choices, Some(96)).scrollbar_thumb()
+        );
+    }
+
+    #[test]
+    fn scrollbar_thumb_grows_as_choices_shrink() {
+        let choices = ListOption::from_list((0..8).collect());
+
+        assert_eq!(
+            Some((0, 2)),
+            paginate(4, &choices, Some(0)).scrollbar_thumb()
+        );
+        assert_eq!(
+            Some((2, 2)),
+            paginate(4, &choices, Some(7)).scrollbar_thumb()
+        );
+    }
 }
@@ -6,12 +6,21 @@
 //! invalid, returning `Ok(Validation::Invalid(ErrorMessage))`, where the
 //! `ErrorMessage` content is an error message to be displayed to the end user.
 //!
+//! Validators might also return `Ok(Validation::Warning(ErrorMessage))` for
+//! answers that are allowed but questionable. The warning is displayed to the
+//! user, who can submit the same answer again to confirm it.
+//!
 //! Validators can also return errors, which propagate to the caller prompt
 //! and cause the prompt to return the error.
 //!
 //! This module also provides several macros as shorthands to the struct
 //! constructor functions, exported with the `macros` feature.
 
+use std::{
+    cell::{Ref, RefCell},
+    rc::Rc,
+};
+
 use dyn_clone::DynClone;
 
 use crate::{error::CustomUserError, list_option::ListOption};
@@ -27,6 +36,12 @@ pub enum ErrorMessage {
 
     /// Custom error message, used instead of the standard one.
     Custom(String),
+
+    /// Several error messages, each one displayed on its own line.
+    ///
+    /// Returned when prompts are configured to display the errors of all
+    /// failing validators, instead of only the first one.
+    Multiple(Vec<ErrorMessage>),
 }
 
 impl<T> From<T> for ErrorMessage
@@ -51,6 +66,81 @@ pub enum Validation {
     /// When empty a standard error message, configured via the RenderConfig struct, will be shown
     /// instead.
     Invalid(ErrorMessage),
+
+    /// Variant that indicates that the input value is allowed, but questionable according to the validator.
+    ///
+    /// The member represents a warning message displayed to the user, styled according to the
+    /// `warning_message` field of the RenderConfig struct. Unlike `Invalid`, the answer can still
+    /// be submitted: submitting the same answer again confirms it.
+    ///
+    /// When multiple validators are registered, an `Invalid` result of any of them takes
+    /// precedence over warnings, and only the first warning is displayed.
+    Warning(ErrorMessage),
+}
+
+/// Combines the results of validators executed in order.
+///
+/// `Invalid` results take precedence over warnings, of which only the first one is kept.
+/// Validators after the first `Invalid` result are not executed, unless `all_errors` is
+/// set, in which case the messages of all `Invalid` results are combined.
+pub(crate) fn merge_validations<I>(
+    results: I,
+    all_errors: bool,
+) -> Result<Validation, CustomUserError>
+where
+    I: IntoIterator<Item = Result<Validation, CustomUserError>>,
+{
+    let mut warning = None;
+    let mut errors = vec![];
+
+    for result in results {
+        match result? {
+            Validation::Valid => {}
+            Validation::Warning(msg) => {
+                warning.get_or_insert(msg);
+            }
+            Validation::Invalid(msg) => {
+                errors.push(msg);
+                if !all_errors {
+                    break;
+                }
+            }
+        }
+    }
+
+    let validation = match errors.len() {
+        0 => warning
+            .map(Validation::Warning)
+            .unwrap_or(Validation::Valid),
+        1 => Validation::Invalid(errors.remove(0)),
+        _ => Validation::Invalid(ErrorMessage::Multiple(errors)),
+    };
+
+    Ok(validation)
+}
+
+/// Warning displayed to the user for a given answer, which is confirmed when
+/// the same answer is submitted again while the warning is displayed.
+#[derive(Clone, Debug)]
+pub(crate) struct AnswerWarning<A> {
+    pub message: ErrorMessage,
+    pub answer: A,
+}
+
+impl<A> AnswerWarning<A>
+where
+    A: PartialEq,
+{
+    /// Returns whether the displayed `warning` confirms `answer`, i.e. it has the same
+    /// message and answer. Otherwise, `warning` is replaced by the new one to be displayed.
+    pub fn confirm(warning: &mut Option<Self>, message: ErrorMessage, answer: A) -> bool {
+        if matches!(warning, Some(w) if w.message == message && w.answer == answer) {
+            return true;
+        }
+
+        *warning = Some(Self { message, answer });
+        false
+    }
 }
 
 /// Validator that receives a string slice as the input, such as [`Text`](crate::Text) and
@@ -101,6 +191,138 @@ where
     }
 }
 
+/// Value shared between the prompts of a sequence, e.g. the answers of a
+/// wizard, so that validators of later prompts can check the input against
+/// earlier answers.
+///
+/// Every clone of a context refers to the same value, which can be updated
+/// after each prompt is answered and read by validators registered with
+/// [`Text::with_context_validator`](crate::Text::with_context_validator).
+/// A context can also be created from an existing `Rc<RefCell<C>>`.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{PromptContext, StringValidator, Validation};
+///
+/// #[derive(Default)]
+/// struct Answers {
+///     email: String,
+/// }
+///
+/// let context = PromptContext::new(Answers::default());
+/// let validator = context.validator(|input: &str, answers: &Answers| {
+///     if input == answers.email {
+///         Ok(Validation::Valid)
+///     } else {
+///         Ok(Validation::Invalid("The emails do not match".into()))
+///     }
+/// });
+///
+/// context.update(|answers| answers.email = "ferris@rust-lang.org".into());
+///
+/// assert_eq!(Validation::Valid, validator.validate("ferris@rust-lang.org")?);
+/// assert_eq!(
+///     Validation::Invalid("The emails do not match".into()),
+///     validator.validate("ferris@rust.org")?
+/// );
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct PromptContext<C> {
+    value: Rc<RefCell<C>>,
+}
+
+impl<C> PromptContext<C> {
+    /// Creates a context holding the given value.
+    pub fn new(value: C) -> Self {
+        Self {
+            value: Rc::new(RefCell::new(value)),
+        }
+    }
+
+    /// Borrows the current value of the context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is being updated at the same time.
+    pub fn get(&self) -> Ref<'_, C> {
+        self.value.borrow()
+    }
+
+    /// Replaces the value of the context.
+    pub fn set(&self, value: C) {
+        *self.value.borrow_mut() = value;
+    }
+
+    /// Updates the value of the context in place, e.g. to record an answer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is borrowed at the same time, e.g. when called
+    /// from a validator of this context.
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut C),
+    {
+        f(&mut self.value.borrow_mut());
+    }
+
+    /// Creates a validator receiving the current value of the context along
+    /// with the user input.
+    pub fn validator<F>(&self, validator: F) -> ContextValidator<C, F>
+    where
+        F: Fn(&str, &C) -> Result<Validation, CustomUserError> + Clone,
+    {
+        ContextValidator {
+            context: self.clone(),
+            validator,
+        }
+    }
+}
+
+impl<C> Clone for PromptContext<C> {
+    fn clone(&self) -> Self {
+        Self {
+            value: Rc::clone(&self.value),
+        }
+    }
+}
+
+impl<C> From<Rc<RefCell<C>>> for PromptContext<C> {
+    fn from(value: Rc<RefCell<C>>) -> Self {
+        Self { value }
+    }
+}
+
+/// Validator that receives the current value of a [`PromptContext`] along
+/// with the user input, created with [`PromptContext::validator`].
+pub struct ContextValidator<C, F> {
+    context: PromptContext<C>,
+    validator: F,
+}
+
+impl<C, F> Clone for ContextValidator<C, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            validator: self.validator.clone(),
+        }
+    }
+}
+
+impl<C, F> StringValidator for ContextValidator<C, F>
+where
+    F: Fn(&str, &C) -> Result<Validation, CustomUserError> + Clone,
+{
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        (self.validator)(input, &self.context.get())
+    }
+}
+
 /// Validator used in [`DateSelect`](crate::DateSelect) prompts.
 ///
 /// If the input provided by the user is valid, your validator should return `Ok(Validation::Valid)`.
@@ -191,7 +413,7 @@ pub trait MultiOptionValidator<T: ?Sized>: DynClone {
     fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError>;
 }
 
-impl<T> Clone for Box<dyn MultiOptionValidator<T>> {
+impl<T: ?Sized> Clone for Box<dyn MultiOptionValidator<T>> {
     fn clone(&self) -> Self {
         dyn_clone::clone_box(&**self)
     }
@@ -218,34 +440,54 @@ where
 /// # Examples
 ///
 /// ```
-/// use inquire::list_option::ListOption;
-/// use inquire::validator::{MultiOptionValidator, Validation};
+/// use inquire::validator::{CustomTypeValidator, Validation};
 ///
-/// let validator = |input: &[ListOption<&&str>]| {
-///     if input.len() <= 2 {
+/// let validator = |input: &f64| {
+///     if *input > 0.0 {
 ///         Ok(Validation::Valid)
 ///     } else {
-///         Ok(Validation::Invalid("You should select at most two options".into()))
+///         Ok(Validation::Invalid("The amount must be positive".into()))
 ///     }
 /// };
 ///
-/// let mut ans = vec![ListOption::new(0, &"a"), ListOption::new(1, &"b")];
-///
-/// assert_eq!(Validation::Valid, validator.validate(&ans[..])?);
-///
-/// ans.push(ListOption::new(3, &"d"));
+/// assert_eq!(Validation::Valid, validator.validate(&12.5)?);
 /// assert_eq!(
-///     Validation::Invalid("You should select at most two options".into()),
-///     validator.validate(&ans[..])?
+///     Validation::Invalid("The amount must be positive".into()),
+///     validator.validate(&-1.0)?
 /// );
 /// # Ok::<(), inquire::error::CustomUserError>(())
 /// ```
+///
+/// Validators are cloned along with the prompts holding them, so validators
+/// that are not closures must implement [`Clone`]:
+///
+/// ```
+/// use inquire::{
+///     validator::{CustomTypeValidator, Validation},
+///     CustomType,
+/// };
+///
+/// #[derive(Clone)]
+/// struct AtMost(u32);
+///
+/// impl CustomTypeValidator<u32> for AtMost {
+///     fn validate(&self, input: &u32) -> Result<Validation, inquire::CustomUserError> {
+///         match *input <= self.0 {
+///             true => Ok(Validation::Valid),
+///             false => Ok(Validation::Invalid(format!("At most {}", self.0).into())),
+///         }
+///     }
+/// }
+///
+/// let prompt = CustomType::<u32>::new("Guests:").with_validator(AtMost(8));
+/// let _copy = prompt.clone();
+/// ```
 pub trait CustomTypeValidator<T: ?Sized>: DynClone {
     /// Confirm the given input list is a valid value.
     fn validate(&self, input: &T) -> Result<Validation, CustomUserError>;
 }
 
-impl<T> Clone for Box<dyn CustomTypeValidator<T>> {
+impl<T: ?Sized> Clone for Box<dyn CustomTypeValidator<T>> {
     fn clone(&self) -> Self {
         dyn_clone::clone_box(&**self)
     }
@@ -694,6 +936,149 @@ macro_rules! length {
     };
 }
 
+/// How the input of a [`RegexValidator`] is matched against its pattern.
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MatchKind {
+    /// The whole input must be matched by the pattern, as if it was surrounded
+    /// by `^` and `$`.
+    #[default]
+    Full,
+
+    /// Any substring of the input may be matched by the pattern.
+    Partial,
+}
+
+/// Built-in validator that checks whether the answer matches a regular expression.
+///
+/// The validator receives an already compiled [`regex::Regex`], so invalid
+/// patterns are reported by [`Regex::new`](regex::Regex::new) before the
+/// prompt is ever displayed.
+///
+/// # Examples
+///
+/// ```
+/// use inquire::validator::{MatchKind, RegexValidator, StringValidator, Validation};
+/// use regex::Regex;
+///
+/// let validator = RegexValidator::new(Regex::new(r"\d{3}")?);
+/// assert_eq!(Validation::Valid, validator.validate("123")?);
+/// assert_eq!(
+///     Validation::Invalid("The response does not match the expected format".into()),
+///     validator.validate("1234")?,
+/// );
+///
+/// let validator = RegexValidator::new(Regex::new(r"\d{3}")?)
+///     .with_match_kind(MatchKind::Partial)
+///     .with_message("Include an area code");
+/// assert_eq!(Validation::Valid, validator.validate("call 555 now")?);
+/// assert_eq!(Validation::Invalid("Include an area code".into()), validator.validate("call")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[cfg(feature = "regex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+#[derive(Clone)]
+pub struct RegexValidator {
+    regex: regex::Regex,
+    /// Copy of `regex` anchored at both ends, set for full matches.
+    anchored: Option<regex::Regex>,
+    message: String,
+}
+
+#[cfg(feature = "regex")]
+impl RegexValidator {
+    /// Create a new instance of this validator, requiring the whole answer to
+    /// match the given regular expression, otherwise returning an error with
+    /// default message.
+    pub fn new(regex: regex::Regex) -> Self {
+        Self {
+            anchored: Some(anchor(&regex)),
+            regex,
+            message: "The response does not match the expected format".to_owned(),
+        }
+    }
+
+    /// Define how the answer is matched against the regular expression.
+    /// Defaults to [`MatchKind::Full`].
+    pub fn with_match_kind(mut self, match_kind: MatchKind) -> Self {
+        self.anchored = match match_kind {
+            MatchKind::Full => Some(anchor(&self.regex)),
+            MatchKind::Partial => None,
+        };
+        self
+    }
+
+    /// Define a custom error message returned by the validator.
+    /// Defaults to `The response does not match the expected format`.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    fn is_match(&self, input: &str) -> bool {
+        match &self.anchored {
+            Some(anchored) => anchored.is_match(input),
+            None => self.regex.is_match(input),
+        }
+    }
+}
+
+/// Compiles `regex` anchored at both ends, so that it only matches whole inputs.
+#[cfg(feature = "regex")]
+fn anchor(regex: &regex::Regex) -> regex::Regex {
+    regex::Regex::new(&format!("^(?:{})$", regex.as_str()))
+        .expect("wrapping a valid pattern in an anchored group keeps it valid")
+}
+
+#[cfg(feature = "regex")]
+impl StringValidator for RegexValidator {
+    fn validate(&self, input: &str) -> Result<Validation, CustomUserError> {
+        Ok(if self.is_match(input) {
+            Validation::Valid
+        } else {
+            Validation::Invalid(self.message.as_str().into())
+        })
+    }
+}
+
+/// Shorthand for the built-in [`RegexValidator`] that checks whether the whole
+/// answer matches a regular expression.
+///
+/// # Arguments
+///
+/// * `$regex` - Compiled [`regex::Regex`] the answer must match.
+/// * `$message` - optional - Error message returned by the validator.
+///   Defaults to "The response does not match the expected format"
+///
+/// # Examples
+///
+/// ```
+/// use inquire::{regex_match, validator::{StringValidator, Validation}};
+/// use regex::Regex;
+///
+/// let validator = regex_match!(Regex::new(r"[a-z]+")?);
+/// assert_eq!(Validation::Valid, validator.validate("inquire")?);
+/// assert_eq!(Validation::Invalid("The response does not match the expected format".into()), validator.validate("Inquire")?);
+///
+/// let validator = regex_match!(Regex::new(r"[a-z]+")?, "Lowercase letters only!");
+/// assert_eq!(Validation::Valid, validator.validate("inquire")?);
+/// assert_eq!(Validation::Invalid("Lowercase letters only!".into()), validator.validate("Inquire")?);
+/// # Ok::<(), inquire::error::CustomUserError>(())
+/// ```
+#[macro_export]
+#[cfg(all(feature = "macros", feature = "regex"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "regex")))]
+macro_rules! regex_match {
+    ($regex:expr) => {
+        $crate::validator::RegexValidator::new($regex)
+    };
+
+    ($regex:expr, $message:expr) => {
+        $crate::regex_match!($regex).with_message($message)
+    };
+}
+
 #[cfg(test)]
 mod validators_test {
     use crate::{
@@ -925,4 +1310,94 @@ mod validators_test {
 
         Ok(())
     }
+
+    #[cfg(feature = "regex")]
+    mod regex {
+        use regex::Regex;
+
+        use crate::{
+            error::CustomUserError,
+            validator::{MatchKind, RegexValidator, StringValidator, Validation},
+        };
+
+        #[test]
+        fn full_match_requires_whole_input() -> Result<(), CustomUserError> {
+            let validator = RegexValidator::new(Regex::new(r"[0-9]+")?);
+
+            assert_eq!(Validation::Valid, validator.validate("2024")?);
+            assert!(matches!(
+                validator.validate("year 2024")?,
+                Validation::Invalid(_)
+            ));
+            assert!(matches!(validator.validate("")?, Validation::Invalid(_)));
+
+            Ok(())
+        }
+
+        #[test]
+        fn full_match_tries_every_alternation_branch() -> Result<(), CustomUserError> {
+            let validator = RegexValidator::new(Regex::new(r"[0-9]|[1-9][0-9]")?);
+
+            assert_eq!(Validation::Valid, validator.validate("7")?);
+            assert_eq!(Validation::Valid, validator.validate("10")?);
+            assert!(matches!(validator.validate("100")?, Validation::Invalid(_)));
+
+            let validator = RegexValidator::new(Regex::new(r"a|ab")?)
+                .with_match_kind(MatchKind::Partial)
+                .with_match_kind(MatchKind::Full);
+
+            assert_eq!(Validation::Valid, validator.validate("ab")?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn partial_match_accepts_substrings() -> Result<(), CustomUserError> {
+            let validator =
+                RegexValidator::new(Regex::new(r"[0-9]+")?).with_match_kind(MatchKind::Partial);
+
+            assert_eq!(Validation::Valid, validator.validate("year 2024")?);
+            assert!(matches!(
+                validator.validate("no digits")?,
+                Validation::Invalid(_)
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn unicode_patterns_are_supported() -> Result<(), CustomUserError> {
+            let validator = RegexValidator::new(Regex::new(r"\p{Greek}+")?);
+
+            assert_eq!(Validation::Valid, validator.validate("αβγ")?);
+            assert!(matches!(validator.validate("abc")?, Validation::Invalid(_)));
+
+            let validator = RegexValidator::new(Regex::new(r"(?i)ñandú")?);
+
+            assert_eq!(Validation::Valid, validator.validate("ÑANDÚ")?);
+
+            Ok(())
+        }
+
+        #[test]
+        fn invalid_patterns_fail_before_building_the_validator() {
+            let pattern = String::from("[0-9");
+
+            let validator = Regex::new(&pattern).map(RegexValidator::new);
+
+            assert!(validator.is_err());
+        }
+
+        #[test]
+        fn custom_message_is_returned() -> Result<(), CustomUserError> {
+            let validator = RegexValidator::new(Regex::new(r"[a-z]+")?).with_message("Nope");
+
+            assert_eq!(
+                Validation::Invalid("Nope".into()),
+                validator.validate("ABC")?
+            );
+
+            Ok(())
+        }
+    }
 }